@@ -0,0 +1,76 @@
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{expression, integer, half};
+use arithmetic::canonical::{plus_all, times_all, power_of};
+use calculus::series;
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Plus" => Some(plus_builtin),
+        "Times" => Some(times_builtin),
+        "Power" => Some(power_builtin),
+        "Subtract" => Some(subtract_builtin),
+        "Minus" => Some(minus_builtin),
+        "Divide" => Some(divide_builtin),
+        "Sqrt" => Some(sqrt_builtin),
+        _ => None,
+    }
+}
+
+// Anything combined with a SeriesData becomes a series itself.
+fn plus_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    series::plus(args).or_else(|| plus_all(args))
+}
+
+fn times_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    series::times(args).or_else(|| times_all(args))
+}
+
+fn power_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    match args.len() {
+        0 => Some(integer(1)),
+        1 => Some(args[0].clone()),
+        2 => series::power_of(&args[0], &args[1]).or_else(|| power_of(&args[0], &args[1])),
+        // Power is right associative: a^b^c is a^(b^c).
+        _ => {
+            let exponent = expression("Power", &args[1..]);
+            Some(expression("Power", &[args[0].clone(), exponent]))
+        }
+    }
+}
+
+// The other arithmetic heads are sugar for the canonical three.
+fn subtract_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    let negated = expression("Times", &[integer(-1), args[1].clone()]);
+    Some(expression("Plus", &[args[0].clone(), negated]))
+}
+
+fn minus_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(expression("Times", &[integer(-1), args[0].clone()]))
+}
+
+fn divide_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    let reciprocal = expression("Power", &[args[1].clone(), integer(-1)]);
+    Some(expression("Times", &[args[0].clone(), reciprocal]))
+}
+
+fn sqrt_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(expression("Power", &[args[0].clone(), half()]))
+}
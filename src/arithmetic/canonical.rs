@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{expression, list, integer, symbol, is_head};
+use arithmetic::roots::exact_power;
+use numerical::precise::negative_power;
+use elementary::builtins::exponential;
+use arithmetic::terms::{number, is_one, operands, coefficient_and_term, base_and_exponent, term_order};
+
+// Combines numbers and packed arrays element by element straight over the
+// machine storage, None as soon as anything else turns up or an element
+// leaves the machine numbers.
+fn packed_fold<I, R, F>(args: &[SimplexPointer], integers: I, reals: R, numbers: F) -> Option<SimplexPointer>
+    where I: Fn(i64, i64) -> Option<i64>,
+          R: Fn(d128, d128) -> Option<d128>,
+          F: Fn(Numeric, Numeric) -> Option<Numeric>
+{
+    if !args.iter().any(|a| a.get_packed().is_some()) {
+        return None;
+    }
+
+    let mut accumulated = args[0].clone();
+
+    for a in &args[1..] {
+        let combined = match (accumulated.get_packed(), a.get_packed(), accumulated.as_numeric(), a.as_numeric()) {
+            (Some(x), Some(y), _, _) => x.zip_with(&y, &integers, &reals).map(SimplexPointer::from),
+            (Some(x), None, _, Some(n)) => x.map_with(&n, &integers, &reals).map(SimplexPointer::from),
+            (None, Some(y), Some(m), _) => y.map_with(&m, |b, a| integers(a, b), |b, a| reals(a, b)).map(SimplexPointer::from),
+            (None, None, Some(m), Some(n)) => numbers(m, n).map(number),
+            _ => None,
+        };
+
+        accumulated = match combined {
+            Some(c) => c,
+            None => return None,
+        };
+    }
+
+    Some(accumulated)
+}
+
+// Powers of packed elements that stay machine numbers: 0^0, 0^-1, 2^-1 and
+// Sqrt[-2] all give up.
+fn integer_power(b: i64, e: i64) -> Option<i64> {
+    if e < 0 || (b == 0 && e == 0) || e > u32::max_value() as i64 {
+        None
+    } else {
+        b.checked_pow(e as u32)
+    }
+}
+
+fn real_power(b: d128, e: d128) -> Option<d128> {
+    match Numeric::LittleReal(b).power(Numeric::LittleReal(e)) {
+        Some(Numeric::LittleReal(r)) => Some(r),
+        _ => None,
+    }
+}
+
+// Listable heads thread over lists of equal length, {a, b} + x being
+// {a + x, b + x}. None when there is no list, or the lengths disagree.
+pub fn thread(head_name: &str, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let lengths = args.iter()
+        .filter(|a| is_head(a, "List"))
+        .map(|a| a.get_length())
+        .collect::<Vec<usize>>();
+
+    let n = match lengths.first() {
+        Some(&n) if lengths.iter().all(|&m| m == n) => n,
+        _ => return None,
+    };
+
+    let elements = (1..n + 1)
+        .map(|i| {
+            let arguments = args.iter()
+                .map(|a| if is_head(a, "List") { a.get_part(i).unwrap() } else { a.clone() })
+                .collect::<Vec<SimplexPointer>>();
+
+            expression(head_name, &arguments)
+        })
+        .collect::<Vec<SimplexPointer>>();
+
+    Some(list(&elements))
+}
+
+// Gathers items under their keys, keys in order of first appearance.
+fn collect<I>(items: I) -> Vec<(SimplexPointer, Vec<SimplexPointer>)>
+    where I: Iterator<Item = (SimplexPointer, SimplexPointer)>
+{
+    let mut groups: Vec<(SimplexPointer, Vec<SimplexPointer>)> = Vec::new();
+    let mut index = HashMap::new();
+
+    for (k, v) in items {
+        let slot = *index.entry(k.clone()).or_insert(groups.len());
+
+        if slot == groups.len() {
+            groups.push((k, Vec::new()));
+        }
+
+        groups[slot].1.push(v);
+    }
+
+    groups
+}
+
+fn with_coefficient(c: Numeric, term: &SimplexPointer) -> SimplexPointer {
+    if is_one(&c) {
+        return term.clone();
+    }
+
+    let mut factors = vec![number(c)];
+    factors.extend(operands(term, "Times"));
+    expression("Times", &factors)
+}
+
+// The direction of an infinite quantity: 1 for Infinity, -1 for -Infinity,
+// the argument of DirectedInfinity[±1], and 0 for ComplexInfinity.
+fn direction(e: &SimplexPointer) -> Option<i64> {
+    match e.as_str().as_ref() {
+        "Infinity" => return Some(1),
+        "ComplexInfinity" => return Some(0),
+        _ => {}
+    }
+
+    if is_head(e, "DirectedInfinity") && e.get_length() == 1 {
+        return e.get_part(1).unwrap().as_integer().filter(|&n| n == 1 || n == -1);
+    }
+
+    if is_head(e, "Times") && e.get_length() == 2 && e.get_part(2).unwrap().as_str() == "Infinity" {
+        return e.get_part(1).unwrap().as_numeric().map(|c| if c < Numeric::LittleInteger(0) { -1 } else { 1 });
+    }
+
+    None
+}
+
+fn is_indeterminate(e: &SimplexPointer) -> bool {
+    e.as_str() == "Indeterminate"
+}
+
+// Flattens nested sums, adds up the numbers and collects like terms by
+// their coefficients: x + 2 x + y - x is 2 x + y.
+pub fn sum(args: &[SimplexPointer]) -> SimplexPointer {
+    let mut total = Numeric::LittleInteger(0);
+    let mut terms = Vec::new();
+    let mut addends = args.iter().flat_map(|a| operands(a, "Plus")).collect::<Vec<SimplexPointer>>();
+
+    if addends.iter().any(is_indeterminate) {
+        return symbol("Indeterminate");
+    }
+
+    // Infinities swallow numbers and each other when they agree; opposite
+    // ones, or ComplexInfinity with any other, have no sum.
+    let directions = addends.iter().filter_map(direction).collect::<Vec<i64>>();
+
+    if let Some(&first) = directions.first() {
+        if directions.len() > 1 && directions.iter().any(|&d| d != first || d == 0) {
+            return symbol("Indeterminate");
+        }
+
+        let infinity = addends.iter().find(|a| direction(a).is_some()).unwrap().clone();
+        addends.retain(|a| direction(a).is_none() && a.as_numeric().is_none());
+        addends.insert(0, infinity);
+    }
+
+    for a in addends {
+        match coefficient_and_term(&a) {
+            (n, None) => total = total + n,
+            (n, Some(t)) => terms.push((t, number(n))),
+        }
+    }
+
+    let mut collected = collect(terms.into_iter())
+        .into_iter()
+        .map(|(t, cs)| (t, cs.iter().fold(Numeric::LittleInteger(0), |c, n| c + n.as_numeric().unwrap())))
+        .filter(|&(_, ref c)| !c.is_zero())
+        .collect::<Vec<(SimplexPointer, Numeric)>>();
+
+    collected.sort_by(|a, b| term_order(&a.0, &b.0));
+
+    let mut result = Vec::with_capacity(collected.len() + 1);
+
+    if !total.is_zero() {
+        result.push(number(total));
+    }
+
+    result.extend(collected.iter().map(|&(ref t, ref c)| with_coefficient(c.clone(), t)));
+
+    match result.len() {
+        0 => integer(0),
+        1 => result.pop().unwrap(),
+        _ => expression("Plus", &result),
+    }
+}
+
+// Flattens nested products, multiplies the numbers together and merges the
+// powers of identical bases: x x^2 is x^3.
+pub fn product(args: &[SimplexPointer]) -> SimplexPointer {
+    let mut coefficient = Numeric::LittleInteger(1);
+    let mut factors = Vec::new();
+    let multiplicands = args.iter().flat_map(|a| operands(a, "Times")).collect::<Vec<SimplexPointer>>();
+
+    if multiplicands.iter().any(is_indeterminate) {
+        return symbol("Indeterminate");
+    }
+
+    let directions = multiplicands.iter().filter_map(direction).collect::<Vec<i64>>();
+
+    for a in multiplicands {
+        match a.as_numeric() {
+            Some(n) => coefficient = coefficient * n,
+            None => factors.push(base_and_exponent(&a)),
+        }
+    }
+
+    // Zero times an infinity has no value; anything else only keeps its sign,
+    // and not even that against ComplexInfinity.
+    if coefficient == Numeric::LittleInteger(0) {
+        return if directions.is_empty() { number(coefficient) } else { symbol("Indeterminate") };
+    }
+
+    if !directions.is_empty() {
+        let negative = coefficient < Numeric::LittleInteger(0) && !directions.contains(&0);
+        coefficient = Numeric::LittleInteger(if negative { -1 } else { 1 });
+    }
+
+    let mut merged = Vec::with_capacity(factors.len());
+
+    for (base, exponents) in collect(factors.into_iter()) {
+        let exponent = if exponents.len() == 1 { exponents[0].clone() } else { sum(&exponents) };
+
+        // Exact roots can come back as a coefficient times a power.
+        for p in operands(&power(&base, &exponent), "Times") {
+            match p.as_numeric() {
+                Some(n) => coefficient = coefficient * n,
+                None => merged.push(p),
+            }
+        }
+    }
+
+    merged.sort_by(term_order);
+
+    if !is_one(&coefficient) || merged.is_empty() {
+        merged.insert(0, number(coefficient));
+    }
+
+    match merged.len() {
+        1 => merged.pop().unwrap(),
+        _ => expression("Times", &merged),
+    }
+}
+
+// Numbers raise exactly where they can, perfect powers come out of roots,
+// powers of powers and of products distribute over integer exponents, x^0
+// is 1 and x^1 is x.
+pub fn power(base: &SimplexPointer, exponent: &SimplexPointer) -> SimplexPointer {
+    if let (Some(b), Some(e)) = (base.as_numeric(), exponent.as_numeric()) {
+        if b.is_zero() && e <= Numeric::LittleInteger(0) {
+            return symbol(if e.is_zero() { "Indeterminate" } else { "ComplexInfinity" });
+        }
+
+        if b.is_zero() {
+            return integer(0);
+        }
+
+        if let Some(n) = b.clone().power(e.clone()) {
+            return number(n);
+        }
+
+        // Inexact powers of negative numbers are complex.
+        if b < Numeric::LittleInteger(0) && !(b.is_exact() && e.is_exact()) {
+            if let Some((re, im)) = negative_power(&b.clone().negate(), &e) {
+                return sum(&[number(re), product(&[number(im), symbol("I")])]);
+            }
+        }
+
+        if let Some((p, q)) = e.as_rational() {
+            if q > 1 {
+                if let Some(root) = exact_power(b.clone(), p, q) {
+                    return root;
+                }
+            }
+        }
+    }
+
+    if let Some(e) = exponent.as_numeric() {
+        if direction(base) == Some(1) || direction(base) == Some(0) {
+            if e.is_zero() {
+                return symbol("Indeterminate");
+            }
+
+            return if e < Numeric::LittleInteger(0) { integer(0) } else { base.clone() };
+        }
+
+        if e.is_zero() {
+            return integer(1);
+        }
+
+        if is_one(&e) {
+            return base.clone();
+        }
+    }
+
+    if let Some(b) = base.as_numeric() {
+        if is_one(&b) {
+            return integer(1);
+        }
+    }
+
+    if exponent.as_integer().is_some() {
+        if is_head(base, "Power") {
+            let (inner_base, inner_exponent) = base_and_exponent(base);
+            return power(&inner_base, &product(&[inner_exponent, exponent.clone()]));
+        }
+
+        if is_head(base, "Times") {
+            let factors = base.get_parts().iter().map(|f| power(f, exponent)).collect::<Vec<SimplexPointer>>();
+            return product(&factors);
+        }
+    }
+
+    expression("Power", &[base.clone(), exponent.clone()])
+}
+
+pub fn plus_all(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(folded) = packed_fold(args, i64::checked_add, |a, b| Some(a + b), |a, b| Some(a + b)) {
+        return Some(folded);
+    }
+
+    if args.iter().any(|a| is_head(a, "List")) {
+        return thread("Plus", args);
+    }
+
+    Some(sum(args))
+}
+
+pub fn times_all(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(folded) = packed_fold(args, i64::checked_mul, |a, b| Some(a * b), |a, b| Some(a * b)) {
+        return Some(folded);
+    }
+
+    if args.iter().any(|a| is_head(a, "List")) {
+        return thread("Times", args);
+    }
+
+    Some(product(args))
+}
+
+pub fn power_of(base: &SimplexPointer, exponent: &SimplexPointer) -> Option<SimplexPointer> {
+    // Only where every element has a machine number for its power, anything
+    // else threads instead.
+    let raised = packed_fold(&[base.clone(), exponent.clone()], integer_power, real_power, |b, e| {
+        if b.is_zero() && e <= Numeric::LittleInteger(0) { None } else { b.power(e) }
+    });
+
+    if let Some(folded) = raised {
+        return Some(folded);
+    }
+
+    if is_head(base, "List") || is_head(exponent, "List") {
+        return thread("Power", &[base.clone(), exponent.clone()]);
+    }
+
+    if *base == symbol("E") {
+        if let Some(value) = exponential(exponent) {
+            return Some(value);
+        }
+    }
+
+    Some(power(base, exponent))
+}
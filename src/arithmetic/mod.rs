@@ -1,3 +1,7 @@
 pub mod plus;
 pub mod subtract;
+pub mod terms;
+pub mod roots;
+pub mod canonical;
+pub mod builtins;
 pub mod test;
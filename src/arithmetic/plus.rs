@@ -1 +1,60 @@
 // TO BE REIMPLEMENTED AS 1 -> 1 SExpression WRAPPER.
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::structure::SimplexAtom;
+
+use evaluation::utilities::{expression, is_head};
+
+// Numbers add, lists of equal length add element by element and scalars
+// thread over lists (packed ones element-wise without unpacking), anything
+// else stays a Plus[ ... ].
+pub fn plus(lhs: &SimplexPointer, rhs: &SimplexPointer) -> SimplexPointer {
+    if let (Some(a), Some(b)) = (lhs.as_numeric(), rhs.as_numeric()) {
+        return SimplexPointer::from(SimplexAtom::SimplexNumeric(a + b));
+    }
+
+    match (lhs.get_packed(), rhs.get_packed(), lhs.as_numeric(), rhs.as_numeric()) {
+        (Some(a), Some(b), _, _) => {
+            if let Some(sum) = a.zip_with(&b, i64::checked_add, |x, y| Some(x + y)) {
+                return SimplexPointer::from(sum);
+            }
+        }
+        (Some(a), None, _, Some(y)) => {
+            if let Some(sum) = a.map_with(&y, i64::checked_add, |x, y| Some(x + y)) {
+                return SimplexPointer::from(sum);
+            }
+        }
+        (None, Some(b), Some(x), _) => {
+            if let Some(sum) = b.map_with(&x, i64::checked_add, |y, x| Some(x + y)) {
+                return SimplexPointer::from(sum);
+            }
+        }
+        _ => {}
+    }
+
+    match (is_head(lhs, "List"), is_head(rhs, "List")) {
+        (true, true) if lhs.get_length() == rhs.get_length() => {
+            let sums = lhs.get_parts().iter()
+                .zip(rhs.get_parts().iter())
+                .map(|(a, b)| plus(a, b))
+                .collect::<Vec<SimplexPointer>>();
+
+            lhs.with_parts(&sums)
+        }
+        (true, false) => lhs.with_parts(&lhs.get_parts().iter().map(|a| plus(a, rhs)).collect::<Vec<SimplexPointer>>()),
+        (false, true) => rhs.with_parts(&rhs.get_parts().iter().map(|b| plus(lhs, b)).collect::<Vec<SimplexPointer>>()),
+        _ => {
+            let mut terms = Vec::new();
+
+            for term in &[lhs, rhs] {
+                if is_head(term, "Plus") {
+                    terms.extend(term.get_parts());
+                } else {
+                    terms.push((*term).clone());
+                }
+            }
+
+            expression("Plus", &terms)
+        }
+    }
+}
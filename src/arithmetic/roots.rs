@@ -0,0 +1,110 @@
+use num::{BigInt, BigRational, One, Zero, Signed};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{expression, integer, ratio};
+use arithmetic::terms::number;
+
+// Trial division stops here; past it only a perfect power is pulled out of
+// what is left, which leaves some huge numbers less than fully simplified.
+const TRIAL_LIMIT: u64 = 1 << 21;
+
+// The q-th root of n >= 0 when n is a perfect q-th power.
+fn exact_root(n: &BigInt, q: u32) -> Option<BigInt> {
+    let r = n.nth_root(q);
+
+    if r.pow(q) == *n { Some(r) } else { None }
+}
+
+// Splits n > 0 into a^q b with b free of q-th powers. Trial division only
+// has to run up to the cube root: what is left after that has at most two
+// prime factors, which can only form a perfect power by being a square.
+pub fn extract_root(n: &BigInt, q: u32) -> (BigInt, BigInt) {
+    if let Some(r) = exact_root(n, q) {
+        return (r, BigInt::one());
+    }
+
+    let mut outside = BigInt::one();
+    let mut inside = BigInt::one();
+    let mut m = n.clone();
+    let mut d = BigInt::from(2);
+    let limit = BigInt::from(TRIAL_LIMIT);
+
+    while d <= limit && &d * &d * &d <= m {
+        let mut e = 0;
+
+        while (&m % &d).is_zero() {
+            m = m / &d;
+            e = e + 1;
+        }
+
+        outside = outside * d.pow(e / q);
+        inside = inside * d.pow(e % q);
+        d = d + 1;
+    }
+
+    if let Some(r) = exact_root(&m, q) {
+        outside = outside * r;
+        m = BigInt::one();
+    }
+
+    (outside, inside * m)
+}
+
+// Exact numbers to the power p/q (q > 1) with every perfect q-th power
+// pulled out front: Sqrt[8] is 2 Sqrt[2], 8^(1/3) is 2 and (-8)^(1/3) is
+// 2 (-1)^(1/3). None when there is nothing to pull out.
+pub fn exact_power(base: Numeric, p: i64, q: i64) -> Option<SimplexPointer> {
+    let x = match base.to_big_rational() {
+        Some(x) => x,
+        None => return None,
+    };
+
+    if x.is_negative() {
+        if x == -BigRational::one() {
+            return None;
+        }
+
+        let magnitude = exact_power(base.clone().negate(), p, q)
+            .unwrap_or_else(|| expression("Power", &[number(base.negate()), ratio(p, q)]));
+
+        return Some(expression("Times", &[magnitude, expression("Power", &[integer(-1), ratio(p, q)])]));
+    }
+
+    let k = p.div_euclid(q);
+    let r = p.rem_euclid(q);
+    let (n, d) = (x.numer(), x.denom());
+
+    // n^(r/q) is a^r b^(r/q), and d^(-r/q) is c^(q-r) e^((q-r)/q) / d.
+    let (a, b) = extract_root(n, q as u32);
+    let (c, e) = extract_root(d, q as u32);
+
+    if d.is_one() && k == 0 && a.is_one() {
+        return None;
+    }
+
+    let mut coefficient = match base.power(Numeric::LittleInteger(k)) {
+        Some(c) => c,
+        None => return None,
+    };
+
+    coefficient = coefficient * Numeric::from_big_integer(a.pow(r as u32) * c.pow((q - r) as u32));
+    coefficient = coefficient / Numeric::from_big_integer(d.clone());
+
+    let mut factors = vec![number(coefficient)];
+
+    if b > BigInt::one() {
+        factors.push(expression("Power", &[number(Numeric::from_big_integer(b)), ratio(r, q)]));
+    }
+
+    if d > &BigInt::one() && e > BigInt::one() {
+        factors.push(expression("Power", &[number(Numeric::from_big_integer(e)), ratio(q - r, q)]));
+    }
+
+    if factors.len() == 1 {
+        factors.pop()
+    } else {
+        Some(expression("Times", &factors))
+    }
+}
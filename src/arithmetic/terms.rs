@@ -0,0 +1,89 @@
+use std::cmp::Ordering;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::structure::SimplexAtom;
+use expression::atom::numbers::number::Numeric;
+use expression::ordering::canonical_order;
+
+use evaluation::utilities::{expression, integer, is_head};
+
+pub fn number(n: Numeric) -> SimplexPointer {
+    SimplexPointer::from(SimplexAtom::SimplexNumeric(n))
+}
+
+// Exact one only; 1. is kept as written.
+pub fn is_one(n: &Numeric) -> bool {
+    match n {
+        &Numeric::LittleInteger(1) => true,
+        _ => false,
+    }
+}
+
+// Parts of e if it has the given head, e alone otherwise.
+pub fn operands(e: &SimplexPointer, head_name: &str) -> Vec<SimplexPointer> {
+    if is_head(e, head_name) {
+        e.get_parts()
+    } else {
+        vec![e.clone()]
+    }
+}
+
+// 3 x y is the coefficient 3 on the term x y, a lone number has no term.
+pub fn coefficient_and_term(e: &SimplexPointer) -> (Numeric, Option<SimplexPointer>) {
+    if let Some(n) = e.as_numeric() {
+        return (n, None);
+    }
+
+    if is_head(e, "Times") {
+        let factors = e.get_parts();
+
+        if let Some(n) = factors.first().and_then(|f| f.as_numeric()) {
+            let rest = &factors[1..];
+
+            return match rest.len() {
+                0 => (n, None),
+                1 => (n, Some(rest[0].clone())),
+                _ => (n, Some(expression("Times", rest))),
+            };
+        }
+    }
+
+    (Numeric::LittleInteger(1), Some(e.clone()))
+}
+
+// x^2 is the base x to the exponent 2, anything else is its own base to 1.
+pub fn base_and_exponent(e: &SimplexPointer) -> (SimplexPointer, SimplexPointer) {
+    if is_head(e, "Power") && e.get_length() == 2 {
+        (e.get_part(1).unwrap(), e.get_part(2).unwrap())
+    } else {
+        (e.clone(), integer(1))
+    }
+}
+
+// Orders terms the way they print in a sum: by their bases first, so that x^2
+// comes before y, then by exponents, so that x comes before x^2.
+pub fn term_order(lhs: &SimplexPointer, rhs: &SimplexPointer) -> Ordering {
+    let lhs_factors = operands(lhs, "Times").iter().map(base_and_exponent).collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+    let rhs_factors = operands(rhs, "Times").iter().map(base_and_exponent).collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+
+    for (a, b) in lhs_factors.iter().zip(rhs_factors.iter()) {
+        match canonical_order(&a.0, &b.0) {
+            Ordering::Equal => {}
+            unequal => return unequal,
+        }
+    }
+
+    lhs_factors.len().cmp(&rhs_factors.len())
+        .then_with(|| {
+            for (a, b) in lhs_factors.iter().zip(rhs_factors.iter()) {
+                match canonical_order(&a.1, &b.1) {
+                    Ordering::Equal => {}
+                    unequal => return unequal,
+                }
+            }
+
+            Ordering::Equal
+        })
+        .then_with(|| canonical_order(lhs, rhs))
+}
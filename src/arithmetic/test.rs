@@ -1,3 +1,203 @@
 #[cfg(test)]
 mod tests {
+    mod test_canonical {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_adds_numbers() {
+            assert_eq!(run("1 + 2 + 3"), "6");
+            assert_eq!(run("1.5 + 2"), "3.5");
+            assert_eq!(run("2 * 3 * 4"), "24");
+            assert_eq!(run("Plus[]"), "0");
+            assert_eq!(run("Times[]"), "1");
+        }
+
+        #[test]
+        fn it_adds_past_machine_integers() {
+            assert_eq!(run("Plus[9223372036854775807, 1]"), "9223372036854775808");
+            assert_eq!(run("Times[9223372036854775807, 2]"), "18446744073709551614");
+            assert_eq!(run("2^70 - 2^70 + 5"), "5");
+            assert_eq!(run("1*^30 / 1*^28"), "100");
+            assert_eq!(run("Head[2^70]"), "Integer");
+        }
+
+        #[test]
+        fn it_collects_like_terms() {
+            assert_eq!(run("x + 2 x + y - x"), "Plus[Times[2, x], y]");
+            assert_eq!(run("x - x"), "0");
+            assert_eq!(run("3 + x + 2 + x y - y x"), "Plus[5, x]");
+            assert_eq!(run("y + x + 1"), "Plus[1, x, y]");
+        }
+
+        #[test]
+        fn it_merges_powers() {
+            assert_eq!(run("x Power[x, 2]"), "Power[x, 3]");
+            assert_eq!(run("x * x"), "Power[x, 2]");
+            assert_eq!(run("x / x"), "1");
+            assert_eq!(run("2 x y 3 x"), "Times[6, Power[x, 2], y]");
+            assert_eq!(run("Power[x, a] Power[x, b]"), "Power[x, Plus[a, b]]");
+            assert_eq!(run("0 x"), "0");
+        }
+
+        #[test]
+        fn it_flattens_nested_sums_and_products() {
+            assert_eq!(run("Plus[a, Plus[b, Plus[a, c]]]"), "Plus[Times[2, a], b, c]");
+            assert_eq!(run("Times[a, Times[b, a]]"), "Times[Power[a, 2], b]");
+        }
+
+        #[test]
+        fn it_orders_terms_canonically() {
+            assert_eq!(run("Power[x, 2] + x + y"), "Plus[x, Power[x, 2], y]");
+            assert_eq!(run("f[x] + x"), "Plus[x, f[x]]");
+            assert_eq!(run("b a"), "Times[a, b]");
+        }
+
+        #[test]
+        fn it_raises_powers() {
+            assert_eq!(run("Power[2, 10]"), "1024");
+            assert_eq!(run("Power[x, 0]"), "1");
+            assert_eq!(run("Power[x, 1]"), "x");
+            assert_eq!(run("Power[1, x]"), "1");
+            assert_eq!(run("Power[Power[x, 2], 3]"), "Power[x, 6]");
+            assert_eq!(run("Power[Times[2, x], 2]"), "Times[4, Power[x, 2]]");
+            assert_eq!(run("Power[0, 0]"), "Indeterminate");
+        }
+
+        #[test]
+        fn it_threads_over_lists() {
+            assert_eq!(run("{1, 2} + x"), "List[Plus[1, x], Plus[2, x]]");
+            assert_eq!(run("{1, 2} {3, 4}"), "List[3, 8]");
+            assert_eq!(run("Range[3] + 1"), "List[2, 3, 4]");
+            assert_eq!(run("PackedArrayQ[2 Range[3]]"), "True");
+        }
+
+        #[test]
+        fn it_keeps_packed_arrays_packed() {
+            assert_eq!(run("Range[5]^2"), "List[1, 4, 9, 16, 25]");
+            assert_eq!(run("PackedArrayQ[Range[5]^2]"), "True");
+            assert_eq!(run("PackedArrayQ[2^Range[5]]"), "True");
+            assert_eq!(run("PackedArrayQ[Range[5]^Range[5]]"), "True");
+            assert_eq!(run("PackedArrayQ[Range[5.]^0.5]"), "True");
+            assert_eq!(run("Range[0, 2]^0"), "List[Indeterminate, 1, 1]");
+            assert_eq!(run("Range[3]^-1"), "List[1, Rational[1, 2], Rational[1, 3]]");
+        }
+
+        #[test]
+        fn it_unpacks_packed_arrays_on_overflow() {
+            assert_eq!(run("Total[{9223372036854775807, 1}]"), "9223372036854775808");
+            assert_eq!(run("{9223372036854775807, 1} + 1"), "List[9223372036854775808, 2]");
+            assert_eq!(run("{4611686018427387904, 1} 2"), "List[9223372036854775808, 2]");
+            assert_eq!(run("{2, 3}^64"), "List[18446744073709551616, 3433683820292512484657849089281]");
+        }
+
+        #[test]
+        fn it_rewrites_sugar_heads() {
+            assert_eq!(run("Subtract[x, y]"), "Plus[x, Times[-1, y]]");
+            assert_eq!(run("Minus[x]"), "Times[-1, x]");
+            assert_eq!(run("Divide[x, y]"), "Times[x, Power[y, -1]]");
+        }
+
+        #[test]
+        fn it_leaves_undefined_combinations_of_infinities_indeterminate() {
+            assert_eq!(run("0/0"), "Indeterminate");
+            assert_eq!(run("Infinity - Infinity"), "Indeterminate");
+            assert_eq!(run("1/0 - 1/0"), "Indeterminate");
+            assert_eq!(run("Infinity/Infinity"), "Indeterminate");
+            assert_eq!(run("0 Infinity"), "Indeterminate");
+            assert_eq!(run("Indeterminate + 1"), "Indeterminate");
+        }
+
+        #[test]
+        fn it_combines_infinities() {
+            assert_eq!(run("Infinity + 1"), "Infinity");
+            assert_eq!(run("Infinity + Infinity"), "Infinity");
+            assert_eq!(run("-2 Infinity"), "Times[-1, Infinity]");
+            assert_eq!(run("Infinity Infinity"), "Infinity");
+            assert_eq!(run("-1/0"), "ComplexInfinity");
+            assert_eq!(run("1/Infinity"), "0");
+        }
+    }
+
+    mod test_powers {
+        use expression::traits::BaseExpression;
+        use parsing::expression::parse;
+        use arithmetic::roots::extract_root;
+        use evaluation::testing::run;
+        use num::BigInt;
+
+        fn roots(n: i64, q: u32) -> (i64, i64) {
+            let (a, b) = extract_root(&BigInt::from(n), q);
+            (a.to_string().parse().unwrap(), b.to_string().parse().unwrap())
+        }
+
+        #[test]
+        fn it_extracts_roots() {
+            assert_eq!(roots(72, 2), (6, 2));
+            assert_eq!(roots(72, 3), (2, 9));
+            assert_eq!(roots(49, 2), (7, 1));
+            assert_eq!(roots(97, 2), (1, 97));
+            assert_eq!(roots(1000000007 * 1000000007, 2), (1000000007, 1));
+        }
+
+        #[test]
+        fn it_extracts_roots_past_machine_integers() {
+            assert_eq!(run("Sqrt[10^20]"), "10000000000");
+            assert_eq!(run("(10^30)^(1/2)"), "1000000000000000");
+            assert_eq!(run("(10^30)^(1/3)"), "10000000000");
+            assert_eq!(run("Sqrt[2 10^20]"), "Times[10000000000, Power[2, Rational[1, 2]]]");
+            assert_eq!(run("Sqrt[10^20/9]"), "Rational[10000000000, 3]");
+            assert_eq!(run("(-10^30)^(1/3)"), "Times[10000000000, Power[-1, Rational[1, 3]]]");
+        }
+
+        #[test]
+        fn it_raises_to_negative_integers() {
+            assert_eq!(run("2^-1"), "Rational[1, 2]");
+            assert_eq!(run("2^-3 + 1"), "Rational[9, 8]");
+            assert_eq!(run("(2/3)^-2"), "Rational[9, 4]");
+            assert_eq!(run("(-1)^-3"), "-1");
+        }
+
+        #[test]
+        fn it_divides_exactly() {
+            assert_eq!(run("6/4"), "Rational[3, 2]");
+            assert_eq!(run("1/2 + 1/2"), "1");
+            assert_eq!(run("x/2 + x/2"), "x");
+            assert_eq!(run("1/3 + 0.5"), "0.8333333333333333");
+        }
+
+        #[test]
+        fn it_simplifies_perfect_roots() {
+            assert_eq!(run("Sqrt[16]"), "4");
+            assert_eq!(run("Sqrt[8]"), "Times[2, Power[2, Rational[1, 2]]]");
+            assert_eq!(run("8^(1/3)"), "2");
+            assert_eq!(run("4^(3/2)"), "8");
+            assert_eq!(run("Sqrt[4/9]"), "Rational[2, 3]");
+            assert_eq!(run("Sqrt[2] Sqrt[2]"), "2");
+            assert_eq!(run("(-8)^(1/3)"), "Times[2, Power[-1, Rational[1, 3]]]");
+        }
+
+        #[test]
+        fn it_leaves_irrational_powers_symbolic() {
+            assert_eq!(run("Sqrt[2]"), "Power[2, Rational[1, 2]]");
+            assert_eq!(run("Sqrt[x]"), "Power[x, Rational[1, 2]]");
+            assert_eq!(run("Power[Sqrt[x], 2]"), "x");
+        }
+
+        #[test]
+        fn it_raises_reals() {
+            assert_eq!(run("2.25^0.5"), "1.5");
+            assert_eq!(run("4^0.5"), "2");
+            assert_eq!(run("0^0"), "Indeterminate");
+            assert_eq!(run("0^-1"), "ComplexInfinity");
+            assert_eq!(run("0^(1/2)"), "0");
+        }
+
+        #[test]
+        fn it_parses_powers() {
+            assert_eq!(parse("-x^2").unwrap().as_str(), "Times[-1, Power[x, 2]]");
+            assert_eq!(parse("a^b^c").unwrap().as_str(), "Power[a, Power[b, c]]");
+            assert_eq!(parse("2^-1").unwrap().as_str(), "Power[2, -1]");
+            assert_eq!(run("2^3^2"), "512");
+        }
+    }
 }
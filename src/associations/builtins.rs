@@ -0,0 +1,170 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::association::structure::SimplexAssociation;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{list, boolean, is_head};
+use associations::operations::{lookup as lookup_key, merge, group_by, counts};
+use calculus::series;
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Association" => Some(association_builtin),
+        "Lookup" => Some(lookup_builtin),
+        "Keys" => Some(keys_builtin),
+        "Values" => Some(values_builtin),
+        "KeyExistsQ" => Some(key_exists_q_builtin),
+        "AssociateTo" => Some(associate_to_builtin),
+        "KeyDropFrom" => Some(key_drop_from_builtin),
+        "Merge" => Some(merge_builtin),
+        "GroupBy" => Some(group_by_builtin),
+        "Counts" => Some(counts_builtin),
+        "Normal" => Some(normal_builtin),
+        _ => None,
+    }
+}
+
+// An association standing as a head looks its argument up, so that
+// <| "a" -> 1 |>["a"] is 1.
+pub fn apply_head(head: &SimplexPointer, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    match (head.get_association(), args.len()) {
+        (Some(a), 1) => Some(lookup_key(&a, &args[0], None)),
+        _ => None,
+    }
+}
+
+// Associations themselves, or lists of rules standing in for one.
+fn association_of(e: &SimplexPointer) -> Option<SimplexAssociation> {
+    e.get_association().or_else(|| {
+        if is_head(e, "List") {
+            SimplexAssociation::from_rules(&e.get_parts())
+        } else {
+            None
+        }
+    })
+}
+
+// A single key, or every key of a list of them.
+fn keys_of(e: &SimplexPointer) -> Vec<SimplexPointer> {
+    if is_head(e, "List") {
+        e.get_parts()
+    } else {
+        vec![e.clone()]
+    }
+}
+
+fn association_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    SimplexAssociation::from_rules(args).map(SimplexPointer::from)
+}
+
+fn lookup_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 || args.len() > 3 {
+        return None;
+    }
+
+    let default = args.get(2);
+
+    // Lookup[{a1, a2, ... }, key] looks the key up in each.
+    if is_head(&args[0], "List") {
+        return args[0].get_parts().iter()
+            .map(|a| a.get_association().map(|a| lookup_key(&a, &args[1], default)))
+            .collect::<Option<Vec<SimplexPointer>>>()
+            .map(|values| list(&values));
+    }
+
+    let a = match args[0].get_association() {
+        Some(a) => a,
+        None => return None,
+    };
+
+    if is_head(&args[1], "List") {
+        Some(list(&args[1].get_parts().iter().map(|k| lookup_key(&a, k, default)).collect::<Vec<SimplexPointer>>()))
+    } else {
+        Some(lookup_key(&a, &args[1], default))
+    }
+}
+
+fn keys_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    association_of(&args[0]).map(|a| list(&a.keys()))
+}
+
+fn values_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    association_of(&args[0]).map(|a| list(&a.values()))
+}
+
+fn key_exists_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    association_of(&args[0]).map(|a| boolean(a.contains_key(&args[1])))
+}
+
+// Without symbol values to update in place, AssociateTo and KeyDropFrom
+// return the updated association.
+fn associate_to_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    match (args[0].get_association(), SimplexAssociation::from_rules(&args[1..])) {
+        (Some(a), Some(additions)) => {
+            let updated = additions.pairs().iter().fold(a, |a, &(ref k, ref v)| a.insert(k, v));
+            Some(SimplexPointer::from(updated))
+        }
+        _ => None,
+    }
+}
+
+fn key_drop_from_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    args[0].get_association().map(|a| SimplexPointer::from(a.remove(&keys_of(&args[1]))))
+}
+
+fn merge_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_head(&args[0], "List") {
+        return None;
+    }
+
+    args[0].get_parts().iter()
+        .map(association_of)
+        .collect::<Option<Vec<SimplexAssociation>>>()
+        .and_then(|associations| merge(&associations, &args[1]))
+        .map(SimplexPointer::from)
+}
+
+fn group_by_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 || args.len() > 3 || args[0].is_atomic() {
+        return None;
+    }
+
+    group_by(&args[0].get_parts(), &args[1], args.get(2)).map(SimplexPointer::from)
+}
+
+fn counts_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !is_head(&args[0], "List") {
+        return None;
+    }
+
+    Some(SimplexPointer::from(counts(&args[0].get_parts())))
+}
+
+// Associations back to lists of rules, SeriesData back to polynomials.
+fn normal_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    args[0].get_association().map(|a| list(&a.rules())).or_else(|| series::normal(&args[0]))
+}
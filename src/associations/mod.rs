@@ -0,0 +1,3 @@
+pub mod operations;
+pub mod builtins;
+pub mod test;
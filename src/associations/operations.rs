@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use expression::structure::SimplexPointer;
+use expression::association::structure::SimplexAssociation;
+use expression::atom::structure::SimplexAtom;
+
+use evaluation::dispatch::apply;
+use evaluation::utilities::{expression, list, integer};
+
+pub fn missing(key: &SimplexPointer) -> SimplexPointer {
+    let reason = SimplexPointer::from(SimplexAtom::SimplexString("\"KeyAbsent\"".to_string()));
+    expression("Missing", &[reason, key.clone()])
+}
+
+pub fn lookup(a: &SimplexAssociation, key: &SimplexPointer, default: Option<&SimplexPointer>) -> SimplexPointer {
+    match (a.get(key), default) {
+        (Some(v), _) => v,
+        (None, Some(d)) => d.clone(),
+        (None, None) => missing(key),
+    }
+}
+
+// Groups values under their keys, keys in order of first appearance.
+fn collect<I>(pairs: I) -> Vec<(SimplexPointer, Vec<SimplexPointer>)>
+    where I: Iterator<Item = (SimplexPointer, SimplexPointer)>
+{
+    let mut groups: Vec<(SimplexPointer, Vec<SimplexPointer>)> = Vec::new();
+    let mut index = HashMap::new();
+
+    for (k, v) in pairs {
+        let slot = *index.entry(k.clone()).or_insert(groups.len());
+
+        if slot == groups.len() {
+            groups.push((k, Vec::new()));
+        }
+
+        groups[slot].1.push(v);
+    }
+
+    groups
+}
+
+// Applies f to each group's list of values.
+fn reduce(groups: Vec<(SimplexPointer, Vec<SimplexPointer>)>, f: &SimplexPointer) -> Option<SimplexAssociation> {
+    let mut reduced = Vec::with_capacity(groups.len());
+
+    for (k, values) in groups {
+        match apply(f, &[list(&values)]) {
+            Some(v) => reduced.push((k, v)),
+            None => return None,
+        }
+    }
+
+    Some(SimplexAssociation::from_pairs(&reduced))
+}
+
+// Values of every key across the associations, handed to f as one list.
+pub fn merge(associations: &[SimplexAssociation], f: &SimplexPointer) -> Option<SimplexAssociation> {
+    reduce(collect(associations.iter().flat_map(|a| a.pairs())), f)
+}
+
+// Elements keyed by f[e], each group optionally reduced by g.
+pub fn group_by(parts: &[SimplexPointer], f: &SimplexPointer, g: Option<&SimplexPointer>) -> Option<SimplexAssociation> {
+    let mut keyed = Vec::with_capacity(parts.len());
+
+    for p in parts {
+        match apply(f, &[p.clone()]) {
+            Some(k) => keyed.push((k, p.clone())),
+            None => return None,
+        }
+    }
+
+    let groups = collect(keyed.into_iter());
+
+    match g {
+        Some(g) => reduce(groups, g),
+        None => {
+            let pairs = groups.into_iter().map(|(k, members)| (k, list(&members))).collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+            Some(SimplexAssociation::from_pairs(&pairs))
+        }
+    }
+}
+
+pub fn counts(parts: &[SimplexPointer]) -> SimplexAssociation {
+    let pairs = collect(parts.iter().map(|p| (p.clone(), p.clone())))
+        .into_iter()
+        .map(|(k, members)| (k, integer(members.len() as i64)))
+        .collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+
+    SimplexAssociation::from_pairs(&pairs)
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    mod test_builtins {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_builds_associations() {
+            assert_eq!(run("<|a -> Length[{x}], b -> 2|>"), "Association[Rule[a, 1], Rule[b, 2]]");
+            assert_eq!(run("Association[{a -> 1}, b -> 2]"), "Association[Rule[a, 1], Rule[b, 2]]");
+            assert_eq!(run("Length[<|a -> 1, b -> 2, a -> 3|>]"), "2");
+        }
+
+        #[test]
+        fn it_looks_up_keys() {
+            assert_eq!(run("<|\"a\" -> 1|>[\"a\"]"), "1");
+            assert_eq!(run("<|\"a\" -> 1|>[\"b\"]"), "Missing[\"KeyAbsent\", \"b\"]");
+            assert_eq!(run("Lookup[<|a -> 1|>, b, 0]"), "0");
+            assert_eq!(run("Lookup[<|a -> 1, b -> 2|>, {b, a}]"), "List[2, 1]");
+            assert_eq!(run("Lookup[{<|a -> 1|>, <|a -> 2|>}, a]"), "List[1, 2]");
+            assert_eq!(run("Part[<|a -> {1, 2}|>, a, 2]"), "2");
+            assert_eq!(run("<|a -> 1, b -> 2|>[[Key[b]]]"), "2");
+            assert_eq!(run("<|a -> 1, b -> 2|>[[1]]"), "1");
+        }
+
+        #[test]
+        fn it_looks_up_keys_by_structure() {
+            assert_eq!(run("<|{1, 2} -> a|>[Range[2]]"), "a");
+            assert_eq!(run("<|Range[2] -> b|>[{1, 2}]"), "b");
+            assert_eq!(run("<|f[x, {1, 2}] -> c|>[f[x, Range[2]]]"), "c");
+        }
+
+        #[test]
+        fn it_lists_keys_and_values() {
+            assert_eq!(run("Keys[<|a -> 1, b -> 2|>]"), "List[a, b]");
+            assert_eq!(run("Values[<|a -> 1, b -> 2|>]"), "List[1, 2]");
+            assert_eq!(run("Keys[{a -> 1, b -> 2}]"), "List[a, b]");
+            assert_eq!(run("KeyExistsQ[<|a -> 1|>, a]"), "True");
+            assert_eq!(run("KeyExistsQ[<|a -> 1|>, b]"), "False");
+            assert_eq!(run("Normal[<|a -> 1|>]"), "List[Rule[a, 1]]");
+        }
+
+        #[test]
+        fn it_updates_associations() {
+            assert_eq!(run("AssociateTo[<|a -> 1|>, {a -> 2, b -> 3}]"), "Association[Rule[a, 2], Rule[b, 3]]");
+            assert_eq!(run("KeyDropFrom[<|a -> 1, b -> 2, c -> 3|>, {a, c}]"), "Association[Rule[b, 2]]");
+            assert_eq!(run("KeyDropFrom[<|a -> 1|>, z]"), "Association[Rule[a, 1]]");
+        }
+
+        #[test]
+        fn it_merges_associations() {
+            assert_eq!(run("Merge[{<|a -> 1, b -> 2|>, <|a -> 3|>}, Total]"),
+                       "Association[Rule[a, 4], Rule[b, 2]]");
+            assert_eq!(run("Merge[{<|a -> 1|>, <|a -> 3|>}, f]"), "Association[Rule[a, f[List[1, 3]]]]");
+        }
+
+        #[test]
+        fn it_groups_and_counts() {
+            assert_eq!(run("GroupBy[{1, x, 2}, Head]"),
+                       "Association[Rule[Integer, List[1, 2]], Rule[Symbol, List[x]]]");
+            assert_eq!(run("GroupBy[{1, 2, 3}, Head, Length]"), "Association[Rule[Integer, 3]]");
+            assert_eq!(run("Counts[{a, b, a, c, a}]"), "Association[Rule[a, 3], Rule[b, 1], Rule[c, 1]]");
+        }
+    }
+}
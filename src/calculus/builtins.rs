@@ -0,0 +1,151 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{integer, symbol, is_head, rule_parts, split_options, option_value};
+use calculus::derivative::{d, derivative_head, apply_derivative};
+use calculus::integrate::{integrate, integrate_between};
+use calculus::series::{self, series, big_o};
+use calculus::limit::{limit, Direction};
+use calculus::variables::free_of;
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "D" => Some(d_builtin),
+        "Integrate" => Some(integrate_builtin),
+        "Series" => Some(series_builtin),
+        "O" => Some(o_builtin),
+        "Limit" => Some(limit_builtin),
+        _ => None,
+    }
+}
+
+// Derivative[n][f][x ... ] for the elementary functions.
+pub fn apply_head(head: &SimplexPointer, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    derivative_head(head).and_then(|(orders, f)| apply_derivative(&orders, &f, args))
+}
+
+// Elementary functions of a SeriesData, which have no builtin of their own.
+pub fn apply_function(name: &str, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    series::apply(name, args)
+}
+
+// D[e, x], D[e, {x, n}] and D[e, x, y, ... ] one variable after the other.
+fn d_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 {
+        return None;
+    }
+
+    let mut result = args[0].clone();
+
+    for v in &args[1..] {
+        let (x, n) = if is_head(v, "List") && v.get_length() == 2 {
+            (v.get_part(1).unwrap(), v.get_part(2).unwrap().as_integer()?)
+        } else {
+            (v.clone(), 1)
+        };
+
+        if n < 0 || x.as_numeric().is_some() {
+            return None;
+        }
+
+        for _ in 0..n {
+            result = d(&result, &x)?;
+        }
+    }
+
+    Some(result)
+}
+
+fn is_infinite(e: &SimplexPointer) -> bool {
+    !free_of(e, &symbol("Infinity")) || !free_of(e, &symbol("DirectedInfinity"))
+}
+
+// Integrate[f, x], Integrate[f, {x, a, b}], and several of them with the
+// last variable innermost. Infinite bounds are left to Limit.
+fn integrate_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 {
+        return None;
+    }
+
+    let mut result = args[0].clone();
+
+    for v in args[1..].iter().rev() {
+        result = if is_head(v, "List") && v.get_length() == 3 {
+            let parts = v.get_parts();
+
+            if parts.iter().any(is_infinite) || parts[0].as_symbol().is_none() {
+                return None;
+            }
+
+            integrate_between(&result, &parts[0], &parts[1], &parts[2])?
+        } else if v.as_symbol().is_some() {
+            integrate(&result, v)?
+        } else {
+            return None;
+        };
+    }
+
+    Some(result)
+}
+
+// Series[f, {x, x0, n}] up to and including (x - x0)^n.
+fn series_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_head(&args[1], "List") || args[1].get_length() != 3 {
+        return None;
+    }
+
+    let parts = args[1].get_parts();
+
+    if parts[0].as_symbol().is_none() || is_infinite(&parts[1]) {
+        return None;
+    }
+
+    let n = parts[2].as_integer()?;
+    series(&args[0], &parts[0], &parts[1], n + 1).map(|s| s.to_expression())
+}
+
+// O[x] and O[x, x0], raised to a power with ^ for the higher orders.
+fn o_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    match args.len() {
+        1 if args[0].as_symbol().is_some() => Some(big_o(&args[0], &integer(0))),
+        2 if args[0].as_symbol().is_some() => Some(big_o(&args[0], &args[1])),
+        _ => None,
+    }
+}
+
+// Direction -> "FromAbove" or -1 approaches from the right, "FromBelow" or 1
+// from the left.
+fn direction(options: &[(String, SimplexPointer)]) -> Option<Direction> {
+    match option_value(options, "Direction") {
+        None => Some(Direction::TwoSided),
+        Some(d) => match d.as_str().as_ref() {
+            "\"FromAbove\"" | "-1" => Some(Direction::FromAbove),
+            "\"FromBelow\"" | "1" => Some(Direction::FromBelow),
+            "\"TwoSided\"" | "Reals" => Some(Direction::TwoSided),
+            _ => None,
+        },
+    }
+}
+
+// Limit[f, x -> a, Direction -> ... ], where x -> a itself looks like an
+// option and so never gets peeled off with them.
+fn limit_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 {
+        return None;
+    }
+
+    let (rest, options) = split_options(&args[2..]);
+
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let (x, a) = rule_parts(&args[1])?;
+
+    if x.as_symbol().is_none() {
+        return None;
+    }
+
+    limit(&args[0], &x, &a, direction(&options)?)
+}
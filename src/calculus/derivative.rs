@@ -0,0 +1,196 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+
+use evaluation::utilities::{expression, integer, symbol, is_head, ratio, negative};
+use arithmetic::terms::base_and_exponent;
+use arithmetic::canonical::{sum, product, power};
+use calculus::variables::{free_of, substitute};
+
+fn call(name: &str, u: &SimplexPointer) -> SimplexPointer {
+    expression(name, &[u.clone()])
+}
+
+// The derivative of f[u] with respect to u, for the elementary functions.
+fn elementary(name: &str, u: &SimplexPointer) -> Option<SimplexPointer> {
+    let minus_half = ratio(-1, 2);
+    let square = power(u, &integer(2));
+
+    Some(match name {
+        "Exp" => call("Exp", u),
+        "Log" => power(u, &integer(-1)),
+        "Sin" => call("Cos", u),
+        "Cos" => negative(call("Sin", u)),
+        "Tan" => power(&call("Sec", u), &integer(2)),
+        "Cot" => negative(power(&call("Csc", u), &integer(2))),
+        "Sec" => product(&[call("Sec", u), call("Tan", u)]),
+        "Csc" => negative(product(&[call("Cot", u), call("Csc", u)])),
+        "ArcSin" => power(&sum(&[integer(1), negative(square)]), &minus_half),
+        "ArcCos" => negative(power(&sum(&[integer(1), negative(square)]), &minus_half)),
+        "ArcTan" => power(&sum(&[integer(1), square]), &integer(-1)),
+        "ArcCot" => negative(power(&sum(&[integer(1), square]), &integer(-1))),
+        "Sinh" => call("Cosh", u),
+        "Cosh" => call("Sinh", u),
+        "Tanh" => power(&call("Sech", u), &integer(2)),
+        "Coth" => negative(power(&call("Csch", u), &integer(2))),
+        "Sech" => negative(product(&[call("Sech", u), call("Tanh", u)])),
+        "Csch" => negative(product(&[call("Coth", u), call("Csch", u)])),
+        "ArcSinh" => power(&sum(&[integer(1), square]), &minus_half),
+        "ArcCosh" => power(&sum(&[integer(-1), square]), &minus_half),
+        "ArcTanh" => power(&sum(&[integer(1), negative(square)]), &integer(-1)),
+        _ => return None,
+    })
+}
+
+// Derivative[n1, n2, ...][f] as the orders and the function.
+pub fn derivative_head(h: &SimplexPointer) -> Option<(Vec<i64>, SimplexPointer)> {
+    if h.is_atomic() || h.get_length() != 1 {
+        return None;
+    }
+
+    match h.get_head() {
+        Some(ref d) if is_head(d, "Derivative") => {
+            let orders = d.get_parts().iter().map(|n| n.as_integer()).collect::<Option<Vec<i64>>>()?;
+            Some((orders, h.get_part(1).unwrap()))
+        }
+        _ => None,
+    }
+}
+
+pub fn derivative_of(orders: &[i64], f: &SimplexPointer) -> SimplexPointer {
+    let d = expression("Derivative", &orders.iter().map(|&n| integer(n)).collect::<Vec<SimplexPointer>>());
+    SimplexPointer::from(SimplexList::with_head(&d).push_all(&[f.clone()]))
+}
+
+fn apply(f: &SimplexPointer, args: &[SimplexPointer]) -> SimplexPointer {
+    SimplexPointer::from(SimplexList::with_head(f).push_all(args))
+}
+
+// f[u1, u2, ...] or Derivative[...][f][u1, u2, ...] by the chain rule, each
+// argument contributing a derivative one order higher in its slot.
+fn chain(orders: &[i64], f: &SimplexPointer, args: &[SimplexPointer], x: &SimplexPointer) -> Option<SimplexPointer> {
+    let mut terms = Vec::new();
+
+    for (i, u) in args.iter().enumerate() {
+        if free_of(u, x) {
+            continue;
+        }
+
+        let du = d(u, x)?;
+        let mut raised = orders.to_vec();
+        raised[i] = raised[i] + 1;
+
+        terms.push(product(&[apply(&derivative_of(&raised, f), args), du]));
+    }
+
+    Some(sum(&terms))
+}
+
+// The derivative of e with respect to x, None for anything it can't
+// differentiate.
+pub fn d(e: &SimplexPointer, x: &SimplexPointer) -> Option<SimplexPointer> {
+    if e == x {
+        return Some(integer(1));
+    }
+
+    if free_of(e, x) {
+        return Some(integer(0));
+    }
+
+    let head = e.get_head()?;
+
+    // Compound heads such as an InterpolatingFunction[ ... ] differentiate
+    // like a symbol would, through Derivative[ ... ] of the head.
+    if !head.is_atomic() {
+        let (orders, f) = derivative_head(&head).unwrap_or_else(|| (vec![0; e.get_length()], head.clone()));
+
+        if orders.len() != e.get_length() || !free_of(&f, x) {
+            return None;
+        }
+
+        return chain(&orders, &f, &e.get_parts(), x);
+    }
+
+    let parts = e.get_parts();
+
+    match e.head_name().as_str() {
+        "List" => {
+            let ds = parts.iter().map(|p| d(p, x)).collect::<Option<Vec<SimplexPointer>>>()?;
+            Some(expression("List", &ds))
+        }
+        "Plus" => {
+            let ds = parts.iter().map(|p| d(p, x)).collect::<Option<Vec<SimplexPointer>>>()?;
+            Some(sum(&ds))
+        }
+        "Times" => {
+            let mut terms = Vec::with_capacity(parts.len());
+
+            for i in 0..parts.len() {
+                if free_of(&parts[i], x) {
+                    continue;
+                }
+
+                let mut factors = parts.clone();
+                factors[i] = d(&parts[i], x)?;
+                terms.push(product(&factors));
+            }
+
+            Some(sum(&terms))
+        }
+        "Power" if parts.len() == 2 => {
+            let (b, n) = base_and_exponent(e);
+
+            if free_of(&n, x) {
+                let inner = power(&b, &sum(&[n.clone(), integer(-1)]));
+                return Some(product(&[n, inner, d(&b, x)?]));
+            }
+
+            // b^n (n' Log[b] + n b' / b), which is b^n Log[b] n' for b free of x.
+            let log = product(&[d(&n, x)?, call("Log", &b)]);
+            let rest = if free_of(&b, x) {
+                integer(0)
+            } else {
+                product(&[n.clone(), d(&b, x)?, power(&b, &integer(-1))])
+            };
+
+            Some(product(&[e.clone(), sum(&[log, rest])]))
+        }
+        name => {
+            if parts.len() == 1 {
+                if let Some(outer) = elementary(name, &parts[0]) {
+                    return Some(product(&[outer, d(&parts[0], x)?]));
+                }
+            }
+
+            chain(&vec![0; parts.len()], &head, &parts, x)
+        }
+    }
+}
+
+// Derivative[n1, ...][f] applied to arguments: the orders taken one by one
+// on f at fresh slots, which the arguments then fill in. None for an f it
+// knows nothing about, which then stays as it is.
+pub fn apply_derivative(orders: &[i64], f: &SimplexPointer, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if orders.len() != args.len() {
+        return None;
+    }
+
+    if orders.iter().all(|&n| n == 0) {
+        return Some(apply(f, args));
+    }
+
+    let name = f.as_symbol()?;
+
+    if args.len() != 1 || elementary(&name, &args[0]).is_none() {
+        return None;
+    }
+
+    let slot = symbol("$Slot");
+    let mut result = call(&name, &slot);
+
+    for _ in 0..orders[0] {
+        result = d(&result, &slot)?;
+    }
+
+    Some(substitute(&result, &slot, &args[0]))
+}
@@ -0,0 +1,474 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::dispatch::evaluate;
+use evaluation::utilities::{expression, integer, symbol, is_head, is_zero, ratio, half, negative, over};
+use arithmetic::terms::{number, operands, base_and_exponent};
+use arithmetic::canonical::{sum, product, power};
+use polynomials::expand::expand;
+use polynomials::rational::{fraction, together, cancel};
+use polynomials::apart::apart;
+use polynomials::terms::{monomials, exact_coefficients};
+use calculus::variables::{free_of, substitute};
+use calculus::derivative::d;
+use calculus::limit::{limit, Direction};
+use solving::roots::polynomial_roots;
+use numerical::value::approximate;
+use numerical::complex::complex_parts;
+use numerical::real::MACHINE_PRECISION;
+use numbers::rounding::floor;
+
+// Substitution and integration by parts recurse; past this depth they give up.
+const DEPTH_LIMIT: usize = 8;
+
+// Past this many poles of Tan, Cot, Sec or Csc between the bounds a definite
+// integral is left alone.
+const POLE_LIMIT: i64 = 1000;
+
+fn call(name: &str, u: &SimplexPointer) -> SimplexPointer {
+    expression(name, &[u.clone()])
+}
+
+// The slope of u in x when u is linear in it and depends on it at all.
+fn slope(u: &SimplexPointer, x: &SimplexPointer) -> Option<SimplexPointer> {
+    if free_of(u, x) {
+        return None;
+    }
+
+    d(u, x).filter(|a| free_of(a, x))
+}
+
+fn is_polynomial(p: &SimplexPointer, x: &SimplexPointer) -> bool {
+    monomials(p, &[x.clone()]).iter().all(|&(ref k, ref c)| k[0] >= 0 && free_of(c, x))
+}
+
+// Antiderivatives of f[u] with respect to u.
+fn table(name: &str, u: &SimplexPointer) -> Option<SimplexPointer> {
+    Some(match name {
+        "Exp" => call("Exp", u),
+        "Log" => sum(&[product(&[u.clone(), call("Log", u)]), negative(u.clone())]),
+        "Sin" => negative(call("Cos", u)),
+        "Cos" => call("Sin", u),
+        "Tan" => negative(call("Log", &call("Cos", u))),
+        "Cot" => call("Log", &call("Sin", u)),
+        "Sec" => call("Log", &sum(&[call("Sec", u), call("Tan", u)])),
+        "Csc" => negative(call("Log", &sum(&[call("Csc", u), call("Cot", u)]))),
+        "Sinh" => call("Cosh", u),
+        "Cosh" => call("Sinh", u),
+        "Tanh" => call("Log", &call("Cosh", u)),
+        _ => return None,
+    })
+}
+
+// Antiderivatives of f[u]^2 with respect to u.
+fn squares(name: &str, u: &SimplexPointer) -> Option<SimplexPointer> {
+    let double = call(if name == "Sin" || name == "Cos" { "Sin" } else { "Sinh" }, &product(&[integer(2), u.clone()]));
+    let quarter = ratio(1, 4);
+
+    Some(match name {
+        "Sec" => call("Tan", u),
+        "Csc" => negative(call("Cot", u)),
+        "Sin" => sum(&[product(&[half(), u.clone()]), negative(product(&[quarter, double]))]),
+        "Cos" => sum(&[product(&[half(), u.clone()]), product(&[quarter, double])]),
+        _ => return None,
+    })
+}
+
+// f[a x + b], (a x + b)^n and c^(a x + b) straight from the tables.
+fn direct(e: &SimplexPointer, x: &SimplexPointer) -> Option<SimplexPointer> {
+    if e == x {
+        return Some(product(&[half(), power(x, &integer(2))]));
+    }
+
+    if is_head(e, "Power") {
+        let (b, n) = base_and_exponent(e);
+
+        if free_of(&n, x) {
+            if let Some(a) = slope(&b, x) {
+                return Some(if n.as_integer() == Some(-1) {
+                    over(call("Log", &b), &a)
+                } else {
+                    let m = sum(&[n.clone(), integer(1)]);
+                    over(power(&b, &m), &product(&[a, m]))
+                });
+            }
+
+            if n.as_integer() == Some(2) && !b.is_atomic() && b.get_length() == 1 {
+                let u = b.get_part(1).unwrap();
+
+                if let (Some(a), Some(f)) = (slope(&u, x), squares(&b.head_name(), &u)) {
+                    return Some(over(f, &a));
+                }
+            }
+        }
+
+        if free_of(&b, x) {
+            if let Some(a) = slope(&n, x) {
+                return Some(over(e.clone(), &product(&[a, call("Log", &b)])));
+            }
+        }
+
+        return None;
+    }
+
+    if !e.is_atomic() && e.get_length() == 1 {
+        let u = e.get_part(1).unwrap();
+
+        if let (Some(a), Some(f)) = (slope(&u, x), table(&e.head_name(), &u)) {
+            return Some(over(f, &a));
+        }
+    }
+
+    None
+}
+
+// (p x + q) / (a x^2 + b x + c) for a quadratic without rational roots: a
+// logarithm of the denominator and an arctangent, or a logarithm of a ratio
+// when the roots are real.
+fn quadratic(e: &SimplexPointer, x: &SimplexPointer) -> Option<SimplexPointer> {
+    let (n, den) = fraction(e);
+    let denominator = exact_coefficients(&den, x)?;
+    let numerator = exact_coefficients(&n, x)?;
+
+    if denominator.len() != 3 || numerator.len() > 2 {
+        return None;
+    }
+
+    let (c, b, a) = (denominator[0].clone(), denominator[1].clone(), denominator[2].clone());
+    let q = numerator[0].clone();
+    let p = if numerator.len() == 2 { numerator[1].clone() } else { Numeric::LittleInteger(0) };
+
+    // (p x + q) = p / (2 a) (2 a x + b) + (q - p b / (2 a)).
+    let two = Numeric::LittleInteger(2);
+    let log_part = product(&[number(p.clone() / (two.clone() * a.clone())), call("Log", &den)]);
+    let rest = q - p * b.clone() / (two.clone() * a.clone());
+
+    let discriminant = Numeric::LittleInteger(4) * a.clone() * c - b.clone() * b.clone();
+
+    if discriminant == Numeric::LittleInteger(0) {
+        return None;
+    }
+
+    let linear = sum(&[product(&[number(two * a), x.clone()]), number(b)]);
+
+    let inverse = if discriminant > Numeric::LittleInteger(0) {
+        let root = power(&number(discriminant), &half());
+        product(&[integer(2), power(&root, &integer(-1)), call("ArcTan", &over(linear, &root))])
+    } else {
+        let root = power(&number(Numeric::LittleInteger(0) - discriminant), &half());
+        let ratio = over(sum(&[linear.clone(), negative(root.clone())]), &sum(&[linear, root.clone()]));
+        over(call("Log", &ratio), &root)
+    };
+
+    Some(sum(&[log_part, product(&[number(rest), inverse])]))
+}
+
+// Rational functions of x through their partial fractions, with what is
+// left over a quadratic handled by quadratic.
+fn rational(e: &SimplexPointer, x: &SimplexPointer, depth: usize) -> Option<SimplexPointer> {
+    let (_, den) = fraction(&together(e));
+
+    if exact_coefficients(&den, x).map_or(true, |c| c.len() < 2) {
+        return None;
+    }
+
+    let parts = apart(e, x)?;
+
+    if parts == *e {
+        return quadratic(e, x);
+    }
+
+    let terms = operands(&parts, "Plus").iter()
+        .map(|t| antiderivative(t, x, depth + 1).or_else(|| quadratic(t, x)))
+        .collect::<Option<Vec<SimplexPointer>>>()?;
+
+    Some(sum(&terms))
+}
+
+// Subexpressions other than x itself and products that depend on x,
+// outermost first: the candidates for u in a substitution.
+fn inner(e: &SimplexPointer, x: &SimplexPointer, found: &mut Vec<SimplexPointer>) {
+    if e.is_atomic() || free_of(e, x) {
+        return;
+    }
+
+    for p in e.get_parts() {
+        if p != *x && !is_head(&p, "Times") && !free_of(&p, x) && !found.contains(&p) {
+            found.push(p.clone());
+        }
+
+        inner(&p, x, found);
+    }
+}
+
+// The integrand as g(u) u' for some inner u, integrated as g(t) dt.
+fn substitution(e: &SimplexPointer, x: &SimplexPointer, depth: usize) -> Option<SimplexPointer> {
+    let mut candidates = Vec::new();
+    inner(e, x, &mut candidates);
+
+    let t = symbol("$Integration");
+
+    for u in candidates {
+        let du = match d(&u, x) {
+            Some(du) => du,
+            None => continue,
+        };
+
+        let quotient = over(e.clone(), &du);
+
+        for q in &[quotient.clone(), cancel(&together(&quotient))] {
+            let g = substitute(q, &u, &t);
+
+            if free_of(&g, x) {
+                if let Some(f) = antiderivative(&evaluate(&g), &t, depth + 1) {
+                    return Some(substitute(&f, &t, &u));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// p f with p a polynomial: p F - Integrate[p' F] for the exponentials and
+// trig functions, P f - Integrate[P f'] for logarithms and inverse trig.
+fn by_parts(e: &SimplexPointer, x: &SimplexPointer, depth: usize) -> Option<SimplexPointer> {
+    let factors = operands(e, "Times");
+
+    for i in 0..factors.len() {
+        let f = &factors[i];
+        let mut rest = factors.clone();
+        rest.remove(i);
+        let p = product(&rest);
+
+        if !is_polynomial(&p, x) {
+            continue;
+        }
+
+        let name = if f.is_atomic() { String::new() } else { f.head_name() };
+
+        match name.as_str() {
+            "Log" | "ArcTan" | "ArcSin" | "ArcCos" | "ArcSinh" | "ArcTanh" => {
+                let big_p = antiderivative(&p, x, depth + 1)?;
+                let df = d(f, x)?;
+                let remaining = antiderivative(&expand(&product(&[big_p.clone(), df])), x, depth + 1)?;
+
+                return Some(expand(&sum(&[product(&[big_p, f.clone()]), negative(remaining)])));
+            }
+            _ => {}
+        }
+
+        if free_of(&p, x) {
+            continue;
+        }
+
+        if let Some(big_f) = direct(f, x) {
+            let dp = d(&p, x)?;
+            let remaining = antiderivative(&expand(&product(&[dp, big_f.clone()])), x, depth + 1)?;
+
+            return Some(expand(&sum(&[product(&[p, big_f]), negative(remaining)])));
+        }
+    }
+
+    None
+}
+
+fn antiderivative(e: &SimplexPointer, x: &SimplexPointer, depth: usize) -> Option<SimplexPointer> {
+    if depth > DEPTH_LIMIT {
+        return None;
+    }
+
+    if free_of(e, x) {
+        return Some(product(&[e.clone(), x.clone()]));
+    }
+
+    if is_head(e, "Plus") {
+        let terms = e.get_parts().iter()
+            .map(|t| antiderivative(t, x, depth))
+            .collect::<Option<Vec<SimplexPointer>>>();
+
+        if terms.is_some() {
+            return terms.map(|ts| sum(&ts));
+        }
+    }
+
+    if is_head(e, "Times") {
+        let (constants, rest): (Vec<SimplexPointer>, Vec<SimplexPointer>) =
+            e.get_parts().into_iter().partition(|f| free_of(f, x));
+
+        if !constants.is_empty() {
+            let f = antiderivative(&product(&rest), x, depth)?;
+            return Some(product(&[product(&constants), f]));
+        }
+    }
+
+    if let Some(f) = direct(e, x) {
+        return Some(f);
+    }
+
+    if let Some(f) = rational(e, x, depth) {
+        return Some(f);
+    }
+
+    let expanded = expand(e);
+
+    if expanded != *e {
+        if let Some(f) = antiderivative(&expanded, x, depth + 1) {
+            return Some(f);
+        }
+    }
+
+    substitution(e, x, depth).or_else(|| by_parts(e, x, depth))
+}
+
+// An antiderivative of e in x, None when none of the rules find one.
+pub fn integrate(e: &SimplexPointer, x: &SimplexPointer) -> Option<SimplexPointer> {
+    antiderivative(e, x, 0)
+}
+
+// F[b] - F[a] for an antiderivative F, with no check for singularities
+// between the bounds.
+// The numeric value of e as its real and imaginary parts, None when it
+// isn't a number.
+fn value(e: &SimplexPointer) -> Option<(Numeric, Numeric)> {
+    complex_parts(&approximate(e, MACHINE_PRECISION))
+}
+
+fn real_value(e: &SimplexPointer) -> Option<Numeric> {
+    value(e).and_then(|(re, im)| if im.is_zero() { Some(re) } else { None })
+}
+
+// The real zeros of u in x, None when it can't find them.
+fn zeros(u: &SimplexPointer, x: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    let (n, _) = fraction(&together(u));
+    let n = expand(&n);
+
+    if free_of(&n, x) {
+        return Some(Vec::new());
+    }
+
+    if !is_polynomial(&n, x) {
+        return None;
+    }
+
+    let mut real = Vec::new();
+
+    for r in polynomial_roots(&n, x)? {
+        if value(&r)?.1.is_zero() {
+            real.push(r);
+        }
+    }
+
+    Some(real)
+}
+
+// Where u, linear in x, is offset plus a multiple of Pi for x from a to b,
+// with a pole to either side to spare.
+fn periodic(u: &SimplexPointer, x: &SimplexPointer, offset: &SimplexPointer, a: &SimplexPointer, b: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    let s = slope(u, x)?;
+    let t = substitute(u, x, &integer(0));
+
+    let turns = |c: &SimplexPointer| {
+        let k = real_value(&over(sum(&[substitute(u, x, c), negative(offset.clone())]), &symbol("Pi")))?;
+        floor(k)
+    };
+
+    let (i, j) = match (turns(a)?, turns(b)?) {
+        (Numeric::LittleInteger(i), Numeric::LittleInteger(j)) => (i.min(j), i.max(j) + 1),
+        _ => return None,
+    };
+
+    if j - i > POLE_LIMIT {
+        return None;
+    }
+
+    Some((i..j + 1)
+        .map(|k| {
+            let point = sum(&[offset.clone(), product(&[integer(k), symbol("Pi")]), negative(t.clone())]);
+            evaluate(&over(point, &s))
+        })
+        .collect())
+}
+
+// Points where e may blow up between a and b: the real zeros of what it
+// divides by or takes the Log of, and the poles of Tan, Cot, Sec and Csc.
+// None when it can't tell where they are.
+fn singularities(e: &SimplexPointer, x: &SimplexPointer, a: &SimplexPointer, b: &SimplexPointer, found: &mut Vec<SimplexPointer>) -> Option<()> {
+    if e.is_atomic() || free_of(e, x) {
+        return Some(());
+    }
+
+    for p in e.get_parts() {
+        singularities(&p, x, a, b, found)?;
+    }
+
+    let points = if is_head(e, "Power") {
+        let (base, exponent) = base_and_exponent(e);
+
+        match exponent.as_numeric() {
+            _ if free_of(&base, x) => Vec::new(),
+            Some(ref k) if *k < Numeric::LittleInteger(0) => zeros(&base, x)?,
+            Some(_) => Vec::new(),
+            None => return None,
+        }
+    } else if e.get_length() != 1 {
+        Vec::new()
+    } else if is_head(e, "Log") {
+        zeros(&e.get_part(1)?, x)?
+    } else if is_head(e, "Tan") || is_head(e, "Sec") {
+        periodic(&e.get_part(1)?, x, &product(&[half(), symbol("Pi")]), a, b)?
+    } else if is_head(e, "Cot") || is_head(e, "Csc") {
+        periodic(&e.get_part(1)?, x, &integer(0), a, b)?
+    } else {
+        Vec::new()
+    };
+
+    found.extend(points);
+    Some(())
+}
+
+fn is_finite(e: &SimplexPointer) -> bool {
+    ["Infinity", "ComplexInfinity", "DirectedInfinity", "Indeterminate"].iter().all(|s| free_of(e, &symbol(s)))
+}
+
+// The antiderivative only gives the integral where the integrand is finite:
+// a singularity strictly between a and b leaves the integral alone, and one
+// at either bound is approached from inside.
+pub fn integrate_between(e: &SimplexPointer, x: &SimplexPointer, a: &SimplexPointer, b: &SimplexPointer) -> Option<SimplexPointer> {
+    let f = integrate(e, x)?;
+
+    let mut points = Vec::new();
+    singularities(e, x, a, b, &mut points)?;
+
+    let at = |p: &SimplexPointer, c: &SimplexPointer| is_zero(&evaluate(&sum(&[p.clone(), negative(c.clone())])));
+    let (mut at_a, mut at_b) = (false, false);
+
+    for p in &points {
+        if at(p, a) {
+            at_a = true;
+        } else if at(p, b) {
+            at_b = true;
+        } else {
+            let (v, low, high) = (real_value(p)?, real_value(a)?, real_value(b)?);
+
+            if (low < v && v < high) || (high < v && v < low) {
+                return None;
+            }
+        }
+    }
+
+    let ascending = if at_a || at_b { real_value(a)? < real_value(b)? } else { true };
+
+    let end = |c: &SimplexPointer, singular: bool, direction: Direction| if singular {
+        limit(&f, x, c, direction).filter(is_finite)
+    } else {
+        Some(substitute(&f, x, c))
+    };
+
+    let (above, below) = if ascending { (Direction::FromAbove, Direction::FromBelow) } else { (Direction::FromBelow, Direction::FromAbove) };
+    let upper = end(b, at_b, below)?;
+    let lower = end(a, at_a, above)?;
+
+    Some(evaluate(&expression("Plus", &[upper, negative(lower)])))
+}
@@ -0,0 +1,412 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::evaluate;
+use evaluation::utilities::{expression, integer, symbol, is_head, ratio, half};
+use arithmetic::terms::base_and_exponent;
+use arithmetic::canonical::{sum, product, power};
+use polynomials::rational::{fraction, together};
+use calculus::variables::{free_of, substitute};
+use calculus::derivative::d;
+use calculus::series::series;
+use numerical::constants::sign;
+
+// Rounds of L'Hôpital's rule a limit gets before it is left unevaluated.
+const DEPTH_LIMIT: usize = 6;
+
+// Terms of a series looked through for the side of a point a function
+// leaves it on.
+const SIDE_ORDER: i64 = 8;
+
+// Functions with a jump or a corner, which have no series there and go by
+// the side their argument comes from instead.
+const PIECEWISE: &'static [&'static str] = &["Abs", "Sign", "Floor", "Ceiling", "Round", "IntegerPart", "FractionalPart"];
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Direction {
+    TwoSided,
+    FromAbove,
+    FromBelow,
+}
+
+// Where an expression goes as x approaches the point. Infinite carries the
+// sign, +1 or -1.
+#[derive(Clone, Debug)]
+enum Value {
+    Finite(SimplexPointer),
+    Infinite(i64),
+    Complex,
+    Indeterminate,
+}
+
+impl Value {
+    fn to_expression(&self) -> SimplexPointer {
+        match *self {
+            Value::Finite(ref e) => evaluate(e),
+            Value::Infinite(1) => symbol("Infinity"),
+            Value::Infinite(_) => product(&[integer(-1), symbol("Infinity")]),
+            Value::Complex => symbol("ComplexInfinity"),
+            Value::Indeterminate => symbol("Indeterminate"),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match *self {
+            Value::Finite(ref e) => sign(e) == Some(0),
+            _ => false,
+        }
+    }
+}
+
+
+// Infinity, -Infinity and DirectedInfinity[±1] as their sign.
+pub fn infinite_point(a: &SimplexPointer) -> Option<i64> {
+    if a.as_str() == "Infinity" {
+        return Some(1);
+    }
+
+    if is_head(a, "Times") && a.get_length() == 2 && a.get_part(2).unwrap().as_str() == "Infinity" {
+        return sign(&a.get_part(1).unwrap());
+    }
+
+    if is_head(a, "DirectedInfinity") && a.get_length() == 1 {
+        return sign(&a.get_part(1).unwrap());
+    }
+
+    None
+}
+
+fn is_piecewise(e: &SimplexPointer) -> bool {
+    !e.is_atomic() && e.get_length() == 1 && PIECEWISE.contains(&e.head_name().as_str())
+}
+
+fn has_piecewise(e: &SimplexPointer, x: &SimplexPointer) -> bool {
+    !free_of(e, x) && !e.is_atomic() && (is_piecewise(e) || e.get_parts().iter().any(|p| has_piecewise(p, x)))
+}
+
+fn is_integer(c: &SimplexPointer) -> bool {
+    evaluate(&expression("Floor", &[c.clone()])) == *c
+}
+
+// f[u] close to the point, where u goes to c from the given side of it: +1
+// from above, -1 from below and 0 when u is c all along.
+fn piece(name: &str, u: &SimplexPointer, c: &SimplexPointer, side: i64) -> Option<SimplexPointer> {
+    let offset = |k: i64| evaluate(&sum(&[c.clone(), integer(k)]));
+    let at = expression(name, &[c.clone()]);
+    let s = sign(c)?;
+
+    Some(match name {
+        "Abs" => product(&[integer(if s != 0 { s } else { side }), u.clone()]),
+        "Sign" => integer(if s != 0 { s } else { side }),
+        "Floor" if is_integer(c) && side < 0 => offset(-1),
+        "Ceiling" if is_integer(c) && side > 0 => offset(1),
+        "Round" if side != 0 && is_integer(&evaluate(&sum(&[c.clone(), half()]))) => {
+            evaluate(&sum(&[c.clone(), ratio(side, 2)]))
+        }
+        "IntegerPart" if is_integer(c) && s > 0 && side < 0 => offset(-1),
+        "IntegerPart" if is_integer(c) && s < 0 && side > 0 => offset(1),
+        "FractionalPart" => sum(&[u.clone(), product(&[integer(-1), piece("IntegerPart", u, c, side)?])]),
+        _ => at,
+    })
+}
+
+struct Approach {
+    variable: SimplexPointer,
+    point: SimplexPointer,
+    direction: Direction,
+}
+
+impl Approach {
+    fn value(&self, e: &SimplexPointer, depth: usize) -> Option<Value> {
+        if free_of(e, &self.variable) {
+            return Some(Value::Finite(e.clone()));
+        }
+
+        if has_piecewise(e, &self.variable) {
+            let local = self.localize(e)?;
+            return self.value(&local, depth);
+        }
+
+        // Everything below (x - a)^1 decides the limit on its own.
+        if let Some(s) = series(e, &self.variable, &self.point, 1) {
+            let v = s.valuation();
+
+            return if v > 0 {
+                Some(Value::Finite(integer(0)))
+            } else if v == 0 {
+                Some(Value::Finite(s.coefficient(0)))
+            } else {
+                self.pole(&s.coefficient(v), v)
+            };
+        }
+
+        if is_head(e, "Plus") {
+            self.plus(e, depth)
+        } else if is_head(e, "Times") {
+            self.times(e, depth)
+        } else if is_head(e, "Power") {
+            self.power(e, depth)
+        } else if !e.is_atomic() && e.get_length() == 1 {
+            let u = self.value(&e.get_part(1).unwrap(), depth)?;
+            self.function(&e.head_name(), &u)
+        } else {
+            None
+        }
+    }
+
+    // e with each piecewise function replaced by what it is on this side of
+    // the point, close enough to it.
+    fn localize(&self, e: &SimplexPointer) -> Option<SimplexPointer> {
+        let x = &self.variable;
+
+        if !has_piecewise(e, x) {
+            return Some(e.clone());
+        }
+
+        if self.direction == Direction::TwoSided || !e.get_head()?.is_atomic() {
+            return None;
+        }
+
+        let parts = e.get_parts().iter().map(|p| self.localize(p)).collect::<Option<Vec<SimplexPointer>>>()?;
+        let e = evaluate(&expression(&e.head_name(), &parts));
+
+        if !is_piecewise(&e) {
+            return Some(e);
+        }
+
+        let name = e.head_name();
+        let u = e.get_part(1)?;
+
+        let local = match (self.value(&u, DEPTH_LIMIT)?, name.as_str()) {
+            (Value::Finite(c), _) => {
+                let c = evaluate(&c);
+                piece(&name, &u, &c, self.side(&u, &c)?)?
+            }
+            (Value::Infinite(s), "Abs") => product(&[integer(s), u.clone()]),
+            (Value::Infinite(s), "Sign") => integer(s),
+            _ => return None,
+        };
+
+        Some(evaluate(&local))
+    }
+
+    // Which side of c u is on just off the point, 0 if it stays at c.
+    fn side(&self, u: &SimplexPointer, c: &SimplexPointer) -> Option<i64> {
+        let w = evaluate(&sum(&[u.clone(), product(&[integer(-1), c.clone()])]));
+
+        if sign(&w) == Some(0) {
+            return Some(0);
+        }
+
+        let s = series(&w, &self.variable, &self.point, SIDE_ORDER)?;
+        let v = s.valuation();
+
+        if v >= SIDE_ORDER {
+            return None;
+        }
+
+        let k = sign(&s.coefficient(v))?;
+        Some(if self.direction == Direction::FromBelow && v % 2 != 0 { -k } else { k })
+    }
+
+    // c (x - a)^v for a negative v: even powers keep their sign on both
+    // sides, odd ones flip it below the point.
+    fn pole(&self, c: &SimplexPointer, v: i64) -> Option<Value> {
+        let s = sign(c)?;
+
+        Some(match self.direction {
+            _ if v % 2 == 0 => Value::Infinite(s),
+            Direction::FromAbove => Value::Infinite(s),
+            Direction::FromBelow => Value::Infinite(-s),
+            Direction::TwoSided => Value::Complex,
+        })
+    }
+
+    // Infinities of opposite sign cancel only once the terms are over a
+    // common denominator.
+    fn plus(&self, e: &SimplexPointer, depth: usize) -> Option<Value> {
+        let mut finite = Vec::new();
+        let mut infinite = None;
+
+        for t in e.get_parts() {
+            match self.value(&t, depth)? {
+                Value::Finite(c) => finite.push(c),
+                Value::Infinite(s) if infinite.is_none() || infinite == Some(s) => infinite = Some(s),
+                Value::Infinite(_) => {
+                    let combined = together(e);
+
+                    return if depth > 0 && combined != *e {
+                        self.value(&combined, depth - 1)
+                    } else {
+                        Some(Value::Indeterminate)
+                    };
+                }
+                other => return Some(other),
+            }
+        }
+
+        Some(infinite.map_or_else(|| Value::Finite(sum(&finite)), Value::Infinite))
+    }
+
+    fn times(&self, e: &SimplexPointer, depth: usize) -> Option<Value> {
+        let (numerator, denominator) = fraction(e);
+
+        if denominator.as_integer() != Some(1) {
+            return self.quotient(&numerator, &denominator, depth);
+        }
+
+        let mut finite = Vec::new();
+        let mut zeros = Vec::new();
+        let mut infinite = Vec::new();
+        let mut s = 1;
+
+        for f in e.get_parts() {
+            match self.value(&f, depth)? {
+                ref v if v.is_zero() => zeros.push(f.clone()),
+                Value::Finite(c) => finite.push(c),
+                Value::Infinite(t) => {
+                    infinite.push(f.clone());
+                    s = s * t;
+                }
+                other => return Some(other),
+            }
+        }
+
+        // 0 Infinity as Infinity / (1 / 0), where L'Hôpital's rule applies.
+        if !zeros.is_empty() && !infinite.is_empty() {
+            let reciprocal = power(&product(&zeros), &integer(-1));
+            let rest = product(&finite);
+
+            return self.quotient(&product(&[rest, product(&infinite)]), &reciprocal, depth);
+        }
+
+        if !zeros.is_empty() {
+            return Some(Value::Finite(integer(0)));
+        }
+
+        if infinite.is_empty() {
+            return Some(Value::Finite(product(&finite)));
+        }
+
+        Some(Value::Infinite(s * sign(&product(&finite))?))
+    }
+
+    // The numerator over the denominator, by L'Hôpital's rule for 0 / 0 and Infinity / Infinity.
+    fn quotient(&self, numerator: &SimplexPointer, denominator: &SimplexPointer, depth: usize) -> Option<Value> {
+        let x = &self.variable;
+
+        match (self.value(numerator, depth)?, self.value(denominator, depth)?) {
+            (ref a, ref b) if (a.is_zero() && b.is_zero()) || (unbounded(a) && unbounded(b)) => {
+                if depth == 0 {
+                    return None;
+                }
+
+                let ratio = product(&[d(numerator, x)?, power(&d(denominator, x)?, &integer(-1))]);
+                self.value(&ratio, depth - 1)
+            }
+            (Value::Finite(a), Value::Finite(b)) => {
+                match sign(&b) {
+                    Some(0) => Some(Value::Complex),
+                    _ => Some(Value::Finite(product(&[a, power(&b, &integer(-1))]))),
+                }
+            }
+            (Value::Finite(_), Value::Infinite(_)) => Some(Value::Finite(integer(0))),
+            (Value::Infinite(s), Value::Finite(b)) => {
+                match sign(&evaluate(&b))? {
+                    0 => Some(Value::Complex),
+                    t => Some(Value::Infinite(s * t)),
+                }
+            }
+            _ => Some(Value::Indeterminate),
+        }
+    }
+
+    fn power(&self, e: &SimplexPointer, depth: usize) -> Option<Value> {
+        let (b, n) = base_and_exponent(e);
+
+        // b^n as Exp[n Log[b]] once the exponent moves as well.
+        if !free_of(&n, &self.variable) {
+            let exponent = product(&[n, expression("Log", &[b])]);
+            let u = self.value(&exponent, depth)?;
+            return self.function("Exp", &u);
+        }
+
+        let positive = sign(&n)?;
+
+        Some(match self.value(&b, depth)? {
+            ref v if v.is_zero() && positive < 0 => {
+                match n.as_integer() {
+                    Some(k) if k % 2 == 0 => Value::Infinite(1),
+                    _ => Value::Complex,
+                }
+            }
+            Value::Finite(c) => Value::Finite(power(&c, &n)),
+            Value::Infinite(_) if positive < 0 => Value::Finite(integer(0)),
+            Value::Infinite(1) => Value::Infinite(1),
+            Value::Infinite(_) => {
+                match n.as_integer() {
+                    Some(k) => Value::Infinite(if k % 2 == 0 { 1 } else { -1 }),
+                    None => Value::Complex,
+                }
+            }
+            Value::Complex if positive < 0 => Value::Finite(integer(0)),
+            other => other,
+        })
+    }
+
+    // f[u] from where u goes, assuming f is continuous wherever u stays
+    // finite.
+    fn function(&self, name: &str, u: &Value) -> Option<Value> {
+        let half_pi = |s: i64| product(&[ratio(s, 2), symbol("Pi")]);
+
+        Some(match (name, u) {
+            (_, &Value::Indeterminate) => Value::Indeterminate,
+            ("Log", v) if v.is_zero() => Value::Infinite(-1),
+            (_, &Value::Finite(ref c)) => Value::Finite(expression(name, &[c.clone()])),
+            ("Exp", &Value::Infinite(1)) | ("Log", &Value::Infinite(1)) => Value::Infinite(1),
+            ("Sinh", &Value::Infinite(s)) | ("ArcSinh", &Value::Infinite(s)) => Value::Infinite(s),
+            ("Cosh", &Value::Infinite(_)) => Value::Infinite(1),
+            ("Exp", &Value::Infinite(_)) => Value::Finite(integer(0)),
+            ("Tanh", &Value::Infinite(s)) => Value::Finite(integer(s)),
+            ("ArcTan", &Value::Infinite(s)) => Value::Finite(half_pi(s)),
+            ("ArcCot", &Value::Infinite(_)) => Value::Finite(integer(0)),
+            ("Sin", &Value::Infinite(_)) | ("Cos", &Value::Infinite(_)) => Value::Indeterminate,
+            _ => return None,
+        })
+    }
+}
+
+// Infinite in either sense, which is all L'Hôpital's rule needs.
+fn unbounded(v: &Value) -> bool {
+    match *v {
+        Value::Infinite(_) | Value::Complex => true,
+        _ => false,
+    }
+}
+
+// The limit of e as x goes to a, None where it can't tell.
+pub fn limit(e: &SimplexPointer, x: &SimplexPointer, a: &SimplexPointer, direction: Direction) -> Option<SimplexPointer> {
+    // x -> ±Infinity is t -> 0 from above with x = ±1 / t.
+    if let Some(s) = infinite_point(a) {
+        let t = symbol("$Limit");
+        let reciprocal = product(&[integer(s), power(&t, &integer(-1))]);
+
+        return limit(&evaluate(&substitute(e, x, &reciprocal)), &t, &integer(0), Direction::FromAbove);
+    }
+
+    // Piecewise functions only have one-sided limits, which have to agree.
+    if direction == Direction::TwoSided && has_piecewise(e, x) {
+        let above = limit(e, x, a, Direction::FromAbove)?;
+        let below = limit(e, x, a, Direction::FromBelow)?;
+
+        return Some(if above == below { above } else { symbol("Indeterminate") });
+    }
+
+    let approach = Approach {
+        variable: x.clone(),
+        point: a.clone(),
+        direction: direction,
+    };
+
+    approach.value(e, DEPTH_LIMIT).map(|v| v.to_expression())
+}
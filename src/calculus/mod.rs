@@ -0,0 +1,7 @@
+pub mod variables;
+pub mod derivative;
+pub mod integrate;
+pub mod series;
+pub mod limit;
+pub mod builtins;
+pub mod test;
@@ -0,0 +1,587 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::dispatch::{evaluate, lookup};
+use evaluation::utilities::{expression, list, integer, symbol, is_head, is_zero, ratio};
+use arithmetic::terms::{number, base_and_exponent};
+use arithmetic::canonical::{sum, product, power};
+use calculus::variables::{free_of, substitute};
+use calculus::derivative::d;
+
+// How far past the requested order a factor is expanded while looking for
+// its first non-zero term.
+const LOOKAHEAD: i64 = 8;
+
+// A truncated power series in (x - x0): c_k for offset <= k < order, and
+// O((x - x0)^order) for the rest.
+#[derive(Clone, Debug)]
+pub struct Series {
+    variable: SimplexPointer,
+    point: SimplexPointer,
+    offset: i64,
+    coefficients: Vec<SimplexPointer>,
+    order: i64,
+}
+
+impl Series {
+    fn new(x: &SimplexPointer, x0: &SimplexPointer, offset: i64, coefficients: Vec<SimplexPointer>, order: i64) -> Series {
+        let mut s = Series {
+            variable: x.clone(),
+            point: x0.clone(),
+            offset: offset.min(order),
+            coefficients: coefficients,
+            order: order,
+        };
+
+        let length = (s.order - s.offset) as usize;
+        s.coefficients.truncate(length);
+
+        while s.coefficients.len() < length {
+            s.coefficients.push(integer(0));
+        }
+
+        s
+    }
+
+    fn constant(c: &SimplexPointer, x: &SimplexPointer, x0: &SimplexPointer, order: i64) -> Series {
+        Series::new(x, x0, 0, vec![c.clone()], order)
+    }
+
+    fn like(&self, offset: i64, coefficients: Vec<SimplexPointer>, order: i64) -> Series {
+        Series::new(&self.variable, &self.point, offset, coefficients, order)
+    }
+
+    pub fn coefficient(&self, k: i64) -> SimplexPointer {
+        if k < self.offset || k >= self.order {
+            integer(0)
+        } else {
+            self.coefficients[(k - self.offset) as usize].clone()
+        }
+    }
+
+    // The power of the first non-zero term, the order if there is none.
+    pub fn valuation(&self) -> i64 {
+        (self.offset..self.order).find(|&k| !is_zero(&self.coefficient(k))).unwrap_or(self.order)
+    }
+
+    fn truncate(&self, order: i64) -> Series {
+        let order = order.min(self.order);
+        self.like(self.offset, (self.offset..order).map(|k| self.coefficient(k)).collect(), order)
+    }
+
+    pub fn add(&self, other: &Series) -> Series {
+        let offset = self.offset.min(other.offset);
+        let order = self.order.min(other.order);
+
+        let coefficients = (offset..order)
+            .map(|k| sum(&[self.coefficient(k), other.coefficient(k)]))
+            .collect();
+
+        self.like(offset, coefficients, order)
+    }
+
+    pub fn scale(&self, c: &SimplexPointer) -> Series {
+        let coefficients = self.coefficients.iter().map(|a| product(&[c.clone(), a.clone()])).collect();
+        self.like(self.offset, coefficients, self.order)
+    }
+
+    // Known up to the smaller of the two orders each factor's error term
+    // reaches once multiplied by the other's leading term.
+    pub fn mul(&self, other: &Series) -> Series {
+        let (u, v) = (self.valuation(), other.valuation());
+        let order = (u + other.order).min(v + self.order);
+        let offset = u + v;
+
+        let coefficients = (offset..order)
+            .map(|k| {
+                let terms = (u..k - v + 1)
+                    .map(|i| product(&[self.coefficient(i), other.coefficient(k - i)]))
+                    .collect::<Vec<SimplexPointer>>();
+
+                sum(&terms)
+            })
+            .collect();
+
+        self.like(offset, coefficients, order)
+    }
+
+    // 1 / (c0 x^v (1 + ...)) by the usual recurrence, None without a
+    // non-zero term to divide by.
+    pub fn inverse(&self) -> Option<Series> {
+        let v = self.valuation();
+
+        if v == self.order {
+            return None;
+        }
+
+        let c0 = power(&self.coefficient(v), &integer(-1));
+        let precision = self.order - v;
+        let mut b: Vec<SimplexPointer> = vec![c0.clone()];
+
+        for k in 1..precision {
+            let terms = (1..k + 1)
+                .map(|j| product(&[self.coefficient(v + j), b[(k - j) as usize].clone()]))
+                .collect::<Vec<SimplexPointer>>();
+
+            b.push(product(&[integer(-1), c0.clone(), sum(&terms)]));
+        }
+
+        Some(self.like(-v, b, precision - v))
+    }
+
+    // Every factor leaves the same number of terms known past its leading
+    // one, which is as many as 1 starts out with.
+    pub fn power(&self, n: i64) -> Option<Series> {
+        let base = if n < 0 { self.inverse()? } else { self.clone() };
+        let precision = self.order - self.valuation();
+        let mut result = Series::constant(&integer(1), &self.variable, &self.point, precision);
+
+        for _ in 0..n.abs() {
+            result = result.mul(&base);
+        }
+
+        Some(result)
+    }
+
+    // f_0 + f_1 u + f_2 u^2 + ... by Horner's rule, u with no constant term
+    // so that nothing past its order is ever needed.
+    fn compose(f: &[SimplexPointer], u: &Series) -> Series {
+        let mut result = Series::constant(&integer(0), &u.variable, &u.point, u.order);
+
+        for c in f.iter().rev() {
+            result = result.mul(u).add(&Series::constant(c, &u.variable, &u.point, u.order));
+        }
+
+        result
+    }
+
+    fn derivative(&self) -> Series {
+        let coefficients = (self.offset..self.order)
+            .map(|k| product(&[integer(k), self.coefficient(k)]))
+            .collect();
+
+        self.like(self.offset - 1, coefficients, self.order - 1)
+    }
+
+    // The c_k with k in place of the running index, integrated term by term.
+    fn integral(&self) -> Option<Series> {
+        if !is_zero(&self.coefficient(-1)) {
+            return None;
+        }
+
+        let coefficients = (self.offset..self.order)
+            .map(|k| product(&[self.coefficient(k), ratio(1, k + 1)]))
+            .collect();
+
+        Some(self.like(self.offset + 1, coefficients, self.order + 1))
+    }
+
+    fn displacement(&self) -> SimplexPointer {
+        sum(&[self.variable.clone(), product(&[integer(-1), self.point.clone()])])
+    }
+
+    pub fn normal(&self) -> SimplexPointer {
+        let h = self.displacement();
+
+        let terms = (self.offset..self.order)
+            .map(|k| product(&[self.coefficient(k), power(&h, &integer(k))]))
+            .collect::<Vec<SimplexPointer>>();
+
+        sum(&terms)
+    }
+
+    // SeriesData[x, x0, {c...}, nmin, nmax, 1] with the zeros at either end
+    // of the coefficients trimmed off.
+    pub fn to_expression(&self) -> SimplexPointer {
+        let first = self.valuation();
+        let last = (first..self.order).rev().find(|&k| !is_zero(&self.coefficient(k))).map_or(first, |k| k + 1);
+
+        let coefficients = (first..last).map(|k| self.coefficient(k)).collect::<Vec<SimplexPointer>>();
+
+        expression("SeriesData", &[self.variable.clone(), self.point.clone(), list(&coefficients),
+                                   integer(first), integer(self.order), integer(1)])
+    }
+
+    pub fn from_expression(e: &SimplexPointer) -> Option<Series> {
+        if !is_head(e, "SeriesData") || e.get_length() != 6 {
+            return None;
+        }
+
+        let parts = e.get_parts();
+
+        if !is_head(&parts[2], "List") || parts[5].as_integer() != Some(1) {
+            return None;
+        }
+
+        Some(Series::new(&parts[0], &parts[1], parts[3].as_integer()?, parts[2].get_parts(), parts[4].as_integer()?))
+    }
+}
+
+// Coefficients of the Taylor series at 0 of the elementary functions, up to
+// but not including t^n.
+fn taylor(name: &str, n: i64) -> Option<Vec<SimplexPointer>> {
+    let mut factorial = Numeric::LittleInteger(1);
+    let mut coefficients = Vec::with_capacity(n as usize);
+
+    for k in 0..n {
+        if k > 0 {
+            factorial = factorial * Numeric::LittleInteger(k);
+        }
+
+        let reciprocal = Numeric::LittleInteger(1) / factorial.clone();
+        let sign = if (k / 2) % 2 == 0 { 1 } else { -1 };
+
+        let c = match name {
+            "Exp" => reciprocal,
+            "Sin" if k % 2 == 1 => Numeric::LittleInteger(sign) * reciprocal,
+            "Cos" if k % 2 == 0 => Numeric::LittleInteger(sign) * reciprocal,
+            "Sinh" if k % 2 == 1 => reciprocal,
+            "Cosh" if k % 2 == 0 => reciprocal,
+            "Sin" | "Cos" | "Sinh" | "Cosh" => Numeric::LittleInteger(0),
+            _ => return None,
+        };
+
+        coefficients.push(number(c));
+    }
+
+    Some(coefficients)
+}
+
+// (1 + t)^a as a binomial series, up to but not including t^n.
+fn binomial(a: &SimplexPointer, n: i64) -> Vec<SimplexPointer> {
+    let mut coefficients = vec![integer(1)];
+
+    for k in 1..n {
+        let previous = coefficients[(k - 1) as usize].clone();
+        let factor = sum(&[a.clone(), integer(-(k - 1))]);
+        coefficients.push(product(&[previous, factor, ratio(1, k)]));
+    }
+
+    coefficients
+}
+
+// Inverse functions and logarithms come from integrating their derivatives;
+// their value at u0 is the constant of integration, exact where it is a
+// plain number and None where the function isn't analytic.
+fn initial(name: &str, u0: &SimplexPointer) -> Option<SimplexPointer> {
+    match (name, u0.as_integer()) {
+        ("Log", Some(0)) => None,
+        ("Log", Some(1)) => Some(integer(0)),
+        ("ArcTan", Some(0)) | ("ArcSin", Some(0)) | ("ArcSinh", Some(0)) | ("ArcTanh", Some(0)) => Some(integer(0)),
+        ("ArcCos", Some(0)) | ("ArcCot", Some(0)) => Some(product(&[ratio(1, 2), symbol("Pi")])),
+        _ => Some(evaluate(&expression(name, &[u0.clone()]))),
+    }
+}
+
+const INTEGRATED: &'static [&'static str] = &["Log", "ArcTan", "ArcSin", "ArcCos", "ArcCot", "ArcSinh", "ArcTanh"];
+
+const ELEMENTARY: &'static [&'static str] = &["Exp", "Sin", "Cos", "Tan", "Cot", "Sec", "Csc",
+                                               "Sinh", "Cosh", "Tanh", "Coth", "Sech", "Csch"];
+
+fn is_elementary(e: &SimplexPointer) -> bool {
+    if e.is_atomic() || e.get_length() != 1 {
+        return false;
+    }
+
+    let name = e.head_name();
+    INTEGRATED.contains(&name.as_str()) || ELEMENTARY.contains(&name.as_str())
+}
+
+// Builtins smooth wherever they're finite, which Taylor expansion may take
+// the derivatives of.
+const SMOOTH: &'static [&'static str] = &["ArcSec", "ArcCsc", "ArcCosh", "ArcCoth", "ArcSech", "ArcCsch",
+                                          "Gamma", "LogGamma", "Beta", "Zeta", "Erf", "Erfc", "BesselJ",
+                                          "BesselY", "Binomial", "Factorial", "Pochhammer", "LegendreP",
+                                          "ChebyshevT", "HermiteH", "LaguerreL"];
+
+// Known smooth builtins, and functions nothing is known about, which are
+// taken to be smooth. Abs, Sign, Floor and the like aren't.
+fn is_smooth(e: &SimplexPointer) -> bool {
+    match e.get_head() {
+        Some(ref h) if h.is_atomic() => {
+            let name = e.head_name();
+            SMOOTH.contains(&name.as_str()) || lookup(&name).is_none()
+        }
+        _ => true,
+    }
+}
+
+// The quotient forms of the remaining trig functions.
+fn quotient(name: &str, u: &SimplexPointer) -> Option<SimplexPointer> {
+    let call = |f: &str| expression(f, &[u.clone()]);
+    let over = |n: SimplexPointer, f: &str| product(&[n, power(&call(f), &integer(-1))]);
+
+    Some(match name {
+        "Tan" => over(call("Sin"), "Cos"),
+        "Cot" => over(call("Cos"), "Sin"),
+        "Sec" => over(integer(1), "Cos"),
+        "Csc" => over(integer(1), "Sin"),
+        "Tanh" => over(call("Sinh"), "Cosh"),
+        "Coth" => over(call("Cosh"), "Sinh"),
+        "Sech" => over(integer(1), "Cosh"),
+        "Csch" => over(integer(1), "Sinh"),
+        _ => return None,
+    })
+}
+
+struct Expansion {
+    variable: SimplexPointer,
+    point: SimplexPointer,
+}
+
+impl Expansion {
+    fn constant(&self, c: &SimplexPointer, order: i64) -> Series {
+        Series::constant(c, &self.variable, &self.point, order)
+    }
+
+    // Expanded far enough to see a non-zero term, where one shows up soon.
+    fn leading(&self, e: &SimplexPointer, order: i64) -> Option<Series> {
+        let mut s = self.expand(e, order)?;
+
+        if s.valuation() == s.order {
+            if let Some(longer) = self.expand(e, order + LOOKAHEAD) {
+                s = longer;
+            }
+        }
+
+        Some(s)
+    }
+
+    fn expand(&self, e: &SimplexPointer, order: i64) -> Option<Series> {
+        let x = &self.variable;
+
+        if free_of(e, x) {
+            return Some(self.constant(e, order));
+        }
+
+        if e == x {
+            return Some(Series::new(x, &self.point, 0, vec![self.point.clone(), integer(1)], order));
+        }
+
+        if let Some(s) = Series::from_expression(e) {
+            return if s.variable == *x && s.point == self.point { Some(s.truncate(order)) } else { None };
+        }
+
+        // Arithmetic and the elementary functions fail only where they really
+        // are singular, and other smooth functions go by their derivatives.
+        let s = if is_head(e, "Plus") {
+            let mut total = self.constant(&integer(0), order);
+
+            for t in e.get_parts() {
+                total = total.add(&self.expand(&t, order)?);
+            }
+
+            total
+        } else if is_head(e, "Times") {
+            self.times(&e.get_parts(), order)?
+        } else if is_head(e, "Power") {
+            self.power(e, order)?
+        } else if is_elementary(e) {
+            self.function(&e.head_name(), &e.get_part(1).unwrap(), order)?
+        } else if is_smooth(e) {
+            self.taylor(e, order)?
+        } else {
+            return None;
+        };
+
+        Some(s.truncate(order))
+    }
+
+    // Each factor to the order the others' leading powers leave it to make up.
+    fn times(&self, factors: &[SimplexPointer], order: i64) -> Option<Series> {
+        let mut series = factors.iter().map(|f| self.leading(f, order)).collect::<Option<Vec<Series>>>()?;
+        let total = series.iter().map(|s| s.valuation()).sum::<i64>();
+
+        for (f, s) in factors.iter().zip(series.iter_mut()) {
+            let needed = order - (total - s.valuation());
+
+            if needed > s.order {
+                *s = self.expand(f, needed)?;
+            }
+        }
+
+        let first = series[0].clone();
+        Some(series[1..].iter().fold(first, |p, s| p.mul(s)))
+    }
+
+    fn power(&self, e: &SimplexPointer, order: i64) -> Option<Series> {
+        let (b, n) = base_and_exponent(e);
+
+        // b^n as Exp[n Log[b]] whenever the exponent moves with x.
+        if free_of(&b, &self.variable) || !free_of(&n, &self.variable) {
+            let exponent = product(&[n, expression("Log", &[b])]);
+            return self.function("Exp", &exponent, order);
+        }
+
+        let s = self.leading(&b, order)?;
+        let v = s.valuation();
+
+        if v == s.order {
+            return None;
+        }
+
+        if let Some(k) = n.as_integer() {
+            let needed = order - (k - 1) * v;
+            let s = if needed > s.order { self.expand(&b, needed)? } else { s };
+            return s.power(k);
+        }
+
+        // s = c x^v (1 + w), raised to n term by term; x^(v n) has to be a
+        // whole power.
+        let shift = if v == 0 {
+            0
+        } else {
+            match n.as_numeric().and_then(|r| r.as_rational()) {
+                Some((p, q)) if (v * p) % q == 0 => v * p / q,
+                _ => return None,
+            }
+        };
+
+        let needed = order - shift + v;
+        let s = if needed > s.order { self.expand(&b, needed)? } else { s };
+        let c = s.coefficient(v);
+
+        let unit = s.mul(&self.monomial(&power(&c, &integer(-1)), -v, s.order - v));
+        let w = unit.add(&self.constant(&integer(-1), unit.order));
+        let series = Series::compose(&binomial(&n, w.order), &w);
+
+        Some(series.mul(&self.monomial(&power(&c, &n), shift, series.order)))
+    }
+
+    // c (x - x0)^k, known for as many terms past it as the series it scales.
+    fn monomial(&self, c: &SimplexPointer, k: i64, precision: i64) -> Series {
+        Series::new(&self.variable, &self.point, k, vec![c.clone()], k + precision)
+    }
+
+    fn function(&self, name: &str, u: &SimplexPointer, order: i64) -> Option<Series> {
+        if let Some(q) = quotient(name, u) {
+            return self.expand(&q, order);
+        }
+
+        let s = self.expand(u, order)?;
+
+        if s.valuation() < 0 {
+            return None;
+        }
+
+        let u0 = s.coefficient(0);
+        let w = s.add(&self.constant(&product(&[integer(-1), u0.clone()]), s.order));
+        let call = |f: &str| expression(f, &[u0.clone()]);
+
+        // f'(u) u' by the chain rule, with u' taken from its own series so
+        // that u can be a SeriesData.
+        if INTEGRATED.contains(&name) {
+            let slot = symbol("$Slot");
+            let outer = substitute(&d(&expression(name, &[slot.clone()]), &slot)?, &slot, u);
+            let value = initial(name, &u0)?;
+            let integral = self.expand(&outer, order - 1)?.mul(&s.derivative()).integral()?;
+
+            return Some(integral.add(&self.constant(&value, integral.order)));
+        }
+
+        let series = |f: &str| taylor(f, w.order).map(|t| Series::compose(&t, &w));
+
+        if is_zero(&u0) {
+            return series(name);
+        }
+
+        let (even, odd, sign) = match name {
+            "Exp" => return series("Exp").map(|s| s.scale(&call("Exp"))),
+            "Sin" => (("Sin", "Cos"), ("Cos", "Sin"), 1),
+            "Cos" => (("Cos", "Cos"), ("Sin", "Sin"), -1),
+            "Sinh" => (("Sinh", "Cosh"), ("Cosh", "Sinh"), 1),
+            "Cosh" => (("Cosh", "Cosh"), ("Sinh", "Sinh"), 1),
+            _ => return None,
+        };
+
+        // f(u0 + w) by the addition theorems.
+        let first = series(even.1)?.scale(&call(even.0));
+        let second = series(odd.1)?.scale(&product(&[integer(sign), call(odd.0)]));
+
+        Some(first.add(&second))
+    }
+
+    // Straight from the derivatives, for whatever else is smooth at the point.
+    fn taylor(&self, e: &SimplexPointer, order: i64) -> Option<Series> {
+        let mut coefficients = Vec::new();
+        let mut derivative = e.clone();
+        let mut factorial = Numeric::LittleInteger(1);
+
+        for k in 0..order.max(0) {
+            if k > 0 {
+                derivative = d(&derivative, &self.variable)?;
+                factorial = factorial * Numeric::LittleInteger(k);
+            }
+
+            let value = evaluate(&substitute(&derivative, &self.variable, &self.point));
+
+            if !free_of(&value, &symbol("ComplexInfinity")) || !free_of(&value, &symbol("Indeterminate")) {
+                return None;
+            }
+
+            coefficients.push(product(&[value, number(Numeric::LittleInteger(1) / factorial.clone())]));
+        }
+
+        Some(Series::new(&self.variable, &self.point, 0, coefficients, order))
+    }
+}
+
+// The series of e in x about x0 with the terms below (x - x0)^order.
+pub fn series(e: &SimplexPointer, x: &SimplexPointer, x0: &SimplexPointer, order: i64) -> Option<Series> {
+    let expansion = Expansion {
+        variable: x.clone(),
+        point: x0.clone(),
+    };
+
+    expansion.expand(e, order)
+}
+
+fn first_series(args: &[SimplexPointer]) -> Option<Series> {
+    args.iter().filter_map(Series::from_expression).next()
+}
+
+// Sums and products with a SeriesData in them expand everything else in the
+// same variable about the same point.
+pub fn plus(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let s = first_series(args)?;
+    series(&expression("Plus", args), &s.variable, &s.point, s.order).map(|r| r.to_expression())
+}
+
+pub fn times(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let s = first_series(args)?;
+    let order = args.iter().filter_map(Series::from_expression).map(|t| t.order).max().unwrap();
+
+    series(&expression("Times", args), &s.variable, &s.point, order).map(|r| r.to_expression())
+}
+
+pub fn power_of(base: &SimplexPointer, exponent: &SimplexPointer) -> Option<SimplexPointer> {
+    let s = Series::from_expression(base)?;
+
+    match exponent.as_integer() {
+        Some(n) => s.power(n).map(|r| r.to_expression()),
+        None => {
+            let e = expression("Power", &[base.clone(), exponent.clone()]);
+            series(&e, &s.variable, &s.point, s.order).map(|r| r.to_expression())
+        }
+    }
+}
+
+// f[SeriesData[ ... ]] for the elementary functions it knows how to expand.
+pub fn apply(name: &str, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !(INTEGRATED.contains(&name) || ELEMENTARY.contains(&name)) {
+        return None;
+    }
+
+    let s = Series::from_expression(&args[0])?;
+    series(&expression(name, args), &s.variable, &s.point, s.order).map(|r| r.to_expression())
+}
+
+pub fn normal(e: &SimplexPointer) -> Option<SimplexPointer> {
+    Series::from_expression(e).map(|s| s.normal())
+}
+
+// O[x] and O[x, x0]: nothing known from the first power on.
+pub fn big_o(x: &SimplexPointer, x0: &SimplexPointer) -> SimplexPointer {
+    Series::new(x, x0, 1, Vec::new(), 1).to_expression()
+}
@@ -0,0 +1,277 @@
+#[cfg(test)]
+mod tests {
+    mod test_derivatives {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_differentiates_polynomials() {
+            assert_eq!(run("D[x^3, x]"), "Times[3, Power[x, 2]]");
+            assert_eq!(run("D[x^2 + 3 x + 1, x]"), "Plus[3, Times[2, x]]");
+            assert_eq!(run("D[a, x]"), "0");
+            assert_eq!(run("D[x^n, x]"), "Times[n, Power[x, Plus[-1, n]]]");
+            assert_eq!(run("D[{x, x^2}, x]"), "List[1, Times[2, x]]");
+        }
+
+        #[test]
+        fn it_applies_product_quotient_and_chain_rules() {
+            assert_eq!(run("D[x Sin[x], x]"), "Plus[Times[x, Cos[x]], Sin[x]]");
+            assert_eq!(run("D[1/x, x]"), "Times[-1, Power[x, -2]]");
+            assert_eq!(run("D[(x + 1)/(x - 1), x]"),
+                       "Plus[Power[Plus[-1, x], -1], Times[-1, Power[Plus[-1, x], -2], Plus[1, x]]]");
+            assert_eq!(run("D[Sin[x^2], x]"), "Times[2, x, Cos[Power[x, 2]]]");
+            assert_eq!(run("D[Log[x], x]"), "Power[x, -1]");
+            assert_eq!(run("D[ArcTan[x], x]"), "Power[Plus[1, Power[x, 2]], -1]");
+        }
+
+        #[test]
+        fn it_differentiates_exponentials() {
+            assert_eq!(run("D[2^x, x]"), "Times[Power[2, x], Log[2]]");
+            assert_eq!(run("D[x^x, x]"), "Times[Power[x, x], Plus[1, Log[x]]]");
+            assert_eq!(run("D[Exp[2 x], {x, 3}]"), "Times[8, Power[E, Times[2, x]]]");
+        }
+
+        #[test]
+        fn it_takes_mixed_partials() {
+            assert_eq!(run("D[x^2 y^3, x, y]"), "Times[6, x, Power[y, 2]]");
+            assert_eq!(run("D[x^2 y^3, {x, 2}, {y, 0}]"), "Times[2, Power[y, 3]]");
+        }
+
+        #[test]
+        fn it_keeps_derivatives_of_unknown_functions() {
+            assert_eq!(run("D[f[x], x]"), "Derivative[1][f][x]");
+            assert_eq!(run("D[f[x], {x, 2}]"), "Derivative[2][f][x]");
+            assert_eq!(run("D[f[x^2], x]"), "Times[2, x, Derivative[1][f][Power[x, 2]]]");
+            assert_eq!(run("D[g[x, y], x, y]"), "Derivative[1, 1][g][x, y]");
+            assert_eq!(run("D[f[y], x]"), "0");
+            assert_eq!(run("D[f[a][x], x]"), "Derivative[1][f[a]][x]");
+            assert_eq!(run("D[f[x][x], x]"), "D[f[x][x], x]");
+        }
+
+        #[test]
+        fn it_applies_derivative_heads() {
+            assert_eq!(run("f'[x]"), "Derivative[1][f][x]");
+            assert_eq!(run("Sin'[x]"), "Cos[x]");
+            assert_eq!(run("Derivative[2][Sin][y]"), "Times[-1, Sin[y]]");
+            assert_eq!(run("Derivative[0][f][x]"), "f[x]");
+        }
+    }
+
+    mod test_integrals {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_integrates_polynomials_and_powers() {
+            assert_eq!(run("Integrate[x^2, x]"), "Times[Rational[1, 3], Power[x, 3]]");
+            assert_eq!(run("Integrate[3 x^2 + 2 x + 1, x]"), "Plus[x, Power[x, 2], Power[x, 3]]");
+            assert_eq!(run("Integrate[1/x, x]"), "Log[x]");
+            assert_eq!(run("Integrate[(x + 1)^5, x]"), "Times[Rational[1, 6], Power[Plus[1, x], 6]]");
+            assert_eq!(run("Integrate[Sqrt[x], x]"), "Times[Rational[2, 3], Power[x, Rational[3, 2]]]");
+            assert_eq!(run("Integrate[x y, x, y]"), "Times[Rational[1, 4], Power[x, 2], Power[y, 2]]");
+        }
+
+        #[test]
+        fn it_integrates_rational_functions() {
+            assert_eq!(run("Integrate[1/(x^2 - 1), x]"),
+                       "Plus[Times[Rational[1, 2], Log[Plus[-1, x]]], Times[Rational[-1, 2], Log[Plus[1, x]]]]");
+            assert_eq!(run("Integrate[1/(x^2 + 1), x]"), "ArcTan[x]");
+            assert_eq!(run("Integrate[1/(x (x + 1)^2), x]"),
+                       "Plus[Log[x], Times[-1, Log[Plus[1, x]]], Power[Plus[1, x], -1]]");
+            assert_eq!(run("Integrate[(x^3 + 1)/(x^2 + 1), x]"),
+                       "Plus[Times[Rational[1, 2], Power[x, 2]], ArcTan[x], Times[Rational[-1, 2], Log[Plus[1, Power[x, 2]]]]]");
+            assert_eq!(run("Integrate[(2 x + 3)/(x^2 + 2 x + 5), x]"),
+                       "Plus[Times[Rational[1, 2], ArcTan[Times[Rational[1, 4], Plus[2, Times[2, x]]]]], Log[Plus[5, Times[2, x], Power[x, 2]]]]");
+        }
+
+        #[test]
+        fn it_integrates_elementary_functions() {
+            assert_eq!(run("Integrate[Exp[2 x], x]"), "Times[Rational[1, 2], Power[E, Times[2, x]]]");
+            assert_eq!(run("Integrate[Sin[3 x + 1], x]"), "Times[Rational[-1, 3], Cos[Plus[1, Times[3, x]]]]");
+            assert_eq!(run("Integrate[Log[x], x]"), "Plus[Times[-1, x], Times[x, Log[x]]]");
+            assert_eq!(run("Integrate[Tan[x], x]"), "Times[-1, Log[Cos[x]]]");
+            assert_eq!(run("Integrate[2^x, x]"), "Times[Power[2, x], Power[Log[2], -1]]");
+            assert_eq!(run("Integrate[Sin[x]^2, x]"), "Plus[Times[Rational[1, 2], x], Times[Rational[-1, 4], Sin[Times[2, x]]]]");
+        }
+
+        #[test]
+        fn it_integrates_by_substitution() {
+            assert_eq!(run("Integrate[2 x Cos[x^2], x]"), "Sin[Power[x, 2]]");
+            assert_eq!(run("Integrate[x Exp[x^2], x]"), "Times[Rational[1, 2], Power[E, Power[x, 2]]]");
+            assert_eq!(run("Integrate[Log[x]/x, x]"), "Times[Rational[1, 2], Power[Log[x], 2]]");
+            assert_eq!(run("Integrate[Cos[x] Exp[Sin[x]], x]"), "Power[E, Sin[x]]");
+            assert_eq!(run("Integrate[1/(x Log[x]), x]"), "Log[Log[x]]");
+            assert_eq!(run("Integrate[x Sqrt[x^2 + 1], x]"), "Times[Rational[1, 3], Power[Plus[1, Power[x, 2]], Rational[3, 2]]]");
+        }
+
+        #[test]
+        fn it_integrates_by_parts() {
+            assert_eq!(run("Integrate[x Exp[x], x]"), "Plus[Times[-1, Power[E, x]], Times[Power[E, x], x]]");
+            assert_eq!(run("Integrate[x^2 Cos[x], x]"), "Plus[Times[2, x, Cos[x]], Times[Power[x, 2], Sin[x]], Times[-2, Sin[x]]]");
+            assert_eq!(run("Integrate[x Log[x], x]"), "Plus[Times[Rational[-1, 4], Power[x, 2]], Times[Rational[1, 2], Power[x, 2], Log[x]]]");
+            assert_eq!(run("Integrate[ArcTan[x], x]"), "Plus[Times[x, ArcTan[x]], Times[Rational[-1, 2], Log[Plus[1, Power[x, 2]]]]]");
+        }
+
+        #[test]
+        fn it_evaluates_definite_integrals() {
+            assert_eq!(run("Integrate[x^2, {x, 0, 3}]"), "9");
+            assert_eq!(run("Integrate[x, {x, a, b}]"), "Plus[Times[Rational[-1, 2], Power[a, 2]], Times[Rational[1, 2], Power[b, 2]]]");
+        }
+
+        #[test]
+        fn it_leaves_integrals_across_singularities_alone() {
+            assert_eq!(run("Integrate[1/x^2, {x, -1, 1}]"), "Integrate[Power[x, -2], List[x, -1, 1]]");
+            assert_eq!(run("Integrate[1/(x - 2)^2, {x, 0, 3}]"), "Integrate[Power[Plus[-2, x], -2], List[x, 0, 3]]");
+            assert_eq!(run("Integrate[1/x, {x, -1, 1}]"), "Integrate[Power[x, -1], List[x, -1, 1]]");
+            assert_eq!(run("Integrate[Tan[x], {x, 0, Pi}]"), "Integrate[Tan[x], List[x, 0, Pi]]");
+            assert_eq!(run("Integrate[1/x, {x, 0, 1}]"), "Integrate[Power[x, -1], List[x, 0, 1]]");
+        }
+
+        #[test]
+        fn it_approaches_singular_bounds_from_inside() {
+            assert_eq!(run("Integrate[1/Sqrt[x], {x, 0, 1}]"), "2");
+            assert_eq!(run("Integrate[Log[x], {x, 0, 1}]"), "-1");
+            assert_eq!(run("Integrate[1/(1 + x^2), {x, -1, 1}]"), "Times[Rational[1, 2], Pi]");
+        }
+
+        #[test]
+        fn it_leaves_unknown_integrals_alone() {
+            assert_eq!(run("Integrate[Exp[x^2], x]"), "Integrate[Power[E, Power[x, 2]], x]");
+            assert_eq!(run("Integrate[f[x], x]"), "Integrate[f[x], x]");
+            assert_eq!(run("Integrate[1/x, {x, 1, Infinity}]"), "Integrate[Power[x, -1], List[x, 1, Infinity]]");
+        }
+    }
+
+    mod test_series {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_expands_elementary_functions() {
+            assert_eq!(run("Series[Exp[x], {x, 0, 4}]"),
+                       "SeriesData[x, 0, List[1, 1, Rational[1, 2], Rational[1, 6], Rational[1, 24]], 0, 5, 1]");
+            assert_eq!(run("Series[Sin[x], {x, 0, 5}]"), "SeriesData[x, 0, List[1, 0, Rational[-1, 6], 0, Rational[1, 120]], 1, 6, 1]");
+            assert_eq!(run("Series[Tan[x], {x, 0, 5}]"), "SeriesData[x, 0, List[1, 0, Rational[1, 3], 0, Rational[2, 15]], 1, 6, 1]");
+            assert_eq!(run("Series[Log[1 + x], {x, 0, 3}]"), "SeriesData[x, 0, List[1, Rational[-1, 2], Rational[1, 3]], 1, 4, 1]");
+            assert_eq!(run("Series[ArcTan[x], {x, 0, 5}]"), "SeriesData[x, 0, List[1, 0, Rational[-1, 3], 0, Rational[1, 5]], 1, 6, 1]");
+            assert_eq!(run("Series[Sqrt[1 + x], {x, 0, 2}]"), "SeriesData[x, 0, List[1, Rational[1, 2], Rational[-1, 8]], 0, 3, 1]");
+            assert_eq!(run("Series[1/(1 - x), {x, 0, 3}]"), "SeriesData[x, 0, List[1, 1, 1, 1], 0, 4, 1]");
+        }
+
+        #[test]
+        fn it_expands_compositions_and_laurent_series() {
+            assert_eq!(run("Series[Exp[Sin[x]], {x, 0, 4}]"), "SeriesData[x, 0, List[1, 1, Rational[1, 2], 0, Rational[-1, 8]], 0, 5, 1]");
+            assert_eq!(run("Series[Log[Cos[x]], {x, 0, 4}]"), "SeriesData[x, 0, List[Rational[-1, 2], 0, Rational[-1, 12]], 2, 5, 1]");
+            assert_eq!(run("Series[1/Sin[x], {x, 0, 3}]"), "SeriesData[x, 0, List[1, 0, Rational[1, 6], 0, Rational[7, 360]], -1, 4, 1]");
+            assert_eq!(run("Series[Sin[x]/x^2, {x, 0, 1}]"), "SeriesData[x, 0, List[1, 0, Rational[-1, 6]], -1, 2, 1]");
+            assert_eq!(run("Series[Sqrt[x^2 + x^3], {x, 0, 3}]"), "SeriesData[x, 0, List[1, Rational[1, 2], Rational[-1, 8]], 1, 4, 1]");
+        }
+
+        #[test]
+        fn it_expands_about_other_points() {
+            assert_eq!(run("Series[Log[x], {x, 1, 3}]"), "SeriesData[x, 1, List[1, Rational[-1, 2], Rational[1, 3]], 1, 4, 1]");
+            assert_eq!(run("Series[Exp[x], {x, a, 2}]"),
+                       "SeriesData[x, a, List[Power[E, a], Power[E, a], Times[Rational[1, 2], Power[E, a]]], 0, 3, 1]");
+            assert_eq!(run("Series[f[x], {x, 0, 2}]"),
+                       "SeriesData[x, 0, List[f[0], Derivative[1][f][0], Times[Rational[1, 2], Derivative[2][f][0]]], 0, 3, 1]");
+        }
+
+        #[test]
+        fn it_does_arithmetic_on_series() {
+            assert_eq!(run("Series[Exp[x], {x, 0, 4}] + Series[Sin[x], {x, 0, 2}]"), "SeriesData[x, 0, List[1, 2, Rational[1, 2]], 0, 3, 1]");
+            assert_eq!(run("Series[Exp[x], {x, 0, 4}] Series[Sin[x], {x, 0, 4}]"), "SeriesData[x, 0, List[1, 1, Rational[1, 3]], 1, 5, 1]");
+            assert_eq!(run("1/Series[Cos[x], {x, 0, 4}]"), "SeriesData[x, 0, List[1, 0, Rational[1, 2], 0, Rational[5, 24]], 0, 5, 1]");
+            assert_eq!(run("(1 + x + O[x]^3)^(-2)"), "SeriesData[x, 0, List[1, -2, 3], 0, 3, 1]");
+            assert_eq!(run("Sin[x + O[x]^6]"), "SeriesData[x, 0, List[1, 0, Rational[-1, 6], 0, Rational[1, 120]], 1, 6, 1]");
+            assert_eq!(run("Log[1 + x + O[x]^3]"), "SeriesData[x, 0, List[1, Rational[-1, 2]], 1, 3, 1]");
+        }
+
+        #[test]
+        fn it_reads_big_o_and_truncates() {
+            assert_eq!(run("O[x]^3"), "SeriesData[x, 0, List[], 3, 3, 1]");
+            assert_eq!(run("x + O[x]^3"), "SeriesData[x, 0, List[1], 1, 3, 1]");
+            assert_eq!(run("1/(1 - x) + O[x]^3"), "SeriesData[x, 0, List[1, 1, 1], 0, 3, 1]");
+            assert_eq!(run("Normal[Series[Exp[x], {x, 0, 2}]]"), "Plus[1, x, Times[Rational[1, 2], Power[x, 2]]]");
+            assert_eq!(run("Normal[Series[Log[x], {x, 1, 2}]]"), "Plus[-1, x, Times[Rational[-1, 2], Power[Plus[-1, x], 2]]]");
+        }
+
+        #[test]
+        fn it_leaves_singular_expansions_alone() {
+            assert_eq!(run("Series[Log[x], {x, 0, 3}]"), "Series[Log[x], List[x, 0, 3]]");
+            assert_eq!(run("Series[Exp[1/x], {x, 0, 3}]"), "Series[Power[E, Power[x, -1]], List[x, 0, 3]]");
+            assert_eq!(run("f[x + O[x]^2]"), "f[SeriesData[x, 0, List[1], 1, 2, 1]]");
+        }
+
+        #[test]
+        fn it_leaves_non_analytic_functions_alone() {
+            assert_eq!(run("Series[Abs[x], {x, 0, 2}]"), "Series[Abs[x], List[x, 0, 2]]");
+            assert_eq!(run("Series[Floor[x], {x, 0, 2}]"), "Series[Floor[x], List[x, 0, 2]]");
+        }
+    }
+
+    mod test_limits {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_takes_polynomial_and_rational_limits() {
+            assert_eq!(run("Limit[x^2 + 1, x -> 2]"), "5");
+            assert_eq!(run("Limit[a x, x -> 3]"), "Times[3, a]");
+            assert_eq!(run("Limit[(x^2 - 1)/(x - 1), x -> 1]"), "2");
+            assert_eq!(run("Limit[f[x], x -> a]"), "f[a]");
+        }
+
+        #[test]
+        fn it_takes_limits_at_infinity() {
+            assert_eq!(run("Limit[(2 x^2 + 1)/(x^2 - 3), x -> Infinity]"), "2");
+            assert_eq!(run("Limit[(x + 1)/(x^2 + 1), x -> Infinity]"), "0");
+            assert_eq!(run("Limit[x^3/(x + 1), x -> -Infinity]"), "Infinity");
+            assert_eq!(run("Limit[x - x^2, x -> Infinity]"), "Times[-1, Infinity]");
+            assert_eq!(run("Limit[Sqrt[x^2 + x] - x, x -> Infinity]"), "Rational[1, 2]");
+            assert_eq!(run("Limit[Exp[-x], x -> Infinity]"), "0");
+            assert_eq!(run("Limit[ArcTan[x], x -> -Infinity]"), "Times[Rational[-1, 2], Pi]");
+        }
+
+        #[test]
+        fn it_resolves_indeterminate_forms() {
+            assert_eq!(run("Limit[Sin[x]/x, x -> 0]"), "1");
+            assert_eq!(run("Limit[(1 - Cos[x])/x^2, x -> 0]"), "Rational[1, 2]");
+            assert_eq!(run("Limit[1/x - 1/Sin[x], x -> 0]"), "0");
+            assert_eq!(run("Limit[x Log[x], x -> 0]"), "0");
+            assert_eq!(run("Limit[Log[x]/x, x -> Infinity]"), "0");
+            assert_eq!(run("Limit[Exp[x]/x^2, x -> Infinity]"), "Infinity");
+            assert_eq!(run("Limit[(1 + 1/x)^x, x -> Infinity]"), "E");
+        }
+
+        #[test]
+        fn it_takes_one_sided_limits() {
+            assert_eq!(run("Limit[1/x, x -> 0, Direction -> \"FromAbove\"]"), "Infinity");
+            assert_eq!(run("Limit[1/x, x -> 0, Direction -> \"FromBelow\"]"), "Times[-1, Infinity]");
+            assert_eq!(run("Limit[1/x, x -> 0, Direction -> -1]"), "Infinity");
+            assert_eq!(run("Limit[Exp[1/x], x -> 0, Direction -> \"FromBelow\"]"), "0");
+            assert_eq!(run("Limit[Exp[1/x], x -> 0, Direction -> \"FromAbove\"]"), "Infinity");
+        }
+
+        #[test]
+        fn it_takes_limits_of_piecewise_functions_by_side() {
+            assert_eq!(run("Limit[Abs[x]/x, x -> 0, Direction -> \"FromAbove\"]"), "1");
+            assert_eq!(run("Limit[Abs[x]/x, x -> 0, Direction -> \"FromBelow\"]"), "-1");
+            assert_eq!(run("Limit[Floor[x], x -> 0, Direction -> \"FromBelow\"]"), "-1");
+            assert_eq!(run("Limit[Abs[x]/x, x -> 0]"), "Indeterminate");
+            assert_eq!(run("Limit[Sign[x], x -> 0]"), "Indeterminate");
+            assert_eq!(run("Limit[Floor[x], x -> 0]"), "Indeterminate");
+            assert_eq!(run("Limit[Abs[x], x -> 0]"), "0");
+            assert_eq!(run("Limit[Floor[x], x -> 1/2]"), "0");
+        }
+
+        #[test]
+        fn it_reports_infinite_and_indeterminate_limits() {
+            assert_eq!(run("Limit[1/x, x -> 0]"), "ComplexInfinity");
+            assert_eq!(run("Limit[1/x^2, x -> 0]"), "Infinity");
+            assert_eq!(run("Limit[Log[x], x -> 0]"), "Times[-1, Infinity]");
+            assert_eq!(run("Limit[Sin[x], x -> Infinity]"), "Indeterminate");
+        }
+
+        #[test]
+        fn it_leaves_unknown_limits_alone() {
+            assert_eq!(run("Limit[Exp[-1/x^2]/x, x -> 0]"), "Limit[Times[Power[E, Times[-1, Power[x, -2]]], Power[x, -1]], Rule[x, 0]]");
+            assert_eq!(run("Limit[1/x, x -> 0, Direction -> Up]"), "Limit[Power[x, -1], Rule[x, 0], Rule[Direction, Up]]");
+        }
+    }
+}
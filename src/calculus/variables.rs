@@ -0,0 +1,43 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+
+// Whether x occurs nowhere in e, heads included.
+pub fn free_of(e: &SimplexPointer, x: &SimplexPointer) -> bool {
+    if e == x {
+        return false;
+    }
+
+    if e.is_atomic() {
+        return true;
+    }
+
+    if let Some(h) = e.get_head() {
+        if !h.is_atomic() && !free_of(&h, x) {
+            return false;
+        }
+    }
+
+    e.get_parts().iter().all(|p| free_of(p, x))
+}
+
+// Every occurrence of x in e replaced by value, left unevaluated.
+pub fn substitute(e: &SimplexPointer, x: &SimplexPointer, value: &SimplexPointer) -> SimplexPointer {
+    if e == x {
+        return value.clone();
+    }
+
+    if e.is_atomic() || free_of(e, x) {
+        return e.clone();
+    }
+
+    let parts = e.get_parts().iter().map(|p| substitute(p, x, value)).collect::<Vec<SimplexPointer>>();
+
+    match e.get_head() {
+        Some(ref h) if !h.is_atomic() => {
+            let head = substitute(h, x, value);
+            SimplexPointer::from(SimplexList::with_head(&head).push_all(&parts))
+        }
+        _ => e.with_parts(&parts),
+    }
+}
@@ -0,0 +1,238 @@
+use num::Zero;
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+use expression::atom::numbers::big_real::BigReal;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::symbol;
+use arithmetic::canonical::{thread, sum, product, power};
+use arithmetic::terms::number;
+use numerical::real::{self, MACHINE_PRECISION, MAXIMUM_PRECISION};
+use numerical::precise;
+use elementary::exact::{rewrite, logarithm_to_base, logarithm_as_quotient};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Exp" => Some(exp_builtin),
+        "Log" => Some(log_builtin),
+        "Sin" => Some(sin_builtin),
+        "Cos" => Some(cos_builtin),
+        "Tan" => Some(tan_builtin),
+        "Cot" => Some(cot_builtin),
+        "Sec" => Some(sec_builtin),
+        "Csc" => Some(csc_builtin),
+        "ArcSin" => Some(arc_sin_builtin),
+        "ArcCos" => Some(arc_cos_builtin),
+        "ArcTan" => Some(arc_tan_builtin),
+        "ArcCot" => Some(arc_cot_builtin),
+        "ArcSec" => Some(arc_sec_builtin),
+        "ArcCsc" => Some(arc_csc_builtin),
+        "Sinh" => Some(sinh_builtin),
+        "Cosh" => Some(cosh_builtin),
+        "Tanh" => Some(tanh_builtin),
+        "Coth" => Some(coth_builtin),
+        "Sech" => Some(sech_builtin),
+        "Csch" => Some(csch_builtin),
+        "ArcSinh" => Some(arc_sinh_builtin),
+        "ArcCosh" => Some(arc_cosh_builtin),
+        "ArcTanh" => Some(arc_tanh_builtin),
+        "ArcCoth" => Some(arc_coth_builtin),
+        _ => None,
+    }
+}
+
+fn is_real(n: &Numeric) -> bool {
+    match *n {
+        Numeric::LittleReal(_) | Numeric::BigReal(_) => true,
+        _ => false,
+    }
+}
+
+// f[x] for a BigReal x, to the digits x leaves it.
+fn arbitrary(name: &str, x: &BigReal) -> Option<SimplexPointer> {
+    if name == "Log" && x.is_negative() {
+        let magnitude = precise::arbitrary(|xs, p| precise::function("Log", &xs[0], p), &[Numeric::BigReal(x.negate())])?;
+        let angle = precise::arbitrary_constant("Pi", x.precision())?;
+        return Some(sum(&[number(magnitude), product(&[number(Numeric::BigReal(angle)), symbol("I")])]));
+    }
+
+    precise::arbitrary(|xs, p| precise::function(name, &xs[0], p), &[Numeric::BigReal(x.clone())]).map(number)
+}
+
+// f[x] for a real x, worked out to every digit a d128 holds and correct to
+// machine precision. Log of a negative real is the Log of its magnitude
+// plus Pi I.
+fn numeric(name: &str, n: Numeric) -> Option<SimplexPointer> {
+    if let Numeric::BigReal(ref x) = n {
+        return arbitrary(name, x);
+    }
+
+    let x = n.to_d128();
+
+    if name == "Log" && x.is_negative() {
+        let magnitude = precise::evaluate("Log", Numeric::LittleReal(x.abs()), MAXIMUM_PRECISION)?;
+        let angle = real::to_expression(real::pi(), MACHINE_PRECISION);
+        return Some(sum(&[real::to_expression(magnitude, MACHINE_PRECISION), product(&[angle, symbol("I")])]));
+    }
+
+    precise::evaluate(name, n, MAXIMUM_PRECISION).map(|y| real::to_expression(y, MACHINE_PRECISION))
+}
+
+// Lists thread, reals evaluate, and everything else is left to the exact
+// rules.
+fn elementary(name: &str, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread(name, args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    match args[0].as_numeric() {
+        Some(n) if is_real(&n) => numeric(name, n),
+        _ => rewrite(name, &args[0]),
+    }
+}
+
+// E^x where it has a value of its own: numeric for a real x, exact at the
+// special values and x for E^Log[x].
+pub fn exponential(x: &SimplexPointer) -> Option<SimplexPointer> {
+    match x.as_numeric() {
+        Some(n) if is_real(&n) => numeric("Exp", n),
+        _ => rewrite("Exp", x),
+    }
+}
+
+// Exp[x] is E^x, so that the two cancel.
+fn exp_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("Exp", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(exponential(&args[0]).unwrap_or_else(|| power(&symbol("E"), &args[0])))
+}
+
+// Log[b, x] is exact where x is a power of b, numeric when either is real,
+// and Log[x] / Log[b] otherwise.
+fn log_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return elementary("Log", args);
+    }
+
+    if let Some(threaded) = thread("Log", args) {
+        return Some(threaded);
+    }
+
+    if let (Some(b), Some(x)) = (args[0].as_numeric(), args[1].as_numeric()) {
+        if b.is_machine() || x.is_machine() {
+            return precise::logarithm(b, x, MAXIMUM_PRECISION).map(|y| real::to_expression(y, MACHINE_PRECISION));
+        }
+
+        if is_real(&b) || is_real(&x) {
+            return precise::arbitrary(|xs, p| {
+                let base = precise::ln(&xs[0], p)?;
+                if base.is_zero() { None } else { Some(precise::divide(&precise::ln(&xs[1], p)?, &base, p)) }
+            }, &[b, x]).map(number);
+        }
+
+        if let Some(k) = logarithm_to_base(b, x) {
+            return Some(k);
+        }
+    }
+
+    Some(logarithm_as_quotient(&args[0], &args[1]))
+}
+
+fn sin_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Sin", args)
+}
+
+fn cos_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Cos", args)
+}
+
+fn tan_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Tan", args)
+}
+
+fn cot_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Cot", args)
+}
+
+fn sec_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Sec", args)
+}
+
+fn csc_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Csc", args)
+}
+
+fn arc_sin_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcSin", args)
+}
+
+fn arc_cos_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcCos", args)
+}
+
+fn arc_tan_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcTan", args)
+}
+
+fn arc_cot_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcCot", args)
+}
+
+fn arc_sec_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcSec", args)
+}
+
+fn arc_csc_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcCsc", args)
+}
+
+fn sinh_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Sinh", args)
+}
+
+fn cosh_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Cosh", args)
+}
+
+fn tanh_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Tanh", args)
+}
+
+fn coth_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Coth", args)
+}
+
+fn sech_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Sech", args)
+}
+
+fn csch_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("Csch", args)
+}
+
+fn arc_sinh_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcSinh", args)
+}
+
+fn arc_cosh_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcCosh", args)
+}
+
+fn arc_tanh_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcTanh", args)
+}
+
+fn arc_coth_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    elementary("ArcCoth", args)
+}
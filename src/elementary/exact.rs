@@ -0,0 +1,273 @@
+use num::Integer;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{expression, integer, symbol, is_head, is_zero, ratio, negative};
+use arithmetic::terms::coefficient_and_term;
+use arithmetic::canonical::{sum, product, power};
+use numerical::value::real_value;
+
+const ODD: &'static [&'static str] = &["Sin", "Tan", "Cot", "Csc", "ArcSin", "ArcTan", "ArcCot", "ArcCsc",
+                                       "Sinh", "Tanh", "Coth", "Csch", "ArcSinh", "ArcTanh", "ArcCoth"];
+
+const EVEN: &'static [&'static str] = &["Cos", "Sec", "Cosh", "Sech"];
+
+const INVERSES: &'static [(&'static str, &'static str)] = &[
+    ("Exp", "Log"), ("Sin", "ArcSin"), ("Cos", "ArcCos"), ("Tan", "ArcTan"), ("Cot", "ArcCot"),
+    ("Sec", "ArcSec"), ("Csc", "ArcCsc"), ("Sinh", "ArcSinh"), ("Cosh", "ArcCosh"),
+    ("Tanh", "ArcTanh"), ("Coth", "ArcCoth"),
+];
+
+// a / b, ComplexInfinity when b is zero.
+fn quotient(a: &SimplexPointer, b: &SimplexPointer) -> SimplexPointer {
+    if is_zero(b) {
+        symbol("ComplexInfinity")
+    } else {
+        product(&[a.clone(), power(b, &integer(-1))])
+    }
+}
+
+fn pi_times(n: i64, d: i64) -> SimplexPointer {
+    product(&[ratio(n, d), symbol("Pi")])
+}
+
+// The rational r with x = r Pi, counting a Degree as Pi / 180.
+fn multiple_of_pi(x: &SimplexPointer) -> Option<(i64, i64)> {
+    match coefficient_and_term(x) {
+        (c, None) if c.is_zero() => Some((0, 1)),
+        (c, Some(ref t)) if *t == symbol("Pi") => c.as_rational(),
+        (c, Some(ref t)) if *t == symbol("Degree") => (c / Numeric::LittleInteger(180)).as_rational(),
+        _ => None,
+    }
+}
+
+// Sin[n Pi / d] where it is one of the values in the table, brought into
+// [0, Pi/2] first by its period and its symmetries.
+fn sine(n: i64, d: i64) -> Option<SimplexPointer> {
+    let n = n.mod_floor(&(2 * d));
+
+    if n >= d {
+        return sine(n - d, d).map(negative);
+    }
+
+    if 2 * n > d {
+        return sine(d - n, d);
+    }
+
+    match Numeric::ratio(n, d).as_rational()? {
+        (0, _) => Some(integer(0)),
+        (1, 6) => Some(ratio(1, 2)),
+        (1, 4) => Some(power(&integer(2), &ratio(-1, 2))),
+        (1, 3) => Some(product(&[ratio(1, 2), power(&integer(3), &ratio(1, 2))])),
+        (1, 2) => Some(integer(1)),
+        _ => None,
+    }
+}
+
+fn cosine(n: i64, d: i64) -> Option<SimplexPointer> {
+    sine(2 * n + d, 2 * d)
+}
+
+fn trigonometric(name: &str, x: &SimplexPointer) -> Option<SimplexPointer> {
+    let (n, d) = multiple_of_pi(x)?;
+    let (s, c) = (sine(n, d)?, cosine(n, d)?);
+
+    Some(match name {
+        "Sin" => s,
+        "Cos" => c,
+        "Tan" => quotient(&s, &c),
+        "Cot" => quotient(&c, &s),
+        "Sec" => quotient(&integer(1), &c),
+        "Csc" => quotient(&integer(1), &s),
+        _ => return None,
+    })
+}
+
+// The angles in [0, Pi/2] of the table by their sines squared, and by
+// their tangents squared.
+fn by_sine(square: (i64, i64)) -> Option<(i64, i64)> {
+    match square {
+        (0, 1) => Some((0, 1)),
+        (1, 4) => Some((1, 6)),
+        (1, 2) => Some((1, 4)),
+        (3, 4) => Some((1, 3)),
+        (1, 1) => Some((1, 2)),
+        _ => None,
+    }
+}
+
+fn by_tangent(square: (i64, i64)) -> Option<(i64, i64)> {
+    match square {
+        (0, 1) => Some((0, 1)),
+        (1, 3) => Some((1, 6)),
+        (1, 1) => Some((1, 4)),
+        (3, 1) => Some((1, 3)),
+        _ => None,
+    }
+}
+
+// ArcSin or ArcTan of an exact x, looked up by x^2, which is rational for
+// every value in the table, and given the sign of x.
+fn arc(x: &SimplexPointer, table: fn((i64, i64)) -> Option<(i64, i64)>) -> Option<SimplexPointer> {
+    let (n, d) = table(power(x, &integer(2)).as_numeric()?.as_rational()?)?;
+    let sign = if real_value(x, &[])?.is_negative() { -1 } else { 1 };
+
+    Some(pi_times(sign * n, d))
+}
+
+fn inverse_trigonometric(name: &str, x: &SimplexPointer) -> Option<SimplexPointer> {
+    let reciprocal = || if is_zero(x) { None } else { Some(power(x, &integer(-1))) };
+    let complement = |a: SimplexPointer| sum(&[pi_times(1, 2), negative(a)]);
+
+    match name {
+        "ArcSin" => arc(x, by_sine),
+        "ArcCos" => arc(x, by_sine).map(complement),
+        "ArcTan" if *x == symbol("Infinity") => Some(pi_times(1, 2)),
+        "ArcTan" if *x == negative(symbol("Infinity")) => Some(pi_times(-1, 2)),
+        "ArcTan" => arc(x, by_tangent),
+        "ArcCot" if is_zero(x) => Some(pi_times(1, 2)),
+        "ArcCot" => arc(&reciprocal()?, by_tangent),
+        "ArcCsc" | "ArcSec" if is_zero(x) => Some(symbol("ComplexInfinity")),
+        "ArcCsc" => arc(&reciprocal()?, by_sine),
+        "ArcSec" => arc(&reciprocal()?, by_sine).map(complement),
+        _ => None,
+    }
+}
+
+// Exp[0], Exp[1] and Exp[r I Pi] for the angles of the table.
+fn exponential(x: &SimplexPointer) -> Option<SimplexPointer> {
+    if is_zero(x) {
+        return Some(integer(1));
+    }
+
+    if *x == integer(1) {
+        return Some(symbol("E"));
+    }
+
+    match coefficient_and_term(x) {
+        (c, Some(ref t)) if *t == product(&[symbol("I"), symbol("Pi")]) => {
+            let (n, d) = c.as_rational()?;
+            Some(sum(&[cosine(n, d)?, product(&[sine(n, d)?, symbol("I")])]))
+        }
+        _ => None,
+    }
+}
+
+// Log[1], Log[0], Log[-1], and Log of a rational power of E.
+fn logarithm(x: &SimplexPointer) -> Option<SimplexPointer> {
+    if let Some(n) = x.as_numeric() {
+        return match n.as_rational()? {
+            (1, 1) => Some(integer(0)),
+            (0, 1) => Some(negative(symbol("Infinity"))),
+            (-1, 1) => Some(product(&[symbol("I"), symbol("Pi")])),
+            _ => None,
+        };
+    }
+
+    if *x == symbol("E") {
+        return Some(integer(1));
+    }
+
+    if is_head(x, "Power") && x.get_length() == 2 && x.get_part(1).unwrap() == symbol("E") {
+        let r = x.get_part(2).unwrap();
+        return r.as_numeric().and_then(|n| n.as_rational()).map(|_| r);
+    }
+
+    None
+}
+
+// Log[b, x] as the rational k with b^k = x or x^k = b, for k up to the
+// size of an integer.
+pub fn logarithm_to_base(b: Numeric, x: Numeric) -> Option<SimplexPointer> {
+    if !b.is_exact() || !x.is_exact() || b <= Numeric::LittleInteger(0) || x <= Numeric::LittleInteger(0) || b == Numeric::LittleInteger(1) {
+        return None;
+    }
+
+    if x == Numeric::LittleInteger(1) {
+        return Some(integer(0));
+    }
+
+    for k in 1..64 {
+        for &sign in [1, -1].iter() {
+            let exponent = Numeric::LittleInteger(sign * k);
+
+            if b.clone().power(exponent.clone()) == Some(x.clone()) {
+                return Some(integer(sign * k));
+            }
+
+            if x.clone().power(exponent) == Some(b.clone()) {
+                return Some(ratio(sign, k));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn logarithm_as_quotient(b: &SimplexPointer, x: &SimplexPointer) -> SimplexPointer {
+    quotient(&expression("Log", &[x.clone()]), &expression("Log", &[b.clone()]))
+}
+
+fn hyperbolic(name: &str, x: &SimplexPointer) -> Option<SimplexPointer> {
+    if *x == integer(1) && name == "ArcCosh" {
+        return Some(integer(0));
+    }
+
+    if !is_zero(x) {
+        return None;
+    }
+
+    match name {
+        "Sinh" | "Tanh" | "ArcSinh" | "ArcTanh" => Some(integer(0)),
+        "Cosh" | "Sech" => Some(integer(1)),
+        "Coth" | "Csch" => Some(symbol("ComplexInfinity")),
+        _ => None,
+    }
+}
+
+// f[-x] as -f[x] for the odd functions and as f[x] for the even ones,
+// anything with a negative coefficient counting as -x.
+fn symmetric(name: &str, x: &SimplexPointer) -> Option<SimplexPointer> {
+    if !(coefficient_and_term(x).0 < Numeric::LittleInteger(0)) {
+        return None;
+    }
+
+    let reflected = expression(name, &[negative(x.clone())]);
+
+    if ODD.contains(&name) {
+        Some(negative(reflected))
+    } else if EVEN.contains(&name) {
+        Some(reflected)
+    } else {
+        None
+    }
+}
+
+// f[g[x]] as x when g is the inverse of f.
+fn composed(name: &str, x: &SimplexPointer) -> Option<SimplexPointer> {
+    let &(_, inverse) = INVERSES.iter().find(|&&(f, _)| f == name)?;
+
+    if is_head(x, inverse) && x.get_length() == 1 {
+        x.get_part(1)
+    } else {
+        None
+    }
+}
+
+// f[x] for an exact or symbolic x: special values, symmetries and
+// inverses, None when x is none of those.
+pub fn rewrite(name: &str, x: &SimplexPointer) -> Option<SimplexPointer> {
+    let special = match name {
+        "Exp" => exponential(x),
+        "Log" => logarithm(x),
+        "Sin" | "Cos" | "Tan" | "Cot" | "Sec" | "Csc" => trigonometric(name, x),
+        "ArcSin" | "ArcCos" | "ArcTan" | "ArcCot" | "ArcSec" | "ArcCsc" => inverse_trigonometric(name, x),
+        _ => hyperbolic(name, x),
+    };
+
+    special
+        .or_else(|| symmetric(name, x))
+        .or_else(|| composed(name, x))
+}
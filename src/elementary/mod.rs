@@ -0,0 +1,3 @@
+pub mod exact;
+pub mod builtins;
+pub mod test;
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    mod test_exact {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_gives_trigonometric_special_values() {
+            assert_eq!(run("Sin[Pi/6]"), "Rational[1, 2]");
+            assert_eq!(run("Cos[Pi/6]"), "Times[Rational[1, 2], Power[3, Rational[1, 2]]]");
+            assert_eq!(run("Cos[5 Pi/4]"), "Times[Rational[-1, 2], Power[2, Rational[1, 2]]]");
+            assert_eq!(run("Tan[Pi/3]"), "Power[3, Rational[1, 2]]");
+            assert_eq!(run("Tan[Pi/2]"), "ComplexInfinity");
+            assert_eq!(run("Sin[{0, Pi/2, Pi}]"), "List[0, 1, 0]");
+        }
+
+        #[test]
+        fn it_gives_inverse_trigonometric_special_values() {
+            assert_eq!(run("ArcTan[1]"), "Times[Rational[1, 4], Pi]");
+            assert_eq!(run("ArcSin[-1/2]"), "Times[Rational[-1, 6], Pi]");
+            assert_eq!(run("ArcCos[-1/Sqrt[2]]"), "Times[Rational[3, 4], Pi]");
+            assert_eq!(run("ArcSec[-2]"), "Times[Rational[2, 3], Pi]");
+            assert_eq!(run("ArcTan[Infinity]"), "Times[Rational[1, 2], Pi]");
+        }
+
+        #[test]
+        fn it_gives_exponential_and_logarithmic_special_values() {
+            assert_eq!(run("Exp[0]"), "1");
+            assert_eq!(run("Exp[1]"), "E");
+            assert_eq!(run("Exp[I Pi]"), "-1");
+            assert_eq!(run("Log[1]"), "0");
+            assert_eq!(run("Log[0]"), "Times[-1, Infinity]");
+            assert_eq!(run("Log[E^3]"), "3");
+            assert_eq!(run("Cosh[0]"), "1");
+            assert_eq!(run("ArcCosh[1]"), "0");
+        }
+
+        #[test]
+        fn it_takes_logarithms_to_a_base() {
+            assert_eq!(run("Log[2, 8]"), "3");
+            assert_eq!(run("Log[8, 2]"), "Rational[1, 3]");
+            assert_eq!(run("Log[2, 3]"), "Times[Power[Log[2], -1], Log[3]]");
+        }
+
+        #[test]
+        fn it_stays_symbolic_otherwise() {
+            assert_eq!(run("Sin[2]"), "Sin[2]");
+            assert_eq!(run("Sin[-x]"), "Times[-1, Sin[x]]");
+            assert_eq!(run("Cos[-2 x]"), "Cos[Times[2, x]]");
+            assert_eq!(run("Sin[ArcSin[x]]"), "x");
+            assert_eq!(run("Exp[Log[y]]"), "y");
+        }
+
+        #[test]
+        fn it_writes_exponentials_as_powers_of_e() {
+            assert_eq!(run("Exp[x]"), "Power[E, x]");
+            assert_eq!(run("Exp[x] - E^x"), "0");
+            assert_eq!(run("E^(I Pi)"), "-1");
+            assert_eq!(run("E^Log[y]"), "y");
+        }
+    }
+
+    mod test_numeric {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_evaluates_reals_to_the_last_digit() {
+            assert_eq!(run("Sin[0.5]"), "0.479425538604203");
+            assert_eq!(run("Exp[-1.5]"), "0.2231301601484298");
+            assert_eq!(run("Log[1.5]"), "0.4054651081081644");
+            assert_eq!(run("ArcTanh[0.5]"), "0.5493061443340548");
+            assert_eq!(run("Cosh[1.5]"), "2.352409615243247");
+        }
+
+        #[test]
+        fn it_survives_cancellation() {
+            assert_eq!(run("Sin[3.141592653589793238462643383279502]"), "8.841971693993751E-34");
+            assert_eq!(run("Sin[10000000000.5]"), "-0.009230941065539761");
+        }
+
+        #[test]
+        fn it_leaves_the_real_domain_alone() {
+            assert_eq!(run("ArcSin[2.5]"), "ArcSin[2.5]");
+            assert_eq!(run("Log[-2.5]"), "Plus[0.9162907318741551, Times[3.141592653589793, I]]");
+        }
+
+        #[test]
+        fn it_keeps_real_inputs_inexact() {
+            assert_eq!(run("Head[-1.]"), "Real");
+            assert_eq!(run("Head[2.0 + 1]"), "Real");
+            assert_eq!(run("Head[2.0 3]"), "Real");
+            assert_eq!(run("Head[6.0 / 3]"), "Real");
+            assert_eq!(run("Head[N[2]]"), "Real");
+            assert_eq!(run("IntegerQ[NIntegrate[Sin[x], {x, 0, Pi}]]"), "False");
+            assert_eq!(run("Log[-1.]"), "Plus[0, Times[3.141592653589793, I]]");
+            assert_eq!(run("(-2.)^0.5"), "Times[1.414213562373095, I]");
+            assert_eq!(run("N[Sqrt[-2]]"), "Times[1.414213562373095, I]");
+            assert_eq!(run("N[Sqrt[-2], 40]"), "Times[1.414213562373095048801688724209698078570, I]");
+        }
+
+        #[test]
+        fn it_reduces_arguments_near_poles() {
+            assert_eq!(run("N[Tan[Pi/2 - 10^-20], 20]"), "1.0000000000000000000E+20");
+        }
+    }
+}
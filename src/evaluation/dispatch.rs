@@ -0,0 +1,110 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+
+use evaluation::utilities::expression;
+use arithmetic;
+use structural;
+use lists;
+use polynomials;
+use associations;
+use calculus;
+use solving;
+use numerical;
+use matrices;
+use elementary;
+use special;
+use integers;
+use numbers;
+
+// A builtin receives its already evaluated arguments and returns None when
+// it has no rule for them, leaving the expression unevaluated.
+pub type Builtin = fn(&[SimplexPointer]) -> Option<SimplexPointer>;
+
+// Guards against rewrite cycles such as x = x + 1 ever looping forever.
+const ITERATION_LIMIT: usize = 4096;
+
+// Heads whose builtins see their arguments exactly as written, the way
+// Table[ ... ] has to see its body before the iterator is bound.
+const HOLD_ALL: &'static [&'static str] = &["Table"];
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    arithmetic::builtins::lookup(name)
+        .or_else(|| structural::builtins::lookup(name))
+        .or_else(|| lists::builtins::lookup(name))
+        .or_else(|| associations::builtins::lookup(name))
+        .or_else(|| polynomials::builtins::lookup(name))
+        .or_else(|| calculus::builtins::lookup(name))
+        .or_else(|| solving::builtins::lookup(name))
+        .or_else(|| numerical::builtins::lookup(name))
+        .or_else(|| matrices::builtins::lookup(name))
+        .or_else(|| elementary::builtins::lookup(name))
+        .or_else(|| special::builtins::lookup(name))
+        .or_else(|| integers::builtins::lookup(name))
+        .or_else(|| numbers::builtins::lookup(name))
+}
+
+// A rewrite into a different representation of the same expression, such
+// as Association[ ... ] becoming an association, compares equal but still
+// counts as a change.
+fn changed(result: &SimplexPointer, rebuilt: &SimplexPointer) -> bool {
+    *result != *rebuilt || result.get_association().is_some() != rebuilt.get_association().is_some()
+}
+
+// Parts are evaluated first, then the builtin for the head gets a go; only a
+// rewrite that actually changed something is evaluated again.
+pub fn evaluate(e: &SimplexPointer) -> SimplexPointer {
+    let mut current = e.clone();
+
+    for _ in 0..ITERATION_LIMIT {
+        // Packed arrays hold nothing but numbers, so are as evaluated as atoms.
+        if current.is_atomic() || current.get_packed().is_some() {
+            return current;
+        }
+
+        // Associations are built once; after that only their values evaluate.
+        if current.get_association().is_some() {
+            let values = current.get_parts().iter().map(|p| evaluate(p)).collect::<Vec<SimplexPointer>>();
+            return current.with_parts(&values);
+        }
+
+        let head = match current.get_head() {
+            Some(h) => if h.is_atomic() { h } else { evaluate(&h) },
+            None => return current,
+        };
+
+        let head_name = current.head_name();
+
+        let parts = if HOLD_ALL.contains(&head_name.as_str()) {
+            current.get_parts()
+        } else {
+            current.get_parts().iter().map(|p| evaluate(p)).collect::<Vec<SimplexPointer>>()
+        };
+
+        // Compound heads such as <| ... |>[key] apply through their value, and
+        // heads without a builtin such as Sin still apply to a SeriesData.
+        let (rebuilt, result) = if head.is_atomic() {
+            (current.with_parts(&parts),
+             lookup(head_name.as_str())
+                 .and_then(|f| f(&parts))
+                 .or_else(|| calculus::builtins::apply_function(head_name.as_str(), &parts)))
+        } else {
+            (SimplexPointer::from(SimplexList::with_head(&head).push_all(&parts)),
+             associations::builtins::apply_head(&head, &parts)
+                 .or_else(|| calculus::builtins::apply_head(&head, &parts))
+                 .or_else(|| numerical::builtins::apply_head(&head, &parts)))
+        };
+
+        match result {
+            Some(ref result) if changed(result, &rebuilt) => current = result.clone(),
+            _ => return rebuilt,
+        }
+    }
+
+    current
+}
+
+// Evaluates f[args ... ], None when f can't stand as a head.
+pub fn apply(f: &SimplexPointer, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    f.as_symbol().map(|name| evaluate(&expression(name.as_str(), args)))
+}
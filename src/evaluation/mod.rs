@@ -0,0 +1,6 @@
+pub mod dispatch;
+pub mod utilities;
+pub mod test;
+
+#[cfg(test)]
+pub mod testing;
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    mod test_dispatch {
+        use expression::traits::BaseExpression;
+        use evaluation::utilities::{expression, list, symbol};
+        use evaluation::dispatch::evaluate;
+
+        #[test]
+        fn it_leaves_unknown_heads_alone() {
+            let e = expression("f", &[symbol("x")]);
+            assert_eq!(evaluate(&e).as_str(), "f[x]");
+        }
+
+        #[test]
+        fn it_evaluates_arguments_first() {
+            let inner = expression("Length", &[list(&[symbol("a"), symbol("b")])]);
+            let e = expression("f", &[inner]);
+            assert_eq!(evaluate(&e).as_str(), "f[2]");
+        }
+    }
+
+    mod test_utilities {
+        use expression::traits::BaseExpression;
+        use evaluation::utilities::{expression, symbol, integer, split_options, option_value};
+
+        #[test]
+        fn it_splits_options() {
+            let args = vec![integer(1), expression("Rule", &[symbol("Heads"), symbol("True")])];
+            let (positional, options) = split_options(&args);
+
+            assert_eq!(positional.len(), 1);
+            assert_eq!(option_value(&options, "Heads").unwrap().as_str(), "True");
+            assert_eq!(option_value(&options, "Method"), None);
+        }
+    }
+}
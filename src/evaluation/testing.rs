@@ -0,0 +1,8 @@
+use expression::traits::BaseExpression;
+use parsing::expression::parse;
+use evaluation::dispatch::evaluate;
+
+// The FullForm of what the InputForm text s evaluates to.
+pub fn run(s: &str) -> String {
+    evaluate(&parse(s).unwrap()).as_str().into_owned()
+}
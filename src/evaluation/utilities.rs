@@ -0,0 +1,94 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+use expression::atom::structure::SimplexAtom;
+use expression::atom::numbers::number::Numeric;
+
+use arithmetic::terms::number;
+use arithmetic::canonical::{product, power};
+
+pub fn expression(head_name: &str, parts: &[SimplexPointer]) -> SimplexPointer {
+    SimplexPointer::from(SimplexList::new(head_name).push_all(parts))
+}
+
+pub fn list(parts: &[SimplexPointer]) -> SimplexPointer {
+    expression("List", parts)
+}
+
+pub fn integer(i: i64) -> SimplexPointer {
+    SimplexPointer::from(SimplexAtom::from(i))
+}
+
+pub fn symbol(s: &str) -> SimplexPointer {
+    SimplexPointer::from(SimplexAtom::SimplexSymbol(s.to_string()))
+}
+
+pub fn ratio(n: i64, d: i64) -> SimplexPointer {
+    number(Numeric::ratio(n, d))
+}
+
+pub fn half() -> SimplexPointer {
+    ratio(1, 2)
+}
+
+pub fn negative(e: SimplexPointer) -> SimplexPointer {
+    product(&[integer(-1), e])
+}
+
+pub fn over(n: SimplexPointer, d: &SimplexPointer) -> SimplexPointer {
+    product(&[n, power(d, &integer(-1))])
+}
+
+pub fn boolean(b: bool) -> SimplexPointer {
+    symbol(if b { "True" } else { "False" })
+}
+
+// An exact 0; 0. and anything that only simplifies to zero are not.
+pub fn is_zero(e: &SimplexPointer) -> bool {
+    e.as_numeric().map_or(false, |n| n.is_zero())
+}
+
+pub fn is_head(e: &SimplexPointer, head_name: &str) -> bool {
+    !e.is_atomic() && e.head_name() == head_name
+}
+
+// Splits Rule[lhs, rhs] (and RuleDelayed) into its two sides.
+pub fn rule_parts(e: &SimplexPointer) -> Option<(SimplexPointer, SimplexPointer)> {
+    if (is_head(e, "Rule") || is_head(e, "RuleDelayed")) && e.get_length() == 2 {
+        Some((e.get_part(1).unwrap(), e.get_part(2).unwrap()))
+    } else {
+        None
+    }
+}
+
+// Peels trailing `Name -> value` options off of an argument sequence.
+pub fn split_options(args: &[SimplexPointer]) -> (Vec<SimplexPointer>, Vec<(String, SimplexPointer)>) {
+    let mut split = args.len();
+
+    while split > 0 {
+        match rule_parts(&args[split - 1]) {
+            Some((ref lhs, _)) if lhs.as_symbol().is_some() => split = split - 1,
+            _ => break,
+        }
+    }
+
+    let options = args[split..]
+        .iter()
+        .filter_map(|o| rule_parts(o))
+        .map(|(lhs, rhs)| (lhs.as_str().to_string(), rhs))
+        .collect();
+
+    (args[..split].to_vec(), options)
+}
+
+pub fn option_value(options: &[(String, SimplexPointer)], name: &str) -> Option<SimplexPointer> {
+    options.iter().find(|&&(ref n, _)| n == name).map(|&(_, ref v)| v.clone())
+}
+
+pub fn integer_list(e: &SimplexPointer) -> Option<Vec<i64>> {
+    if !is_head(e, "List") {
+        return None;
+    }
+
+    e.get_parts().iter().map(|p| p.as_integer()).collect()
+}
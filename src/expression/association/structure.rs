@@ -0,0 +1,192 @@
+use std::fmt;
+use std::rc::Rc;
+use std::collections::HashMap;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+use expression::atom::structure::SimplexAtom;
+
+// Keyed values in insertion order, the keys hashed structurally so any
+// expression can be one. Parts are the values, as with Part[assoc, n].
+#[derive(Clone)]
+pub struct SimplexAssociation {
+    entries: Rc<Vec<(SimplexPointer, SimplexPointer)>>,
+    index: Rc<HashMap<SimplexPointer, usize>>,
+}
+
+impl fmt::Debug for SimplexAssociation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SimplexAssociation: {} entries", self.entries.len())
+    }
+}
+
+fn rule(key: &SimplexPointer, value: &SimplexPointer) -> SimplexPointer {
+    SimplexPointer::from(SimplexList::new("Rule").push(key).push(value))
+}
+
+impl SimplexAssociation {
+    pub fn new() -> SimplexAssociation {
+        SimplexAssociation {
+            entries: Rc::new(Vec::new()),
+            index: Rc::new(HashMap::new()),
+        }
+    }
+
+    // A key given twice keeps its first position and its last value.
+    pub fn from_pairs(pairs: &[(SimplexPointer, SimplexPointer)]) -> SimplexAssociation {
+        let mut association = SimplexAssociation::new();
+
+        for &(ref key, ref value) in pairs {
+            association = association.insert(key, value);
+        }
+
+        association
+    }
+
+    // Reads a sequence of Rule[k, v] (lists of them included), None if
+    // anything else turns up.
+    pub fn from_rules(rules: &[SimplexPointer]) -> Option<SimplexAssociation> {
+        let mut pairs = Vec::with_capacity(rules.len());
+
+        for r in rules {
+            if let Some(a) = r.get_association() {
+                pairs.extend(a.pairs());
+                continue;
+            }
+
+            match (r.get_atom(), r.get_head().map(|h| h.as_str().into_owned())) {
+                (None, Some(ref h)) if (h == "Rule" || h == "RuleDelayed") && r.get_length() == 2 => {
+                    pairs.push((r.get_part(1).unwrap(), r.get_part(2).unwrap()));
+                }
+                (None, Some(ref h)) if h == "List" => {
+                    match SimplexAssociation::from_rules(&r.get_parts()) {
+                        Some(a) => pairs.extend(a.pairs()),
+                        None => return None,
+                    }
+                }
+                _ => return None,
+            }
+        }
+
+        Some(SimplexAssociation::from_pairs(&pairs))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn pairs(&self) -> Vec<(SimplexPointer, SimplexPointer)> {
+        self.entries.as_ref().clone()
+    }
+
+    pub fn keys(&self) -> Vec<SimplexPointer> {
+        self.entries.iter().map(|&(ref k, _)| k.clone()).collect()
+    }
+
+    pub fn values(&self) -> Vec<SimplexPointer> {
+        self.entries.iter().map(|&(_, ref v)| v.clone()).collect()
+    }
+
+    pub fn rules(&self) -> Vec<SimplexPointer> {
+        self.entries.iter().map(|&(ref k, ref v)| rule(k, v)).collect()
+    }
+
+    pub fn get(&self, key: &SimplexPointer) -> Option<SimplexPointer> {
+        self.index.get(key).map(|&i| self.entries[i].1.clone())
+    }
+
+    pub fn contains_key(&self, key: &SimplexPointer) -> bool {
+        self.index.contains_key(key)
+    }
+
+    pub fn insert(mut self, key: &SimplexPointer, value: &SimplexPointer) -> SimplexAssociation {
+        let existing = self.index.get(key).cloned();
+
+        match existing {
+            Some(i) => Rc::make_mut(&mut self.entries)[i].1 = value.clone(),
+            None => {
+                let position = self.entries.len();
+                Rc::make_mut(&mut self.entries).push((key.clone(), value.clone()));
+                Rc::make_mut(&mut self.index).insert(key.clone(), position);
+            }
+        }
+
+        self
+    }
+
+    pub fn remove(&self, keys: &[SimplexPointer]) -> SimplexAssociation {
+        let remaining = self.entries.iter()
+            .filter(|&&(ref k, _)| !keys.contains(k))
+            .cloned()
+            .collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+
+        SimplexAssociation::from_pairs(&remaining)
+    }
+
+    // A body of the same length keeps the keys and takes the new values,
+    // a body of rules builds a fresh association, anything else can only be
+    // left as an unevaluated Association[ ... ].
+    pub fn with_parts(&self, parts: &[SimplexPointer]) -> SimplexPointer {
+        if parts.len() == self.entries.len() {
+            let pairs = self.keys().into_iter().zip(parts.iter().cloned()).collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+            return SimplexPointer::from(SimplexAssociation::from_pairs(&pairs));
+        }
+
+        match SimplexAssociation::from_rules(parts) {
+            Some(a) => SimplexPointer::from(a),
+            None => SimplexPointer::from(SimplexList::new("Association").push_all(parts)),
+        }
+    }
+}
+
+impl BaseExpression for SimplexAssociation {
+    fn get_head(&self) -> Option<SimplexPointer> {
+        Some(SimplexPointer::from(SimplexAtom::SimplexSymbol("Association".to_string())))
+    }
+
+    fn get_rest(&self) -> Option<SimplexPointer> {
+        if self.entries.len() <= 1 {
+            None
+        } else {
+            Some(SimplexPointer::from(SimplexAssociation::from_pairs(&self.entries[1..])))
+        }
+    }
+
+    fn get_part(&self, n: usize) -> Option<SimplexPointer> {
+        if n == 0 {
+            self.get_head()
+        } else {
+            self.entries.get(n - 1).map(|&(_, ref v)| v.clone())
+        }
+    }
+
+    fn get_length(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get_association(&self) -> Option<SimplexAssociation> {
+        Some(self.clone())
+    }
+
+    fn to_string(&self) -> String {
+        let rules = self.rules().iter().map(|r| r.as_str().into_owned()).collect::<Vec<String>>();
+        format!("Association[{}]", rules.join(", "))
+    }
+
+    fn replace_symbol(&self, symbol: &BaseExpression, new: &BaseExpression) -> SimplexPointer {
+        let pairs = self.entries.iter()
+            .map(|&(ref k, ref v)| (k.replace_symbol(symbol, new), v.replace_symbol(symbol, new)))
+            .collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+
+        SimplexPointer::from(SimplexAssociation::from_pairs(&pairs))
+    }
+
+    fn evaluate(&self, v: &Vec<SimplexPointer>) -> SimplexPointer {
+        let pairs = self.entries.iter()
+            .map(|&(ref k, ref value)| (k.clone(), value.evaluate(v)))
+            .collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+
+        SimplexPointer::from(SimplexAssociation::from_pairs(&pairs))
+    }
+}
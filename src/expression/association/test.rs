@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    mod test_association {
+        use expression::traits::BaseExpression;
+        use expression::association::structure::SimplexAssociation;
+        use evaluation::utilities::{expression, integer, symbol};
+
+        fn rule(k: &str, v: i64) -> ::expression::structure::SimplexPointer {
+            expression("Rule", &[symbol(k), integer(v)])
+        }
+
+        #[test]
+        fn it_keeps_insertion_order() {
+            let a = SimplexAssociation::from_rules(&[rule("b", 1), rule("a", 2)]).unwrap();
+            assert_eq!(a.to_string(), "Association[Rule[b, 1], Rule[a, 2]]");
+            assert_eq!(a.get_length(), 2);
+            assert_eq!(a.get_part(2).unwrap().as_str(), "2");
+        }
+
+        #[test]
+        fn it_keeps_the_last_value_at_the_first_position() {
+            let a = SimplexAssociation::from_rules(&[rule("a", 1), rule("b", 2), rule("a", 3)]).unwrap();
+            assert_eq!(a.to_string(), "Association[Rule[a, 3], Rule[b, 2]]");
+        }
+
+        #[test]
+        fn it_hashes_compound_keys() {
+            let key = expression("f", &[symbol("x")]);
+            let a = SimplexAssociation::new().insert(&key, &integer(1));
+
+            assert_eq!(a.get(&expression("f", &[symbol("x")])).unwrap().as_str(), "1");
+            assert!(!a.contains_key(&expression("f", &[symbol("y")])));
+        }
+
+        #[test]
+        fn it_rejects_non_rules() {
+            assert!(SimplexAssociation::from_rules(&[symbol("x")]).is_none());
+        }
+
+        #[test]
+        fn it_removes_keys() {
+            let a = SimplexAssociation::from_rules(&[rule("a", 1), rule("b", 2)]).unwrap();
+            assert_eq!(a.remove(&[symbol("a")]).to_string(), "Association[Rule[b, 2]]");
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::str::FromStr;
+
+use decimal::d128;
+use num::{BigInt, BigRational, Signed, Zero};
+
+use expression::atom::numbers::number::Numeric;
+use numerical::precise::{quotient, power_of_ten};
+
+// A real with more digits than a d128 holds: digits times ten to the
+// exponent, keeping every digit known and no others, so 1.50`3 is 150 and
+// -2 and its precision is the number of digits. Zero has no digits, and its
+// exponent is where the digits it hides start.
+#[derive(Clone, Debug)]
+pub struct BigReal {
+    digits: BigInt,
+    exponent: i64,
+}
+
+fn length(n: &BigInt) -> u32 {
+    if n.is_zero() { 0 } else { n.abs().to_string().len() as u32 }
+}
+
+fn scale(n: BigInt, exponent: i64) -> BigRational {
+    if exponent >= 0 {
+        BigRational::from_integer(n * power_of_ten(exponent as u32))
+    } else {
+        BigRational::new(n, power_of_ten((-exponent) as u32))
+    }
+}
+
+// The power of ten of the first digit of a nonzero q: the quotient of
+// numerator and denominator lengths is within one of it.
+pub fn magnitude(q: &BigRational) -> i64 {
+    let m = length(q.numer()) as i64 - length(q.denom()) as i64;
+    if q.abs() >= scale(BigInt::from(1), m) { m } else { m - 1 }
+}
+
+impl BigReal {
+    // q to the nearest multiple of 10^exponent.
+    pub fn with_exponent(q: &BigRational, exponent: i64) -> BigReal {
+        let digits = if exponent >= 0 {
+            quotient(q.numer(), &(q.denom() * power_of_ten(exponent as u32)))
+        } else {
+            quotient(&(q.numer() * power_of_ten((-exponent) as u32)), q.denom())
+        };
+
+        BigReal { digits: digits, exponent: exponent }
+    }
+
+    // q to the given number of significant digits.
+    pub fn with_precision(q: &BigRational, precision: u32) -> BigReal {
+        if q.is_zero() {
+            return BigReal { digits: BigInt::zero(), exponent: -(precision as i64) };
+        }
+
+        BigReal::with_exponent(q, magnitude(q) - precision as i64 + 1).round(precision)
+    }
+
+    // Significant digits, none for zero.
+    pub fn precision(&self) -> u32 {
+        length(&self.digits)
+    }
+
+    // The power of ten of the last digit known.
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.digits.is_negative()
+    }
+
+    // Exactly the rational the digits stand for.
+    pub fn to_rational(&self) -> BigRational {
+        scale(self.digits.clone(), self.exponent)
+    }
+
+    pub fn to_d128(&self) -> d128 {
+        d128::from_str(&format!("{}E{}", self.digits, self.exponent)).unwrap()
+    }
+
+    // The same real to at most the given number of digits.
+    pub fn round(&self, precision: u32) -> BigReal {
+        let drop = self.precision().saturating_sub(precision);
+
+        if drop == 0 {
+            return self.clone();
+        }
+
+        let x = BigReal { digits: quotient(&self.digits, &power_of_ten(drop)), exponent: self.exponent + drop as i64 };
+
+        // 9.96 to two digits carries into a third.
+        if x.precision() > precision { x.round(precision) } else { x }
+    }
+
+    pub fn negate(&self) -> BigReal {
+        BigReal { digits: -&self.digits, exponent: self.exponent }
+    }
+}
+
+// A number meeting a BigReal: its value, and the real itself for reals.
+// Exact numbers know every digit, so they never hold a result back.
+pub enum Operand {
+    Exact(BigRational),
+    Real(BigReal),
+}
+
+impl Operand {
+    pub fn value(&self) -> BigRational {
+        match *self {
+            Operand::Exact(ref q) => q.clone(),
+            Operand::Real(ref x) => x.to_rational(),
+        }
+    }
+
+    fn exponent(&self) -> Option<i64> {
+        match *self {
+            Operand::Real(ref x) => Some(x.exponent),
+            _ => None,
+        }
+    }
+
+    fn precision(&self) -> Option<u32> {
+        match *self {
+            Operand::Real(ref x) => Some(x.precision()),
+            _ => None,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match *self {
+            Operand::Exact(ref q) => q.is_zero(),
+            Operand::Real(ref x) => x.is_zero(),
+        }
+    }
+}
+
+// Sums know their digits down to the last one the least accurate term
+// knows, so cancellation leaves fewer of them.
+pub fn add(a: &Operand, b: &Operand) -> Numeric {
+    let value = a.value() + b.value();
+
+    match a.exponent().into_iter().chain(b.exponent()).max() {
+        Some(e) => Numeric::BigReal(BigReal::with_exponent(&value, e)),
+        None => Numeric::from_big_rational(value),
+    }
+}
+
+pub fn subtract(a: &Operand, b: &Operand) -> Numeric {
+    let negated = match *b {
+        Operand::Exact(ref q) => Operand::Exact(-q),
+        Operand::Real(ref x) => Operand::Real(x.negate()),
+    };
+
+    add(a, &negated)
+}
+
+// Products and quotients have as many digits as the least precise factor.
+// A zero real keeps how small it is known to be, scaled by the other
+// factor, and exact zero times anything is exactly zero.
+fn scaled(a: &Operand, b: &Operand, value: BigRational, inverse: bool) -> Numeric {
+    if value.is_zero() && (a.precision() == Some(0) || b.precision() == Some(0)) {
+        let e = match (a, b) {
+            (&Operand::Real(ref x), &Operand::Real(ref y)) if x.is_zero() && y.is_zero() => x.exponent + y.exponent,
+            (&Operand::Real(ref x), other) | (other, &Operand::Real(ref x)) if x.is_zero() => {
+                let m = magnitude(&other.value());
+                if inverse { x.exponent - m } else { x.exponent + m }
+            }
+            _ => 0,
+        };
+
+        return Numeric::BigReal(BigReal { digits: BigInt::zero(), exponent: e });
+    }
+
+    if value.is_zero() {
+        return Numeric::LittleInteger(0);
+    }
+
+    match a.precision().into_iter().chain(b.precision()).min() {
+        Some(p) => Numeric::BigReal(BigReal::with_precision(&value, p)),
+        None => Numeric::from_big_rational(value),
+    }
+}
+
+pub fn multiply(a: &Operand, b: &Operand) -> Numeric {
+    scaled(a, b, a.value() * b.value(), false)
+}
+
+pub fn divide(a: &Operand, b: &Operand) -> Numeric {
+    if b.is_zero() {
+        return Numeric::NaN;
+    }
+
+    scaled(a, b, a.value() / b.value(), true)
+}
+
+// The way a d128 prints: plainly while the exponent is at most zero and
+// the first digit no further than six places after the point, and as
+// digits with an exponent otherwise.
+impl fmt::Display for BigReal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let ds = self.digits.abs().to_string();
+        let n = ds.len() as i64;
+        let adjusted = self.exponent + n - 1;
+
+        if self.exponent <= 0 && adjusted >= -6 {
+            let places = (-self.exponent) as usize;
+
+            return if places == 0 {
+                write!(f, "{}{}", sign, ds)
+            } else if ds.len() > places {
+                write!(f, "{}{}.{}", sign, &ds[..ds.len() - places], &ds[ds.len() - places..])
+            } else {
+                write!(f, "{}0.{}{}", sign, "0".repeat(places - ds.len()), ds)
+            };
+        }
+
+        let mantissa = if ds.len() > 1 { format!("{}.{}", &ds[..1], &ds[1..]) } else { ds.clone() };
+        write!(f, "{}{}E{}{}", sign, mantissa, if adjusted >= 0 { "+" } else { "-" }, adjusted.abs())
+    }
+}
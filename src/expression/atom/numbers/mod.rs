@@ -1,3 +1,4 @@
 pub mod number;
+pub mod big_real;
 pub mod traits;
 pub mod test;
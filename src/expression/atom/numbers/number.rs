@@ -1,27 +1,46 @@
 use std::ops::{Add, Sub, Mul, Div};
-use std::cmp::PartialEq;
+use std::cmp::{PartialEq, PartialOrd, Ordering};
 use std::borrow::Cow;
 use parsing::utilities::numerics::get_representable_integer;
+use expression::atom::numbers::big_real::{self, BigReal, Operand};
+use numerical::precise;
+use numerical::real;
 
 extern crate decimal;
 use decimal::d128;
 
 extern crate num;
 use std::str::FromStr;
+use self::num::rational::Rational64;
+use self::num::{BigInt, BigRational, Integer, Zero, ToPrimitive};
 
 // TODO: Make emulated integer using d128.
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum Numeric {
     LittleInteger(i64),
     LittleReal(d128),
+    // Always in lowest terms with a positive denominator other than one.
+    LittleRational(Rational64),
+    // Exact numbers past the machine ones; only ever holding values the
+    // little kinds above can't.
+    BigInteger(BigInt),
+    BigRational(BigRational),
+    // Reals past the digits a d128 holds, knowing how many are right.
+    BigReal(BigReal),
     NaN,
 }
 
+// The most bits an exact power is allowed to take before it is left alone.
+const POWER_BITS: u64 = 1 << 20;
+
 impl Numeric {
     pub fn as_str<'a>(&'a self) -> Cow<'a, str> {
         match self {
             &Numeric::LittleInteger(i) => Cow::Owned(i.to_string()),
-            &Numeric::LittleReal(ref r) => Cow::Owned(r.to_string()),
+            &Numeric::LittleReal(ref r) => Cow::Owned(real::machine(*r).to_string()),
+            &Numeric::BigInteger(ref n) => Cow::Owned(n.to_string()),
+            &Numeric::BigReal(ref x) => Cow::Owned(x.to_string()),
+            &Numeric::LittleRational(_) | &Numeric::BigRational(_) => Cow::Owned(self.to_string()),
             &Numeric::NaN => Cow::Borrowed("NaN"),
         }
     }
@@ -29,11 +48,24 @@ impl Numeric {
     pub fn to_string(&self) -> String {
         match self {
             &Numeric::LittleInteger(i) => format!("{}", i),
-            &Numeric::LittleReal(ref r) => format!("{}", r),
+            &Numeric::LittleReal(ref r) => format!("{}", real::machine(*r)),
+            &Numeric::LittleRational(ref q) => format!("Rational[{}, {}]", q.numer(), q.denom()),
+            &Numeric::BigInteger(ref n) => format!("{}", n),
+            &Numeric::BigRational(ref q) => format!("Rational[{}, {}]", q.numer(), q.denom()),
+            &Numeric::BigReal(ref x) => format!("{}", x),
             &Numeric::NaN => format!("NaN"),
         }
     }
 
+    pub fn head_name(&self) -> &'static str {
+        match self {
+            &Numeric::LittleInteger(_) | &Numeric::BigInteger(_) => "Integer",
+            &Numeric::LittleReal(_) | &Numeric::BigReal(_) => "Real",
+            &Numeric::LittleRational(_) | &Numeric::BigRational(_) => "Rational",
+            &Numeric::NaN => "Symbol",
+        }
+    }
+
     pub fn simplify(self) -> Numeric {
         match self {
             Numeric::LittleReal(r) => {
@@ -55,11 +87,223 @@ impl Numeric {
         }
     }
 
+    // The exact ratio n/d, an integer when d divides n and NaN when d is zero.
+    pub fn ratio(n: i64, d: i64) -> Numeric {
+        Numeric::wide_ratio(n as i128, d as i128)
+    }
+
+    // n/d from products and sums of machine integers, which always fit an
+    // i128, in whichever kind holds the result.
+    fn wide_ratio(n: i128, d: i128) -> Numeric {
+        if d == 0 {
+            return Numeric::NaN;
+        }
+
+        let g = n.gcd(&d);
+        let (n, d) = if d < 0 { (-n / g, -d / g) } else { (n / g, d / g) };
+
+        if d == 1 {
+            return Numeric::wide_integer(n);
+        }
+
+        if n >= i64::min_value() as i128 && n <= i64::max_value() as i128 && d <= i64::max_value() as i128 {
+            Numeric::LittleRational(Rational64::new_raw(n as i64, d as i64))
+        } else {
+            Numeric::BigRational(BigRational::new_raw(BigInt::from(n), BigInt::from(d)))
+        }
+    }
+
+    fn wide_integer(n: i128) -> Numeric {
+        if n >= i64::min_value() as i128 && n <= i64::max_value() as i128 {
+            Numeric::LittleInteger(n as i64)
+        } else {
+            Numeric::BigInteger(BigInt::from(n))
+        }
+    }
+
+    // An integer in the smallest kind that holds it.
+    pub fn from_big_integer(n: BigInt) -> Numeric {
+        match n.to_i64() {
+            Some(i) => Numeric::LittleInteger(i),
+            None => Numeric::BigInteger(n),
+        }
+    }
+
+    // A rational in the smallest kind that holds it.
+    pub fn from_big_rational(q: BigRational) -> Numeric {
+        if q.is_integer() {
+            return Numeric::from_big_integer(q.to_integer());
+        }
+
+        match (q.numer().to_i64(), q.denom().to_i64()) {
+            (Some(n), Some(d)) => Numeric::LittleRational(Rational64::new_raw(n, d)),
+            _ => Numeric::BigRational(q),
+        }
+    }
+
+    // Integers and rationals exactly, whatever their size.
+    pub fn to_big_rational(&self) -> Option<BigRational> {
+        match self {
+            &Numeric::LittleInteger(i) => Some(BigRational::from_integer(BigInt::from(i))),
+            &Numeric::LittleRational(q) => Some(BigRational::new_raw(BigInt::from(*q.numer()), BigInt::from(*q.denom()))),
+            &Numeric::BigInteger(ref n) => Some(BigRational::from_integer(n.clone())),
+            &Numeric::BigRational(ref q) => Some(q.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn as_big_integer(&self) -> Option<BigInt> {
+        match self {
+            &Numeric::LittleInteger(i) => Some(BigInt::from(i)),
+            &Numeric::BigInteger(ref n) => Some(n.clone()),
+            _ => None,
+        }
+    }
+
+    // Machine integers and rationals as a numerator over a denominator.
+    pub fn as_rational(&self) -> Option<(i64, i64)> {
+        match self {
+            &Numeric::LittleInteger(i) => Some((i, 1)),
+            &Numeric::LittleRational(q) => Some((*q.numer(), *q.denom())),
+            _ => None,
+        }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.to_big_rational().is_some()
+    }
+
+    // Exact zero only; 0. is kept as written.
+    pub fn is_zero(&self) -> bool {
+        match *self {
+            Numeric::LittleInteger(0) => true,
+            _ => false,
+        }
+    }
+
+    // None where the result isn't a single number: fractional powers of
+    // exact numbers, powers too big to hold, zero to a non-positive power
+    // and roots of negative reals.
+    pub fn power(self, exponent: Numeric) -> Option<Numeric> {
+        match (self, exponent) {
+            (Numeric::NaN, _) | (_, Numeric::NaN) => None,
+            (Numeric::LittleInteger(0), Numeric::LittleInteger(e)) if e <= 0 => None,
+            (Numeric::LittleInteger(base), Numeric::LittleInteger(e)) if e >= 0 && e <= u32::max_value() as i64 => {
+                match base.checked_pow(e as u32) {
+                    Some(p) => Some(Numeric::LittleInteger(p)),
+                    None => Numeric::LittleInteger(base).exact_power(e),
+                }
+            }
+            (base, Numeric::LittleInteger(e)) if base.is_exact() => base.exact_power(e),
+            (ref base, ref e) if base.is_exact() && e.is_exact() => None,
+            (Numeric::BigReal(x), Numeric::LittleInteger(e)) => Numeric::BigReal(x).real_power(e),
+            (base @ Numeric::BigReal(_), e) | (base, e @ Numeric::BigReal(_)) if !base.is_machine() && !e.is_machine() => {
+                precise::arbitrary(|xs, p| precise::power(&xs[0], &xs[1], p), &[base, e])
+            }
+            (base, e) => {
+                let result = base.to_d128().pow(e.to_d128());
+
+                if result.is_nan() || result.is_infinite() {
+                    None
+                } else {
+                    Some(Numeric::LittleReal(result.reduce()))
+                }
+            }
+        }
+    }
+
+    // -x in the same kind as x, which 0 - x isn't for a whole real.
+    pub fn negate(self) -> Numeric {
+        match self {
+            Numeric::LittleReal(r) => Numeric::LittleReal(-r),
+            Numeric::BigReal(x) => Numeric::BigReal(x.negate()),
+            n => Numeric::LittleInteger(0) - n,
+        }
+    }
+
+    pub fn is_machine(&self) -> bool {
+        match *self {
+            Numeric::LittleReal(_) => true,
+            _ => false,
+        }
+    }
+
+    // Integer powers of a BigReal by repeated squaring, which keeps track of
+    // the digits lost on the way.
+    fn real_power(self, e: i64) -> Option<Numeric> {
+        if e == 0 {
+            return Some(Numeric::LittleInteger(1));
+        }
+
+        if e < 0 && self == Numeric::LittleInteger(0) {
+            return None;
+        }
+
+        let magnitude = e.checked_abs()?;
+
+        if magnitude > POWER_BITS as i64 {
+            return None;
+        }
+
+        let mut result = Numeric::LittleInteger(1);
+        let mut square = self;
+        let mut remaining = magnitude;
+
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                result = result * square.clone();
+            }
+
+            square = square.clone() * square;
+            remaining = remaining / 2;
+        }
+
+        Some(if e < 0 { Numeric::LittleInteger(1) / result } else { result })
+    }
+
+    fn exact_power(&self, e: i64) -> Option<Numeric> {
+        let q = self.to_big_rational()?;
+
+        if q.is_zero() {
+            return if e > 0 { Some(Numeric::LittleInteger(0)) } else { None };
+        }
+
+        // 1 and -1 never grow, whatever the exponent.
+        let bits = ::std::cmp::max(q.numer().bits(), q.denom().bits()) - 1;
+        let magnitude = e.checked_abs()? as u64;
+
+        if bits > 0 && (magnitude > POWER_BITS || bits * magnitude > POWER_BITS) {
+            return None;
+        }
+
+        let magnitude = if bits == 0 { magnitude % 2 } else { magnitude } as usize;
+        let p = num::pow(q, magnitude);
+        Some(Numeric::from_big_rational(if e < 0 { p.recip() } else { p }))
+    }
+
+    pub fn to_d128(&self) -> d128 {
+        match self {
+            &Numeric::LittleInteger(i) => d128::from(i),
+            &Numeric::LittleReal(r) => r,
+            &Numeric::LittleRational(q) => d128::from(*q.numer()) / d128::from(*q.denom()),
+            &Numeric::BigInteger(ref n) => d128::from_str(&n.to_string()).unwrap(),
+            &Numeric::BigRational(ref q) => {
+                d128::from_str(&q.numer().to_string()).unwrap() / d128::from_str(&q.denom().to_string()).unwrap()
+            }
+            &Numeric::BigReal(ref x) => x.to_d128(),
+            &Numeric::NaN => d128::from_str("NaN").unwrap(),
+        }
+    }
+
     #[allow(dead_code)]
     pub fn capacity(&self) -> usize {
         match self {
             &Numeric::LittleInteger(_) => 64,
             &Numeric::LittleReal(_) => 128,
+            &Numeric::LittleRational(_) => 128,
+            &Numeric::BigInteger(ref n) => n.bits() as usize,
+            &Numeric::BigRational(ref q) => (q.numer().bits() + q.denom().bits()) as usize,
+            &Numeric::BigReal(ref x) => (x.precision() as f64 * 10f64.log2()).ceil() as usize + 64,
             &Numeric::NaN => 8,
         }
     }
@@ -67,17 +311,69 @@ impl Numeric {
 
 impl Eq for Numeric {}
 
+// The kind two numbers combine in: machine integers and rationals in
+// i128, where no product or sum of two of them overflows, anything bigger
+// exactly, anything meeting a machine real as a machine real and anything
+// else meeting a BigReal as a BigReal.
+enum Promoted {
+    Integers(i128, i128),
+    Rationals((i128, i128), (i128, i128)),
+    Exact(BigRational, BigRational),
+    Reals(d128, d128),
+    Arbitrary(Operand, Operand),
+    NaN,
+}
+
+fn operand(n: &Numeric) -> Operand {
+    match *n {
+        Numeric::BigReal(ref x) => Operand::Real(x.clone()),
+        _ => Operand::Exact(n.to_big_rational().unwrap()),
+    }
+}
+
+fn promote(lhs: &Numeric, rhs: &Numeric) -> Promoted {
+    match (lhs, rhs) {
+        (&Numeric::NaN, _) | (_, &Numeric::NaN) => Promoted::NaN,
+        (&Numeric::LittleInteger(a), &Numeric::LittleInteger(b)) => Promoted::Integers(a as i128, b as i128),
+        (&Numeric::LittleReal(_), _) | (_, &Numeric::LittleReal(_)) => Promoted::Reals(lhs.to_d128(), rhs.to_d128()),
+        (&Numeric::BigReal(_), _) | (_, &Numeric::BigReal(_)) => Promoted::Arbitrary(operand(lhs), operand(rhs)),
+        (a, b) => {
+            match (a.as_rational(), b.as_rational()) {
+                (Some((an, ad)), Some((bn, bd))) => Promoted::Rationals((an as i128, ad as i128), (bn as i128, bd as i128)),
+                _ => Promoted::Exact(a.to_big_rational().unwrap(), b.to_big_rational().unwrap()),
+            }
+        }
+    }
+}
+
 impl PartialEq for Numeric {
     fn eq(&self, other: &Numeric) -> bool {
-        match (self, other) {
-            (&Numeric::NaN, &Numeric::NaN) => true,
-            (&Numeric::NaN, _) => false,
-            (_, &Numeric::NaN) => false,
-            (&Numeric::LittleInteger(lhs), &Numeric::LittleInteger(rhs)) => lhs == rhs,
-            (&Numeric::LittleInteger(_), &Numeric::LittleReal(_)) => *self == other.simplify(),
-            (&Numeric::LittleReal(_), &Numeric::LittleInteger(_)) => other.simplify() == *self,
-            (&Numeric::LittleReal(lhs), &Numeric::LittleReal(rhs)) => lhs == rhs,
+        match promote(self, other) {
+            Promoted::NaN => {
+                match (self, other) {
+                    (&Numeric::NaN, &Numeric::NaN) => true,
+                    _ => false,
+                }
+            }
+            Promoted::Integers(a, b) => a == b,
+            Promoted::Rationals((an, ad), (bn, bd)) => an * bd == bn * ad,
+            Promoted::Exact(a, b) => a == b,
+            Promoted::Reals(a, b) => a == b,
+            Promoted::Arbitrary(a, b) => a.value() == b.value(),
+        }
+    }
+}
 
+impl PartialOrd for Numeric {
+    fn partial_cmp(&self, other: &Numeric) -> Option<Ordering> {
+        match promote(self, other) {
+            Promoted::NaN => None,
+            Promoted::Integers(a, b) => a.partial_cmp(&b),
+            // Denominators are positive, so cross multiplying keeps the order.
+            Promoted::Rationals((an, ad), (bn, bd)) => (an * bd).partial_cmp(&(bn * ad)),
+            Promoted::Exact(a, b) => a.partial_cmp(&b),
+            Promoted::Reals(a, b) => a.partial_cmp(&b),
+            Promoted::Arbitrary(a, b) => a.value().partial_cmp(&b.value()),
         }
     }
 }
@@ -86,24 +382,13 @@ impl Add for Numeric {
     type Output = Numeric;
 
     fn add(self, other: Numeric) -> Numeric {
-        // TODO: Use d128 constructor more intellegently: This is extremely slow.
-        match (self, other) {
-            (Numeric::NaN, _) => Numeric::NaN,
-            (_, Numeric::NaN) => Numeric::NaN,
-            (Numeric::LittleInteger(lhs), Numeric::LittleInteger(rhs)) => {
-                Numeric::LittleInteger(lhs + rhs)
-            }
-            (Numeric::LittleInteger(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(d128::from_str(lhs.to_string().as_str()).unwrap() + rhs)
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleInteger(rhs)) => {
-                Numeric::LittleReal(lhs + d128::from_str(rhs.to_string().as_str()).unwrap())
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(lhs + rhs).simplify()
-            }
+        match promote(&self, &other) {
+            Promoted::NaN => Numeric::NaN,
+            Promoted::Integers(a, b) => Numeric::wide_integer(a + b),
+            Promoted::Rationals((an, ad), (bn, bd)) => Numeric::wide_ratio(an * bd + bn * ad, ad * bd),
+            Promoted::Exact(a, b) => Numeric::from_big_rational(a + b),
+            Promoted::Reals(a, b) => Numeric::LittleReal(a + b),
+            Promoted::Arbitrary(a, b) => big_real::add(&a, &b),
         }
     }
 }
@@ -112,23 +397,13 @@ impl Sub for Numeric {
     type Output = Numeric;
 
     fn sub(self, other: Numeric) -> Numeric {
-        match (self, other) {
-            (Numeric::NaN, _) => Numeric::NaN,
-            (_, Numeric::NaN) => Numeric::NaN,
-            (Numeric::LittleInteger(lhs), Numeric::LittleInteger(rhs)) => {
-                Numeric::LittleInteger(lhs - rhs)
-            }
-            (Numeric::LittleInteger(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(d128::from_str(lhs.to_string().as_str()).unwrap() - rhs)
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleInteger(rhs)) => {
-                Numeric::LittleReal(lhs - d128::from_str(rhs.to_string().as_str()).unwrap())
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(lhs - rhs).simplify()
-            }
+        match promote(&self, &other) {
+            Promoted::NaN => Numeric::NaN,
+            Promoted::Integers(a, b) => Numeric::wide_integer(a - b),
+            Promoted::Rationals((an, ad), (bn, bd)) => Numeric::wide_ratio(an * bd - bn * ad, ad * bd),
+            Promoted::Exact(a, b) => Numeric::from_big_rational(a - b),
+            Promoted::Reals(a, b) => Numeric::LittleReal(a - b),
+            Promoted::Arbitrary(a, b) => big_real::subtract(&a, &b),
         }
     }
 }
@@ -137,54 +412,30 @@ impl Mul for Numeric {
     type Output = Numeric;
 
     fn mul(self, other: Numeric) -> Numeric {
-        // TODO: Use d128 constructor more intellegently: This is extremely slow.
-        match (self, other) {
-            (Numeric::NaN, _) => Numeric::NaN,
-            (_, Numeric::NaN) => Numeric::NaN,
-            (Numeric::LittleInteger(lhs), Numeric::LittleInteger(rhs)) => {
-                Numeric::LittleInteger(lhs * rhs)
-            }
-            (Numeric::LittleInteger(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(d128::from_str(lhs.to_string().as_str()).unwrap() * rhs)
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleInteger(rhs)) => {
-                Numeric::LittleReal(lhs * d128::from_str(rhs.to_string().as_str()).unwrap())
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(lhs * rhs).simplify()
-            }
+        match promote(&self, &other) {
+            Promoted::NaN => Numeric::NaN,
+            Promoted::Integers(a, b) => Numeric::wide_integer(a * b),
+            Promoted::Rationals((an, ad), (bn, bd)) => Numeric::wide_ratio(an * bn, ad * bd),
+            Promoted::Exact(a, b) => Numeric::from_big_rational(a * b),
+            Promoted::Reals(a, b) => Numeric::LittleReal(a * b),
+            Promoted::Arbitrary(a, b) => big_real::multiply(&a, &b),
         }
     }
 }
 
+// Exact division stays exact, 1/2 being Rational[1, 2] rather than 0.5.
 impl Div for Numeric {
     type Output = Numeric;
 
     fn div(self, other: Numeric) -> Numeric {
-        // TODO: Use d128 constructor more intellegently: This is extremely slow.
-        match (self, other) {
-            (Numeric::NaN, _) => Numeric::NaN,
-            (_, Numeric::NaN) => Numeric::NaN,
-            (Numeric::LittleInteger(lhs), Numeric::LittleInteger(rhs)) => {
-                // Unsafe, very very unsafe.
-                // This should use two d128s wrapped from lhs and rhs as division.
-                Numeric::LittleReal(d128::from_str((lhs as f64 / rhs as f64).to_string().as_str())
-                        .unwrap())
-                    .simplify()
-            }
-            (Numeric::LittleInteger(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(d128::from_str(lhs.to_string().as_str()).unwrap() / rhs)
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleInteger(rhs)) => {
-                Numeric::LittleReal(lhs / d128::from_str(rhs.to_string().as_str()).unwrap())
-                    .simplify()
-            }
-            (Numeric::LittleReal(lhs), Numeric::LittleReal(rhs)) => {
-                Numeric::LittleReal(lhs / rhs).simplify()
-            }
+        match promote(&self, &other) {
+            Promoted::NaN => Numeric::NaN,
+            Promoted::Integers(a, b) => Numeric::wide_ratio(a, b),
+            Promoted::Rationals((an, ad), (bn, bd)) => Numeric::wide_ratio(an * bd, ad * bn),
+            Promoted::Exact(_, ref b) if b.is_zero() => Numeric::NaN,
+            Promoted::Exact(a, b) => Numeric::from_big_rational(a / b),
+            Promoted::Reals(a, b) => Numeric::LittleReal(a / b),
+            Promoted::Arbitrary(a, b) => big_real::divide(&a, &b),
         }
     }
 }
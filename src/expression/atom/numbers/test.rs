@@ -92,7 +92,7 @@ mod tests {
         fn it_computes_add_real_real() {
             let x = Numeric::from("55.55");
             let y = Numeric::from("45.55");
-            assert_eq!((x - y).as_str(), "10");
+            assert_eq!((x - y).as_str(), "10.00");
         }
 
         #[test]
@@ -130,14 +130,14 @@ mod tests {
         fn it_computes_mul_int_real() {
             let x = Numeric::from("50");
             let y = Numeric::from(".5");
-            assert_eq!((x * y).as_str(), "25");
+            assert_eq!((x * y).as_str(), "25.0");
         }
 
         #[test]
         fn it_computes_mul_real_int() {
             let x = Numeric::from("55.10");
             let y = Numeric::from("10");
-            assert_eq!((x * y).as_str(), "551");
+            assert_eq!((x * y).as_str(), "551.00");
         }
     }
 
@@ -241,7 +241,7 @@ mod tests {
         fn it_computes_big_mul_real_real() {
             let x = Numeric::from("25.5");
             let y = Numeric::from("200.0");
-            assert_eq!((x * y).as_str(), "5100");
+            assert_eq!((x * y).as_str(), "5100.00");
         }
 
         #[test]
@@ -255,13 +255,100 @@ mod tests {
         }
 
         #[test]
-        fn it_casts_lte_ten_lhs() {
+        fn it_doesnt_cast_past_an_integer() {
+            let x = Numeric::from("2.5E+30");
+            let y = Numeric::from("2.0");
+            assert_eq!((x * y).as_str(), "5.00E+30");
+        }
+
+        #[test]
+        fn it_doesnt_cast_lte_ten_lhs() {
             let x = Numeric::from("2100000000.0");
             let y = Numeric::from("2000000000000000000000000000.0");
             let z = Numeric::from("2000000000000000000000000000.0");
             let a = x * y;
             let b = a / z;
-            assert_eq!((b).as_str(), "2100000000");
+            assert_eq!((b).as_str(), "2.10000E+9");
+            assert_eq!((b).head_name(), "Real");
+        }
+    }
+
+    mod low_sized_rationals {
+        use expression::atom::numbers::number::Numeric;
+        #[test]
+        fn it_divides_ints_exactly() {
+            let x = Numeric::from("3");
+            let y = Numeric::from("6");
+            assert_eq!((x.clone() / y.clone()).as_str(), "Rational[1, 2]");
+            assert_eq!((x / y).head_name(), "Rational");
+        }
+
+        #[test]
+        fn it_collapses_to_int() {
+            let x = Numeric::ratio(1, 3);
+            assert_eq!((x.clone() + x.clone() + x).as_str(), "1");
+        }
+
+        #[test]
+        fn it_mixes_with_reals() {
+            let x = Numeric::ratio(1, 4);
+            let y = Numeric::from("0.5");
+            assert_eq!((x.clone() + y.clone()).as_str(), "0.75");
+            assert!(x < y);
+        }
+
+        #[test]
+        fn it_raises_to_int_powers() {
+            let x = Numeric::ratio(2, 3);
+            assert_eq!(x.power(Numeric::LittleInteger(-2)).unwrap().as_str(), "Rational[9, 4]");
+            assert_eq!(Numeric::from("2").power(Numeric::ratio(1, 2)), None);
+        }
+
+        #[test]
+        fn it_doesnt_divide_by_zero() {
+            assert_eq!(Numeric::from("1") / Numeric::from("0"), Numeric::NaN);
+        }
+    }
+
+    mod big_sized_tests {
+        use expression::atom::numbers::number::Numeric;
+
+        #[test]
+        fn it_promotes_on_overflow() {
+            let x = Numeric::from("9223372036854775807");
+            assert_eq!((x.clone() + Numeric::from("1")).as_str(), "9223372036854775808");
+            assert_eq!((x.clone() * Numeric::from("2")).as_str(), "18446744073709551614");
+            assert_eq!((Numeric::from("-9223372036854775808") - Numeric::from("1")).as_str(), "-9223372036854775809");
+            assert_eq!((x + Numeric::from("1")).head_name(), "Integer");
+        }
+
+        #[test]
+        fn it_parses_big_integers_exactly() {
+            let x = Numeric::from("123456789012345678901234567890");
+            assert_eq!(x.as_str(), "123456789012345678901234567890");
+            assert_eq!(x.head_name(), "Integer");
+        }
+
+        #[test]
+        fn it_demotes_when_it_fits() {
+            let x = Numeric::from("18446744073709551616");
+            assert_eq!(x.clone() / Numeric::from("4294967296"), Numeric::LittleInteger(4294967296));
+            assert_eq!(x.clone() - x, Numeric::LittleInteger(0));
+        }
+
+        #[test]
+        fn it_keeps_big_rationals_exact() {
+            let x = Numeric::ratio(1, 9223372036854775807) + Numeric::ratio(1, 9223372036854775806);
+            assert_eq!(x.as_str(), "Rational[18446744073709551613, 85070591730234615838173535747377725442]");
+            assert_eq!(x.head_name(), "Rational");
+        }
+
+        #[test]
+        fn it_raises_past_machine_integers() {
+            let x = Numeric::from("2").power(Numeric::from("70")).unwrap();
+            assert_eq!(x.as_str(), "1180591620717411303424");
+            assert!(x > Numeric::from("1E+21"));
         }
     }
 }
+
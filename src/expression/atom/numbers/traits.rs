@@ -1,9 +1,11 @@
 use expression::atom::numbers::number::Numeric;
+use parsing::utilities::numerics::numeral;
 
 extern crate decimal;
 use decimal::d128;
 
 extern crate num;
+use self::num::BigInt;
 use std::str::FromStr;
 
 int_explicit_conversion!(i8, Numeric, Numeric::LittleInteger);
@@ -21,16 +23,15 @@ impl<'a> From<&'a str> for Numeric {
     fn from(s: &str) -> Numeric {
         match s.parse::<i64>() {
             Ok(num) => Numeric::LittleInteger(num),
+            Err(_) if s.chars().all(|c| c.is_digit(10) || c == '-') => {
+                // Integers past an i64 stay exact.
+                BigInt::from_str(s).map(Numeric::from_big_integer).unwrap_or(Numeric::NaN)
+            }
             Err(_) => {
                 match d128::from_str(s) {
-                    Ok(num) => {
-                        if num.to_string() != "NaN" {
-                            Numeric::LittleReal(num)
-                        } else {
-                            Numeric::NaN
-                        }
-                    }
-                    Err(_) => Numeric::NaN,
+                    Ok(num) if num.to_string() != "NaN" => Numeric::LittleReal(num),
+                    // Mathematica numerals such as 1.5*^10 or 16^^FF.
+                    _ => numeral(s).unwrap_or(Numeric::NaN),
                 }
             }
         }
@@ -39,20 +40,6 @@ impl<'a> From<&'a str> for Numeric {
 
 impl From<String> for Numeric {
     fn from(s: String) -> Numeric {
-        match s.parse::<i64>() {
-            Ok(num) => Numeric::LittleInteger(num),
-            Err(_) => {
-                match d128::from_str(s.as_str()) {
-                    Ok(num) => {
-                        if num.to_string() != "NaN" {
-                            Numeric::LittleReal(num)
-                        } else {
-                            Numeric::NaN
-                        }
-                    }
-                    Err(_) => Numeric::NaN,
-                }
-            }
-        }
+        Numeric::from(s.as_str())
     }
 }
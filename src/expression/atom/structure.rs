@@ -29,6 +29,17 @@ impl fmt::Debug for SimplexAtom {
     }
 }
 
+impl SimplexAtom {
+    // The symbol Head[ ... ] reports for an atom, atoms being their own head internally.
+    pub fn head_name(&self) -> &'static str {
+        match self {
+            &SimplexAtom::SimplexSymbol(_) => "Symbol",
+            &SimplexAtom::SimplexString(_) => "String",
+            &SimplexAtom::SimplexNumeric(ref n) => n.head_name(),
+        }
+    }
+}
+
 impl BaseExpression for SimplexAtom {
     fn get_head(&self) -> Option<SimplexPointer> {
         Some(SimplexPointer::from(self.clone()))
@@ -38,6 +49,18 @@ impl BaseExpression for SimplexAtom {
         None
     }
 
+    fn get_part(&self, _n: usize) -> Option<SimplexPointer> {
+        None
+    }
+
+    fn get_length(&self) -> usize {
+        0
+    }
+
+    fn get_atom(&self) -> Option<SimplexAtom> {
+        Some(self.clone())
+    }
+
     fn to_string(&self) -> String {
         match self {
             &SimplexAtom::SimplexNumeric(ref n) => n.to_string().clone(),
@@ -70,6 +70,18 @@ impl BaseExpression for SimplexFunction {
         Some(SimplexPointer::from(x))
     }
 
+    fn get_part(&self, n: usize) -> Option<SimplexPointer> {
+        if n == 0 {
+            self.get_head()
+        } else {
+            self.s_expression.get_part(n)
+        }
+    }
+
+    fn get_length(&self) -> usize {
+        self.s_expression.get_length()
+    }
+
     fn to_string(&self) -> String {
         format!("{}[{}] := {}", self.get_head().unwrap().as_str(), self.m_vars_to_string(), self.s_expression.to_string())
     }
@@ -0,0 +1,158 @@
+// Timings for the operations the contiguous body was written for, run on the
+// LinkedList body it replaced and on the current one side by side. Nothing
+// here goes through the evaluator. Run with:
+//
+//     cargo test --release bench -- --ignored --nocapture
+#[cfg(test)]
+mod benches {
+    mod list_bodies {
+        use std::collections::LinkedList;
+        use std::time::{Duration, Instant};
+
+        use expression::list::structure::SimplexList;
+        use expression::atom::structure::SimplexAtom;
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::integer;
+
+        const SIZE: i64 = 20000;
+
+        fn time<F: FnMut()>(label: &str, mut workload: F) -> Duration {
+            let start = Instant::now();
+            workload();
+            let elapsed = start.elapsed();
+
+            println!("{:<36} {:>10.3} ms", label, elapsed.as_secs() as f64 * 1e3 + elapsed.subsec_nanos() as f64 / 1e6);
+            elapsed
+        }
+
+        // The old SimplexList body, kept to the parts timed here: a head and
+        // a LinkedList that indexing walks and every copy clones.
+        #[derive(Clone)]
+        struct LinkedBody {
+            head: SimplexAtom,
+            expressions: LinkedList<SimplexPointer>,
+        }
+
+        impl LinkedBody {
+            fn new(head_name: &str) -> LinkedBody {
+                LinkedBody { head: SimplexAtom::from(head_name), expressions: LinkedList::new() }
+            }
+
+            fn push_all(mut self, es: &[SimplexPointer]) -> LinkedBody {
+                for e in es {
+                    self.expressions.push_back(e.clone());
+                }
+                self
+            }
+
+            fn len(&self) -> usize {
+                self.expressions.len()
+            }
+
+            fn pop_back(mut self) -> LinkedBody {
+                self.expressions.pop_back();
+                self
+            }
+
+            fn pop_front(mut self) -> LinkedBody {
+                self.expressions.pop_front();
+                self
+            }
+
+            fn get_part(&self, n: usize) -> Option<SimplexPointer> {
+                if n == 0 {
+                    Some(SimplexPointer::from(self.head.clone()))
+                } else {
+                    self.expressions.iter().nth(n - 1).cloned()
+                }
+            }
+        }
+
+        fn parts(n: i64) -> Vec<SimplexPointer> {
+            (0..n).map(integer).collect()
+        }
+
+        #[test]
+        #[ignore]
+        fn bench_get_part() {
+            let parts = parts(SIZE);
+            let linked = LinkedBody::new("List").push_all(&parts);
+            let contiguous = SimplexList::new("List").push_all(&parts);
+
+            let mut linked_found = 0;
+            let mut contiguous_found = 0;
+
+            time("get_part (LinkedList)", || {
+                for i in 1..(SIZE as usize + 1) {
+                    linked_found = linked_found + linked.get_part(i).map_or(0, |_| 1);
+                }
+            });
+
+            time("get_part (contiguous)", || {
+                for i in 1..(SIZE as usize + 1) {
+                    contiguous_found = contiguous_found + contiguous.get_part(i).map_or(0, |_| 1);
+                }
+            });
+
+            assert_eq!(linked_found, SIZE);
+            assert_eq!(contiguous_found, SIZE);
+        }
+
+        // with_parts builds a body of the same head around new parts, as
+        // every Map and element-wise rewrite does once per list.
+        #[test]
+        #[ignore]
+        fn bench_with_parts() {
+            let parts = parts(SIZE);
+            let head = SimplexPointer::from(SimplexAtom::from("List"));
+            let rounds = 200;
+
+            time("with_parts (LinkedList)", || {
+                for _ in 0..rounds {
+                    assert_eq!(LinkedBody::new("List").push_all(&parts).len(), SIZE as usize);
+                }
+            });
+
+            time("with_parts (contiguous)", || {
+                for _ in 0..rounds {
+                    assert_eq!(SimplexList::with_head(&head).push_all(&parts).len(), SIZE as usize);
+                }
+            });
+        }
+
+        // Every window of a list that is kept, as Rest and Most walks take
+        // them: the old body copies the whole list before each pop.
+        #[test]
+        #[ignore]
+        fn bench_slicing() {
+            let parts = parts(SIZE / 4);
+            let linked = LinkedBody::new("List").push_all(&parts);
+            let contiguous = SimplexList::new("List").push_all(&parts);
+
+            time("rest and most windows (LinkedList)", || {
+                let mut rest = linked.clone();
+                let mut most = linked.clone();
+
+                while rest.len() > 0 {
+                    rest = rest.clone().pop_front();
+                    most = most.clone().pop_back();
+                }
+
+                assert_eq!(most.len(), 0);
+            });
+
+            time("rest and most windows (contiguous)", || {
+                let mut rest = contiguous.clone();
+                let mut most = contiguous.clone();
+
+                while rest.len() > 0 {
+                    rest = rest.clone().pop_front();
+                    most = most.clone().pop_back();
+                }
+
+                assert_eq!(most.len(), 0);
+            });
+        }
+    }
+}
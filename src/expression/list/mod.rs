@@ -1,2 +1,3 @@
 pub mod structure;
 pub mod test;
+pub mod bench;
@@ -1,5 +1,5 @@
 use std::fmt;
-use std::collections::LinkedList;
+use std::rc::Rc;
 
 use expression::traits::BaseExpression;
 use expression::structure::SimplexPointer;
@@ -8,10 +8,15 @@ use expression::atom::structure::SimplexAtom;
 use parsing::utilities::symbols::representable_symbol;
 
 // SExpression == SimplexList
+// The body is a window [start, end) onto a shared vector: get_rest and the
+// pops only move the window, and pushes copy the vector only when it is shared
+// or the window no longer covers all of it.
 #[derive(Clone)]
 pub struct SimplexList {
-    pub head: SimplexAtom,
-    expressions: LinkedList<SimplexPointer>,
+    pub head: SimplexPointer,
+    expressions: Rc<Vec<SimplexPointer>>,
+    start: usize,
+    end: usize,
 }
 
 impl fmt::Debug for SimplexList {
@@ -24,8 +29,10 @@ impl SimplexList {
     pub fn new(head_name: &str) -> SimplexList {
         if representable_symbol(head_name) {
             SimplexList {
-                head: SimplexAtom::from(head_name),
-                expressions: LinkedList::new(),
+                head: SimplexPointer::from(SimplexAtom::SimplexSymbol(head_name.to_string())),
+                expressions: Rc::new(Vec::new()),
+                start: 0,
+                end: 0,
             }
         } else {
             // Implement Error Type 
@@ -33,22 +40,66 @@ impl SimplexList {
         }
     }
 
+    // For heads that are expressions themselves, as in Derivative[1][f].
+    pub fn with_head(head: &SimplexPointer) -> SimplexList {
+        SimplexList {
+            head: head.clone(),
+            expressions: Rc::new(Vec::new()),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    fn with_body(&self, expressions: Vec<SimplexPointer>) -> SimplexList {
+        SimplexList {
+            head: self.head.clone(),
+            start: 0,
+            end: expressions.len(),
+            expressions: Rc::new(expressions),
+        }
+    }
+
+    fn body_mut(&mut self) -> &mut Vec<SimplexPointer> {
+        if self.start != 0 || self.end != self.expressions.len() {
+            self.expressions = Rc::new(self.as_slice().to_vec());
+            self.start = 0;
+            self.end = self.expressions.len();
+        }
+
+        Rc::make_mut(&mut self.expressions)
+    }
+
     pub fn push(mut self, e: &SimplexPointer) -> SimplexList {
-        self.expressions.push_back(e.clone());
+        self.body_mut().push(e.clone());
+        self.end = self.end + 1;
+        self
+    }
+
+    pub fn push_all(mut self, es: &[SimplexPointer]) -> SimplexList {
+        self.body_mut().extend_from_slice(es);
+        self.end = self.end + es.len();
         self
     }
 
     pub fn len(&self) -> usize {
-        self.expressions.len()
+        self.end - self.start
+    }
+
+    pub fn as_slice(&self) -> &[SimplexPointer] {
+        &self.expressions[self.start..self.end]
     }
 
     pub fn pop_back(mut self) -> SimplexList {
-        self.expressions.pop_back();
+        if self.end > self.start {
+            self.end = self.end - 1;
+        }
         self
     }
 
     pub fn pop_front(mut self) -> SimplexList {
-        self.expressions.pop_front();
+        if self.start < self.end {
+            self.start = self.start + 1;
+        }
         self
     }
 
@@ -57,12 +108,12 @@ impl SimplexList {
     // that ability to calculate its own length and use that instead of an approximation.
 
     let delimiter = ", ";
-    let mut body = String::with_capacity(self.expressions.len() * 5);
+    let mut body = String::with_capacity(self.len() * 5);
 
-    for (entry_number, entry) in self.expressions.iter().enumerate() {
+    for (entry_number, entry) in self.as_slice().iter().enumerate() {
         body.push_str(&entry.as_str());
 
-        if entry_number != (self.expressions.len() - 1) {
+        if entry_number != (self.len() - 1) {
             body.push_str(delimiter);
         }
     }
@@ -73,20 +124,34 @@ impl SimplexList {
 
 impl BaseExpression for SimplexList {
     fn get_head(&self) -> Option<SimplexPointer> {
-        Some(SimplexPointer::from(self.head.clone()))
+        Some(self.head.clone())
     }
 
     fn get_rest(&self) -> Option<SimplexPointer> {
         println!("[Get Rest] Begin..");
-        let mut new_list = self.expressions.clone();
-        new_list.pop_front();
-
-        if new_list.len() == 0 {
+        if self.len() <= 1 {
             None
         } else {
-            Some(SimplexPointer::from(SimplexList{head: SimplexAtom::from("List"), expressions: new_list}))
+            Some(SimplexPointer::from(SimplexList {
+                head: SimplexPointer::from(SimplexAtom::SimplexSymbol("List".to_string())),
+                expressions: self.expressions.clone(),
+                start: self.start + 1,
+                end: self.end,
+            }))
+        }
+
+    }
+
+    fn get_part(&self, n: usize) -> Option<SimplexPointer> {
+        if n == 0 {
+            self.get_head()
+        } else {
+            self.as_slice().get(n - 1).cloned()
         }
+    }
 
+    fn get_length(&self) -> usize {
+        self.len()
     }
 
     fn to_string(&self) -> String {
@@ -95,23 +160,12 @@ impl BaseExpression for SimplexList {
     }
 
     fn replace_symbol(&self, symbol: &BaseExpression, new: &BaseExpression) -> SimplexPointer {
-        SimplexPointer::from(
-            SimplexList {
-                head: self.head.clone(),
-                expressions: self.expressions.iter().map(|x| x.replace_symbol(symbol, new)).collect::<LinkedList<SimplexPointer>>(),
-            }
-        )
+        let expressions = self.as_slice().iter().map(|x| x.replace_symbol(symbol, new)).collect::<Vec<SimplexPointer>>();
+        SimplexPointer::from(self.with_body(expressions))
     }
 
     fn evaluate(&self, v: &Vec<SimplexPointer>) -> SimplexPointer {
-        let mut new_list = self.clone();
-
-        for i in &mut new_list.expressions {
-            let mut changed = i.clone();
-            changed = changed.evaluate(v);
-            *i = changed;
-        }
-
-        SimplexPointer::from(new_list)
+        let expressions = self.as_slice().iter().map(|x| x.evaluate(v)).collect::<Vec<SimplexPointer>>();
+        SimplexPointer::from(self.with_body(expressions))
     }
 }
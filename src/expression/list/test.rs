@@ -425,4 +425,59 @@ mod test_intrinsics {
             assert_eq!(list_e.as_str(), "List[2, List[2, List[3, List[\"Hello\"]], HelloWorld], List[2, List[3, List[\"Hello\"]], HelloWorld]]")
         }
     }
+
+    mod test_contiguous_storage {
+        use expression::list::structure::SimplexList;
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+
+        #[test]
+        fn it_indexes_parts() {
+            let s_exp = SimplexList::new("List")
+                .push(&SimplexPointer::from("x"))
+                .push(&SimplexPointer::from("y"))
+                .push(&SimplexPointer::from("z"));
+
+            assert_eq!(s_exp.get_part(0).unwrap().as_str(), "List");
+            assert_eq!(s_exp.get_part(2).unwrap().as_str(), "y");
+            assert_eq!(s_exp.get_part(4), None);
+            assert_eq!(s_exp.get_length(), 3);
+        }
+
+        #[test]
+        fn it_pushes_onto_clones_independently() {
+            let a = SimplexList::new("List")
+                .push(&SimplexPointer::from("x"));
+
+            let b = a.clone().push(&SimplexPointer::from("y"));
+
+            assert_eq!(a.as_str(), "List[x]");
+            assert_eq!(b.as_str(), "List[x, y]");
+        }
+
+        #[test]
+        fn it_pushes_onto_popped_windows() {
+            let a = SimplexList::new("List")
+                .push(&SimplexPointer::from("x"))
+                .push(&SimplexPointer::from("y"))
+                .push(&SimplexPointer::from("z"));
+
+            let b = a.clone().pop_front().pop_back().push(&SimplexPointer::from("w"));
+
+            assert_eq!(b.as_str(), "List[y, w]");
+            assert_eq!(a.as_str(), "List[x, y, z]");
+        }
+
+        #[test]
+        fn it_pops_past_empty() {
+            let a = SimplexList::new("List")
+                .push(&SimplexPointer::from("x"))
+                .pop_back()
+                .pop_back()
+                .pop_front();
+
+            assert_eq!(a.len(), 0);
+            assert_eq!(a.as_str(), "List[]");
+        }
+    }
 }
@@ -1,9 +1,12 @@
 pub mod atom;
 pub mod list;
 pub mod function;
+pub mod packed;
+pub mod association;
 
 pub mod traits;
 pub mod structure;
+pub mod ordering;
 
 pub mod macros;
 pub mod test;
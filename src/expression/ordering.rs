@@ -0,0 +1,52 @@
+use std::cmp::Ordering;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::structure::SimplexAtom;
+
+// Sort[ ... ] order: numbers by value, then strings, then symbols, then
+// compound expressions; shorter expressions before longer ones, then by head
+// and finally part by part.
+pub fn canonical_order(lhs: &SimplexPointer, rhs: &SimplexPointer) -> Ordering {
+    match (lhs.get_atom(), rhs.get_atom()) {
+        (Some(a), Some(b)) => atom_order(&a, &b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => {
+            lhs.get_length().cmp(&rhs.get_length())
+                .then_with(|| lhs.head_name().cmp(&rhs.head_name()))
+                .then_with(|| {
+                    for (a, b) in lhs.get_parts().iter().zip(rhs.get_parts().iter()) {
+                        match canonical_order(a, b) {
+                            Ordering::Equal => {}
+                            unequal => return unequal,
+                        }
+                    }
+
+                    Ordering::Equal
+                })
+        }
+    }
+}
+
+fn atom_rank(a: &SimplexAtom) -> u8 {
+    match a {
+        &SimplexAtom::SimplexNumeric(_) => 0,
+        &SimplexAtom::SimplexString(_) => 1,
+        &SimplexAtom::SimplexSymbol(_) => 2,
+    }
+}
+
+fn atom_order(lhs: &SimplexAtom, rhs: &SimplexAtom) -> Ordering {
+    match (lhs, rhs) {
+        (&SimplexAtom::SimplexNumeric(ref a), &SimplexAtom::SimplexNumeric(ref b)) => {
+            a.partial_cmp(b).unwrap_or_else(|| a.to_string().cmp(&b.to_string()))
+        }
+        (&SimplexAtom::SimplexString(ref a), &SimplexAtom::SimplexString(ref b)) |
+        (&SimplexAtom::SimplexSymbol(ref a), &SimplexAtom::SimplexSymbol(ref b)) => {
+            // Alphabetical first, lower case ahead of upper case on ties.
+            a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| b.cmp(a))
+        }
+        _ => atom_rank(lhs).cmp(&atom_rank(rhs)),
+    }
+}
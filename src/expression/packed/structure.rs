@@ -0,0 +1,324 @@
+use std::fmt;
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+use expression::atom::structure::SimplexAtom;
+use expression::atom::numbers::number::Numeric;
+
+#[derive(Clone)]
+pub enum PackedData {
+    Integers(Rc<Vec<i64>>),
+    Reals(Rc<Vec<d128>>),
+}
+
+// A rectangular List of machine integers or machine reals, stored flat in
+// row major order. Sub-arrays share the storage and only move the offset.
+#[derive(Clone)]
+pub struct SimplexPackedArray {
+    dimensions: Vec<usize>,
+    data: PackedData,
+    offset: usize,
+}
+
+impl fmt::Debug for SimplexPackedArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SimplexPackedArray: {:?}", self.dimensions)
+    }
+}
+
+impl SimplexPackedArray {
+    // A vector of however many values there are, None when there are none.
+    // Integers stay integers unless a real is among them, NaN can't be packed.
+    pub fn from_vector<I>(values: I) -> Option<SimplexPackedArray>
+        where I: IntoIterator<Item = Numeric>
+    {
+        let mut storage = Storage::Integers(Vec::new());
+
+        for v in values {
+            if !storage.push(v) {
+                return None;
+            }
+        }
+
+        match storage.len() {
+            0 => None,
+            n => Some(SimplexPackedArray::with_storage(vec![n], storage)),
+        }
+    }
+
+    // Packs a rectangular List whose leaves are all machine numbers.
+    pub fn from_expression(e: &SimplexPointer) -> Option<SimplexPackedArray> {
+        if let Some(packed) = e.get_packed() {
+            return Some(packed.clone());
+        }
+
+        let mut dimensions = Vec::new();
+        let mut probe = e.clone();
+
+        while !probe.is_atomic() && probe.head_name() == "List" {
+            dimensions.push(probe.get_length());
+
+            probe = match probe.get_part(1) {
+                Some(p) => p,
+                None => break,
+            };
+        }
+
+        if dimensions.is_empty() {
+            return None;
+        }
+
+        let mut storage = Storage::Integers(Vec::with_capacity(dimensions.iter().product()));
+
+        if collect_leaves(e, &dimensions, &mut storage) {
+            Some(SimplexPackedArray::with_storage(dimensions, storage))
+        } else {
+            None
+        }
+    }
+
+    fn with_storage(dimensions: Vec<usize>, storage: Storage) -> SimplexPackedArray {
+        let data = match storage {
+            Storage::Integers(v) => PackedData::Integers(Rc::new(v)),
+            Storage::Reals(v) => PackedData::Reals(Rc::new(v)),
+        };
+
+        SimplexPackedArray {
+            dimensions: dimensions,
+            data: data,
+            offset: 0,
+        }
+    }
+
+    pub fn dimensions(&self) -> &[usize] {
+        &self.dimensions
+    }
+
+    pub fn size(&self) -> usize {
+        self.dimensions.iter().product()
+    }
+
+    pub fn is_real(&self) -> bool {
+        match self.data {
+            PackedData::Reals(_) => true,
+            PackedData::Integers(_) => false,
+        }
+    }
+
+    pub fn element(&self, i: usize) -> Numeric {
+        match self.data {
+            PackedData::Integers(ref v) => Numeric::LittleInteger(v[self.offset + i]),
+            PackedData::Reals(ref v) => Numeric::LittleReal(v[self.offset + i]),
+        }
+    }
+
+    // The elements of this array proper, sub-arrays only seeing their rows.
+    fn integers(&self) -> Option<&[i64]> {
+        match self.data {
+            PackedData::Integers(ref v) => Some(&v[self.offset..self.offset + self.size()]),
+            PackedData::Reals(_) => None,
+        }
+    }
+
+    fn reals<'a>(&'a self) -> Cow<'a, [d128]> {
+        match self.data {
+            PackedData::Integers(ref v) => Cow::Owned(v[self.offset..self.offset + self.size()].iter().map(|&i| d128::from(i)).collect()),
+            PackedData::Reals(ref v) => Cow::Borrowed(&v[self.offset..self.offset + self.size()]),
+        }
+    }
+
+    // The zero based nth row: an atom for vectors, a sub-array otherwise.
+    fn row(&self, n: usize) -> SimplexPointer {
+        if self.dimensions.len() == 1 {
+            return SimplexPointer::from(SimplexAtom::SimplexNumeric(self.element(n)));
+        }
+
+        let stride = self.dimensions[1..].iter().product::<usize>();
+
+        SimplexPointer::from(SimplexPackedArray {
+            dimensions: self.dimensions[1..].to_vec(),
+            data: self.data.clone(),
+            offset: self.offset + n * stride,
+        })
+    }
+
+    pub fn unpack(&self) -> SimplexPointer {
+        let rows = (0..self.dimensions[0])
+            .map(|n| {
+                let row = self.row(n);
+                let unpacked = row.get_packed().map(|p| p.unpack());
+                unpacked.unwrap_or(row)
+            })
+            .collect::<Vec<SimplexPointer>>();
+
+        SimplexPointer::from(SimplexList::new("List").push_all(&rows))
+    }
+
+    // Element-wise combination of two arrays of the same shape, over the
+    // integers when both are integer arrays and over the reals otherwise.
+    // None as soon as an op gives up on an element, overflowing integers
+    // included.
+    pub fn zip_with<I, R>(&self, other: &SimplexPackedArray, integers: I, reals: R) -> Option<SimplexPackedArray>
+        where I: Fn(i64, i64) -> Option<i64>,
+              R: Fn(d128, d128) -> Option<d128>
+    {
+        if self.dimensions != other.dimensions {
+            return None;
+        }
+
+        let storage = match (self.integers(), other.integers()) {
+            (Some(a), Some(b)) => Storage::Integers(a.iter().zip(b).map(|(&x, &y)| integers(x, y)).collect::<Option<Vec<i64>>>()?),
+            _ => Storage::Reals(self.reals().iter().zip(other.reals().iter()).map(|(&x, &y)| reals(x, y)).collect::<Option<Vec<d128>>>()?),
+        };
+
+        Some(SimplexPackedArray::with_storage(self.dimensions.clone(), storage))
+    }
+
+    // Combines every element with the machine number n, the element on the
+    // left. None for any other kind of number.
+    pub fn map_with<I, R>(&self, n: &Numeric, integers: I, reals: R) -> Option<SimplexPackedArray>
+        where I: Fn(i64, i64) -> Option<i64>,
+              R: Fn(d128, d128) -> Option<d128>
+    {
+        let storage = match (self.integers(), n) {
+            (Some(a), &Numeric::LittleInteger(j)) => Storage::Integers(a.iter().map(|&x| integers(x, j)).collect::<Option<Vec<i64>>>()?),
+            (_, &Numeric::LittleInteger(j)) => Storage::Reals(self.reals().iter().map(|&x| reals(x, d128::from(j))).collect::<Option<Vec<d128>>>()?),
+            (_, &Numeric::LittleReal(r)) => Storage::Reals(self.reals().iter().map(|&x| reals(x, r)).collect::<Option<Vec<d128>>>()?),
+            _ => return None,
+        };
+
+        Some(SimplexPackedArray::with_storage(self.dimensions.clone(), storage))
+    }
+
+    pub fn same_elements(&self, other: &SimplexPackedArray) -> bool {
+        if self.dimensions != other.dimensions {
+            return false;
+        }
+
+        match (self.integers(), other.integers()) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => self.reals() == other.reals(),
+            _ => false,
+        }
+    }
+}
+
+// Packed storage as it fills up: integers until the first real, which turns
+// everything so far into reals.
+enum Storage {
+    Integers(Vec<i64>),
+    Reals(Vec<d128>),
+}
+
+impl Storage {
+    fn len(&self) -> usize {
+        match *self {
+            Storage::Integers(ref v) => v.len(),
+            Storage::Reals(ref v) => v.len(),
+        }
+    }
+
+    // False for anything without a machine representation, rationals and
+    // big numbers included.
+    fn push(&mut self, n: Numeric) -> bool {
+        let converted = match (&mut *self, n) {
+            (&mut Storage::Integers(ref mut v), Numeric::LittleInteger(i)) => {
+                v.push(i);
+                return true;
+            }
+            (&mut Storage::Reals(ref mut v), Numeric::LittleInteger(i)) => {
+                v.push(d128::from(i));
+                return true;
+            }
+            (&mut Storage::Reals(ref mut v), Numeric::LittleReal(r)) => {
+                v.push(r);
+                return true;
+            }
+            (&mut Storage::Integers(ref v), Numeric::LittleReal(r)) => {
+                let mut reals = v.iter().map(|&i| d128::from(i)).collect::<Vec<d128>>();
+                reals.push(r);
+                reals
+            }
+            _ => return false,
+        };
+
+        *self = Storage::Reals(converted);
+        true
+    }
+}
+
+fn collect_leaves(e: &SimplexPointer, dimensions: &[usize], storage: &mut Storage) -> bool {
+    match dimensions.split_first() {
+        None => {
+            match e.as_numeric() {
+                Some(n) => storage.push(n),
+                None => false,
+            }
+        }
+        Some((&length, rest)) => {
+            if e.is_atomic() || e.head_name() != "List" || e.get_length() != length {
+                return false;
+            }
+
+            e.get_parts().iter().all(|p| collect_leaves(p, rest, storage))
+        }
+    }
+}
+
+impl BaseExpression for SimplexPackedArray {
+    fn get_head(&self) -> Option<SimplexPointer> {
+        Some(SimplexPointer::from(SimplexAtom::SimplexSymbol("List".to_string())))
+    }
+
+    fn get_rest(&self) -> Option<SimplexPointer> {
+        if self.dimensions[0] <= 1 {
+            return None;
+        }
+
+        let stride = self.dimensions[1..].iter().product::<usize>();
+        let mut dimensions = self.dimensions.clone();
+        dimensions[0] = dimensions[0] - 1;
+
+        Some(SimplexPointer::from(SimplexPackedArray {
+            dimensions: dimensions,
+            data: self.data.clone(),
+            offset: self.offset + stride,
+        }))
+    }
+
+    fn get_part(&self, n: usize) -> Option<SimplexPointer> {
+        if n == 0 {
+            self.get_head()
+        } else if n <= self.dimensions[0] {
+            Some(self.row(n - 1))
+        } else {
+            None
+        }
+    }
+
+    fn get_length(&self) -> usize {
+        self.dimensions[0]
+    }
+
+    fn as_packed(&self) -> Option<&SimplexPackedArray> {
+        Some(self)
+    }
+
+    fn to_string(&self) -> String {
+        let rows = (0..self.dimensions[0]).map(|n| self.row(n).as_str().into_owned()).collect::<Vec<String>>();
+        format!("List[{}]", rows.join(", "))
+    }
+
+    fn replace_symbol(&self, _symbol: &BaseExpression, _new: &BaseExpression) -> SimplexPointer {
+        SimplexPointer::from(self.clone())
+    }
+
+    fn evaluate(&self, _v: &Vec<SimplexPointer>) -> SimplexPointer {
+        SimplexPointer::from(self.clone())
+    }
+}
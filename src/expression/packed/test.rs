@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    mod test_packing {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use expression::packed::structure::SimplexPackedArray;
+        use expression::atom::numbers::number::Numeric;
+        use evaluation::utilities::{list, integer, symbol};
+
+        #[test]
+        fn it_packs_integer_vectors() {
+            let l = list(&[integer(1), integer(2), integer(3)]);
+            let packed = SimplexPackedArray::from_expression(&l).unwrap();
+
+            assert_eq!(packed.dimensions(), &[3]);
+            assert!(!packed.is_real());
+            assert_eq!(packed.as_str(), "List[1, 2, 3]");
+        }
+
+        #[test]
+        fn it_packs_mixed_numbers_as_reals() {
+            let l = list(&[integer(1), SimplexPointer::from("2.5")]);
+            let packed = SimplexPackedArray::from_expression(&l).unwrap();
+
+            assert!(packed.is_real());
+            assert_eq!(packed.as_str(), "List[1, 2.5]");
+        }
+
+        #[test]
+        fn it_packs_matrices() {
+            let m = list(&[list(&[integer(1), integer(2)]), list(&[integer(3), integer(4)])]);
+            let packed = SimplexPointer::from(SimplexPackedArray::from_expression(&m).unwrap());
+
+            assert_eq!(packed.get_packed().unwrap().dimensions(), &[2, 2]);
+            assert_eq!(packed.get_part(2).unwrap().as_str(), "List[3, 4]");
+            assert!(packed.get_part(2).unwrap().get_packed().is_some());
+            assert_eq!(packed.get_rest().unwrap().as_str(), "List[List[3, 4]]");
+        }
+
+        #[test]
+        fn it_refuses_ragged_or_symbolic_lists() {
+            let ragged = list(&[list(&[integer(1), integer(2)]), list(&[integer(3)])]);
+            let symbolic = list(&[integer(1), symbol("x")]);
+
+            assert!(SimplexPackedArray::from_expression(&ragged).is_none());
+            assert!(SimplexPackedArray::from_expression(&symbolic).is_none());
+            assert!(SimplexPackedArray::from_vector(vec![Numeric::NaN]).is_none());
+        }
+
+        #[test]
+        fn it_unpacks() {
+            let m = list(&[list(&[integer(1)]), list(&[integer(2)])]);
+            let unpacked = SimplexPackedArray::from_expression(&m).unwrap().unpack();
+
+            assert!(unpacked.get_packed().is_none());
+            assert_eq!(unpacked, m);
+        }
+    }
+
+    mod test_packed_behaviour {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        #[test]
+        fn it_ranges_packed() {
+            let r = evaluate(&expression("Range", &[integer(5)]));
+            assert!(r.get_packed().is_some());
+            assert_eq!(evaluate(&expression("PackedArrayQ", &[r])).as_str(), "True");
+        }
+
+        #[test]
+        fn it_adds_element_wise() {
+            let r = evaluate(&expression("Range", &[integer(3)]));
+            let sum = evaluate(&expression("Total", &[list(&[r.clone(), r.clone()])]));
+
+            assert!(sum.get_packed().is_some());
+            assert_eq!(sum.as_str(), "List[2, 4, 6]");
+        }
+
+        #[test]
+        fn it_evaluates_to_itself() {
+            let r = evaluate(&expression("Range", &[integer(3)]));
+            let again = evaluate(&r);
+
+            assert!(again.get_packed().is_some());
+            assert_eq!(again, r);
+        }
+
+        #[test]
+        fn it_mixes_integers_and_reals_element_wise() {
+            let r = evaluate(&expression("Range", &[integer(3)]));
+            let half = SimplexPointer::from("0.5");
+            let sum = evaluate(&expression("Plus", &[r.clone(), half.clone()]));
+            let product = evaluate(&expression("Times", &[half, r.clone()]));
+            let square = evaluate(&expression("Power", &[r.clone(), integer(2)]));
+
+            assert!(sum.get_packed().unwrap().is_real());
+            assert_eq!(sum.as_str(), "List[1.5, 2.5, 3.5]");
+            assert!(product.get_packed().unwrap().is_real());
+            assert_eq!(square.as_str(), "List[1, 4, 9]");
+            assert!(!square.get_packed().unwrap().is_real());
+            assert_eq!(evaluate(&expression("Depth", &[r])).as_str(), "2");
+        }
+
+        #[test]
+        fn it_unpacks_on_symbolic_inserts() {
+            let r = evaluate(&expression("Range", &[integer(3)]));
+            let appended = evaluate(&expression("Append", &[r.clone(), symbol("x")]));
+            let numeric = evaluate(&expression("Append", &[r, integer(4)]));
+
+            assert!(appended.get_packed().is_none());
+            assert_eq!(appended.as_str(), "List[1, 2, 3, x]");
+            assert!(numeric.get_packed().is_some());
+        }
+
+        #[test]
+        fn it_converts_with_builtins() {
+            let l = list(&[integer(1), integer(2)]);
+            let packed = evaluate(&expression("ToPackedArray", &[l.clone()]));
+            let unpacked = evaluate(&expression("FromPackedArray", &[packed.clone()]));
+
+            assert!(packed.get_packed().is_some());
+            assert!(unpacked.get_packed().is_none());
+            assert_eq!(evaluate(&expression("PackedArrayQ", &[l])).as_str(), "False");
+            assert_eq!(evaluate(&expression("ToPackedArray", &[list(&[symbol("x")])])).as_str(), "List[x]");
+        }
+
+        #[test]
+        fn it_costs_fewer_bytes_packed() {
+            let r = evaluate(&expression("Range", &[integer(100)]));
+            let unpacked = evaluate(&expression("FromPackedArray", &[r.clone()]));
+            let packed_bytes = evaluate(&expression("ByteCount", &[r])).as_integer().unwrap();
+            let unpacked_bytes = evaluate(&expression("ByteCount", &[unpacked])).as_integer().unwrap();
+
+            assert!(packed_bytes < unpacked_bytes);
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, RwLock};
-use std::cell::RefCell;
+use std::cell::{Ref, RefCell};
 use std::rc::Rc;
 
 use expression::traits::BaseExpression;
 use expression::list::structure::SimplexList;
 use expression::function::structure::SimplexFunction;
+use expression::packed::structure::SimplexPackedArray;
+use expression::association::structure::SimplexAssociation;
 use expression::atom::structure::SimplexAtom;
+use expression::atom::numbers::number::Numeric;
 
 #[derive(Clone)]
 pub struct SimplexPointer {
@@ -19,6 +23,86 @@ impl SimplexPointer {
             internal_data: Rc::new(RefCell::new(e))
         }
     }
+
+    pub fn is_atomic(&self) -> bool {
+        self.get_atom().is_some()
+    }
+
+    // Name of the head, with atoms reporting their type like Head[ ... ] does.
+    pub fn head_name(&self) -> String {
+        match self.get_atom() {
+            Some(a) => a.head_name().to_string(),
+            None => {
+                match self.get_head() {
+                    Some(h) => h.as_str().to_string(),
+                    None => "Symbol".to_string(),
+                }
+            }
+        }
+    }
+
+    pub fn get_parts(&self) -> Vec<SimplexPointer> {
+        (1..self.get_length() + 1).filter_map(|n| self.get_part(n)).collect()
+    }
+
+    pub fn as_symbol(&self) -> Option<String> {
+        match self.get_atom() {
+            Some(SimplexAtom::SimplexSymbol(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_numeric(&self) -> Option<Numeric> {
+        match self.get_atom() {
+            Some(SimplexAtom::SimplexNumeric(n)) => Some(n),
+            _ => None,
+        }
+    }
+
+    // The packed array behind this pointer, borrowed rather than copied.
+    // as_packed itself can't see through the pointer, so this is the way in.
+    pub fn get_packed<'a>(&'a self) -> Option<Ref<'a, SimplexPackedArray>> {
+        let data = self.internal_data.borrow();
+
+        if data.as_packed().is_none() {
+            return None;
+        }
+
+        Some(Ref::map(data, |d| d.as_packed().unwrap()))
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match self.as_numeric() {
+            Some(Numeric::LittleInteger(i)) => Some(i),
+            _ => None,
+        }
+    }
+
+    // Rebuilds an expression with the same head around a new body. Packed
+    // arrays stay packed for as long as the new body allows it.
+    pub fn with_parts(&self, parts: &[SimplexPointer]) -> SimplexPointer {
+        if self.is_atomic() {
+            return self.clone();
+        }
+
+        if self.get_packed().is_some() {
+            let list = SimplexPointer::from(SimplexList::new("List").push_all(parts));
+
+            return match SimplexPackedArray::from_expression(&list) {
+                Some(packed) => SimplexPointer::from(packed),
+                None => list,
+            };
+        }
+
+        if let Some(association) = self.get_association() {
+            return association.with_parts(parts);
+        }
+
+        match self.get_head() {
+            Some(h) => SimplexPointer::from(SimplexList::with_head(&h).push_all(parts)),
+            None => self.clone(),
+        }
+    }
 }
 
 impl fmt::Debug for SimplexPointer {
@@ -36,9 +120,53 @@ impl fmt::Display for SimplexPointer {
 impl Eq for SimplexPointer {
 }
 
+// Atoms compare by their printed form, everything else by head and parts,
+// so an association equals the Association[ ... ] it was built from and a
+// packed array the list of its elements.
 impl PartialEq for SimplexPointer{
     fn eq(&self, other: &Self) -> bool {
-        *self.internal_data.borrow().to_string() == *other.internal_data.borrow().to_string()
+        if Rc::ptr_eq(&self.internal_data, &other.internal_data) {
+            return true;
+        }
+
+        if let (Some(a), Some(b)) = (self.get_packed(), other.get_packed()) {
+            return a.same_elements(&b);
+        }
+
+        if self.is_atomic() || other.is_atomic() {
+            return self.is_atomic() && other.is_atomic() && self.as_str() == other.as_str();
+        }
+
+        match (self.get_head(), other.get_head()) {
+            (Some(a), Some(b)) => {
+                let length = self.get_length();
+                a == b && length == other.get_length() && (1..length + 1).all(|n| self.get_part(n) == other.get_part(n))
+            }
+            (None, None) => self.as_str() == other.as_str(),
+            _ => false,
+        }
+    }
+}
+
+// Hashes what PartialEq compares: the printed form of atoms, and the head
+// and parts of everything else.
+impl Hash for SimplexPointer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.is_atomic() {
+            return self.as_str().hash(state);
+        }
+
+        match self.get_head() {
+            Some(h) => {
+                h.hash(state);
+                self.get_length().hash(state);
+
+                for n in 1..self.get_length() + 1 {
+                    self.get_part(n).hash(state);
+                }
+            }
+            None => self.as_str().hash(state),
+        }
     }
 }
 
@@ -51,6 +179,22 @@ impl BaseExpression for SimplexPointer {
         (*self.internal_data.borrow()).get_rest()
     }
 
+    fn get_part(&self, n: usize) -> Option<SimplexPointer> {
+        (*self.internal_data.borrow()).get_part(n)
+    }
+
+    fn get_length(&self) -> usize {
+        (*self.internal_data.borrow()).get_length()
+    }
+
+    fn get_atom(&self) -> Option<SimplexAtom> {
+        (*self.internal_data.borrow()).get_atom()
+    }
+
+    fn get_association(&self) -> Option<SimplexAssociation> {
+        (*self.internal_data.borrow()).get_association()
+    }
+
     fn to_string(&self) -> String {
         (*self.internal_data.borrow()).to_string()
     }
@@ -103,3 +247,19 @@ impl From<SimplexFunction> for SimplexPointer {
         }
     }
 }
+
+impl From<SimplexPackedArray> for SimplexPointer {
+    fn from(s: SimplexPackedArray) -> SimplexPointer {
+        SimplexPointer {
+            internal_data: Rc::new(RefCell::new(Box::new(s))),
+        }
+    }
+}
+
+impl From<SimplexAssociation> for SimplexPointer {
+    fn from(s: SimplexAssociation) -> SimplexPointer {
+        SimplexPointer {
+            internal_data: Rc::new(RefCell::new(Box::new(s))),
+        }
+    }
+}
@@ -2,11 +2,20 @@ use std::borrow::Cow;
 
 use expression::structure::SimplexPointer;
 use expression::atom::structure::SimplexAtom;
+use expression::packed::structure::SimplexPackedArray;
+use expression::association::structure::SimplexAssociation;
 
 pub trait BaseExpression {
     fn get_head(&self) -> Option<SimplexPointer>;
     fn get_rest(&self) -> Option<SimplexPointer>;
 
+    // Part zero is the head, parts one and onward are the body: Same as Part[e, n].
+    fn get_part(&self, n: usize) -> Option<SimplexPointer>;
+    fn get_length(&self) -> usize;
+    fn get_atom(&self) -> Option<SimplexAtom> { None }
+    fn as_packed(&self) -> Option<&SimplexPackedArray> { None }
+    fn get_association(&self) -> Option<SimplexAssociation> { None }
+
     fn replace_symbol(&self, symbol: &BaseExpression, new: &BaseExpression) -> SimplexPointer;
     fn evaluate(&self, v: &Vec<SimplexPointer>) -> SimplexPointer;
 
@@ -0,0 +1,336 @@
+use num::{BigInt, BigRational, Integer, Signed, Zero, One};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{integer, list, boolean, is_head};
+use arithmetic::terms::number;
+use arithmetic::canonical::thread;
+use special::combinatorial::from_numeric;
+use integers::modular::{extended_gcd, power_mod, chinese_remainder, quotient, modulo};
+use integers::primes::{is_prime, next_prime, nth_prime};
+use integers::factor::{factor_integer, euler_phi, divisors, digits, from_digits};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "GCD" => Some(gcd_builtin),
+        "LCM" => Some(lcm_builtin),
+        "ExtendedGCD" => Some(extended_gcd_builtin),
+        "Mod" => Some(mod_builtin),
+        "Quotient" => Some(quotient_builtin),
+        "PowerMod" => Some(power_mod_builtin),
+        "PrimeQ" => Some(prime_q_builtin),
+        "Prime" => Some(prime_builtin),
+        "NextPrime" => Some(next_prime_builtin),
+        "FactorInteger" => Some(factor_integer_builtin),
+        "EulerPhi" => Some(euler_phi_builtin),
+        "Divisors" => Some(divisors_builtin),
+        "IntegerDigits" => Some(integer_digits_builtin),
+        "FromDigits" => Some(from_digits_builtin),
+        "ChineseRemainder" => Some(chinese_remainder_builtin),
+        _ => None,
+    }
+}
+
+fn big(e: &SimplexPointer) -> Option<BigInt> {
+    e.as_numeric()?.as_big_integer()
+}
+
+fn bigs(es: &[SimplexPointer]) -> Option<Vec<BigInt>> {
+    es.iter().map(big).collect()
+}
+
+fn rational(e: &SimplexPointer) -> Option<BigRational> {
+    from_numeric(&e.as_numeric()?)
+}
+
+fn from_big(n: &BigInt) -> SimplexPointer {
+    number(Numeric::from_big_integer(n.clone()))
+}
+
+fn from_bigs(ns: &[BigInt]) -> SimplexPointer {
+    list(&ns.iter().map(from_big).collect::<Vec<SimplexPointer>>())
+}
+
+fn from_rational(q: &BigRational) -> SimplexPointer {
+    number(Numeric::from_big_rational(q.clone()))
+}
+
+fn lcm(a: &BigInt, b: &BigInt) -> BigInt {
+    if a.is_zero() || b.is_zero() { BigInt::zero() } else { a.lcm(b) }
+}
+
+// GCD and LCM of integers, and of rationals through their numerators and
+// denominators.
+fn gcd_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("GCD", args) {
+        return Some(threaded);
+    }
+
+    let qs = args.iter().map(rational).collect::<Option<Vec<BigRational>>>()?;
+    let numerator = qs.iter().fold(BigInt::zero(), |g, q| g.gcd(q.numer()));
+    let denominator = qs.iter().fold(BigInt::one(), |l, q| lcm(&l, q.denom()));
+
+    Some(from_rational(&BigRational::new(numerator, denominator)))
+}
+
+fn lcm_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("LCM", args) {
+        return Some(threaded);
+    }
+
+    let qs = args.iter().map(rational).collect::<Option<Vec<BigRational>>>()?;
+    let numerator = qs.iter().fold(BigInt::one(), |l, q| lcm(&l, q.numer()));
+    let denominator = qs.iter().fold(BigInt::zero(), |g, q| g.gcd(q.denom()));
+
+    if denominator.is_zero() {
+        return Some(integer(1));
+    }
+
+    Some(from_rational(&BigRational::new(numerator, denominator)))
+}
+
+// {g, {s, t}} with s a + t b = g = GCD[a, b].
+fn extended_gcd_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("ExtendedGCD", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 2 {
+        return None;
+    }
+
+    let (g, s, t) = extended_gcd(&big(&args[0])?, &big(&args[1])?);
+    Some(list(&[from_big(&g), from_bigs(&[s, t])]))
+}
+
+// Mod and Quotient of any numbers, with an optional offset.
+fn division(name: &str, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread(name, args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 2 && args.len() != 3 {
+        return None;
+    }
+
+    let (m, n) = (args[0].as_numeric()?, args[1].as_numeric()?);
+    let d = match args.get(2) {
+        Some(d) => d.as_numeric()?,
+        None => Numeric::LittleInteger(0),
+    };
+
+    let result = if name == "Mod" { modulo(m, n, d) } else { quotient(m, n, d) };
+    result.map(number)
+}
+
+fn mod_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    division("Mod", args)
+}
+
+fn quotient_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    division("Quotient", args)
+}
+
+// a^b mod m, a negative b taking the modular inverse of a.
+fn power_mod_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("PowerMod", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 3 {
+        return None;
+    }
+
+    let ns = bigs(args)?;
+    power_mod(&ns[0], &ns[1], &ns[2]).map(|n| from_big(&n))
+}
+
+// Anything but an integer is not a prime.
+fn prime_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("PrimeQ", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(boolean(big(&args[0]).map_or(false, |n| is_prime(&n))))
+}
+
+fn prime_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("Prime", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    nth_prime(args[0].as_integer()?).map(integer)
+}
+
+// NextPrime[n] and NextPrime[n, k], the k-th prime after n.
+fn next_prime_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("NextPrime", args) {
+        return Some(threaded);
+    }
+
+    let k = match args.len() {
+        1 => 1,
+        2 => args[1].as_integer()?,
+        _ => return None,
+    };
+
+    if k == 0 {
+        return None;
+    }
+
+    Some(from_big(&next_prime(&big(&args[0])?, k)))
+}
+
+fn factor_pairs(n: &BigInt, sign: i64) -> Vec<(BigInt, i64)> {
+    factor_integer(n).into_iter().map(|(p, e)| (p, sign * e as i64)).collect()
+}
+
+// {{p, e}, ...} with -1 first for negative n and negative exponents for the
+// primes of the denominator of a rational.
+fn factor_integer_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("FactorInteger", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    let q = rational(&args[0])?;
+
+    if q.is_zero() || q.abs().is_one() {
+        return Some(list(&[list(&[from_rational(&q), integer(1)])]));
+    }
+
+    let mut factors = factor_pairs(&q.numer().abs(), 1);
+    factors.extend(factor_pairs(q.denom(), -1));
+    factors.sort();
+
+    if q.is_negative() {
+        factors.insert(0, (-BigInt::one(), 1));
+    }
+
+    let pairs = factors.iter()
+        .map(|&(ref p, e)| list(&[from_big(p), integer(e)]))
+        .collect::<Vec<SimplexPointer>>();
+
+    Some(list(&pairs))
+}
+
+fn euler_phi_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("EulerPhi", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    let n = big(&args[0])?.abs();
+
+    if n.is_zero() {
+        return Some(integer(0));
+    }
+
+    Some(from_big(&euler_phi(&n)))
+}
+
+fn divisors_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("Divisors", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    let n = big(&args[0])?.abs();
+
+    if n.is_zero() {
+        return None;
+    }
+
+    Some(from_bigs(&divisors(&n)))
+}
+
+// IntegerDigits[n], IntegerDigits[n, b], and IntegerDigits[n, b, len]
+// padded with zeros on the left or cut to the last len digits.
+fn integer_digits_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("IntegerDigits", args) {
+        return Some(threaded);
+    }
+
+    if args.len() < 1 || args.len() > 3 {
+        return None;
+    }
+
+    let n = big(&args[0])?;
+    let b = match args.get(1) {
+        Some(b) => big(b)?,
+        None => BigInt::from(10),
+    };
+
+    if b < BigInt::from(2) {
+        return None;
+    }
+
+    let mut ds = digits(&n, &b);
+
+    if let Some(length) = args.get(2) {
+        let length = length.as_integer()?;
+
+        if length < 0 {
+            return None;
+        }
+
+        let length = length as usize;
+
+        if ds.len() > length {
+            ds = ds.split_off(ds.len() - length);
+        } else {
+            let mut padded = vec![BigInt::zero(); length - ds.len()];
+            padded.extend(ds);
+            ds = padded;
+        }
+    }
+
+    Some(from_bigs(&ds))
+}
+
+fn from_digits_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 1 || args.len() > 2 || !is_head(&args[0], "List") {
+        return None;
+    }
+
+    let b = match args.get(1) {
+        Some(b) => big(b)?,
+        None => BigInt::from(10),
+    };
+
+    Some(from_big(&from_digits(&bigs(&args[0].get_parts())?, &b)))
+}
+
+// The least x >= 0 with x = r[i] mod m[i], left alone when there is none.
+fn chinese_remainder_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_head(&args[0], "List") || !is_head(&args[1], "List") {
+        return None;
+    }
+
+    let (residues, moduli) = (bigs(&args[0].get_parts())?, bigs(&args[1].get_parts())?);
+
+    if residues.len() != moduli.len() {
+        return None;
+    }
+
+    chinese_remainder(&residues, &moduli).map(|n| from_big(&n))
+}
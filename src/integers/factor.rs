@@ -0,0 +1,185 @@
+use num::{self, BigInt, Integer, Signed, Zero, One};
+
+use integers::primes::is_prime;
+
+// Factors below this are found by trial division, the rest by Pollard's rho.
+const TRIAL_LIMIT: u64 = 1000;
+
+// Differences multiplied together between GCDs in rho.
+const BATCH: u64 = 128;
+
+// Pollard's rho with Brent's cycle detection, x -> x^2 + c, taking the
+// GCD of a batch of differences at a time. A nontrivial factor of an odd
+// composite n that is not a prime power.
+fn rho(n: &BigInt) -> BigInt {
+    let one = BigInt::one();
+    let mut c = one.clone();
+
+    loop {
+        let f = |x: &BigInt| (x * x + &c) % n;
+        let (mut y, mut r, mut q) = (BigInt::from(2), 1u64, one.clone());
+        let (mut x, mut saved) = (y.clone(), y.clone());
+        let mut g = one.clone();
+
+        while g.is_one() {
+            x = y.clone();
+
+            for _ in 0..r {
+                y = f(&y);
+            }
+
+            let mut k = 0;
+
+            while k < r && g.is_one() {
+                saved = y.clone();
+
+                for _ in 0..(r - k).min(BATCH) {
+                    y = f(&y);
+                    q = q * (&x - &y).abs() % n;
+                }
+
+                g = q.gcd(n);
+                k = k + BATCH;
+            }
+
+            r = r * 2;
+        }
+
+        // The batch overshot: step through it one difference at a time.
+        if g == *n {
+            loop {
+                saved = f(&saved);
+                g = (&x - &saved).abs().gcd(n);
+
+                if !g.is_one() {
+                    break;
+                }
+            }
+        }
+
+        if g != *n {
+            return g;
+        }
+
+        c = c + &one;
+    }
+}
+
+// The prime factors of n > 1 with repetition, in no particular order.
+fn split(n: BigInt, factors: &mut Vec<BigInt>) {
+    if n.is_one() {
+        return;
+    }
+
+    if is_prime(&n) {
+        factors.push(n);
+        return;
+    }
+
+    // A perfect power m^k splits as k copies of m, which rho can't find.
+    for k in (2..n.bits() as u32 + 1).rev() {
+        let m = n.nth_root(k);
+
+        if num::pow(m.clone(), k as usize) == n {
+            for _ in 0..k {
+                split(m.clone(), factors);
+            }
+
+            return;
+        }
+    }
+
+    let d = rho(&n);
+    split(&n / &d, factors);
+    split(d, factors);
+}
+
+// The factorization of n > 0 as primes and their exponents, smallest
+// prime first.
+pub fn factor_integer(n: &BigInt) -> Vec<(BigInt, u32)> {
+    let mut n = n.clone();
+    let mut factors = vec![];
+    let mut p = 2u64;
+
+    while p < TRIAL_LIMIT && !n.is_one() {
+        let divisor = BigInt::from(p);
+
+        while n.is_multiple_of(&divisor) {
+            factors.push(divisor.clone());
+            n = n / &divisor;
+        }
+
+        p = if p == 2 { 3 } else { p + 2 };
+    }
+
+    split(n, &mut factors);
+    factors.sort();
+
+    let mut result: Vec<(BigInt, u32)> = vec![];
+
+    for f in factors {
+        if result.last().map_or(false, |&(ref q, _)| *q == f) {
+            let last = result.last_mut().unwrap();
+            last.1 = last.1 + 1;
+        } else {
+            result.push((f, 1));
+        }
+    }
+
+    result
+}
+
+// n Product[1 - 1/p] over the primes p dividing n > 0.
+pub fn euler_phi(n: &BigInt) -> BigInt {
+    factor_integer(n).iter().fold(BigInt::one(), |phi, &(ref p, e)| {
+        phi * num::pow(p.clone(), e as usize - 1) * (p - 1)
+    })
+}
+
+// Every divisor of n > 0 in increasing order.
+pub fn divisors(n: &BigInt) -> Vec<BigInt> {
+    let mut result = vec![BigInt::one()];
+
+    for (p, e) in factor_integer(n) {
+        let mut multiples = vec![];
+
+        for d in result.iter() {
+            let mut m = d.clone();
+
+            for _ in 0..e + 1 {
+                multiples.push(m.clone());
+                m = m * &p;
+            }
+        }
+
+        result = multiples;
+    }
+
+    result.sort();
+    result
+}
+
+// The digits of |n| in base b, most significant first.
+pub fn digits(n: &BigInt, b: &BigInt) -> Vec<BigInt> {
+    let mut n = n.abs();
+    let mut result = vec![];
+
+    while !n.is_zero() {
+        let (q, r) = n.div_rem(b);
+        result.push(r);
+        n = q;
+    }
+
+    if result.is_empty() {
+        result.push(BigInt::zero());
+    }
+
+    result.reverse();
+    result
+}
+
+// The inverse of digits, though any integers will do as digits.
+pub fn from_digits(ds: &[BigInt], b: &BigInt) -> BigInt {
+    ds.iter().fold(BigInt::zero(), |n, d| n * b + d)
+}
+
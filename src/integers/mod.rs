@@ -0,0 +1,5 @@
+pub mod modular;
+pub mod primes;
+pub mod factor;
+pub mod builtins;
+pub mod test;
@@ -0,0 +1,109 @@
+use num::{BigInt, BigRational, Integer, Signed, Zero, One};
+
+use expression::atom::numbers::number::Numeric;
+
+use special::combinatorial::from_numeric;
+use numbers::rounding::floor;
+
+// g = GCD[a, b] >= 0 with s a + t b = g, by Euclid's algorithm.
+pub fn extended_gcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (BigInt::one(), BigInt::zero());
+    let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+
+    while !r1.is_zero() {
+        let q = r0.div_floor(&r1);
+        let r = &r0 - &q * &r1;
+        let s = &s0 - &q * &s1;
+        let t = &t0 - &q * &t1;
+        r0 = r1;
+        r1 = r;
+        s0 = s1;
+        s1 = s;
+        t0 = t1;
+        t1 = t;
+    }
+
+    if r0.is_negative() { (-r0, -s0, -t0) } else { (r0, s0, t0) }
+}
+
+// The x in [0, |m|) with a x = 1 mod m, None when a and m share a factor.
+pub fn inverse(a: &BigInt, m: &BigInt) -> Option<BigInt> {
+    let (g, s, _) = extended_gcd(a, m);
+
+    if g.is_one() {
+        Some(s.mod_floor(&m.abs()))
+    } else {
+        None
+    }
+}
+
+// a^e mod m, negative exponents being powers of the inverse of a, with the
+// result on the side of zero that m is on.
+pub fn power_mod(a: &BigInt, e: &BigInt, m: &BigInt) -> Option<BigInt> {
+    if m.is_zero() {
+        return None;
+    }
+
+    let base = if e.is_negative() { inverse(a, m)? } else { a.mod_floor(m) };
+    Some(base.modpow(&e.abs(), m))
+}
+
+// The least x >= 0 with x = r[i] mod m[i] for every i, None when the
+// congruences disagree. The moduli need not be coprime.
+pub fn chinese_remainder(residues: &[BigInt], moduli: &[BigInt]) -> Option<BigInt> {
+    let mut x = BigInt::zero();
+    let mut modulus = BigInt::one();
+
+    for (r, m) in residues.iter().zip(moduli.iter()) {
+        if !m.is_positive() {
+            return None;
+        }
+
+        let (g, s, _) = extended_gcd(&modulus, m);
+        let difference = r - &x;
+
+        if !difference.is_multiple_of(&g) {
+            return None;
+        }
+
+        let step = m / &g;
+        let k = (&difference / &g * s).mod_floor(&step);
+        x = &x + &modulus * k;
+        modulus = &modulus * step;
+        x = x.mod_floor(&modulus);
+    }
+
+    Some(x)
+}
+
+fn exact_quotient(m: &BigRational, n: &BigRational) -> BigRational {
+    (m / n).floor()
+}
+
+// Floor[(m - d) / n], the Quotient of m by n counting from offset d.
+pub fn quotient(m: Numeric, n: Numeric, d: Numeric) -> Option<Numeric> {
+    if n == Numeric::LittleInteger(0) {
+        return None;
+    }
+
+    match (from_numeric(&m), from_numeric(&n), from_numeric(&d)) {
+        (Some(m), Some(n), Some(d)) => Some(Numeric::from_big_rational(exact_quotient(&(m - d), &n))),
+        _ => floor((m - d) / n),
+    }
+}
+
+// m - n Quotient[m, n, d], in [d, d + n) for positive n.
+pub fn modulo(m: Numeric, n: Numeric, d: Numeric) -> Option<Numeric> {
+    if n == Numeric::LittleInteger(0) {
+        return None;
+    }
+
+    match (from_numeric(&m), from_numeric(&n), from_numeric(&d)) {
+        (Some(m), Some(n), Some(d)) => {
+            let q = exact_quotient(&(&m - d), &n);
+            Some(Numeric::from_big_rational(m - n * q))
+        }
+        _ => Some(m.clone() - n.clone() * quotient(m, n, d)?),
+    }
+}
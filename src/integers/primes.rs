@@ -0,0 +1,251 @@
+use num::{BigInt, Integer, Signed, Zero, One, ToPrimitive};
+
+// Miller-Rabin with these bases decides every n below 2^64.
+const WITNESSES: &'static [u64] = &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// Prime[n] is found by sieving for n up to this.
+pub const PRIME_INDEX_LIMIT: i64 = 1000000;
+
+fn multiply_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn power_mod(base: u64, exponent: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut square = base % m;
+    let mut e = exponent;
+
+    while e > 0 {
+        if e % 2 == 1 {
+            result = multiply_mod(result, square, m);
+        }
+
+        square = multiply_mod(square, square, m);
+        e = e / 2;
+    }
+
+    result
+}
+
+// Whether odd n > 2 is a strong probable prime to base a, n - 1 being
+// d 2^s with d odd.
+fn is_strong_probable_prime(n: u64, a: u64) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut x = power_mod(a, d, n);
+
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+
+    for _ in 1..s {
+        x = multiply_mod(x, x, n);
+
+        if x == n - 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn is_small_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    for &p in WITNESSES {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    WITNESSES.iter().all(|&a| is_strong_probable_prime(n, a))
+}
+
+// The same over the big integers.
+fn is_big_strong_probable_prime(n: &BigInt, a: &BigInt) -> bool {
+    let one = BigInt::one();
+    let m = n - &one;
+    let s = m.trailing_zeros().unwrap_or(0);
+    let d = &m >> (s as usize);
+    let mut x = a.modpow(&d, n);
+
+    if x == one || x == m {
+        return true;
+    }
+
+    for _ in 1..s {
+        x = &x * &x % n;
+
+        if x == m {
+            return true;
+        }
+    }
+
+    false
+}
+
+// The Jacobi symbol (a / n) for odd n > 0.
+fn jacobi(a: &BigInt, n: &BigInt) -> i32 {
+    let (mut a, mut n) = (a.mod_floor(n), n.clone());
+    let (four, eight) = (BigInt::from(4), BigInt::from(8));
+    let mut result = 1;
+
+    while !a.is_zero() {
+        while a.is_even() {
+            a = a >> 1;
+            let r = (&n % &eight).to_u32().unwrap();
+
+            if r == 3 || r == 5 {
+                result = -result;
+            }
+        }
+
+        ::std::mem::swap(&mut a, &mut n);
+
+        if (&a % &four).to_u32() == Some(3) && (&n % &four).to_u32() == Some(3) {
+            result = -result;
+        }
+
+        a = a.mod_floor(&n);
+    }
+
+    if n.is_one() { result } else { 0 }
+}
+
+// x / 2 mod odd n.
+fn halve(x: BigInt, n: &BigInt) -> BigInt {
+    let x = if x.is_odd() { x + n } else { x };
+    let half: BigInt = x >> 1;
+    half.mod_floor(n)
+}
+
+// The strong Lucas test with Selfridge's parameters: D the first of 5, -7,
+// 9, -11, ... with (D / n) = -1, P = 1 and Q = (1 - D) / 4. n must be odd
+// and not a square, or no such D exists.
+fn is_strong_lucas_probable_prime(n: &BigInt) -> bool {
+    let (one, two, four) = (BigInt::one(), BigInt::from(2), BigInt::from(4));
+    let mut d = BigInt::from(5);
+
+    loop {
+        match jacobi(&d, n) {
+            -1 => break,
+            0 if d.abs() != *n => return false,
+            _ => {}
+        }
+
+        d = if d.is_positive() { -(d + &two) } else { -(d - &two) };
+    }
+
+    let q = (&one - &d) / &four;
+    let m = n + &one;
+    let s = m.trailing_zeros().unwrap_or(0);
+    let k = &m >> (s as usize);
+
+    // U[1] = 1 and V[1] = P = 1, doubled and stepped along the bits of k.
+    let (mut u, mut v, mut qk) = (one.clone(), one.clone(), q.mod_floor(n));
+
+    for i in (0..k.bits() - 1).rev() {
+        u = (&u * &v).mod_floor(n);
+        v = (&v * &v - &qk * &two).mod_floor(n);
+        qk = (&qk * &qk).mod_floor(n);
+
+        if k.bit(i) {
+            let (w, x) = (halve(&u + &v, n), halve(&d * &u + &v, n));
+            u = w;
+            v = x;
+            qk = (&qk * &q).mod_floor(n);
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+
+    for _ in 1..s {
+        v = (&v * &v - &qk * &two).mod_floor(n);
+        qk = (&qk * &qk).mod_floor(n);
+
+        if v.is_zero() {
+            return true;
+        }
+    }
+
+    false
+}
+
+// Miller-Rabin with a fixed set of bases, which is exact, below 2^64, and
+// Baillie-PSW, which has no known counterexample, above.
+pub fn is_prime(n: &BigInt) -> bool {
+    let n = n.abs();
+
+    if let Some(small) = n.to_u64() {
+        return is_small_prime(small);
+    }
+
+    for &p in WITNESSES {
+        if n.is_multiple_of(&BigInt::from(p)) {
+            return false;
+        }
+    }
+
+    let root = n.sqrt();
+
+    if &root * &root == n {
+        return false;
+    }
+
+    is_big_strong_probable_prime(&n, &BigInt::from(2)) && is_strong_lucas_probable_prime(&n)
+}
+
+// The k-th prime after n, or before it for negative k, the negatives of
+// primes counting as primes.
+pub fn next_prime(n: &BigInt, k: i64) -> BigInt {
+    let step = BigInt::from(k.signum());
+    let mut p = n.clone();
+
+    for _ in 0..k.abs() {
+        p = &p + &step;
+
+        while !is_prime(&p) {
+            p = &p + &step;
+        }
+    }
+
+    p
+}
+
+// The n-th prime by sieving up to n (Log[n] + Log[Log[n]]), which bounds it
+// from n = 6 on.
+pub fn nth_prime(n: i64) -> Option<i64> {
+    if n < 1 || n > PRIME_INDEX_LIMIT {
+        return None;
+    }
+
+    let x = n as f64;
+    let bound = if n < 6 { 15 } else { (x * (x.ln() + x.ln().ln())) as usize + 1 };
+    let mut composite = vec![false; bound + 1];
+    let mut count = 0;
+
+    for i in 2..bound + 1 {
+        if composite[i] {
+            continue;
+        }
+
+        count = count + 1;
+
+        if count == n {
+            return Some(i as i64);
+        }
+
+        let mut j = i * i;
+
+        while j <= bound {
+            composite[j] = true;
+            j = j + i;
+        }
+    }
+
+    None
+}
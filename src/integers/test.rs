@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    mod test_modular {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_finds_greatest_common_divisors_and_least_common_multiples() {
+            assert_eq!(run("GCD[12, 18, 27]"), "3");
+            assert_eq!(run("GCD[1/2, 1/3]"), "Rational[1, 6]");
+            assert_eq!(run("LCM[4, 6, 10]"), "60");
+            assert_eq!(run("ExtendedGCD[12, 18]"), "List[6, List[-1, 1]]");
+            assert_eq!(run("ExtendedGCD[-4, 6]"), "List[2, List[1, 1]]");
+            assert_eq!(run("GCD[2^70, 2^65 3]"), "36893488147419103232");
+            assert_eq!(run("LCM[2^64, 3]"), "55340232221128654848");
+        }
+
+        #[test]
+        fn it_takes_remainders_and_quotients() {
+            assert_eq!(run("Mod[-7, 3]"), "2");
+            assert_eq!(run("Mod[7, -3]"), "-2");
+            assert_eq!(run("Mod[5.5, 2]"), "1.5");
+            assert_eq!(run("Mod[7/2, 1]"), "Rational[1, 2]");
+            assert_eq!(run("Mod[10, 3, 1]"), "1");
+            assert_eq!(run("Mod[7, 0]"), "Mod[7, 0]");
+            assert_eq!(run("Quotient[-7, 2]"), "-4");
+            assert_eq!(run("Mod[{5, 6, 7}, 3]"), "List[2, 0, 1]");
+            assert_eq!(run("Mod[2^70 + 5, 2^64]"), "5");
+            assert_eq!(run("Quotient[2^70, 3]"), "393530540239137101141");
+        }
+
+        #[test]
+        fn it_raises_to_powers_modulo_integers() {
+            assert_eq!(run("PowerMod[2, 10, 1000]"), "24");
+            assert_eq!(run("PowerMod[3, -1, 7]"), "5");
+            assert_eq!(run("PowerMod[2, -1, 4]"), "PowerMod[2, -1, 4]");
+            assert_eq!(run("PowerMod[2, 62, 9223372036854775807]"), "4611686018427387904");
+            assert_eq!(run("PowerMod[3, 2^70, 2^89 - 1]"), "41178497626242977627746810");
+        }
+
+        #[test]
+        fn it_solves_simultaneous_congruences() {
+            assert_eq!(run("ChineseRemainder[{2, 3, 2}, {3, 5, 7}]"), "23");
+            assert_eq!(run("ChineseRemainder[{1, 3}, {4, 6}]"), "9");
+            assert_eq!(run("ChineseRemainder[{1, 2}, {4, 6}]"), "ChineseRemainder[List[1, 2], List[4, 6]]");
+        }
+    }
+
+    mod test_primes {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_tests_primality() {
+            assert_eq!(run("PrimeQ[{1, 2, 97, -7, x}]"), "List[False, True, True, True, False]");
+            assert_eq!(run("PrimeQ[9223372036854775783]"), "True");
+            // A strong pseudoprime to every base up to 23.
+            assert_eq!(run("PrimeQ[3825123056546413051]"), "False");
+            assert_eq!(run("PrimeQ[2^89 - 1]"), "True");
+            assert_eq!(run("PrimeQ[2^89 + 1]"), "False");
+        }
+
+        #[test]
+        fn it_finds_primes_by_index_and_after_a_number() {
+            assert_eq!(run("Prime[1000]"), "7919");
+            assert_eq!(run("NextPrime[10]"), "11");
+            assert_eq!(run("NextPrime[10, 3]"), "17");
+            assert_eq!(run("NextPrime[2, -1]"), "-2");
+            assert_eq!(run("NextPrime[2^64]"), "18446744073709551629");
+        }
+
+        #[test]
+        fn it_factors_integers() {
+            assert_eq!(run("FactorInteger[360]"), "List[List[2, 3], List[3, 2], List[5, 1]]");
+            assert_eq!(run("FactorInteger[-12]"), "List[List[-1, 1], List[2, 2], List[3, 1]]");
+            assert_eq!(run("FactorInteger[3/8]"), "List[List[2, -3], List[3, 1]]");
+            assert_eq!(run("FactorInteger[1000000016000000063]"), "List[List[1000000007, 1], List[1000000009, 1]]");
+            assert_eq!(run("FactorInteger[4611686014132420609]"), "List[List[2147483647, 2]]");
+            assert_eq!(run("FactorInteger[2^64 + 1]"), "List[List[274177, 1], List[67280421310721, 1]]");
+        }
+
+        #[test]
+        fn it_counts_totatives_and_lists_divisors() {
+            assert_eq!(run("EulerPhi[36]"), "12");
+            assert_eq!(run("Divisors[28]"), "List[1, 2, 4, 7, 14, 28]");
+        }
+    }
+
+    mod test_digits {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_splits_integers_into_digits() {
+            assert_eq!(run("IntegerDigits[1234]"), "List[1, 2, 3, 4]");
+            assert_eq!(run("IntegerDigits[-10, 2]"), "List[1, 0, 1, 0]");
+            assert_eq!(run("IntegerDigits[5, 2, 8]"), "List[0, 0, 0, 0, 0, 1, 0, 1]");
+            assert_eq!(run("IntegerDigits[1234, 10, 2]"), "List[3, 4]");
+            assert_eq!(run("IntegerDigits[2^64, 16]"), "List[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]");
+        }
+
+        #[test]
+        fn it_builds_integers_from_digits() {
+            assert_eq!(run("FromDigits[{1, 2, 3}]"), "123");
+            assert_eq!(run("FromDigits[{1, 0, 1}, 2]"), "5");
+            assert_eq!(run("FromDigits[{1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0}, 16]"), "18446744073709551616");
+        }
+    }
+}
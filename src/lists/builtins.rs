@@ -0,0 +1,372 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+use expression::packed::structure::SimplexPackedArray;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{list, boolean, integer_list, is_head};
+use lists::construction::{range_array, constant_array, table};
+use lists::segments::{Padding, span_of, take, drop, rotate_left, partition, join};
+use lists::restructure::{flatten, flatten_all, transpose};
+use lists::sets::{sort, sort_by, delete_duplicates, union, intersection, complement, tally, gather_by};
+use lists::reduction::{total, accumulate};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Range" => Some(range_builtin),
+        "ConstantArray" => Some(constant_array_builtin),
+        "Table" => Some(table_builtin),
+        "Join" => Some(join_builtin),
+        "Append" => Some(append_builtin),
+        "Prepend" => Some(prepend_builtin),
+        "Take" => Some(take_builtin),
+        "Drop" => Some(drop_builtin),
+        "Reverse" => Some(reverse_builtin),
+        "RotateLeft" => Some(rotate_left_builtin),
+        "RotateRight" => Some(rotate_right_builtin),
+        "Flatten" => Some(flatten_builtin),
+        "Partition" => Some(partition_builtin),
+        "Transpose" => Some(transpose_builtin),
+        "Sort" => Some(sort_builtin),
+        "SortBy" => Some(sort_by_builtin),
+        "Union" => Some(union_builtin),
+        "Intersection" => Some(intersection_builtin),
+        "Complement" => Some(complement_builtin),
+        "DeleteDuplicates" => Some(delete_duplicates_builtin),
+        "Tally" => Some(tally_builtin),
+        "GatherBy" => Some(gather_by_builtin),
+        "Total" => Some(total_builtin),
+        "Accumulate" => Some(accumulate_builtin),
+        "PackedArrayQ" => Some(packed_array_q_builtin),
+        "ToPackedArray" => Some(to_packed_array_builtin),
+        "FromPackedArray" => Some(from_packed_array_builtin),
+        _ => None,
+    }
+}
+
+fn is_expression(e: &SimplexPointer) -> bool {
+    !e.is_atomic()
+}
+
+fn parts_of_all(lists: &[SimplexPointer]) -> Option<Vec<Vec<SimplexPointer>>> {
+    if lists.iter().all(is_expression) {
+        Some(lists.iter().map(|l| l.get_parts()).collect())
+    } else {
+        None
+    }
+}
+
+fn range_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let bounds = match args.iter().map(|a| a.as_numeric()).collect::<Option<Vec<Numeric>>>() {
+        Some(b) => b,
+        None => return None,
+    };
+
+    let one = Numeric::LittleInteger(1);
+
+    match bounds.len() {
+        1 => range_array(one.clone(), bounds[0].clone(), one),
+        2 => range_array(bounds[0].clone(), bounds[1].clone(), one),
+        3 => range_array(bounds[0].clone(), bounds[1].clone(), bounds[2].clone()),
+        _ => None,
+    }
+}
+
+fn constant_array_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    let dimensions = match args[1].as_integer() {
+        Some(n) => vec![n],
+        None => {
+            match integer_list(&args[1]) {
+                Some(d) => d,
+                None => return None,
+            }
+        }
+    };
+
+    if dimensions.iter().any(|&d| d < 0) {
+        return None;
+    }
+
+    Some(constant_array(&args[0], &dimensions.iter().map(|&d| d as usize).collect::<Vec<usize>>()))
+}
+
+fn table_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    match args.split_first() {
+        Some((body, iterators)) if !iterators.is_empty() => table(body, iterators),
+        _ => None,
+    }
+}
+
+fn join_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    join(args)
+}
+
+fn append_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    let mut parts = args[0].get_parts();
+    parts.push(args[1].clone());
+    Some(args[0].with_parts(&parts))
+}
+
+fn prepend_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    let mut parts = args[0].get_parts();
+    parts.insert(0, args[1].clone());
+    Some(args[0].with_parts(&parts))
+}
+
+fn take_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    let parts = args[0].get_parts();
+    span_of(&args[1], parts.len()).map(|span| args[0].with_parts(&take(&parts, &span)))
+}
+
+fn drop_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    let parts = args[0].get_parts();
+    span_of(&args[1], parts.len()).map(|span| args[0].with_parts(&drop(&parts, &span)))
+}
+
+fn reverse_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    let mut parts = args[0].get_parts();
+    parts.reverse();
+    Some(args[0].with_parts(&parts))
+}
+
+fn rotation(args: &[SimplexPointer], direction: i64) -> Option<SimplexPointer> {
+    let n = match args.len() {
+        1 => 1,
+        2 => {
+            match args[1].as_integer() {
+                Some(n) => n,
+                None => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    if !is_expression(&args[0]) {
+        return None;
+    }
+
+    Some(args[0].with_parts(&rotate_left(&args[0].get_parts(), n * direction)))
+}
+
+fn rotate_left_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    rotation(args, 1)
+}
+
+fn rotate_right_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    rotation(args, -1)
+}
+
+fn flatten_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.is_empty() || !is_expression(&args[0]) {
+        return None;
+    }
+
+    match args.len() {
+        1 => Some(flatten_all(&args[0])),
+        2 => {
+            if args[1].as_str() == "Infinity" {
+                return Some(flatten_all(&args[0]));
+            }
+
+            match args[1].as_integer() {
+                Some(n) if n >= 0 => Some(flatten(&args[0], n as usize)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn partition_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 || args.len() > 5 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    let n = match args[1].as_integer() {
+        Some(n) if n > 0 => n,
+        _ => return None,
+    };
+
+    let d = match args.get(2) {
+        Some(d) => {
+            match d.as_integer() {
+                Some(d) if d > 0 => d,
+                _ => return None,
+            }
+        }
+        None => n,
+    };
+
+    let (kl, kr) = match args.get(3) {
+        Some(k) => {
+            match (k.as_integer(), integer_list(k)) {
+                (Some(k), _) => (k, k),
+                (None, Some(ref ks)) if ks.len() == 2 => (ks[0], ks[1]),
+                _ => return None,
+            }
+        }
+        None => (1, n),
+    };
+
+    let padding = match args.get(4) {
+        Some(x) if is_head(x, "List") && x.get_length() == 0 => Padding::Ragged,
+        Some(x) if is_head(x, "List") => Padding::Cycle(x.get_parts()),
+        Some(x) => Padding::Element(x.clone()),
+        None => Padding::Cyclic,
+    };
+
+    partition(&args[0].get_parts(), n as usize, d as usize, kl, kr, &padding)
+        .map(|sublists| list(&sublists.iter().map(|s| args[0].with_parts(s)).collect::<Vec<SimplexPointer>>()))
+}
+
+fn transpose_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !is_head(&args[0], "List") {
+        return None;
+    }
+
+    transpose(&args[0].get_parts()).map(|columns| list(&columns))
+}
+
+fn sort_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    Some(args[0].with_parts(&sort(&args[0].get_parts())))
+}
+
+fn sort_by_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    sort_by(&args[0].get_parts(), &args[1]).map(|sorted| args[0].with_parts(&sorted))
+}
+
+fn union_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.is_empty() {
+        return None;
+    }
+
+    parts_of_all(args).map(|lists| args[0].with_parts(&union(&lists)))
+}
+
+fn intersection_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.is_empty() {
+        return None;
+    }
+
+    parts_of_all(args).map(|lists| args[0].with_parts(&intersection(&lists)))
+}
+
+fn complement_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.is_empty() {
+        return None;
+    }
+
+    parts_of_all(args).map(|lists| args[0].with_parts(&complement(&lists[0], &lists[1..])))
+}
+
+fn delete_duplicates_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    Some(args[0].with_parts(&delete_duplicates(&args[0].get_parts())))
+}
+
+fn tally_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    Some(list(&tally(&args[0].get_parts())))
+}
+
+fn gather_by_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    gather_by(&args[0].get_parts(), &args[1]).map(|groups| list(&groups))
+}
+
+fn total_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.is_empty() || !is_expression(&args[0]) {
+        return None;
+    }
+
+    match args.len() {
+        1 => Some(total(&args[0].get_parts())),
+        2 => {
+            match args[1].as_integer() {
+                Some(n) if n >= 1 => Some(total(&flatten(&args[0], (n - 1) as usize).get_parts())),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn accumulate_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 || !is_expression(&args[0]) {
+        return None;
+    }
+
+    Some(args[0].with_parts(&accumulate(&args[0].get_parts())))
+}
+
+fn packed_array_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(boolean(args[0].get_packed().is_some()))
+}
+
+// Lists that can't be packed come back untouched.
+fn to_packed_array_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    match SimplexPackedArray::from_expression(&args[0]) {
+        Some(packed) => Some(SimplexPointer::from(packed)),
+        None => Some(args[0].clone()),
+    }
+}
+
+fn from_packed_array_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    match args[0].get_packed() {
+        Some(packed) => Some(packed.unpack()),
+        None => Some(args[0].clone()),
+    }
+}
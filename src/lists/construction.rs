@@ -0,0 +1,157 @@
+use std::mem;
+
+use expression::structure::SimplexPointer;
+use expression::atom::structure::SimplexAtom;
+use expression::atom::numbers::number::Numeric;
+use expression::packed::structure::SimplexPackedArray;
+
+use evaluation::dispatch::evaluate;
+use evaluation::utilities::{list, is_head};
+use pattern::matching::{Bindings, substitute};
+
+// Everything from start up to and including end in steps of step.
+struct Steps {
+    current: Numeric,
+    end: Numeric,
+    step: Numeric,
+    ascending: bool,
+}
+
+impl Iterator for Steps {
+    type Item = Numeric;
+
+    fn next(&mut self) -> Option<Numeric> {
+        if (self.ascending && self.current > self.end) || (!self.ascending && self.current < self.end) {
+            return None;
+        }
+
+        let next = self.current.clone() + self.step.clone();
+        Some(mem::replace(&mut self.current, next))
+    }
+}
+
+// None for a zero or non numeric step.
+fn steps(start: Numeric, end: Numeric, step: Numeric) -> Option<Steps> {
+    let zero = Numeric::LittleInteger(0);
+
+    if step == zero || step == Numeric::NaN || start == Numeric::NaN || end == Numeric::NaN {
+        return None;
+    }
+
+    Some(Steps {
+        current: start,
+        end: end,
+        ascending: step > zero,
+        step: step,
+    })
+}
+
+pub fn range(start: Numeric, end: Numeric, step: Numeric) -> Option<Vec<SimplexPointer>> {
+    steps(start, end, step).map(|s| s.map(|v| SimplexPointer::from(SimplexAtom::SimplexNumeric(v))).collect())
+}
+
+// Range[ ... ] proper, which comes out packed.
+pub fn range_array(start: Numeric, end: Numeric, step: Numeric) -> Option<SimplexPointer> {
+    let values = steps(start.clone(), end.clone(), step.clone())?;
+
+    match SimplexPackedArray::from_vector(values) {
+        Some(packed) => Some(SimplexPointer::from(packed)),
+        None => range(start, end, step).map(|values| list(&values)),
+    }
+}
+
+pub fn constant_array(c: &SimplexPointer, dimensions: &[usize]) -> SimplexPointer {
+    match dimensions.split_first() {
+        Some((&n, rest)) => {
+            let row = constant_array(c, rest);
+            list(&vec![row; n])
+        }
+        None => c.clone(),
+    }
+}
+
+// The values one Table[ ... ] iterator runs over: n, {n}, {i, max},
+// {i, min, max}, {i, min, max, step} or {i, {values ... }}.
+fn iterator_values(iterator: &SimplexPointer) -> Option<(Option<String>, Vec<SimplexPointer>)> {
+    let one = Numeric::LittleInteger(1);
+
+    let bound = |e: &SimplexPointer| evaluate(e).as_numeric();
+
+    if !is_head(iterator, "List") {
+        return bound(iterator).and_then(|n| range(one.clone(), n, one.clone())).map(|v| (None, v));
+    }
+
+    let parts = iterator.get_parts();
+
+    if parts.len() == 1 {
+        return bound(&parts[0]).and_then(|n| range(one.clone(), n, one.clone())).map(|v| (None, v));
+    }
+
+    let variable = match parts[0].as_symbol() {
+        Some(v) => v,
+        None => return None,
+    };
+
+    let values = match parts.len() {
+        2 => {
+            let max = evaluate(&parts[1]);
+
+            if is_head(&max, "List") {
+                Some(max.get_parts())
+            } else {
+                max.as_numeric().and_then(|n| range(one.clone(), n, one.clone()))
+            }
+        }
+        3 => {
+            match (bound(&parts[1]), bound(&parts[2])) {
+                (Some(min), Some(max)) => range(min, max, one.clone()),
+                _ => None,
+            }
+        }
+        4 => {
+            match (bound(&parts[1]), bound(&parts[2]), bound(&parts[3])) {
+                (Some(min), Some(max), Some(step)) => range(min, max, step),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    values.map(|v| (Some(variable), v))
+}
+
+// Nested lists of body evaluated over every combination of the iterators,
+// the first iterator outermost.
+pub fn table(body: &SimplexPointer, iterators: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let (iterator, rest) = match iterators.split_first() {
+        Some(split) => split,
+        None => return Some(evaluate(body)),
+    };
+
+    let (variable, values) = match iterator_values(iterator) {
+        Some(v) => v,
+        None => return None,
+    };
+
+    let mut rows = Vec::with_capacity(values.len());
+
+    for value in values {
+        let instance = match variable {
+            Some(ref v) => {
+                let mut bindings = Bindings::new();
+                bindings.insert(v.clone(), value);
+
+                let substituted = rest.iter().map(|r| substitute(r, &bindings)).collect::<Vec<SimplexPointer>>();
+                table(&substitute(body, &bindings), &substituted)
+            }
+            None => table(body, rest),
+        };
+
+        match instance {
+            Some(row) => rows.push(row),
+            None => return None,
+        }
+    }
+
+    Some(list(&rows))
+}
@@ -0,0 +1,7 @@
+pub mod construction;
+pub mod segments;
+pub mod restructure;
+pub mod sets;
+pub mod reduction;
+pub mod builtins;
+pub mod test;
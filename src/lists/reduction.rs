@@ -0,0 +1,27 @@
+use expression::structure::SimplexPointer;
+
+use arithmetic::plus::plus;
+use evaluation::utilities::integer;
+
+pub fn total(parts: &[SimplexPointer]) -> SimplexPointer {
+    match parts.split_first() {
+        Some((first, rest)) => rest.iter().fold(first.clone(), |sum, p| plus(&sum, p)),
+        None => integer(0),
+    }
+}
+
+// Running totals: {a, a + b, a + b + c, ...}.
+pub fn accumulate(parts: &[SimplexPointer]) -> Vec<SimplexPointer> {
+    let mut sums: Vec<SimplexPointer> = Vec::with_capacity(parts.len());
+
+    for p in parts {
+        let next = match sums.last() {
+            Some(sum) => plus(sum, p),
+            None => p.clone(),
+        };
+
+        sums.push(next);
+    }
+
+    sums
+}
@@ -0,0 +1,53 @@
+use std::usize;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::{list, is_head};
+
+// Splices subexpressions sharing the outer head into it, descending at most
+// levels deep.
+pub fn flatten(e: &SimplexPointer, levels: usize) -> SimplexPointer {
+    let head = e.head_name();
+    let mut flat = Vec::new();
+
+    flatten_into(e, head.as_str(), levels, &mut flat);
+    e.with_parts(&flat)
+}
+
+pub fn flatten_all(e: &SimplexPointer) -> SimplexPointer {
+    flatten(e, usize::MAX)
+}
+
+fn flatten_into(e: &SimplexPointer, head: &str, levels: usize, flat: &mut Vec<SimplexPointer>) {
+    for part in e.get_parts() {
+        if levels > 0 && !part.is_atomic() && part.head_name() == head {
+            flatten_into(&part, head, levels - 1, flat);
+        } else {
+            flat.push(part);
+        }
+    }
+}
+
+// Rows of a rectangular matrix become its columns, None when ragged.
+pub fn transpose(rows: &[SimplexPointer]) -> Option<Vec<SimplexPointer>> {
+    let width = match rows.first() {
+        Some(r) if is_head(r, "List") => r.get_length(),
+        Some(_) => return None,
+        None => return Some(Vec::new()),
+    };
+
+    let mut columns = vec![Vec::with_capacity(rows.len()); width];
+
+    for row in rows {
+        if !is_head(row, "List") || row.get_length() != width {
+            return None;
+        }
+
+        for (column, entry) in columns.iter_mut().zip(row.get_parts()) {
+            column.push(entry);
+        }
+    }
+
+    Some(columns.iter().map(|c| list(c)).collect())
+}
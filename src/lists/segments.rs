@@ -0,0 +1,186 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::integer_list;
+
+// A resolved sequence specification: zero based indices from start towards
+// end (inclusive) in steps of step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub step: usize,
+}
+
+impl Span {
+    pub fn indices(&self) -> Vec<usize> {
+        if self.start > self.end {
+            return Vec::new();
+        }
+
+        (self.start..self.end + 1).filter(|i| (i - self.start) % self.step == 0).collect()
+    }
+}
+
+fn absolute(index: i64, length: usize) -> Option<usize> {
+    let length = length as i64;
+    let resolved = if index < 0 { length + index } else { index - 1 };
+
+    if resolved >= 0 && resolved < length { Some(resolved as usize) } else { None }
+}
+
+// Reads n, -n, {m}, {m, n}, {m, n, s}, All and None against a length,
+// Some(None) standing for a spec that selects nothing.
+pub fn span_of(spec: &SimplexPointer, length: usize) -> Option<Option<Span>> {
+    if let Some(n) = spec.as_integer() {
+        return match n {
+            0 => Some(None),
+            n if n > 0 && n as usize <= length => Some(Some(Span { start: 0, end: n as usize - 1, step: 1 })),
+            n if n < 0 && (-n) as usize <= length => {
+                Some(Some(Span { start: length - (-n) as usize, end: length - 1, step: 1 }))
+            }
+            _ => None,
+        };
+    }
+
+    match spec.as_symbol() {
+        Some(ref s) if s == "All" => {
+            return Some(if length == 0 { None } else { Some(Span { start: 0, end: length - 1, step: 1 }) });
+        }
+        Some(ref s) if s == "None" => return Some(None),
+        _ => {}
+    }
+
+    let bounds = match integer_list(spec) {
+        Some(b) => b,
+        None => return None,
+    };
+
+    let (start, end, step) = match bounds.len() {
+        1 => (bounds[0], bounds[0], 1),
+        2 => (bounds[0], bounds[1], 1),
+        3 => (bounds[0], bounds[1], bounds[2]),
+        _ => return None,
+    };
+
+    if step <= 0 {
+        return None;
+    }
+
+    match (absolute(start, length), absolute(end, length)) {
+        (Some(s), Some(e)) => Some(if s <= e { Some(Span { start: s, end: e, step: step as usize }) } else { None }),
+        _ => None,
+    }
+}
+
+pub fn take(parts: &[SimplexPointer], span: &Option<Span>) -> Vec<SimplexPointer> {
+    match span {
+        &Some(ref s) => s.indices().iter().map(|&i| parts[i].clone()).collect(),
+        &None => Vec::new(),
+    }
+}
+
+pub fn drop(parts: &[SimplexPointer], span: &Option<Span>) -> Vec<SimplexPointer> {
+    let removed = match span {
+        &Some(ref s) => s.indices(),
+        &None => Vec::new(),
+    };
+
+    parts.iter()
+        .enumerate()
+        .filter(|&(i, _)| removed.binary_search(&i).is_err())
+        .map(|(_, p)| p.clone())
+        .collect()
+}
+
+pub fn rotate_left(parts: &[SimplexPointer], n: i64) -> Vec<SimplexPointer> {
+    if parts.is_empty() {
+        return Vec::new();
+    }
+
+    let length = parts.len() as i64;
+    let shift = (((n % length) + length) % length) as usize;
+
+    let mut rotated = parts[shift..].to_vec();
+    rotated.extend_from_slice(&parts[..shift]);
+    rotated
+}
+
+// What fills a partition that hangs off either end of the list.
+#[derive(Clone, Debug)]
+pub enum Padding {
+    Cyclic,
+    Element(SimplexPointer),
+    Cycle(Vec<SimplexPointer>),
+    Ragged,
+}
+
+// Sublists of length n at offset d, the first element of the list sitting at
+// position kl of the first sublist and the last element at or after
+// position kr of the last one (both counted from 1, negative from the end).
+pub fn partition(parts: &[SimplexPointer], n: usize, d: usize, kl: i64, kr: i64, padding: &Padding) -> Option<Vec<Vec<SimplexPointer>>> {
+    if n == 0 || d == 0 {
+        return None;
+    }
+
+    let alignment = |k: i64| if k < 0 { n as i64 + 1 + k } else { k };
+    let (kl, kr) = (alignment(kl), alignment(kr));
+
+    if kl < 1 || kl > n as i64 || kr < 1 || kr > n as i64 {
+        return None;
+    }
+
+    let length = parts.len() as i64;
+    let mut sublists = Vec::new();
+    let mut start = 1 - kl;
+
+    if length == 0 {
+        return Some(sublists);
+    }
+
+    while start + kr - 1 <= length - 1 {
+        let mut sublist = Vec::with_capacity(n);
+
+        for i in start..(start + n as i64) {
+            if i >= 0 && i < length {
+                sublist.push(parts[i as usize].clone());
+                continue;
+            }
+
+            match padding {
+                &Padding::Cyclic => sublist.push(parts[(((i % length) + length) % length) as usize].clone()),
+                &Padding::Element(ref x) => sublist.push(x.clone()),
+                &Padding::Cycle(ref xs) if !xs.is_empty() => {
+                    let cycle = xs.len() as i64;
+                    sublist.push(xs[(((i % cycle) + cycle) % cycle) as usize].clone());
+                }
+                _ => {}
+            }
+        }
+
+        sublists.push(sublist);
+        start = start + d as i64;
+    }
+
+    Some(sublists)
+}
+
+pub fn join(lists: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let first = match lists.first() {
+        Some(f) => f,
+        None => return None,
+    };
+
+    let head = first.head_name();
+    let mut joined = Vec::with_capacity(lists.iter().map(|l| l.get_length()).sum());
+
+    for l in lists {
+        if l.is_atomic() || l.head_name() != head {
+            return None;
+        }
+
+        joined.extend(l.get_parts());
+    }
+
+    Some(first.with_parts(&joined))
+}
@@ -0,0 +1,111 @@
+use std::collections::{HashMap, HashSet};
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::ordering::canonical_order;
+
+use evaluation::dispatch::apply;
+use evaluation::utilities::{list, integer};
+
+// Expressions compare by their printed form, so that doubles as a hash key.
+fn key(e: &SimplexPointer) -> String {
+    e.as_str().into_owned()
+}
+
+pub fn sort(parts: &[SimplexPointer]) -> Vec<SimplexPointer> {
+    let mut sorted = parts.to_vec();
+    sorted.sort_by(canonical_order);
+    sorted
+}
+
+pub fn sort_by(parts: &[SimplexPointer], f: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    let keys = parts.iter().map(|p| apply(f, &[p.clone()])).collect::<Option<Vec<SimplexPointer>>>();
+
+    keys.map(|keys| {
+        let mut keyed = keys.into_iter().zip(parts.iter().cloned()).collect::<Vec<(SimplexPointer, SimplexPointer)>>();
+        keyed.sort_by(|a, b| canonical_order(&a.0, &b.0));
+        keyed.into_iter().map(|(_, p)| p).collect()
+    })
+}
+
+// Keeps the first of every run of equal elements, in their original order.
+pub fn delete_duplicates(parts: &[SimplexPointer]) -> Vec<SimplexPointer> {
+    let mut seen = HashSet::new();
+    parts.iter().filter(|p| seen.insert(key(p))).cloned().collect()
+}
+
+pub fn union(lists: &[Vec<SimplexPointer>]) -> Vec<SimplexPointer> {
+    let all = lists.iter().flat_map(|l| l.iter().cloned()).collect::<Vec<SimplexPointer>>();
+    delete_duplicates(&sort(&all))
+}
+
+pub fn intersection(lists: &[Vec<SimplexPointer>]) -> Vec<SimplexPointer> {
+    let (first, rest) = match lists.split_first() {
+        Some(split) => split,
+        None => return Vec::new(),
+    };
+
+    let others = rest.iter()
+        .map(|l| l.iter().map(|p| key(p)).collect::<HashSet<String>>())
+        .collect::<Vec<HashSet<String>>>();
+
+    let common = first.iter()
+        .filter(|p| others.iter().all(|o| o.contains(&key(p))))
+        .cloned()
+        .collect::<Vec<SimplexPointer>>();
+
+    delete_duplicates(&sort(&common))
+}
+
+pub fn complement(all: &[SimplexPointer], lists: &[Vec<SimplexPointer>]) -> Vec<SimplexPointer> {
+    let excluded = lists.iter()
+        .flat_map(|l| l.iter().map(|p| key(p)))
+        .collect::<HashSet<String>>();
+
+    let remaining = all.iter()
+        .filter(|p| !excluded.contains(&key(p)))
+        .cloned()
+        .collect::<Vec<SimplexPointer>>();
+
+    delete_duplicates(&sort(&remaining))
+}
+
+// {element, count} pairs in order of first appearance.
+pub fn tally(parts: &[SimplexPointer]) -> Vec<SimplexPointer> {
+    let mut order = Vec::new();
+    let mut counts = HashMap::new();
+
+    for p in parts {
+        let count = counts.entry(key(p)).or_insert(0);
+
+        if *count == 0 {
+            order.push(p.clone());
+        }
+
+        *count = *count + 1;
+    }
+
+    order.iter().map(|p| list(&[p.clone(), integer(counts[&key(p)])])).collect()
+}
+
+pub fn gather_by(parts: &[SimplexPointer], f: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    let mut groups: Vec<Vec<SimplexPointer>> = Vec::new();
+    let mut index = HashMap::new();
+
+    for p in parts {
+        let k = match apply(f, &[p.clone()]) {
+            Some(k) => key(&k),
+            None => return None,
+        };
+
+        let slot = *index.entry(k).or_insert(groups.len());
+
+        if slot == groups.len() {
+            groups.push(Vec::new());
+        }
+
+        groups[slot].push(p.clone());
+    }
+
+    Some(groups.iter().map(|g| list(g)).collect())
+}
@@ -0,0 +1,226 @@
+#[cfg(test)]
+mod tests {
+    mod test_construction {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        #[test]
+        fn it_builds_ranges() {
+            assert_eq!(evaluate(&expression("Range", &[integer(4)])).as_str(), "List[1, 2, 3, 4]");
+            assert_eq!(evaluate(&expression("Range", &[integer(2), integer(5)])).as_str(), "List[2, 3, 4, 5]");
+            assert_eq!(evaluate(&expression("Range", &[integer(5), integer(1), integer(-2)])).as_str(),
+                       "List[5, 3, 1]");
+        }
+
+        #[test]
+        fn it_builds_real_ranges() {
+            let e = expression("Range", &[integer(0), integer(1), SimplexPointer::from("0.25")]);
+            assert_eq!(evaluate(&e).as_str(), "List[0, 0.25, 0.50, 0.75, 1.00]");
+        }
+
+        #[test]
+        fn it_leaves_zero_steps_unevaluated() {
+            let e = expression("Range", &[integer(1), integer(2), integer(0)]);
+            assert_eq!(evaluate(&e).as_str(), "Range[1, 2, 0]");
+        }
+
+        #[test]
+        fn it_builds_tables() {
+            let body = expression("f", &[symbol("i")]);
+            let e = expression("Table", &[body, list(&[symbol("i"), integer(3)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[f[1], f[2], f[3]]");
+        }
+
+        #[test]
+        fn it_holds_table_bodies_until_bound() {
+            let body = expression("Range", &[symbol("i")]);
+            let e = expression("Table", &[body, list(&[symbol("i"), integer(2), integer(3)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[List[1, 2], List[1, 2, 3]]");
+        }
+
+        #[test]
+        fn it_builds_nested_tables() {
+            let body = list(&[symbol("i"), symbol("j")]);
+            let e = expression("Table", &[body, list(&[symbol("i"), integer(2)]),
+                                                list(&[symbol("j"), symbol("i"), integer(2)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[List[List[1, 1], List[1, 2]], List[List[2, 2]]]");
+        }
+
+        #[test]
+        fn it_builds_tables_over_values() {
+            let values = list(&[symbol("a"), list(&[symbol("b")])]);
+            let e = expression("Table", &[expression("g", &[symbol("x")]), list(&[symbol("x"), values])]);
+            assert_eq!(evaluate(&e).as_str(), "List[g[a], g[List[b]]]");
+        }
+
+        #[test]
+        fn it_builds_constant_arrays() {
+            let e = expression("ConstantArray", &[symbol("c"), list(&[integer(2), integer(1)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[List[c], List[c]]");
+        }
+    }
+
+    mod test_segments {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        fn abcde() -> SimplexPointer {
+            list(&["a", "b", "c", "d", "e"].iter().map(|s| symbol(s)).collect::<Vec<SimplexPointer>>())
+        }
+
+        #[test]
+        fn it_joins_appends_and_prepends() {
+            let j = expression("Join", &[list(&[symbol("a")]), list(&[symbol("b"), symbol("c")])]);
+            assert_eq!(evaluate(&j).as_str(), "List[a, b, c]");
+            assert_eq!(evaluate(&expression("Append", &[list(&[symbol("a")]), symbol("b")])).as_str(), "List[a, b]");
+            assert_eq!(evaluate(&expression("Prepend", &[list(&[symbol("a")]), symbol("b")])).as_str(), "List[b, a]");
+        }
+
+        #[test]
+        fn it_takes() {
+            assert_eq!(evaluate(&expression("Take", &[abcde(), integer(2)])).as_str(), "List[a, b]");
+            assert_eq!(evaluate(&expression("Take", &[abcde(), integer(-2)])).as_str(), "List[d, e]");
+            assert_eq!(evaluate(&expression("Take", &[abcde(), list(&[integer(1), integer(-1), integer(2)])])).as_str(),
+                       "List[a, c, e]");
+        }
+
+        #[test]
+        fn it_drops() {
+            assert_eq!(evaluate(&expression("Drop", &[abcde(), integer(2)])).as_str(), "List[c, d, e]");
+            assert_eq!(evaluate(&expression("Drop", &[abcde(), list(&[integer(2), integer(4)])])).as_str(),
+                       "List[a, e]");
+        }
+
+        #[test]
+        fn it_reverses_and_rotates() {
+            assert_eq!(evaluate(&expression("Reverse", &[abcde()])).as_str(), "List[e, d, c, b, a]");
+            assert_eq!(evaluate(&expression("RotateLeft", &[abcde()])).as_str(), "List[b, c, d, e, a]");
+            assert_eq!(evaluate(&expression("RotateRight", &[abcde(), integer(2)])).as_str(), "List[d, e, a, b, c]");
+        }
+
+        #[test]
+        fn it_partitions() {
+            assert_eq!(evaluate(&expression("Partition", &[abcde(), integer(2)])).as_str(),
+                       "List[List[a, b], List[c, d]]");
+            assert_eq!(evaluate(&expression("Partition", &[abcde(), integer(3), integer(1)])).as_str(),
+                       "List[List[a, b, c], List[b, c, d], List[c, d, e]]");
+        }
+
+        #[test]
+        fn it_partitions_with_padding() {
+            let padded = expression("Partition", &[abcde(), integer(2), integer(2), integer(1), symbol("x")]);
+            assert_eq!(evaluate(&padded).as_str(), "List[List[a, b], List[c, d], List[e, x]]");
+
+            let ragged = expression("Partition", &[abcde(), integer(2), integer(2), integer(1), list(&[])]);
+            assert_eq!(evaluate(&ragged).as_str(), "List[List[a, b], List[c, d], List[e]]");
+
+            let cyclic = expression("Partition", &[abcde(), integer(3), integer(3), integer(1)]);
+            assert_eq!(evaluate(&cyclic).as_str(), "List[List[a, b, c], List[d, e, a]]");
+        }
+    }
+
+    mod test_restructure {
+        use expression::traits::BaseExpression;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        #[test]
+        fn it_flattens() {
+            let nested = list(&[symbol("a"), list(&[symbol("b"), list(&[symbol("c")])]), expression("f", &[list(&[])])]);
+            assert_eq!(evaluate(&expression("Flatten", &[nested.clone()])).as_str(), "List[a, b, c, f[List[]]]");
+            assert_eq!(evaluate(&expression("Flatten", &[nested, integer(1)])).as_str(),
+                       "List[a, b, List[c], f[List[]]]");
+        }
+
+        #[test]
+        fn it_transposes() {
+            let m = list(&[list(&[integer(1), integer(2)]), list(&[integer(3), integer(4)])]);
+            assert_eq!(evaluate(&expression("Transpose", &[m])).as_str(), "List[List[1, 3], List[2, 4]]");
+        }
+
+        #[test]
+        fn it_leaves_ragged_transposes() {
+            let m = list(&[list(&[integer(1), integer(2)]), list(&[integer(3)])]);
+            assert_eq!(evaluate(&expression("Transpose", &[m])).as_str(),
+                       "Transpose[List[List[1, 2], List[3]]]");
+        }
+    }
+
+    mod test_sets {
+        use expression::traits::BaseExpression;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        #[test]
+        fn it_sorts_canonically() {
+            let l = list(&[symbol("b"), integer(3), symbol("a"), integer(1), expression("f", &[symbol("x")])]);
+            assert_eq!(evaluate(&expression("Sort", &[l])).as_str(), "List[1, 3, a, b, f[x]]");
+        }
+
+        #[test]
+        fn it_sorts_by_keys() {
+            let l = list(&[list(&[integer(2)]), list(&[integer(1), integer(1)]), list(&[])]);
+            assert_eq!(evaluate(&expression("SortBy", &[l, symbol("Length")])).as_str(),
+                       "List[List[], List[2], List[1, 1]]");
+        }
+
+        #[test]
+        fn it_combines_sets() {
+            let a = list(&[symbol("c"), symbol("a"), symbol("b"), symbol("a")]);
+            let b = list(&[symbol("b"), symbol("d")]);
+
+            assert_eq!(evaluate(&expression("Union", &[a.clone(), b.clone()])).as_str(), "List[a, b, c, d]");
+            assert_eq!(evaluate(&expression("Intersection", &[a.clone(), b.clone()])).as_str(), "List[b]");
+            assert_eq!(evaluate(&expression("Complement", &[a.clone(), b])).as_str(), "List[a, c]");
+            assert_eq!(evaluate(&expression("DeleteDuplicates", &[a])).as_str(), "List[c, a, b]");
+        }
+
+        #[test]
+        fn it_tallies() {
+            let l = list(&[symbol("a"), symbol("b"), symbol("a")]);
+            assert_eq!(evaluate(&expression("Tally", &[l])).as_str(), "List[List[a, 2], List[b, 1]]");
+        }
+
+        #[test]
+        fn it_gathers_by_keys() {
+            let l = list(&[list(&[symbol("a")]), list(&[symbol("b"), symbol("c")]), list(&[symbol("d")])]);
+            assert_eq!(evaluate(&expression("GatherBy", &[l, symbol("Length")])).as_str(),
+                       "List[List[List[a], List[d]], List[List[b, c]]]");
+        }
+    }
+
+    mod test_reduction {
+        use expression::traits::BaseExpression;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        #[test]
+        fn it_totals() {
+            let l = list(&[integer(1), integer(2), integer(3)]);
+            assert_eq!(evaluate(&expression("Total", &[l])).as_str(), "6");
+        }
+
+        #[test]
+        fn it_totals_columns_and_levels() {
+            let m = list(&[list(&[integer(1), integer(2)]), list(&[integer(3), integer(4)])]);
+            assert_eq!(evaluate(&expression("Total", &[m.clone()])).as_str(), "List[4, 6]");
+            assert_eq!(evaluate(&expression("Total", &[m, integer(2)])).as_str(), "10");
+        }
+
+        #[test]
+        fn it_accumulates() {
+            let l = list(&[integer(1), integer(2), integer(3)]);
+            assert_eq!(evaluate(&expression("Accumulate", &[l])).as_str(), "List[1, 3, 6]");
+        }
+
+        #[test]
+        fn it_keeps_symbolic_totals() {
+            let l = list(&[symbol("a"), integer(2)]);
+            assert_eq!(evaluate(&expression("Total", &[l])).as_str(), "Plus[2, a]");
+        }
+    }
+}
@@ -1,6 +1,20 @@
 mod parsing;
 mod expression;
 mod arithmetic;
+mod pattern;
+mod evaluation;
+mod structural;
+mod lists;
+mod associations;
+mod polynomials;
+mod calculus;
+mod solving;
+mod numerical;
+mod matrices;
+mod elementary;
+mod special;
+mod integers;
+mod numbers;
 
 #[macro_use]
 extern crate decimal;
@@ -9,10 +23,12 @@ extern crate decimal;
 extern crate lazy_static;
 
 extern crate regex;
+extern crate num;
 
 use std::io::{self, BufRead};
 use std::collections::HashMap;
 use expression::atom::numbers::number::Numeric;
+use expression::traits::BaseExpression;
 use regex::Regex;
 
 struct State {
@@ -30,29 +46,41 @@ impl State {
 }
 
 fn evaluate(line: String, state: &mut State) {
+    // Plain assignments and lookups of what they stored come first, since
+    // the expression parser has no Set. Everything else goes to the parser,
+    // and the operator regex only sees what it rejects.
     match assignment(&line, state) {
         Some((x, y)) => {
-            state.num_map.insert(x.clone(), y);
+            state.num_map.insert(x.clone(), y.clone());
             println!("Out[{}]= {} == {} [Assignment]",
                      state.current_input,
                      x.clone(),
                      y.as_str());
         }
         None => {
-            match operator(&line, state) {
-                Some(s) => {
-                    println!("Out[{}]= {} [Operator]", state.current_input, s.as_str());
+            match parse_v_name(&line, state) {
+                Some(x) => {
+                    println!("Out[{}]= {} [VName Lookup]",
+                             state.current_input,
+                             x.as_str());
                 }
 
                 None => {
-                    match parse_v_name(&line, state) {
-                        Some(x) => {
-                            println!("Out[{}]= {} [VName Lookup]",
+                    match parsing::expression::parse(&line) {
+                        Ok(e) => {
+                            println!("Out[{}]= {}",
                                      state.current_input,
-                                     x.as_str());
+                                     evaluation::dispatch::evaluate(&e).as_str());
                         }
-                        None => {
-                            println!("Out[{}]= {} [Unknown Op]", state.current_input, line);
+                        Err(_) => {
+                            match operator(&line, state) {
+                                Some(s) => {
+                                    println!("Out[{}]= {} [Operator]", state.current_input, s.as_str());
+                                }
+                                None => {
+                                    println!("Out[{}]= {} [Unknown Op]", state.current_input, line);
+                                }
+                            }
                         }
                     }
                 }
@@ -66,14 +94,14 @@ fn evaluate(line: String, state: &mut State) {
 
 fn assignment(line: &String, state: &mut State) -> Option<(String, Numeric)> {
     lazy_static! {
-        static ref RE : Regex = Regex::new(r"\s*(?P<lhs>[a-zA-Z]+)\s*=\s*(?P<rhs>[a-zA-Z0-9|.]*)\s*$").unwrap();
+        static ref RE : Regex = Regex::new(r"^\s*(?P<lhs>[a-zA-Z]+)\s*=\s*(?P<rhs>[a-zA-Z0-9|.]*)\s*$").unwrap();
     }
 
     let captures = RE.captures(line.as_str());
     match captures {
         Some(c) => {
-            let lhs = c.name("lhs").unwrap();
-            let rhs = c.name("rhs").unwrap();
+            let lhs = c.name("lhs").unwrap().as_str();
+            let rhs = c.name("rhs").unwrap().as_str();
 
             match Numeric::from(rhs).to_string().as_str() {
                 "NaN" => {
@@ -94,35 +122,35 @@ fn assignment(line: &String, state: &mut State) -> Option<(String, Numeric)> {
 
 fn operator(line: &String, state: &mut State) -> Option<String> {
     lazy_static! {
-        static ref RE : Regex = Regex::new(r"\s*(?P<lhs>[a-zA-Z0-9\.]*)\s*(?P<op>[+-/*])\s*(?P<rhs>[a-zA-Z0-9\.]*)\s*$").unwrap();
+        static ref RE : Regex = Regex::new(r"^\s*(?P<lhs>[a-zA-Z0-9\.]*)\s*(?P<op>[+\-/*])\s*(?P<rhs>[a-zA-Z0-9\.]*)\s*$").unwrap();
     }
 
     let captures = RE.captures(line.as_str());
 
     match captures {
         Some(c) => {
-            let lhs = c.name("lhs").unwrap();
-            let rhs = c.name("rhs").unwrap();
-            let op = c.name("op").unwrap();
+            let lhs = c.name("lhs").unwrap().as_str();
+            let rhs = c.name("rhs").unwrap().as_str();
+            let op = c.name("op").unwrap().as_str();
 
             match op {
                 "+" => {
                     match (state.num_map.get(lhs), state.num_map.get(rhs)) {
-                        (Some(x), Some(y)) => Some((*x + *y).to_string()),
+                        (Some(x), Some(y)) => Some((x.clone() + y.clone()).to_string()),
                         (Some(x), None) => {
                             let y = Numeric::from(rhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((x.to_string() + " + " + rhs).to_string())
                             } else {
-                                Some((*x + y).to_string())
+                                Some((x.clone() + y).to_string())
                             }
                         }
                         (None, Some(x)) => {
                             let y = Numeric::from(lhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((y.to_string() + " + " + lhs).to_string())
                             } else {
-                                Some((*x + y).to_string())
+                                Some((x.clone() + y).to_string())
                             }
                         }
                         (None, None) => Some((lhs.to_string() + " + " + rhs)),
@@ -130,21 +158,21 @@ fn operator(line: &String, state: &mut State) -> Option<String> {
                 }
                 "-" => {
                     match (state.num_map.get(lhs), state.num_map.get(rhs)) {
-                        (Some(x), Some(y)) => Some((*x - *y).to_string()),
+                        (Some(x), Some(y)) => Some((x.clone() - y.clone()).to_string()),
                         (Some(x), None) => {
                             let y = Numeric::from(rhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((x.to_string() + " - " + rhs).to_string())
                             } else {
-                                Some((*x - y).to_string())
+                                Some((x.clone() - y).to_string())
                             }
                         }
                         (None, Some(x)) => {
                             let y = Numeric::from(lhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((y.to_string() + " - " + lhs).to_string())
                             } else {
-                                Some((*x - y).to_string())
+                                Some((x.clone() - y).to_string())
                             }
                         }
                         (None, None) => Some((Numeric::from(lhs) - Numeric::from(rhs)).to_string()),
@@ -153,21 +181,21 @@ fn operator(line: &String, state: &mut State) -> Option<String> {
 
                 "/" => {
                     match (state.num_map.get(lhs), state.num_map.get(rhs)) {
-                        (Some(x), Some(y)) => Some((*x / *y).to_string()),
+                        (Some(x), Some(y)) => Some((x.clone() / y.clone()).to_string()),
                         (Some(x), None) => {
                             let y = Numeric::from(rhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((x.to_string() + " / " + rhs).to_string())
                             } else {
-                                Some((*x / y).to_string())
+                                Some((x.clone() / y).to_string())
                             }
                         }
                         (None, Some(x)) => {
                             let y = Numeric::from(lhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((y.to_string() + " / " + lhs).to_string())
                             } else {
-                                Some((*x / y).to_string())
+                                Some((x.clone() / y).to_string())
                             }
                         }
                         (None, None) => Some((Numeric::from(lhs) / Numeric::from(rhs)).to_string()),
@@ -176,21 +204,21 @@ fn operator(line: &String, state: &mut State) -> Option<String> {
 
                 "*" => {
                     match (state.num_map.get(lhs), state.num_map.get(rhs)) {
-                        (Some(x), Some(y)) => Some((*x * *y).to_string()),
+                        (Some(x), Some(y)) => Some((x.clone() * y.clone()).to_string()),
                         (Some(x), None) => {
                             let y = Numeric::from(rhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((x.to_string() + " * " + rhs).to_string())
                             } else {
-                                Some((*x * y).to_string())
+                                Some((x.clone() * y).to_string())
                             }
                         }
                         (None, Some(x)) => {
                             let y = Numeric::from(lhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((y.to_string() + " * " + lhs).to_string())
                             } else {
-                                Some((*x * y).to_string())
+                                Some((x.clone() * y).to_string())
                             }
                         }
                         (None, None) => Some((Numeric::from(lhs) * Numeric::from(rhs)).to_string()),
@@ -202,7 +230,7 @@ fn operator(line: &String, state: &mut State) -> Option<String> {
                         (Some(x), Some(y)) => Some((*x == *y).to_string()),
                         (Some(x), None) => {
                             let y = Numeric::from(rhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((x.to_string() == rhs).to_string())
                             } else {
                                 Some((*x == y).to_string())
@@ -210,7 +238,7 @@ fn operator(line: &String, state: &mut State) -> Option<String> {
                         }
                         (None, Some(x)) => {
                             let y = Numeric::from(lhs);
-                            if y.simplify() == Numeric::NaN {
+                            if y.clone().simplify() == Numeric::NaN {
                                 Some((y.to_string() == lhs).to_string())
                             } else {
                                 Some((*x == y).to_string())
@@ -231,7 +259,7 @@ fn operator(line: &String, state: &mut State) -> Option<String> {
 
 fn parse_v_name(line: &String, state: &mut State) -> Option<Numeric> {
     lazy_static! {
-        static ref RE : Regex = Regex::new(r"\s*(?P<data>[a-zA-Z]+)\s*$").unwrap();
+        static ref RE : Regex = Regex::new(r"^\s*(?P<data>[a-zA-Z]+)\s*$").unwrap();
     }
 
     let captures = RE.captures(line.as_str());
@@ -240,7 +268,7 @@ fn parse_v_name(line: &String, state: &mut State) -> Option<Numeric> {
         Some(c) => {
             match c.name("data") {
                 Some(x) => {
-                    match state.num_map.get(x) {
+                    match state.num_map.get(x.as_str()) {
                         Some(y) => Some(y.to_owned()),
                         None => None,
                     }
@@ -0,0 +1,267 @@
+use decimal::d128;
+
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{list, integer, symbol, is_head};
+use solving::linear::Matrix;
+use numerical::real::zero;
+use numerical::dense::dot;
+use matrices::structure::{vector, matrix, square, to_expression, identity, numbers, reals, from_reals, from_real};
+use matrices::operations::{self, diagonal, trace};
+use matrices::elimination::{reduce, determinant, inverse, rank, null_space, linear_solve};
+use matrices::eigen::{characteristic_polynomial, exact_eigenvalues, exact_eigenvectors, numeric_eigensystem, complex_expression};
+use matrices::decomposition::{lu, condition, qr, cholesky, singular_values};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Dot" => Some(dot_builtin),
+        "IdentityMatrix" => Some(identity_matrix_builtin),
+        "DiagonalMatrix" => Some(diagonal_matrix_builtin),
+        "Tr" => Some(tr_builtin),
+        "Det" => Some(det_builtin),
+        "Inverse" => Some(inverse_builtin),
+        "LinearSolve" => Some(linear_solve_builtin),
+        "RowReduce" => Some(row_reduce_builtin),
+        "MatrixRank" => Some(matrix_rank_builtin),
+        "NullSpace" => Some(null_space_builtin),
+        "Eigenvalues" => Some(eigenvalues_builtin),
+        "Eigenvectors" => Some(eigenvectors_builtin),
+        "Eigensystem" => Some(eigensystem_builtin),
+        "CharacteristicPolynomial" => Some(characteristic_polynomial_builtin),
+        "LUDecomposition" => Some(lu_decomposition_builtin),
+        "QRDecomposition" => Some(qr_decomposition_builtin),
+        "CholeskyDecomposition" => Some(cholesky_decomposition_builtin),
+        "SingularValueDecomposition" => Some(singular_value_decomposition_builtin),
+        _ => None,
+    }
+}
+
+// Left to right, staying unevaluated unless every argument is a list of
+// matching shape.
+fn dot_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.is_empty() || !args.iter().all(|a| is_head(a, "List")) {
+        return None;
+    }
+
+    args[1..].iter().fold(Some(args[0].clone()), |total, a| total.and_then(|t| operations::dot(&t, a)))
+}
+
+fn identity_matrix_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    match args[0].as_integer() {
+        Some(n) if n > 0 => Some(to_expression(&identity(n as usize))),
+        _ => None,
+    }
+}
+
+fn diagonal_matrix_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let k = match args.len() {
+        1 => 0,
+        2 => args[1].as_integer()?,
+        _ => return None,
+    };
+
+    match vector(&args[0]) {
+        Some(ref entries) if !entries.is_empty() => Some(to_expression(&diagonal(entries, k))),
+        _ => None,
+    }
+}
+
+// The sum down the diagonal of a matrix, or of the entries of a vector.
+fn tr_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    match (matrix(&args[0]), vector(&args[0])) {
+        (Some(m), _) => Some(trace(&m)),
+        (None, Some(ref v)) if !v.is_empty() => Some(trace(&diagonal(v, 0))),
+        _ => None,
+    }
+}
+
+fn det_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    square(&args[0]).map(|m| determinant(&m))
+}
+
+// Unevaluated for a singular matrix.
+fn inverse_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    square(&args[0]).and_then(|m| inverse(&m)).map(|i| to_expression(&i))
+}
+
+// LinearSolve[m, b] for a vector b, or a matrix b with a column of the
+// solution for each of its columns.
+fn linear_solve_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    let m = matrix(&args[0])?;
+
+    if let Some(b) = vector(&args[1]) {
+        if b.len() != m.len() {
+            return None;
+        }
+
+        let columns = b.into_iter().map(|e| vec![e]).collect::<Vec<Vec<SimplexPointer>>>();
+        let x = linear_solve(&m, &columns)?;
+        return Some(list(&x.into_iter().map(|mut r| r.remove(0)).collect::<Vec<SimplexPointer>>()));
+    }
+
+    match matrix(&args[1]) {
+        Some(ref b) if b.len() == m.len() => linear_solve(&m, b).map(|x| to_expression(&x)),
+        _ => None,
+    }
+}
+
+fn row_reduce_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    matrix(&args[0]).map(|m| to_expression(&reduce(&m, m[0].len()).0))
+}
+
+fn matrix_rank_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    matrix(&args[0]).map(|m| integer(rank(&m) as i64))
+}
+
+fn null_space_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    matrix(&args[0]).map(|m| to_expression(&null_space(&m)))
+}
+
+// Eigenvalues and eigenvectors of a matrix with a real entry found
+// numerically, otherwise exactly from the characteristic polynomial.
+fn eigensystem(m: &[Vec<SimplexPointer>], vectors: bool) -> Option<(Vec<SimplexPointer>, Matrix)> {
+    if let Some(a) = reals(m) {
+        let pairs = numeric_eigensystem(&a)?;
+        let values = pairs.iter().map(|&(v, _)| complex_expression(v)).collect();
+        let vectors = pairs.iter().map(|&(_, ref x)| x.iter().map(|&z| complex_expression(z)).collect()).collect();
+        return Some((values, vectors));
+    }
+
+    let values = exact_eigenvalues(m)?;
+    let vectors = if vectors { exact_eigenvectors(m, &values) } else { Vec::new() };
+    Some((values, vectors))
+}
+
+fn eigenvalues_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    eigensystem(&square(&args[0])?, false).map(|(values, _)| list(&values))
+}
+
+fn eigenvectors_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    eigensystem(&square(&args[0])?, true).map(|(_, vectors)| to_expression(&vectors))
+}
+
+fn eigensystem_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    eigensystem(&square(&args[0])?, true).map(|(values, vectors)| list(&[list(&values), to_expression(&vectors)]))
+}
+
+fn characteristic_polynomial_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || args[1].as_symbol().is_none() {
+        return None;
+    }
+
+    square(&args[0]).map(|m| characteristic_polynomial(&m, &args[1]))
+}
+
+// {lu, p, c}: lower and upper triangles packed together, the order of the
+// rows counting from one and, for real matrices, the condition number.
+fn lu_decomposition_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    let m = square(&args[0])?;
+
+    let (packed, order, c) = match reals(&m) {
+        Some(a) => {
+            let mut packed = a.clone();
+            let order = lu(&mut packed);
+            let c = condition(&a).map_or(symbol("Infinity"), from_real);
+            (from_reals(&packed), order, c)
+        }
+        None => {
+            let mut packed = m.clone();
+            let order = lu(&mut packed);
+            (packed, order, integer(0))
+        }
+    };
+
+    let order = order.iter().map(|&i| integer(i as i64 + 1)).collect::<Vec<SimplexPointer>>();
+    Some(list(&[to_expression(&packed), list(&order), c]))
+}
+
+// {q, r} with m == Transpose[q].r, the rows of q orthonormal and r upper
+// triangular.
+fn qr_decomposition_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    let a = numbers(&matrix(&args[0])?)?;
+    let (q, sources) = qr(&a);
+
+    let r = q.iter().zip(sources.iter()).map(|(row, &s)| {
+        (0..a[0].len()).map(|j| if j < s { zero() } else { dot(row, &a.iter().map(|r| r[j]).collect::<Vec<d128>>()) }).collect()
+    }).collect::<Vec<Vec<d128>>>();
+
+    Some(list(&[to_expression(&from_reals(&q)), to_expression(&from_reals(&r))]))
+}
+
+fn cholesky_decomposition_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    cholesky(&numbers(&square(&args[0])?)?).map(|u| to_expression(&from_reals(&u)))
+}
+
+// {u, w, v} with m == u.w.Transpose[v].
+fn singular_value_decomposition_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    let a = numbers(&matrix(&args[0])?)?;
+    let (left, values, right) = singular_values(&a)?;
+    let columns = |vs: &[Vec<d128>]| (0..vs[0].len()).map(|i| vs.iter().map(|v| v[i]).collect()).collect::<Vec<Vec<d128>>>();
+
+    let w = (0..a.len()).map(|i| (0..a[0].len()).map(|j| if i == j { values[i] } else { zero() }).collect()).collect::<Vec<Vec<d128>>>();
+
+    Some(list(&[to_expression(&from_reals(&columns(&left))),
+                to_expression(&from_reals(&w)),
+                to_expression(&from_reals(&columns(&right)))]))
+}
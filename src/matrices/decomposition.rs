@@ -0,0 +1,244 @@
+use decimal::d128;
+
+use numerical::real::{self, zero, one, tolerance, MAXIMUM_PRECISION};
+use numerical::dense::{Vector, dot};
+use matrices::entries::Entry;
+use matrices::elimination::row_reduce;
+
+// Doolittle elimination in place with the multipliers of the unit lower
+// triangle stored below the diagonal of the upper one, rows exchanged as
+// the entries' pivoting asks. Gives the order the rows ended up in.
+pub fn lu<T: Entry>(rows: &mut Vec<Vec<T>>) -> Vec<usize> {
+    let n = rows.len();
+    let mut order = (0..n).collect::<Vec<usize>>();
+
+    for k in 0..n {
+        let found = (k..n)
+            .filter(|&i| !rows[i][k].is_zero())
+            .fold(None, |best: Option<usize>, i| match best {
+                Some(b) if !rows[i][k].is_better_pivot(&rows[b][k]) => Some(b),
+                _ => Some(i),
+            });
+
+        let p = match found {
+            Some(p) => p,
+            None => continue,
+        };
+
+        rows.swap(k, p);
+        order.swap(k, p);
+
+        for i in k + 1..n {
+            let f = rows[i][k].divide(&rows[k][k]);
+
+            for j in k + 1..n {
+                rows[i][j] = rows[i][j].subtract(&f.multiply(&rows[k][j]));
+            }
+
+            rows[i][k] = f;
+        }
+    }
+
+    order
+}
+
+fn infinity_norm(a: &[Vec<d128>]) -> d128 {
+    a.iter().fold(zero(), |m, r| m.max(r.iter().fold(zero(), |t, x| t + x.abs())))
+}
+
+// The condition number in the infinity norm, None for a singular matrix.
+pub fn condition(a: &[Vec<d128>]) -> Option<d128> {
+    let n = a.len();
+
+    let mut augmented = a.iter().enumerate().map(|(i, r)| {
+        let mut row = r.clone();
+        row.extend((0..n).map(|j| if i == j { one() } else { zero() }));
+        row
+    }).collect::<Vec<Vec<d128>>>();
+
+    let (pivots, _, _) = row_reduce(&mut augmented, n);
+
+    if pivots.len() < n {
+        return None;
+    }
+
+    let inverse = augmented.iter().map(|r| r[n..].to_vec()).collect::<Vec<Vec<d128>>>();
+    Some(infinity_norm(a) * infinity_norm(&inverse))
+}
+
+fn length(v: &[d128]) -> d128 {
+    real::sqrt(dot(v, v))
+}
+
+fn column(a: &[Vec<d128>], j: usize) -> Vector {
+    a.iter().map(|r| r[j]).collect()
+}
+
+// v with its components along each of the orthonormal vectors removed,
+// twice over so that what is left is orthogonal to working precision.
+fn orthogonalize(v: &[d128], basis: &[Vector]) -> Vector {
+    let mut v = v.to_vec();
+
+    for _ in 0..2 {
+        for q in basis {
+            let c = dot(q, &v);
+
+            for (x, &y) in v.iter_mut().zip(q.iter()) {
+                *x = *x - c * y;
+            }
+        }
+    }
+
+    v
+}
+
+// Orthonormal vectors spanning the columns of a, from Gram-Schmidt, and
+// for each of them the column it came from. Columns dependent on the ones
+// before them are passed over.
+pub fn qr(a: &[Vec<d128>]) -> (Vec<Vector>, Vec<usize>) {
+    let columns = a[0].len();
+    let scale = (0..columns).fold(zero(), |m, j| m.max(length(&column(a, j))));
+    let mut basis = Vec::new();
+    let mut sources = Vec::new();
+
+    for j in 0..columns {
+        let v = orthogonalize(&column(a, j), &basis);
+        let l = length(&v);
+
+        if l > scale * tolerance(MAXIMUM_PRECISION - 6) {
+            basis.push(v.iter().map(|&x| x / l).collect());
+            sources.push(j);
+        }
+    }
+
+    (basis, sources)
+}
+
+// The upper triangular u with a = Transpose[u].u, None unless a is
+// symmetric and positive definite.
+pub fn cholesky(a: &[Vec<d128>]) -> Option<Vec<Vector>> {
+    let n = a.len();
+
+    if (0..n).any(|i| (0..i).any(|j| a[i][j] != a[j][i])) {
+        return None;
+    }
+
+    let mut u = vec![vec![zero(); n]; n];
+
+    for i in 0..n {
+        let s = (0..i).fold(a[i][i], |s, k| s - u[k][i] * u[k][i]);
+
+        if !s.is_positive() {
+            return None;
+        }
+
+        u[i][i] = real::sqrt(s);
+
+        for j in i + 1..n {
+            let t = (0..i).fold(a[i][j], |t, k| t - u[k][i] * u[k][j]);
+            u[i][j] = t / u[i][i];
+        }
+    }
+
+    Some(u)
+}
+
+// Extends orthonormal vectors of length n to a basis of all n dimensions
+// with the unit vectors they leave out.
+fn complete(basis: &mut Vec<Vector>, n: usize) {
+    for i in 0..n {
+        if basis.len() == n {
+            break;
+        }
+
+        let unit = (0..n).map(|j| if i == j { one() } else { zero() }).collect::<Vector>();
+        let v = orthogonalize(&unit, basis);
+        let l = length(&v);
+
+        if l > real::real("0.5") {
+            basis.push(v.iter().map(|&x| x / l).collect());
+        }
+    }
+}
+
+// a = u.w.Transpose[v] with u and v orthogonal and w diagonal with the
+// singular values down it, largest first. One-sided Jacobi rotates pairs
+// of columns of a until they are orthogonal, their lengths then being the
+// singular values.
+pub fn singular_values(a: &[Vec<d128>]) -> Option<(Vec<Vector>, Vector, Vec<Vector>)> {
+    let (m, n) = (a.len(), a[0].len());
+
+    if m < n {
+        let transposed = (0..n).map(|j| column(a, j)).collect::<Vec<Vector>>();
+        return singular_values(&transposed).map(|(u, w, v)| (v, w, u));
+    }
+
+    // Columns of a.v, and of v.
+    let mut u = (0..n).map(|j| column(a, j)).collect::<Vec<Vector>>();
+    let mut v = (0..n).map(|j| (0..n).map(|i| if i == j { one() } else { zero() }).collect()).collect::<Vec<Vector>>();
+    let epsilon = tolerance(MAXIMUM_PRECISION - 2);
+    let mut converged = false;
+
+    for _ in 0..64 {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = dot(&u[p], &u[p]);
+                let beta = dot(&u[q], &u[q]);
+                let gamma = dot(&u[p], &u[q]);
+
+                if gamma.abs() <= epsilon * real::sqrt(alpha * beta) {
+                    continue;
+                }
+
+                rotated = true;
+
+                let zeta = (beta - alpha) / (real::real("2") * gamma);
+                let sign = if zeta.is_negative() { -one() } else { one() };
+                let t = sign / (zeta.abs() + real::sqrt(one() + zeta * zeta));
+                let c = one() / real::sqrt(one() + t * t);
+                let s = c * t;
+
+                for w in [&mut u, &mut v].iter_mut() {
+                    for i in 0..w[p].len() {
+                        let (x, y) = (w[p][i], w[q][i]);
+                        w[p][i] = c * x - s * y;
+                        w[q][i] = s * x + c * y;
+                    }
+                }
+            }
+        }
+
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+
+    if !converged {
+        return None;
+    }
+
+    let mut triples = u.into_iter().zip(v.into_iter()).map(|(x, y)| (length(&x), x, y)).collect::<Vec<(d128, Vector, Vector)>>();
+    triples.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+    let largest = triples[0].0;
+    let mut left = Vec::new();
+    let mut values = Vec::new();
+    let mut right = Vec::new();
+
+    for (sigma, x, y) in triples {
+        if sigma > largest * tolerance(MAXIMUM_PRECISION - 6) {
+            left.push(x.iter().map(|&e| e / sigma).collect::<Vector>());
+            values.push(sigma);
+        } else {
+            values.push(zero());
+        }
+
+        right.push(y);
+    }
+
+    complete(&mut left, m);
+    Some((left, values, right))
+}
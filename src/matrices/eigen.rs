@@ -0,0 +1,529 @@
+use std::cmp::Ordering;
+
+use decimal::d128;
+
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::evaluate;
+use evaluation::utilities::{integer, symbol};
+use arithmetic::canonical::{sum, product};
+use polynomials::expand::expand;
+use solving::linear::Matrix;
+use solving::roots::polynomial_roots;
+use numerical::real::{self, zero, one, tolerance, MAXIMUM_PRECISION, MACHINE_PRECISION};
+use numerical::complex::Complex;
+use numerical::value::real_value;
+use matrices::entries::Entry;
+use matrices::structure::from_real;
+use matrices::elimination::{determinant, null_space};
+
+// Det[m - x I], expanded.
+pub fn characteristic_polynomial(rows: &[Vec<SimplexPointer>], x: &SimplexPointer) -> SimplexPointer {
+    let shifted = rows.iter().enumerate().map(|(i, r)| {
+        r.iter().enumerate().map(|(j, e)| {
+            if i == j { sum(&[e.clone(), product(&[integer(-1), x.clone()])]) } else { e.clone() }
+        }).collect()
+    }).collect::<Matrix>();
+
+    expand(&determinant(&shifted))
+}
+
+// Exact or symbolic eigenvalues as the roots of the characteristic
+// polynomial, repeated by multiplicity and largest first where they all
+// have real values to compare.
+pub fn exact_eigenvalues(rows: &[Vec<SimplexPointer>]) -> Option<Vec<SimplexPointer>> {
+    let x = symbol("$Eigenvalue");
+    let mut roots = polynomial_roots(&characteristic_polynomial(rows, &x), &x)?;
+
+    if let Some(values) = roots.iter().map(|r| real_value(r, &[])).collect::<Option<Vec<d128>>>() {
+        let mut keyed = values.into_iter().zip(roots.into_iter()).collect::<Vec<(d128, SimplexPointer)>>();
+        keyed.sort_by(|a, b| b.0.abs().partial_cmp(&a.0.abs()).unwrap_or(Ordering::Equal).then(b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal)));
+        roots = keyed.into_iter().map(|(_, r)| r).collect();
+    }
+
+    Some(roots)
+}
+
+// A basis of the null space of m - v I for each distinct eigenvalue v,
+// padded with zero vectors where there are fewer than its multiplicity.
+pub fn exact_eigenvectors(rows: &[Vec<SimplexPointer>], values: &[SimplexPointer]) -> Matrix {
+    let n = rows.len();
+    let mut vectors = Vec::new();
+
+    for (k, v) in values.iter().enumerate() {
+        if values[..k].contains(v) {
+            continue;
+        }
+
+        let shifted = rows.iter().enumerate().map(|(i, r)| {
+            r.iter().enumerate().map(|(j, e)| if i == j { e.subtract(v) } else { e.clone() }).collect()
+        }).collect::<Matrix>();
+
+        let multiplicity = values.iter().filter(|w| *w == v).count();
+        let mut basis = null_space(&shifted);
+        basis.truncate(multiplicity);
+
+        while basis.len() < multiplicity {
+            basis.push(vec![integer(0); n]);
+        }
+
+        vectors.extend(basis);
+    }
+
+    vectors
+}
+
+fn is_symmetric(a: &[Vec<d128>]) -> bool {
+    (0..a.len()).all(|i| (0..i).all(|j| a[i][j] == a[j][i]))
+}
+
+// Cyclic Jacobi rotations until the off-diagonal part vanishes, giving the
+// eigenvalues of a real symmetric matrix with orthonormal eigenvectors.
+fn jacobi(a: &[Vec<d128>]) -> Option<(Vec<d128>, Vec<Vec<d128>>)> {
+    let n = a.len();
+    let mut a = a.to_vec();
+    let mut v = (0..n).map(|i| (0..n).map(|j| if i == j { one() } else { zero() }).collect()).collect::<Vec<Vec<d128>>>();
+    let scale = a.iter().fold(zero(), |t, r| r.iter().fold(t, |t, &x| t + x * x));
+    let goal = scale * tolerance(2 * (MAXIMUM_PRECISION - 2));
+
+    for _ in 0..64 {
+        let off = (0..n).fold(zero(), |t, p| (p + 1..n).fold(t, |t, q| t + a[p][q] * a[p][q]));
+
+        if off <= goal {
+            let values = (0..n).map(|i| a[i][i]).collect();
+            let vectors = (0..n).map(|j| (0..n).map(|i| v[i][j]).collect()).collect();
+            return Some((values, vectors));
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].is_zero() {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (real::real("2") * a[p][q]);
+                let t = signed(one(), theta) / (theta.abs() + real::sqrt(theta * theta + one()));
+                let c = one() / real::sqrt(t * t + one());
+                let s = t * c;
+
+                for k in 0..n {
+                    let (x, y) = (a[k][p], a[k][q]);
+                    a[k][p] = c * x - s * y;
+                    a[k][q] = s * x + c * y;
+                }
+
+                for k in 0..n {
+                    let (x, y) = (a[p][k], a[q][k]);
+                    a[p][k] = c * x - s * y;
+                    a[q][k] = s * x + c * y;
+                }
+
+                for k in 0..n {
+                    let (x, y) = (v[k][p], v[k][q]);
+                    v[k][p] = c * x - s * y;
+                    v[k][q] = s * x + c * y;
+                }
+
+                a[p][q] = zero();
+                a[q][p] = zero();
+            }
+        }
+    }
+
+    None
+}
+
+// Similarity reduction to upper Hessenberg form by elimination with
+// pivoting, everything below the subdiagonal left zero.
+fn hessenberg(a: &mut Vec<Vec<d128>>) {
+    let n = a.len();
+
+    for m in 1..n.saturating_sub(1) {
+        let i = (m..n).fold(m, |best, j| if a[j][m - 1].abs() > a[best][m - 1].abs() { j } else { best });
+        let x = a[i][m - 1];
+
+        if i != m {
+            a.swap(i, m);
+
+            for row in a.iter_mut() {
+                row.swap(i, m);
+            }
+        }
+
+        if x.is_zero() {
+            continue;
+        }
+
+        for i in m + 1..n {
+            let y = a[i][m - 1] / x;
+
+            if y.is_zero() {
+                continue;
+            }
+
+            a[i][m - 1] = zero();
+
+            for j in m..n {
+                let t = y * a[m][j];
+                a[i][j] = a[i][j] - t;
+            }
+
+            for j in 0..n {
+                let t = y * a[j][i];
+                a[j][m] = a[j][m] + t;
+            }
+        }
+    }
+}
+
+// |a| with the sign of b.
+fn signed(a: d128, b: d128) -> d128 {
+    if b.is_negative() { -a.abs() } else { a.abs() }
+}
+
+// The eigenvalues of an upper Hessenberg matrix by the Francis double
+// shift QR algorithm, deflating one real or two complex conjugate values
+// at a time from the bottom.
+fn francis(h: &[Vec<d128>]) -> Option<Vec<Complex>> {
+    let n = h.len();
+
+    // One-based, as the algorithm is usually written.
+    let mut a = vec![vec![zero(); n + 1]; n + 1];
+
+    for i in 0..n {
+        for j in 0..n {
+            a[i + 1][j + 1] = h[i][j];
+        }
+    }
+
+    let epsilon = tolerance(MAXIMUM_PRECISION - 1);
+    let anorm = (1..n + 1).fold(zero(), |t, i| ((if i > 1 { i - 1 } else { 1 })..n + 1).fold(t, |t, j| t + a[i][j].abs()));
+    let half = real::real("0.5");
+    let mut values = vec![Complex::real(zero()); n + 1];
+    let mut nn = n;
+    let mut shift = zero();
+
+    while nn >= 1 {
+        let mut its = 0;
+
+        loop {
+            let mut l = nn;
+
+            while l >= 2 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+
+                if s.is_zero() {
+                    s = anorm;
+                }
+
+                if a[l][l - 1].abs() <= epsilon * s {
+                    a[l][l - 1] = zero();
+                    break;
+                }
+
+                l = l - 1;
+            }
+
+            let mut x = a[nn][nn];
+
+            if l == nn {
+                values[nn] = Complex::real(x + shift);
+                nn = nn - 1;
+                break;
+            }
+
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+
+            if l == nn - 1 {
+                let p = half * (y - x);
+                let q = p * p + w;
+                let z = real::sqrt(q.abs());
+                x = x + shift;
+
+                if !q.is_negative() {
+                    let z = p + signed(z, p);
+                    values[nn - 1] = Complex::real(x + z);
+                    values[nn] = Complex::real(if z.is_zero() { x + z } else { x - w / z });
+                } else {
+                    values[nn - 1] = Complex::new(x + p, -z);
+                    values[nn] = Complex::new(x + p, z);
+                }
+
+                nn = nn - 2;
+                break;
+            }
+
+            if its == 60 {
+                return None;
+            }
+
+            if its > 0 && its % 10 == 0 {
+                shift = shift + x;
+
+                for i in 1..nn + 1 {
+                    a[i][i] = a[i][i] - x;
+                }
+
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = real::real("0.75") * s;
+                y = x;
+                w = real::real("-0.4375") * s * s;
+            }
+
+            its = its + 1;
+
+            let (mut p, mut q, mut r, mut z);
+            let mut m = nn - 2;
+
+            loop {
+                z = a[m][m];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - rr - ss;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+
+                if m == l {
+                    break;
+                }
+
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+
+                if u <= epsilon * v {
+                    break;
+                }
+
+                m = m - 1;
+            }
+
+            for i in m + 2..nn + 1 {
+                a[i][i - 2] = zero();
+
+                if i != m + 2 {
+                    a[i][i - 3] = zero();
+                }
+            }
+
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k != nn - 1 { a[k + 2][k - 1] } else { zero() };
+                    x = p.abs() + q.abs() + r.abs();
+
+                    if !x.is_zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+
+                let s = signed(real::sqrt(p * p + q * q + r * r), p);
+
+                if s.is_zero() {
+                    continue;
+                }
+
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q = q / p;
+                r = r / p;
+
+                for j in k..nn + 1 {
+                    let mut t = a[k][j] + q * a[k + 1][j];
+
+                    if k != nn - 1 {
+                        t = t + r * a[k + 2][j];
+                        a[k + 2][j] = a[k + 2][j] - t * z;
+                    }
+
+                    a[k + 1][j] = a[k + 1][j] - t * y;
+                    a[k][j] = a[k][j] - t * x;
+                }
+
+                for i in l..(if nn < k + 3 { nn } else { k + 3 }) + 1 {
+                    let mut t = x * a[i][k] + y * a[i][k + 1];
+
+                    if k != nn - 1 {
+                        t = t + z * a[i][k + 2];
+                        a[i][k + 2] = a[i][k + 2] - t * r;
+                    }
+
+                    a[i][k + 1] = a[i][k + 1] - t * q;
+                    a[i][k] = a[i][k] - t;
+                }
+            }
+        }
+    }
+
+    Some(values[1..].to_vec())
+}
+
+// Vectors spanning the null space of a, found by elimination with complete
+// pivoting that stops once every remaining entry is below the threshold.
+fn null_vectors(a: &[Vec<Complex>], threshold: d128) -> Vec<Vec<Complex>> {
+    let n = a.len();
+    let mut a = a.to_vec();
+    let mut order = (0..n).collect::<Vec<usize>>();
+    let mut rank = 0;
+
+    while rank < n {
+        let k = rank;
+        let (mut pi, mut pj) = (k, k);
+
+        for i in k..n {
+            for j in k..n {
+                if a[i][j].norm() > a[pi][pj].norm() {
+                    pi = i;
+                    pj = j;
+                }
+            }
+        }
+
+        if a[pi][pj].norm() <= threshold {
+            break;
+        }
+
+        a.swap(k, pi);
+        order.swap(k, pj);
+
+        for row in a.iter_mut() {
+            row.swap(k, pj);
+        }
+
+        for i in k + 1..n {
+            let f = a[i][k] / a[k][k];
+
+            for j in k..n {
+                a[i][j] = a[i][j] - f * a[k][j];
+            }
+        }
+
+        rank = rank + 1;
+    }
+
+    (rank..n).rev().map(|free| {
+        let mut x = vec![Complex::real(zero()); n];
+        x[free] = Complex::real(one());
+
+        for i in (0..rank).rev() {
+            let t = (i + 1..n).fold(Complex::real(zero()), |t, j| t + a[i][j] * x[j]);
+            x[i] = -t / a[i][i];
+        }
+
+        let mut v = vec![Complex::real(zero()); n];
+
+        for (j, &o) in order.iter().enumerate() {
+            v[o] = x[j];
+        }
+
+        v
+    }).collect()
+}
+
+// Unit length, turned so that the last significant entry is real and
+// positive, with negligible parts made zero.
+fn normalize(v: &[Complex]) -> Vec<Complex> {
+    let length = real::sqrt(v.iter().fold(zero(), |t, z| t + z.re * z.re + z.im * z.im));
+
+    if length.is_zero() {
+        return v.to_vec();
+    }
+
+    let last = v.iter().rev().find(|z| z.norm() > length * tolerance(MACHINE_PRECISION)).cloned().unwrap();
+    let phase = Complex::new(last.re / last.norm(), last.im / last.norm());
+
+    let part = |t: d128| if t.abs() <= tolerance(MAXIMUM_PRECISION - 4) { zero() } else { t };
+
+    v.iter().map(|&z| {
+        let w = z / phase;
+        Complex::new(part(w.re / length), part(w.im / length))
+    }).collect()
+}
+
+fn by_magnitude(a: &Complex, b: &Complex) -> Ordering {
+    b.norm().partial_cmp(&a.norm()).unwrap_or(Ordering::Equal)
+        .then(b.re.partial_cmp(&a.re).unwrap_or(Ordering::Equal))
+        .then(b.im.partial_cmp(&a.im).unwrap_or(Ordering::Equal))
+}
+
+// Eigenvalues of a real matrix, largest first, each with a unit eigenvector
+// or a zero vector where a defective matrix has too few of them.
+pub fn numeric_eigensystem(a: &[Vec<d128>]) -> Option<Vec<(Complex, Vec<Complex>)>> {
+    let n = a.len();
+
+    if is_symmetric(a) {
+        let (values, vectors) = jacobi(a)?;
+        let mut pairs = values.into_iter().zip(vectors.into_iter()).map(|(v, x)| {
+            (Complex::real(v), normalize(&x.into_iter().map(Complex::real).collect::<Vec<Complex>>()))
+        }).collect::<Vec<(Complex, Vec<Complex>)>>();
+
+        pairs.sort_by(|a, b| by_magnitude(&a.0, &b.0));
+        return Some(pairs);
+    }
+
+    let mut h = a.to_vec();
+    hessenberg(&mut h);
+
+    let mut values = francis(&h)?;
+    values.sort_by(by_magnitude);
+
+    let scale = a.iter().fold(one(), |m, r| r.iter().fold(m, |m, x| m.max(x.abs())));
+    let threshold = scale * tolerance(MACHINE_PRECISION - 2);
+    let mut pairs = Vec::with_capacity(n);
+    let mut k = 0;
+
+    while k < n {
+        let v = values[k];
+        let multiplicity = values[k..].iter().take_while(|w| (**w - v).norm() <= threshold).count();
+
+        let shifted = (0..n).map(|i| (0..n).map(|j| {
+            let e = Complex::real(a[i][j]);
+            if i == j { e - v } else { e }
+        }).collect()).collect::<Vec<Vec<Complex>>>();
+
+        let mut basis = null_vectors(&shifted, threshold).iter().map(|x| normalize(x)).collect::<Vec<Vec<Complex>>>();
+        basis.truncate(multiplicity);
+
+        while basis.len() < multiplicity {
+            basis.push(vec![Complex::real(zero()); n]);
+        }
+
+        for (j, x) in basis.into_iter().enumerate() {
+            pairs.push((values[k + j], x));
+        }
+
+        k = k + multiplicity;
+    }
+
+    Some(pairs)
+}
+
+// Real where the imaginary part is negligible, otherwise a + b I.
+pub fn complex_expression(z: Complex) -> SimplexPointer {
+    let negligible = |t: d128| t.abs() <= z.norm() * tolerance(MAXIMUM_PRECISION - 4);
+
+    if negligible(z.im) {
+        from_real(z.re)
+    } else if negligible(z.re) {
+        evaluate(&product(&[from_real(z.im), symbol("I")]))
+    } else {
+        evaluate(&sum(&[from_real(z.re), product(&[from_real(z.im), symbol("I")])]))
+    }
+}
@@ -0,0 +1,177 @@
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::integer;
+use arithmetic::canonical::product;
+use solving::linear::{Matrix, bareiss};
+use matrices::entries::Entry;
+use matrices::structure::{identity, reals, from_reals, from_real};
+use numerical::real::zero;
+
+// Gauss-Jordan elimination over the first `columns` columns, leaving every
+// pivot one and the only non-zero entry in its column. Gives the pivot
+// column of each leading row, along with the product of the pivots divided
+// out and the number of row swaps, which together make the determinant.
+pub fn row_reduce<T: Entry>(rows: &mut Vec<Vec<T>>, columns: usize) -> (Vec<usize>, T, usize) {
+    let mut pivots = Vec::new();
+    let mut scale = T::one();
+    let mut swaps = 0;
+
+    for c in 0..columns {
+        let r = pivots.len();
+
+        if r == rows.len() {
+            break;
+        }
+
+        let found = (r..rows.len())
+            .filter(|&i| !rows[i][c].is_zero())
+            .fold(None, |best: Option<usize>, i| match best {
+                Some(b) if !rows[i][c].is_better_pivot(&rows[b][c]) => Some(b),
+                _ => Some(i),
+            });
+
+        let found = match found {
+            Some(i) => i,
+            None => continue,
+        };
+
+        if found != r {
+            rows.swap(found, r);
+            swaps = swaps + 1;
+        }
+
+        let pivot = rows[r][c].clone();
+        scale = scale.multiply(&pivot);
+
+        for j in c..rows[r].len() {
+            rows[r][j] = if j == c { T::one() } else { rows[r][j].divide(&pivot) };
+        }
+
+        for i in 0..rows.len() {
+            if i == r || rows[i][c].is_zero() {
+                continue;
+            }
+
+            let factor = rows[i][c].clone();
+
+            for j in c..rows[i].len() {
+                rows[i][j] = if j == c { T::zero() } else { rows[i][j].subtract(&factor.multiply(&rows[r][j])) };
+            }
+        }
+
+        pivots.push(c);
+    }
+
+    (pivots, scale, swaps)
+}
+
+// Row reduction of expressions, going through machine reals where the
+// entries are all numbers and some of them inexact.
+pub fn reduce(rows: &[Vec<SimplexPointer>], columns: usize) -> (Matrix, Vec<usize>) {
+    match reals(rows) {
+        Some(mut r) => {
+            let (pivots, _, _) = row_reduce(&mut r, columns);
+            (from_reals(&r), pivots)
+        }
+        None => {
+            let mut r = rows.to_vec();
+            let (pivots, _, _) = row_reduce(&mut r, columns);
+            (r, pivots)
+        }
+    }
+}
+
+pub fn determinant(rows: &[Vec<SimplexPointer>]) -> SimplexPointer {
+    let n = rows.len();
+
+    if let Some(mut r) = reals(rows) {
+        let (pivots, scale, swaps) = row_reduce(&mut r, n);
+        let d = if pivots.len() < n { zero() } else if swaps % 2 == 0 { scale } else { -scale };
+        return from_real(d);
+    }
+
+    // Fraction-free, so that integer and polynomial determinants come out
+    // without any cancelling along the way.
+    let mut r = rows.to_vec();
+    let (pivots, swaps) = bareiss(&mut r, n);
+
+    if pivots.len() < n {
+        return integer(0);
+    }
+
+    let last = r[n - 1][n - 1].clone();
+
+    if swaps % 2 == 0 {
+        last
+    } else {
+        product(&[integer(-1), last])
+    }
+}
+
+// None for a singular matrix.
+pub fn inverse(rows: &[Vec<SimplexPointer>]) -> Option<Matrix> {
+    let n = rows.len();
+
+    let augmented = rows.iter().zip(identity(n)).map(|(row, unit)| {
+        let mut r = row.clone();
+        r.extend(unit);
+        r
+    }).collect::<Matrix>();
+
+    let (reduced, pivots) = reduce(&augmented, n);
+
+    if pivots.len() < n {
+        None
+    } else {
+        Some(reduced.iter().map(|r| r[n..].to_vec()).collect())
+    }
+}
+
+pub fn rank(rows: &[Vec<SimplexPointer>]) -> usize {
+    reduce(rows, rows[0].len()).1.len()
+}
+
+// A basis of the solutions to m x = 0, one vector for each column without
+// a pivot, the last of them first.
+pub fn null_space(rows: &[Vec<SimplexPointer>]) -> Matrix {
+    let columns = rows[0].len();
+    let (reduced, pivots) = reduce(rows, columns);
+
+    (0..columns).rev().filter(|c| !pivots.contains(c)).map(|free| {
+        let mut v = vec![integer(0); columns];
+        v[free] = integer(1);
+
+        for (r, &c) in pivots.iter().enumerate() {
+            v[c] = reduced[r][free].multiply(&integer(-1));
+        }
+
+        v
+    }).collect()
+}
+
+// One solution x of m x = b for each column of b, with every variable that
+// has no pivot set to zero; None when there is no solution.
+pub fn linear_solve(rows: &[Vec<SimplexPointer>], b: &[Vec<SimplexPointer>]) -> Option<Matrix> {
+    let columns = rows[0].len();
+
+    let augmented = rows.iter().zip(b.iter()).map(|(row, rhs)| {
+        let mut r = row.clone();
+        r.extend(rhs.iter().cloned());
+        r
+    }).collect::<Matrix>();
+
+    let (reduced, pivots) = reduce(&augmented, columns);
+
+    if reduced[pivots.len()..].iter().any(|r| r[columns..].iter().any(|e| !e.is_zero())) {
+        return None;
+    }
+
+    let width = b[0].len();
+    let mut x = vec![vec![integer(0); width]; columns];
+
+    for (r, &c) in pivots.iter().enumerate() {
+        x[c] = reduced[r][columns..].to_vec();
+    }
+
+    Some(x)
+}
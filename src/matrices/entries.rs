@@ -0,0 +1,128 @@
+use decimal::d128;
+
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::{self, integer};
+use arithmetic::terms::number;
+use arithmetic::canonical::{sum, product, power};
+use polynomials::expand::expand;
+use polynomials::rational::{fraction, together};
+use numerical::real::{self, zero, one};
+
+// What elimination needs from the entries of a matrix: expressions, which
+// stay exact, or machine reals, which pivot on size.
+pub trait Entry: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(&self, other: &Self) -> Self;
+    fn subtract(&self, other: &Self) -> Self;
+    fn multiply(&self, other: &Self) -> Self;
+    fn divide(&self, other: &Self) -> Self;
+    fn is_zero(&self) -> bool;
+    // Whether self makes a better pivot than other, both being non-zero.
+    fn is_better_pivot(&self, other: &Self) -> bool;
+}
+
+// Over a single denominator with numerator and denominator expanded, so
+// that entries which are zero look it.
+pub fn simplify(e: &SimplexPointer) -> SimplexPointer {
+    let t = together(e);
+
+    if fraction(&t).1.as_integer() == Some(1) {
+        expand(&t)
+    } else {
+        t
+    }
+}
+
+impl Entry for SimplexPointer {
+    fn zero() -> SimplexPointer {
+        integer(0)
+    }
+
+    fn one() -> SimplexPointer {
+        integer(1)
+    }
+
+    fn add(&self, other: &SimplexPointer) -> SimplexPointer {
+        match (self.as_numeric(), other.as_numeric()) {
+            (Some(a), Some(b)) => number(a + b),
+            _ => simplify(&sum(&[self.clone(), other.clone()])),
+        }
+    }
+
+    fn subtract(&self, other: &SimplexPointer) -> SimplexPointer {
+        match (self.as_numeric(), other.as_numeric()) {
+            (Some(a), Some(b)) => number(a - b),
+            _ => simplify(&sum(&[self.clone(), product(&[integer(-1), other.clone()])])),
+        }
+    }
+
+    fn multiply(&self, other: &SimplexPointer) -> SimplexPointer {
+        match (self.as_numeric(), other.as_numeric()) {
+            (Some(a), Some(b)) => number(a * b),
+            _ => simplify(&product(&[self.clone(), other.clone()])),
+        }
+    }
+
+    fn divide(&self, other: &SimplexPointer) -> SimplexPointer {
+        match (self.as_numeric(), other.as_numeric()) {
+            (Some(a), Some(b)) => number(a / b),
+            _ => simplify(&product(&[self.clone(), power(other, &integer(-1))])),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self.as_numeric() {
+            Some(n) => n.is_zero(),
+            None => utilities::is_zero(&expand(&fraction(self).0)),
+        }
+    }
+
+    fn is_better_pivot(&self, _: &SimplexPointer) -> bool {
+        false
+    }
+}
+
+impl Entry for d128 {
+    fn zero() -> d128 {
+        zero()
+    }
+
+    fn one() -> d128 {
+        one()
+    }
+
+    fn add(&self, other: &d128) -> d128 {
+        self.subtract(&-*other)
+    }
+
+    // Differences lost entirely to cancellation come out as exactly zero,
+    // so that dependent rows are recognised as such.
+    fn subtract(&self, other: &d128) -> d128 {
+        let difference = *self - *other;
+        let scale = self.abs().max(other.abs());
+
+        if difference.abs() <= scale * real::tolerance(real::MAXIMUM_PRECISION - 6) {
+            zero()
+        } else {
+            difference
+        }
+    }
+
+    fn multiply(&self, other: &d128) -> d128 {
+        *self * *other
+    }
+
+    fn divide(&self, other: &d128) -> d128 {
+        *self / *other
+    }
+
+    fn is_zero(&self) -> bool {
+        d128::is_zero(self)
+    }
+
+    fn is_better_pivot(&self, other: &d128) -> bool {
+        self.abs() > other.abs()
+    }
+}
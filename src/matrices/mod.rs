@@ -0,0 +1,8 @@
+pub mod structure;
+pub mod entries;
+pub mod elimination;
+pub mod operations;
+pub mod eigen;
+pub mod decomposition;
+pub mod builtins;
+pub mod test;
@@ -0,0 +1,73 @@
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{list, integer, is_head};
+use arithmetic::terms::number;
+use arithmetic::canonical::{sum, product};
+use lists::restructure::transpose;
+use solving::linear::Matrix;
+
+// The sum of products of matching entries, added up as numbers when they
+// all are.
+fn inner(a: &[SimplexPointer], b: &[SimplexPointer]) -> SimplexPointer {
+    let numbers = a.iter().chain(b.iter()).map(|e| e.as_numeric()).collect::<Option<Vec<Numeric>>>();
+
+    match numbers {
+        Some(ref n) => {
+            let (x, y) = n.split_at(a.len());
+            number(x.iter().zip(y.iter()).fold(Numeric::LittleInteger(0), |total, (p, q)| total + p.clone() * q.clone()))
+        }
+        None => sum(&a.iter().zip(b.iter()).map(|(p, q)| product(&[p.clone(), q.clone()])).collect::<Vec<SimplexPointer>>()),
+    }
+}
+
+// Contracts the last index of a with the first of b, for lists nested to
+// any depth: vector.vector is a scalar, matrix.vector a vector and so on.
+pub fn dot(a: &SimplexPointer, b: &SimplexPointer) -> Option<SimplexPointer> {
+    if !is_head(a, "List") || !is_head(b, "List") {
+        return None;
+    }
+
+    let (left, right) = (a.get_parts(), b.get_parts());
+
+    if !left.is_empty() && left.iter().all(|e| is_head(e, "List")) {
+        return left.iter().map(|r| dot(r, b)).collect::<Option<Vec<SimplexPointer>>>().map(|rows| list(&rows));
+    }
+
+    if left.is_empty() || left.len() != right.len() || left.iter().any(|e| is_head(e, "List")) {
+        return None;
+    }
+
+    if right.iter().all(|e| is_head(e, "List")) {
+        let columns = transpose(&right)?;
+        return columns.iter().map(|c| dot(a, c)).collect::<Option<Vec<SimplexPointer>>>().map(|c| list(&c));
+    }
+
+    if right.iter().any(|e| is_head(e, "List")) {
+        return None;
+    }
+
+    Some(inner(&left, &right))
+}
+
+// n by n with the given entries on the k-th diagonal, above the main one
+// for positive k and below it for negative k.
+pub fn diagonal(entries: &[SimplexPointer], k: i64) -> Matrix {
+    let n = entries.len() + k.abs() as usize;
+
+    (0..n).map(|i| (0..n).map(|j| {
+        let (i, j) = (i as i64, j as i64);
+
+        if j - i == k {
+            entries[if k >= 0 { i } else { j } as usize].clone()
+        } else {
+            integer(0)
+        }
+    }).collect()).collect()
+}
+
+pub fn trace(rows: &[Vec<SimplexPointer>]) -> SimplexPointer {
+    let diagonal = rows.iter().enumerate().filter_map(|(i, r)| r.get(i).cloned()).collect::<Vec<SimplexPointer>>();
+    let ones = vec![integer(1); diagonal.len()];
+    inner(&diagonal, &ones)
+}
@@ -0,0 +1,87 @@
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{list, integer, is_head};
+use solving::linear::Matrix;
+use numerical::real::{self, MACHINE_PRECISION, MAXIMUM_PRECISION};
+
+// A list of entries none of which are themselves lists.
+pub fn vector(e: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    if !is_head(e, "List") {
+        return None;
+    }
+
+    let parts = e.get_parts();
+
+    if parts.iter().any(|p| is_head(p, "List")) {
+        None
+    } else {
+        Some(parts)
+    }
+}
+
+// A non-empty list of vectors all of the same non-zero length.
+pub fn matrix(e: &SimplexPointer) -> Option<Matrix> {
+    if !is_head(e, "List") || e.get_length() == 0 {
+        return None;
+    }
+
+    let rows = e.get_parts().iter().map(vector).collect::<Option<Matrix>>()?;
+    let width = rows[0].len();
+
+    if width == 0 || rows.iter().any(|r| r.len() != width) {
+        None
+    } else {
+        Some(rows)
+    }
+}
+
+pub fn square(e: &SimplexPointer) -> Option<Matrix> {
+    matrix(e).and_then(|m| if m.len() == m[0].len() { Some(m) } else { None })
+}
+
+pub fn to_expression(rows: &[Vec<SimplexPointer>]) -> SimplexPointer {
+    list(&rows.iter().map(|r| list(r)).collect::<Vec<SimplexPointer>>())
+}
+
+pub fn identity(n: usize) -> Matrix {
+    (0..n).map(|i| (0..n).map(|j| integer(if i == j { 1 } else { 0 })).collect()).collect()
+}
+
+fn numerics(rows: &[Vec<SimplexPointer>]) -> Option<Vec<Vec<Numeric>>> {
+    rows.iter()
+        .map(|r| r.iter().map(|e| e.as_numeric()).collect::<Option<Vec<Numeric>>>())
+        .collect()
+}
+
+// The same matrix over d128 when every entry is a number.
+pub fn numbers(rows: &[Vec<SimplexPointer>]) -> Option<Vec<Vec<d128>>> {
+    numerics(rows).map(|m| m.iter().map(|r| r.iter().map(|n| n.to_d128()).collect()).collect())
+}
+
+// As numbers, but only when at least one entry is a real, which is when
+// elimination should pivot on size.
+pub fn reals(rows: &[Vec<SimplexPointer>]) -> Option<Vec<Vec<d128>>> {
+    if numerics(rows)?.iter().all(|r| r.iter().all(|n| n.is_exact())) {
+        None
+    } else {
+        numbers(rows)
+    }
+}
+
+// Back to expressions as machine reals, well short of the last few digits
+// that carry whatever error elimination made, and with entries that are
+// negligible next to the largest of them made zero.
+pub fn from_reals(rows: &[Vec<d128>]) -> Matrix {
+    let largest = rows.iter().fold(real::zero(), |m, r| r.iter().fold(m, |m, x| m.max(x.abs())));
+    let negligible = largest * real::tolerance(MAXIMUM_PRECISION - 4);
+
+    rows.iter().map(|r| r.iter().map(|&x| if x.abs() <= negligible { integer(0) } else { from_real(x) }).collect()).collect()
+}
+
+pub fn from_real(x: d128) -> SimplexPointer {
+    real::to_expression(x, MACHINE_PRECISION)
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    mod test_operations {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_dots_vectors_and_matrices() {
+            assert_eq!(run("{1, 2}.{3, 4}"), "11");
+            assert_eq!(run("{{1, 2}, {3, 4}}.{5, 6}"), "List[17, 39]");
+            assert_eq!(run("{1, 2}.{{1, 2}, {3, 4}}"), "List[7, 10]");
+            assert_eq!(run("{{1, 2}, {3, 4}}.{{5, 6}, {7, 8}}"), "List[List[19, 22], List[43, 50]]");
+            assert_eq!(run("Dot[{1, 2}, {{1, 2}, {3, 4}}, {1, 1}]"), "17");
+            assert_eq!(run("{a, b}.{c, d}"), "Plus[Times[a, c], Times[b, d]]");
+        }
+
+        #[test]
+        fn it_leaves_mismatched_dots() {
+            assert_eq!(run("a.b"), "Dot[a, b]");
+            assert_eq!(run("{1, 2}.{1, 2, 3}"), "Dot[List[1, 2], List[1, 2, 3]]");
+        }
+
+        #[test]
+        fn it_builds_identity_and_diagonal_matrices() {
+            assert_eq!(run("IdentityMatrix[2]"), "List[List[1, 0], List[0, 1]]");
+            assert_eq!(run("DiagonalMatrix[{a, b}]"), "List[List[a, 0], List[0, b]]");
+            assert_eq!(run("DiagonalMatrix[{1, 2}, -1]"), "List[List[0, 0, 0], List[1, 0, 0], List[0, 2, 0]]");
+        }
+
+        #[test]
+        fn it_takes_traces() {
+            assert_eq!(run("Tr[{{1, 2}, {3, 4}}]"), "5");
+            assert_eq!(run("Tr[{{a, b}, {c, d}}]"), "Plus[a, d]");
+            assert_eq!(run("Tr[IdentityMatrix[3]]"), "3");
+        }
+    }
+
+    mod test_elimination {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_finds_determinants() {
+            assert_eq!(run("Det[{{1, 2}, {3, 4}}]"), "-2");
+            assert_eq!(run("Det[{{0, 1}, {1, 0}}]"), "-1");
+            assert_eq!(run("Det[{{1, 2, 3}, {4, 5, 6}, {7, 8, 9}}]"), "0");
+            assert_eq!(run("Det[{{1/2, 1}, {1, 3}}]"), "Rational[1, 2]");
+            assert_eq!(run("Det[{{a, b}, {c, d}}]"), "Plus[Times[a, d], Times[-1, b, c]]");
+            assert_eq!(run("Det[{{2.5, 1}, {1, 3}}]"), "6.5");
+            assert_eq!(run("Det[{{1, 2}}]"), "Det[List[List[1, 2]]]");
+            assert_eq!(run("Det[{{10^10, 1}, {1, 10^10}}]"), "99999999999999999999");
+            assert_eq!(run("Det[{{2^40, 3, 5}, {7, 2^40, 11}, {13, 17, 2^40}}]"), "1329227995784915872903506893605962752");
+        }
+
+        #[test]
+        fn it_inverts_matrices() {
+            assert_eq!(run("Inverse[{{1, 2}, {3, 4}}]"), "List[List[-2, 1], List[Rational[3, 2], Rational[-1, 2]]]");
+            assert_eq!(run("Inverse[{{1, x}, {0, 1}}]"), "List[List[1, Times[-1, x]], List[0, 1]]");
+            assert_eq!(run("Inverse[{{2., 1}, {1, 3}}]"), "List[List[0.6, -0.2], List[-0.2, 0.4]]");
+            assert_eq!(run("Inverse[{{1, 2}, {2, 4}}]"), "Inverse[List[List[1, 2], List[2, 4]]]");
+            assert_eq!(run("Inverse[{{a, 0}, {0, b}}]"), "List[List[Power[a, -1], 0], List[0, Power[b, -1]]]");
+        }
+
+        #[test]
+        fn it_solves_linear_systems() {
+            assert_eq!(run("LinearSolve[{{1, 2}, {3, 4}}, {5, 6}]"), "List[-4, Rational[9, 2]]");
+            assert_eq!(run("LinearSolve[{{1., 2}, {3, 4}}, {5, 6}]"), "List[-4, 4.5]");
+            assert_eq!(run("LinearSolve[{{1, 1, 1}}, {3}]"), "List[3, 0, 0]");
+            assert_eq!(run("LinearSolve[{{1, 2}, {3, 4}}, {{1, 0}, {0, 1}}]"),
+                       "List[List[-2, 1], List[Rational[3, 2], Rational[-1, 2]]]");
+            assert_eq!(run("LinearSolve[{{a, 1}, {1, a}}, {1, 0}]"),
+                       "List[Times[a, Power[Plus[-1, Power[a, 2]], -1]], Times[-1, Power[Plus[-1, Power[a, 2]], -1]]]");
+            assert_eq!(run("LinearSolve[{{1, 1}, {1, 1}}, {1, 2}]"), "LinearSolve[List[List[1, 1], List[1, 1]], List[1, 2]]");
+            assert_eq!(run("LinearSolve[{{10^10, 1}, {1, 10^10}}, {1, 2}]"),
+                       "List[Rational[9999999998, 99999999999999999999], Rational[19999999999, 99999999999999999999]]");
+        }
+
+        #[test]
+        fn it_row_reduces() {
+            assert_eq!(run("RowReduce[{{1, 2, 3}, {4, 5, 6}, {7, 8, 9}}]"), "List[List[1, 0, -1], List[0, 1, 2], List[0, 0, 0]]");
+            assert_eq!(run("RowReduce[{{2, 4}, {1, 3}}]"), "List[List[1, 0], List[0, 1]]");
+            assert_eq!(run("RowReduce[{{1., 2}, {2, 4.}}]"), "List[List[1, 2], List[0, 0]]");
+            assert_eq!(run("RowReduce[{{a, 1}, {2 a, 2}}]"), "List[List[1, Power[a, -1]], List[0, 0]]");
+        }
+
+        #[test]
+        fn it_finds_ranks_and_null_spaces() {
+            assert_eq!(run("MatrixRank[{{1, 2, 3}, {4, 5, 6}, {7, 8, 9}}]"), "2");
+            assert_eq!(run("MatrixRank[{{1., 2, 3}, {4, 5, 6}, {7, 8, 9}}]"), "2");
+            assert_eq!(run("MatrixRank[IdentityMatrix[3]]"), "3");
+            assert_eq!(run("NullSpace[{{1, 2, 3}, {4, 5, 6}, {7, 8, 9}}]"), "List[List[1, -2, 1]]");
+            assert_eq!(run("NullSpace[{{1, 1, 1}}]"), "List[List[-1, 0, 1], List[-1, 1, 0]]");
+            assert_eq!(run("NullSpace[{{1, 2}, {3, 4}}]"), "List[]");
+        }
+    }
+
+    mod test_eigen {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_finds_characteristic_polynomials() {
+            assert_eq!(run("CharacteristicPolynomial[{{1, 2}, {3, 4}}, x]"), "Plus[-2, Times[-5, x], Power[x, 2]]");
+            assert_eq!(run("CharacteristicPolynomial[{{a, b}, {c, d}}, x]"),
+                       "Plus[Times[a, d], Times[-1, a, x], Times[-1, b, c], Times[-1, d, x], Power[x, 2]]");
+        }
+
+        #[test]
+        fn it_finds_exact_eigensystems() {
+            assert_eq!(run("Eigenvalues[{{2, 1}, {1, 2}}]"), "List[3, 1]");
+            assert_eq!(run("Eigenvectors[{{2, 1}, {1, 2}}]"), "List[List[1, 1], List[-1, 1]]");
+            assert_eq!(run("Eigensystem[{{1, 1}, {0, 1}}]"), "List[List[1, 1], List[List[1, 0], List[0, 0]]]");
+            assert_eq!(run("Eigenvalues[{{2, 0, 0}, {0, 3, 4}, {0, 4, 9}}]"), "List[11, 2, 1]");
+        }
+
+        #[test]
+        fn it_finds_symmetric_eigensystems_numerically() {
+            assert_eq!(run("Eigenvalues[{{2., 1}, {1, 2}}]"), "List[3, 1]");
+            assert_eq!(run("Eigenvectors[{{2., 1}, {1, 2}}][[2]]"), "List[-0.7071067811865475, 0.7071067811865475]");
+            assert_eq!(run("Eigenvalues[{{2., 0, 0}, {0, 3, 4}, {0, 4, 9}}]"), "List[11, 2, 1]");
+        }
+
+        #[test]
+        fn it_finds_general_eigensystems_numerically() {
+            assert_eq!(run("Eigenvalues[{{1., 2}, {3, 4}}]"), "List[5.372281323269014, -0.3722813232690143]");
+            assert_eq!(run("Eigenvectors[{{1., 2}, {3, 4}}][[1]]"), "List[0.4159735579192843, 0.9093767091321241]");
+            assert_eq!(run("Eigenvalues[{{1., 2, 3}, {4, 5, 6}, {7, 8, 10}}][[1]]"), "16.70749331612475");
+            assert_eq!(run("Eigenvalues[{{0., 1}, {-1, 0}}]"), "List[Times[1, I], Times[-1, I]]");
+            assert_eq!(run("Eigensystem[{{1., 1}, {0, 1}}]"), "List[List[1, 1], List[List[1, 0], List[0, 0]]]");
+        }
+    }
+
+    mod test_decomposition {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_decomposes_lu() {
+            assert_eq!(run("LUDecomposition[{{1, 2}, {3, 4}}]"), "List[List[List[1, 2], List[3, -2]], List[1, 2], 0]");
+            assert_eq!(run("LUDecomposition[{{1., 2}, {3, 4}}]"),
+                       "List[List[List[3, 4], List[0.3333333333333333, 0.6666666666666667]], List[2, 1], 21]");
+        }
+
+        #[test]
+        fn it_decomposes_qr() {
+            assert_eq!(run("QRDecomposition[{{3., 0}, {4, 5}}]"), "List[List[List[0.6, 0.8], List[-0.8, 0.6]], List[List[5, 4], List[0, 3]]]");
+            assert_eq!(run("Length[QRDecomposition[{{1, 2}, {2, 4}, {3, 6}}][[1]]]"), "1");
+        }
+
+        #[test]
+        fn it_decomposes_cholesky() {
+            assert_eq!(run("CholeskyDecomposition[{{4., 2}, {2, 3}}]"), "List[List[2, 1], List[0, 1.414213562373095]]");
+            assert_eq!(run("CholeskyDecomposition[{{1, 2}, {2, 1}}]"), "CholeskyDecomposition[List[List[1, 2], List[2, 1]]]");
+        }
+
+        #[test]
+        fn it_decomposes_singular_values() {
+            assert_eq!(run("SingularValueDecomposition[{{3., 0}, {0, -2}}]"),
+                       "List[List[List[1, 0], List[0, -1]], List[List[3, 0], List[0, 2]], List[List[1, 0], List[0, 1]]]");
+            assert_eq!(run("SingularValueDecomposition[{{1., 2}, {3, 4}, {5, 6}}][[2]]"),
+                       "List[List[9.525518091565108, 0], List[0, 0.5143005806586443], List[0, 0]]");
+            assert_eq!(run("SingularValueDecomposition[{{1, 2, 3}}][[2]]"), "List[List[3.741657386773941, 0, 0]]");
+        }
+    }
+}
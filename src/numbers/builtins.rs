@@ -0,0 +1,492 @@
+use std::cmp::Ordering;
+
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+use expression::atom::structure::SimplexAtom;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{expression, integer, symbol, boolean, is_head, list, half, negative};
+use arithmetic::terms::{number, coefficient_and_term};
+use arithmetic::canonical::{thread, sum, product, power};
+use numerical::real::{self, one, tolerance, MACHINE_PRECISION, MAXIMUM_PRECISION};
+use numerical::value::real_value;
+use numerical::constants::{is_numeric, numeric_order, sign};
+use numerical::complex::complex_parts;
+use numbers::rounding::{floor, ceiling, round, integer_part, fractional_part, rationalize};
+use numbers::forms::{scientific, engineering, fixed, base_exact, base_real};
+use special::combinatorial::from_numeric;
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Floor" => Some(floor_builtin),
+        "Ceiling" => Some(ceiling_builtin),
+        "Round" => Some(round_builtin),
+        "IntegerPart" => Some(integer_part_builtin),
+        "FractionalPart" => Some(fractional_part_builtin),
+        "Abs" => Some(abs_builtin),
+        "Sign" => Some(sign_builtin),
+        "Max" => Some(max_builtin),
+        "Min" => Some(min_builtin),
+        "Clip" => Some(clip_builtin),
+        "Rationalize" => Some(rationalize_builtin),
+        "IntegerQ" => Some(integer_q_builtin),
+        "NumberQ" => Some(number_q_builtin),
+        "NumericQ" => Some(numeric_q_builtin),
+        "EvenQ" => Some(even_q_builtin),
+        "OddQ" => Some(odd_q_builtin),
+        "Positive" => Some(positive_builtin),
+        "Negative" => Some(negative_builtin),
+        "ScientificForm" => Some(scientific_form_builtin),
+        "EngineeringForm" => Some(engineering_form_builtin),
+        "NumberForm" => Some(number_form_builtin),
+        "BaseForm" => Some(base_form_builtin),
+        _ => None,
+    }
+}
+
+fn has_negative_coefficient(e: &SimplexPointer) -> bool {
+    e.as_numeric().is_none() && coefficient_and_term(e).0 < Numeric::LittleInteger(0)
+}
+
+// f of a numeric quantity such as Pi through its value, as long as values
+// a little either side of it agree: Floor[x] right at an integer is left
+// alone rather than guessed.
+fn through_value(f: fn(Numeric) -> Option<Numeric>, e: &SimplexPointer) -> Option<Numeric> {
+    if !is_numeric(e) {
+        return None;
+    }
+
+    let x = real_value(e, &[])?;
+    let margin = (x.abs() + one()) * tolerance(MAXIMUM_PRECISION - 4);
+    let (low, high) = (f(Numeric::LittleReal(x - margin))?, f(Numeric::LittleReal(x + margin))?);
+
+    if low == high { Some(low) } else { None }
+}
+
+// Floor[x] and Floor[x, a], the greatest multiple of a not above x, and the
+// same for the rest of the rounding functions.
+fn rounding(name: &str, f: fn(Numeric) -> Option<Numeric>, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread(name, args) {
+        return Some(threaded);
+    }
+
+    match args.len() {
+        1 => match args[0].as_numeric() {
+            Some(n) => f(n).map(number),
+            None => through_value(f, &args[0]).map(number),
+        },
+        2 => {
+            let (x, a) = (args[0].as_numeric()?, args[1].as_numeric()?);
+
+            if a.is_zero() {
+                return None;
+            }
+
+            f(x / a.clone()).map(|n| number(n * a))
+        }
+        _ => None,
+    }
+}
+
+fn floor_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    rounding("Floor", floor, args)
+}
+
+fn ceiling_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    rounding("Ceiling", ceiling, args)
+}
+
+fn round_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    rounding("Round", round, args)
+}
+
+fn integer_part_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    rounding("IntegerPart", integer_part, args)
+}
+
+// FractionalPart[x] as x - IntegerPart[x], which for numeric quantities
+// stays exact.
+fn fractional_part_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("FractionalPart", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    match args[0].as_numeric() {
+        Some(n) => fractional_part(n).map(number),
+        None => through_value(integer_part, &args[0]).map(|n| sum(&[args[0].clone(), number(Numeric::LittleInteger(0) - n)])),
+    }
+}
+
+// |x| for numbers, Sqrt[a^2 + b^2] for a + b I, x or -x for numeric
+// quantities of known sign, and Abs[-x] as Abs[x].
+fn abs_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("Abs", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    let x = &args[0];
+
+    if let Some((a, b)) = complex_parts(x) {
+        if a == Numeric::NaN || b == Numeric::NaN {
+            return None;
+        }
+
+        if b.is_zero() {
+            return Some(number(if a < Numeric::LittleInteger(0) { Numeric::LittleInteger(0) - a } else { a }));
+        }
+
+        return Some(power(&number(a.clone() * a + b.clone() * b), &half()));
+    }
+
+    match sign(x) {
+        Some(-1) => Some(negative(x.clone())),
+        Some(0) => Some(integer(0)),
+        Some(_) => Some(x.clone()),
+        None if has_negative_coefficient(x) => Some(expression("Abs", &[negative(x.clone())])),
+        None => None,
+    }
+}
+
+// -1, 0 or 1 for reals, z / Abs[z] for complex numbers, and Sign[-x] as
+// -Sign[x].
+fn sign_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("Sign", args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    let x = &args[0];
+
+    if let Some((_, b)) = complex_parts(x) {
+        if !b.is_zero() {
+            return Some(product(&[x.clone(), power(&expression("Abs", &[x.clone()]), &integer(-1))]));
+        }
+    }
+
+    match sign(x) {
+        Some(s) => Some(integer(s)),
+        None if has_negative_coefficient(x) => Some(negative(expression("Sign", &[negative(x.clone())]))),
+        None => None,
+    }
+}
+
+fn flatten(args: &[SimplexPointer], into: &mut Vec<SimplexPointer>) {
+    for a in args {
+        if is_head(a, "List") {
+            flatten(&a.get_parts(), into);
+        } else if !into.contains(a) {
+            into.push(a.clone());
+        }
+    }
+}
+
+// Max and Min over their arguments and the elements of any lists among
+// them: the extreme numeric one, kept alongside whatever isn't numeric.
+// Infinity decides Max outright and drops out of Min.
+fn extremum(name: &str, wanted: Ordering, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let infinity = symbol("Infinity");
+    let (winner, loser) = if wanted == Ordering::Greater {
+        (infinity.clone(), negative(infinity))
+    } else {
+        (negative(infinity.clone()), infinity)
+    };
+
+    let mut elements = vec![];
+    flatten(args, &mut elements);
+
+    if elements.contains(&winner) {
+        return Some(winner);
+    }
+
+    elements.retain(|e| *e != loser);
+
+    let mut best: Option<SimplexPointer> = None;
+    let mut rest = vec![];
+
+    for e in elements {
+        if !is_numeric(&e) {
+            rest.push(e);
+            continue;
+        }
+
+        best = match best {
+            None => Some(e),
+            Some(b) => match numeric_order(&e, &b)? {
+                o if o == wanted => Some(e),
+                _ => Some(b),
+            },
+        };
+    }
+
+    match (best, rest.len()) {
+        (None, 0) => Some(loser),
+        (Some(b), 0) => Some(b),
+        (None, 1) => Some(rest[0].clone()),
+        (best, _) => {
+            let mut parts = best.into_iter().collect::<Vec<SimplexPointer>>();
+            parts.extend(rest);
+            Some(expression(name, &parts))
+        }
+    }
+}
+
+fn max_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    extremum("Max", Ordering::Greater, args)
+}
+
+fn min_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    extremum("Min", Ordering::Less, args)
+}
+
+fn bounds(e: Option<&SimplexPointer>) -> Option<(SimplexPointer, SimplexPointer)> {
+    match e {
+        Some(e) if is_head(e, "List") && e.get_length() == 2 => Some((e.get_part(1)?, e.get_part(2)?)),
+        Some(_) => None,
+        None => Some((integer(-1), integer(1))),
+    }
+}
+
+// Clip[x], Clip[x, {min, max}] and Clip[x, {min, max}, {below, above}],
+// listable in x alone.
+fn clip_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 1 || args.len() > 3 {
+        return None;
+    }
+
+    if is_head(&args[0], "List") {
+        let clipped = args[0].get_parts().iter()
+            .map(|x| {
+                let mut arguments = vec![x.clone()];
+                arguments.extend_from_slice(&args[1..]);
+                expression("Clip", &arguments)
+            })
+            .collect::<Vec<SimplexPointer>>();
+
+        return Some(list(&clipped));
+    }
+
+    let (low, high) = bounds(args.get(1))?;
+    let (below, above) = match args.get(2) {
+        Some(_) => bounds(args.get(2))?,
+        None => (low.clone(), high.clone()),
+    };
+
+    let x = &args[0];
+
+    if !is_numeric(x) {
+        return None;
+    }
+
+    if numeric_order(x, &low)? == Ordering::Less {
+        Some(below)
+    } else if numeric_order(x, &high)? == Ordering::Greater {
+        Some(above)
+    } else {
+        Some(x.clone())
+    }
+}
+
+// Every real in e rationalized.
+fn rationalized(e: &SimplexPointer, dx: Option<Numeric>) -> SimplexPointer {
+    match e.as_numeric() {
+        Some(Numeric::LittleReal(x)) => rationalize(x, dx.clone()).map_or(e.clone(), number),
+        Some(_) => e.clone(),
+        None if e.is_atomic() => e.clone(),
+        None => e.with_parts(&e.get_parts().iter().map(|p| rationalized(p, dx.clone())).collect::<Vec<SimplexPointer>>()),
+    }
+}
+
+fn rationalize_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread("Rationalize", args) {
+        return Some(threaded);
+    }
+
+    match args.len() {
+        1 => Some(rationalized(&args[0], None)),
+        2 => Some(rationalized(&args[0], Some(args[1].as_numeric()?))),
+        _ => None,
+    }
+}
+
+fn predicate(args: &[SimplexPointer], test: fn(&SimplexPointer) -> bool) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(boolean(test(&args[0])))
+}
+
+fn integer_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    predicate(args, |e| e.as_integer().is_some())
+}
+
+// Reals, integers and rationals, and a + b I made of them.
+fn number_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    predicate(args, |e| complex_parts(e).map_or(false, |(a, b)| a != Numeric::NaN && b != Numeric::NaN))
+}
+
+fn numeric_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    predicate(args, |e| is_numeric(e) || complex_parts(e).is_some())
+}
+
+fn even_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    predicate(args, |e| e.as_integer().map_or(false, |n| n % 2 == 0))
+}
+
+fn odd_q_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    predicate(args, |e| e.as_integer().map_or(false, |n| n % 2 != 0))
+}
+
+// True or False for reals of known sign, False off the real line, and left
+// alone for anything else.
+fn signed(name: &str, wanted: i64, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread(name, args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 1 {
+        return None;
+    }
+
+    if let Some((_, b)) = complex_parts(&args[0]) {
+        if !b.is_zero() {
+            return Some(boolean(false));
+        }
+    }
+
+    sign(&args[0]).map(|s| boolean(s == wanted))
+}
+
+fn positive_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    signed("Positive", 1, args)
+}
+
+fn negative_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    signed("Negative", -1, args)
+}
+
+fn text(s: String) -> SimplexPointer {
+    SimplexPointer::from(SimplexAtom::SimplexString(format!("\"{}\"", s)))
+}
+
+// e with every number f writes out replaced by a string of it, and left
+// alone when there are none.
+fn written(e: &SimplexPointer, f: &Fn(Numeric) -> Option<String>) -> Option<SimplexPointer> {
+    fn rewrite(e: &SimplexPointer, f: &Fn(Numeric) -> Option<String>) -> SimplexPointer {
+        match e.as_numeric() {
+            Some(n) => f(n).map_or(e.clone(), text),
+            None if e.is_atomic() => e.clone(),
+            None => e.with_parts(&e.get_parts().iter().map(|p| rewrite(p, f)).collect::<Vec<SimplexPointer>>()),
+        }
+    }
+
+    let result = rewrite(e, f);
+    if result == *e { None } else { Some(result) }
+}
+
+// Significant digits as asked for by the forms, at most as many as a real
+// holds.
+fn digits_argument(e: &SimplexPointer) -> Option<u32> {
+    match e.as_integer()? {
+        n if n >= 1 && n <= MAXIMUM_PRECISION as i64 => Some(n as u32),
+        _ => None,
+    }
+}
+
+// A real and the digits it is written with unless asked for others:
+// machine precision for machine reals and their own, as far as a d128 goes,
+// for BigReals.
+fn real_digits(n: &Numeric) -> Option<(d128, u32)> {
+    match *n {
+        Numeric::LittleReal(x) => Some((x, MACHINE_PRECISION)),
+        Numeric::BigReal(ref x) => Some((x.to_d128(), x.precision().min(MAXIMUM_PRECISION))),
+        _ => None,
+    }
+}
+
+// Reals as m*^e, to n significant digits when given.
+fn exponential_form(args: &[SimplexPointer], write: fn(d128) -> Option<String>) -> Option<SimplexPointer> {
+    let digits = match args.len() {
+        1 => None,
+        2 => Some(digits_argument(&args[1])?),
+        _ => return None,
+    };
+
+    written(&args[0], &|n| real_digits(&n).and_then(|(x, own)| write(real::round(x, digits.unwrap_or(own)))))
+}
+
+fn scientific_form_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    exponential_form(args, scientific)
+}
+
+fn engineering_form_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    exponential_form(args, engineering)
+}
+
+// Reals written out in full: NumberForm[x, n] to n significant digits and
+// NumberForm[x, {n, f}] with f digits after the point.
+fn number_form_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let (digits, places) = match args.get(1) {
+        None if args.len() == 1 => (None, None),
+        Some(n) if args.len() == 2 && is_head(n, "List") && n.get_length() == 2 => {
+            let places = n.get_part(2)?.as_integer()?;
+
+            if places < 0 || places > MAXIMUM_PRECISION as i64 {
+                return None;
+            }
+
+            (Some(digits_argument(&n.get_part(1)?)?), Some(places as usize))
+        }
+        Some(n) if args.len() == 2 => (Some(digits_argument(n)?), None),
+        _ => return None,
+    };
+
+    written(&args[0], &|n| {
+        let (x, own) = real_digits(&n)?;
+        let x = real::round(x, digits.unwrap_or(own));
+
+        match places {
+            Some(f) => {
+                let quantized = x.quantize(tolerance(f as u32));
+                fixed(if quantized.is_nan() { x } else { quantized }, f)
+            }
+            None => fixed(x, 0),
+        }
+    })
+}
+
+// Numbers in base b as b^^digits, which reads back in as the same number.
+fn base_form_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    let b = match args[1].as_integer()? {
+        b if b >= 2 && b <= 36 => b as u32,
+        _ => return None,
+    };
+
+    written(&args[0], &|n| match n {
+        Numeric::LittleReal(x) => base_real(x, b),
+        Numeric::NaN => None,
+        exact => base_exact(&from_numeric(&exact)?, b),
+    })
+}
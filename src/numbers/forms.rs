@@ -0,0 +1,109 @@
+use std::char;
+
+use decimal::d128;
+use num::{BigInt, BigRational, Integer, Signed, Zero, ToPrimitive};
+
+use parsing::utilities::numerics::decimal_digits;
+use numerical::precise::to_rational;
+use integers::factor::digits;
+
+// The significant digits of x and the power of ten of the first of them:
+// 1234.5 is (false, "12345", 3) and zero (false, "0", 0).
+fn significant(x: d128) -> Option<(bool, String, i64)> {
+    let (negative, ds, exponent) = decimal_digits(&x.to_string())?;
+    let leading = ds.trim_start_matches('0');
+    let kept = leading.trim_end_matches('0');
+
+    if kept.is_empty() {
+        return Some((false, "0".to_string(), 0));
+    }
+
+    Some((negative, kept.to_string(), exponent + leading.len() as i64 - 1))
+}
+
+// Digits with the first of them at the given power of ten, written out with
+// a point and at least `places` digits after it, the way reals are input.
+fn positional(negative: bool, ds: &str, power: i64, places: usize) -> String {
+    let (whole, fraction) = if power < 0 {
+        ("0".to_string(), format!("{}{}", "0".repeat((-power - 1) as usize), ds))
+    } else if ds.len() > power as usize {
+        (ds[..power as usize + 1].to_string(), ds[power as usize + 1..].to_string())
+    } else {
+        (format!("{}{}", ds, "0".repeat(power as usize + 1 - ds.len())), String::new())
+    };
+
+    let padding = places.saturating_sub(fraction.len());
+    format!("{}{}.{}{}", if negative { "-" } else { "" }, whole, fraction, "0".repeat(padding))
+}
+
+// m*^e with e a multiple of step and m as small as that allows.
+fn exponential(x: d128, step: i64) -> Option<String> {
+    let (negative, ds, power) = significant(x)?;
+    let exponent = Integer::div_floor(&power, &step) * step;
+    let mantissa = positional(negative, &ds, power - exponent, 0);
+
+    Some(if exponent == 0 { mantissa } else { format!("{}*^{}", mantissa, exponent) })
+}
+
+// 1234.5 as 1.2345*^3.
+pub fn scientific(x: d128) -> Option<String> {
+    exponential(x, 1)
+}
+
+// 12345.6 as 12.3456*^3, the exponent a multiple of three.
+pub fn engineering(x: d128) -> Option<String> {
+    exponential(x, 3)
+}
+
+// x without an exponent, padded to `places` digits after the point.
+pub fn fixed(x: d128, places: usize) -> Option<String> {
+    let (negative, ds, power) = significant(x)?;
+    Some(positional(negative, &ds, power, places))
+}
+
+fn in_base(n: &BigInt, b: u32) -> Option<String> {
+    digits(n, &BigInt::from(b)).iter().map(|d| char::from_digit(d.to_u32()?, b)).collect()
+}
+
+// b^^digits for integers, and for rationals their numerator over their
+// denominator.
+pub fn base_exact(q: &BigRational, b: u32) -> Option<String> {
+    let sign = if q.is_negative() { "-" } else { "" };
+    let numerator = format!("{}{}^^{}", sign, b, in_base(q.numer(), b)?);
+
+    if q.is_integer() {
+        Some(numerator)
+    } else {
+        Some(format!("{}/{}^^{}", numerator, b, in_base(q.denom(), b)?))
+    }
+}
+
+// b^^digits.digits for reals, to about as many digits as x has in decimal.
+pub fn base_real(x: d128, b: u32) -> Option<String> {
+    let (_, ds, _) = significant(x)?;
+    let q = to_rational(x)?;
+    let sign = if q.is_negative() { "-" } else { "" };
+    let q = q.abs();
+    let base = BigRational::from_integer(BigInt::from(b));
+
+    let whole = q.floor().to_integer();
+    let mut fraction = q - BigRational::from_integer(whole.clone());
+
+    let wanted = (ds.len() as f64 * 10f64.ln() / (b as f64).ln()).ceil() as usize;
+    let mut counted = if whole.is_zero() { 0 } else { in_base(&whole, b)?.len() };
+    let mut places = String::new();
+
+    while !fraction.is_zero() && counted < wanted {
+        fraction = fraction * &base;
+        let d = fraction.floor().to_integer();
+        fraction = fraction - BigRational::from_integer(d.clone());
+
+        if counted > 0 || !d.is_zero() {
+            counted = counted + 1;
+        }
+
+        places.push(char::from_digit(d.to_u32()?, b)?);
+    }
+
+    Some(format!("{}{}^^{}.{}", sign, b, in_base(&whole, b)?, places.trim_end_matches('0')))
+}
@@ -0,0 +1,4 @@
+pub mod rounding;
+pub mod forms;
+pub mod builtins;
+pub mod test;
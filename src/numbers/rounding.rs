@@ -0,0 +1,182 @@
+use decimal::d128;
+use num::{BigInt, BigRational, Integer, Signed, Zero, One};
+
+use expression::atom::numbers::number::Numeric;
+
+use numerical::real;
+use numerical::precise::to_rational;
+use special::combinatorial::{to_numeric, from_numeric};
+
+// Rationalize[x] takes the first convergent p/q of x within this over q^2.
+const CLOSENESS: &'static str = "1E-4";
+
+// An integral real as the exact integer it stands for, however large.
+fn integral(r: d128) -> Numeric {
+    match to_rational(r) {
+        Some(q) => to_numeric(&q),
+        None => Numeric::LittleReal(r),
+    }
+}
+
+fn is_even(r: d128) -> bool {
+    let half = r / d128::from(2);
+    half.quantize(real::one()) == half
+}
+
+// The greatest integer not above n.
+pub fn floor(n: Numeric) -> Option<Numeric> {
+    match n {
+        Numeric::LittleReal(r) => {
+            if !r.is_finite() {
+                return None;
+            }
+
+            let whole = r.quantize(real::one());
+
+            // Past the digits a real holds it is already whole.
+            if whole.is_nan() {
+                return Some(integral(r));
+            }
+
+            Some(integral(if whole > r { whole - real::one() } else { whole }))
+        }
+        Numeric::BigReal(ref x) => Some(to_numeric(&x.to_rational().floor())),
+        Numeric::NaN => None,
+        exact => Some(to_numeric(&from_numeric(&exact)?.floor())),
+    }
+}
+
+pub fn ceiling(n: Numeric) -> Option<Numeric> {
+    floor(n.negate()).map(Numeric::negate)
+}
+
+// The nearest integer, halves going to the even one.
+pub fn round(n: Numeric) -> Option<Numeric> {
+    match n {
+        Numeric::LittleReal(r) => {
+            let below = floor(n)?.to_d128();
+            let half = real::real("0.5");
+            let above = below + real::one();
+
+            Some(integral(match (r - below).partial_cmp(&half)? {
+                ::std::cmp::Ordering::Less => below,
+                ::std::cmp::Ordering::Greater => above,
+                ::std::cmp::Ordering::Equal => if is_even(below) { below } else { above },
+            }))
+        }
+        Numeric::NaN => None,
+        other => {
+            let value = match other {
+                Numeric::BigReal(ref x) => x.to_rational(),
+                ref exact => from_numeric(exact)?,
+            };
+
+            let shifted = value + BigRational::new(BigInt::one(), BigInt::from(2));
+            let below = shifted.floor();
+            let tie = shifted.is_integer() && below.to_integer().is_odd();
+
+            Some(to_numeric(&if tie { below - BigRational::one() } else { below }))
+        }
+    }
+}
+
+// n with its fractional part dropped, towards zero.
+pub fn integer_part(n: Numeric) -> Option<Numeric> {
+    if n < Numeric::LittleInteger(0) { ceiling(n) } else { floor(n) }
+}
+
+pub fn fractional_part(n: Numeric) -> Option<Numeric> {
+    Some(n.clone() - integer_part(n)?)
+}
+
+// The rational with the smallest denominator in [low, high], 0 < low <= high,
+// from the continued fractions the two ends share.
+fn simplest_positive(low: &BigRational, high: &BigRational) -> BigRational {
+    let whole = low.floor();
+
+    if whole == *low {
+        return whole;
+    }
+
+    let next = &whole + BigRational::one();
+
+    if next <= *high {
+        return next;
+    }
+
+    let inner = simplest_positive(&(high - &whole).recip(), &(low - &whole).recip());
+    whole + inner.recip()
+}
+
+fn simplest(low: &BigRational, high: &BigRational) -> BigRational {
+    if low.is_positive() {
+        simplest_positive(low, high)
+    } else if high.is_negative() {
+        -simplest_positive(&-high, &-low)
+    } else {
+        BigRational::zero()
+    }
+}
+
+// The first convergent p/q of x within CLOSENESS / q^2 of it, while that is
+// still coarser than the digits of a machine real: past there every real is
+// a rational and none of them is a good one.
+fn convergent(x: &BigRational) -> Option<BigRational> {
+    let closeness = to_rational(real::real(CLOSENESS)).unwrap();
+    let resolution = to_rational(real::tolerance(real::MACHINE_PRECISION)).unwrap() * ::std::cmp::max(x.abs(), BigRational::one());
+    let (mut h, mut previous_h) = (BigInt::one(), BigInt::zero());
+    let (mut k, mut previous_k) = (BigInt::zero(), BigInt::one());
+    let mut r = x.clone();
+
+    loop {
+        let a = r.floor().to_integer();
+        let next_h = &a * &h + &previous_h;
+        let next_k = &a * &k + &previous_k;
+        previous_h = h;
+        previous_k = k;
+        h = next_h;
+        k = next_k;
+
+        let approximation = BigRational::new(h.clone(), k.clone());
+        let bound = &closeness / BigRational::from_integer(&k * &k);
+
+        if approximation == *x {
+            return Some(approximation);
+        }
+
+        if bound <= resolution {
+            return None;
+        }
+
+        if (&approximation - x).abs() < bound {
+            return Some(approximation);
+        }
+
+        r = (&r - BigRational::from_integer(a)).recip();
+    }
+}
+
+// Rationalize[x] as a nearby rational with a small denominator, and
+// Rationalize[x, dx] as the simplest rational within dx of x. None when
+// there is no such rational.
+pub fn rationalize(x: d128, dx: Option<Numeric>) -> Option<Numeric> {
+    let exact = to_rational(x)?;
+
+    let result = match dx {
+        Some(dx) => {
+            let width = match dx {
+                Numeric::LittleReal(w) => to_rational(w)?,
+                other => from_numeric(&other)?,
+            };
+
+            if width.is_negative() {
+                return None;
+            }
+
+            simplest(&(&exact - &width), &(&exact + &width))
+        }
+        None => convergent(&exact)?,
+    };
+
+    Some(to_numeric(&result))
+}
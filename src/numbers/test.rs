@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    mod test_rounding {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_rounds_halves_to_even() {
+            assert_eq!(run("Round[{2.5, 3.5, -2.5}]"), "List[2, 4, -2]");
+            assert_eq!(run("Round[5/2]"), "2");
+            assert_eq!(run("Round[7/2]"), "4");
+            assert_eq!(run("Round[2.567, 0.01]"), "2.57");
+        }
+
+        #[test]
+        fn it_takes_floors_and_ceilings() {
+            assert_eq!(run("Floor[{1.5, -1.5}]"), "List[1, -2]");
+            assert_eq!(run("Ceiling[-2.5]"), "-2");
+            assert_eq!(run("Floor[-7/2]"), "-4");
+            assert_eq!(run("Floor[7, 2]"), "6");
+            assert_eq!(run("Floor[x]"), "Floor[x]");
+            assert_eq!(run("Floor[10^20 + 0.5]"), "100000000000000000000");
+            assert_eq!(run("Ceiling[10^20 + 0.5]"), "100000000000000000001");
+            assert_eq!(run("Round[-1.5*^40]"), "-15000000000000000000000000000000000000000");
+            assert_eq!(run("Head[Floor[1.5*^25]]"), "Integer");
+        }
+
+        #[test]
+        fn it_rounds_numeric_quantities() {
+            assert_eq!(run("Floor[Pi]"), "3");
+            assert_eq!(run("Ceiling[-E]"), "-2");
+            assert_eq!(run("FractionalPart[Pi]"), "Plus[-3, Pi]");
+        }
+
+        #[test]
+        fn it_splits_integer_and_fractional_parts() {
+            assert_eq!(run("IntegerPart[-2.7]"), "-2");
+            assert_eq!(run("FractionalPart[-2.7]"), "-0.7");
+            assert_eq!(run("FractionalPart[7/3]"), "Rational[1, 3]");
+        }
+
+        #[test]
+        fn it_rationalizes_reals() {
+            assert_eq!(run("Rationalize[0.3333333333333333]"), "Rational[1, 3]");
+            assert_eq!(run("Rationalize[3.14159, 0.01]"), "Rational[22, 7]");
+            assert_eq!(run("Rationalize[0.5 x + 0.25]"), "Plus[Rational[1, 4], Times[Rational[1, 2], x]]");
+            assert_eq!(run("Rationalize[3.14159265358979]"), "3.14159265358979");
+            assert_eq!(run("Rationalize[1.5*^25]"), "15000000000000000000000000");
+        }
+    }
+
+    mod test_magnitudes {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_takes_absolute_values_and_signs() {
+            assert_eq!(run("Abs[3 + 4 I]"), "5");
+            assert_eq!(run("Abs[-3/2]"), "Rational[3, 2]");
+            assert_eq!(run("Abs[-Pi]"), "Pi");
+            assert_eq!(run("Abs[-x]"), "Abs[x]");
+            assert_eq!(run("Abs[-9223372036854775808]"), "9223372036854775808");
+            assert_eq!(run("Abs[-2^70]"), "1180591620717411303424");
+            assert_eq!(run("Sign[{-2.5, 0, 7}]"), "List[-1, 0, 1]");
+            assert_eq!(run("Sign[-x]"), "Times[-1, Sign[x]]");
+        }
+
+        #[test]
+        fn it_finds_maxima_and_minima() {
+            assert_eq!(run("Max[{1, 5}, 3]"), "5");
+            assert_eq!(run("Max[1, x, 2]"), "Max[2, x]");
+            assert_eq!(run("Min[Pi, 3]"), "3");
+            assert_eq!(run("Max[Infinity, 2]"), "Infinity");
+            assert_eq!(run("Min[]"), "Infinity");
+        }
+
+        #[test]
+        fn it_clips() {
+            assert_eq!(run("Clip[{-2, 0.5, 3}]"), "List[-1, 0.5, 1]");
+            assert_eq!(run("Clip[5, {0, 3}, {a, b}]"), "b");
+            assert_eq!(run("Clip[x]"), "Clip[x]");
+        }
+    }
+
+    mod test_predicates {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_tests_kinds_of_numbers() {
+            assert_eq!(run("IntegerQ[3]"), "True");
+            assert_eq!(run("IntegerQ[3.0]"), "False");
+            assert_eq!(run("EvenQ[4]"), "True");
+            assert_eq!(run("OddQ[x]"), "False");
+            assert_eq!(run("NumberQ[1 + 2 I]"), "True");
+            assert_eq!(run("NumberQ[Pi]"), "False");
+            assert_eq!(run("NumericQ[Pi]"), "True");
+            assert_eq!(run("NumericQ[Sin[1] + x]"), "False");
+        }
+
+        #[test]
+        fn it_tests_signs() {
+            assert_eq!(run("Positive[{1, -1, x}]"), "List[True, False, Positive[x]]");
+            assert_eq!(run("Negative[-Pi]"), "True");
+            assert_eq!(run("Positive[I]"), "False");
+        }
+    }
+
+    mod test_forms {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_writes_scientific_and_engineering_notation() {
+            assert_eq!(run("ScientificForm[1234.5]"), "\"1.2345*^3\"");
+            assert_eq!(run("ScientificForm[0.00015]"), "\"1.5*^-4\"");
+            assert_eq!(run("ScientificForm[3.14159, 3]"), "\"3.14\"");
+            assert_eq!(run("ScientificForm[{1234.5, x}]"), "List[\"1.2345*^3\", x]");
+            assert_eq!(run("EngineeringForm[12345.6]"), "\"12.3456*^3\"");
+            assert_eq!(run("EngineeringForm[0.00012]"), "\"120.*^-6\"");
+        }
+
+        #[test]
+        fn it_writes_numbers_out_in_full() {
+            assert_eq!(run("NumberForm[1.5*^10]"), "\"15000000000.\"");
+            assert_eq!(run("NumberForm[3.14159, 3]"), "\"3.14\"");
+            assert_eq!(run("NumberForm[2.5, {4, 3}]"), "\"2.500\"");
+        }
+
+        #[test]
+        fn it_writes_numbers_in_other_bases() {
+            assert_eq!(run("BaseForm[255, 16]"), "\"16^^ff\"");
+            assert_eq!(run("BaseForm[-10, 2]"), "\"-2^^1010\"");
+            assert_eq!(run("BaseForm[11.25, 2]"), "\"2^^1011.01\"");
+            assert_eq!(run("BaseForm[1/3, 2]"), "\"2^^1/2^^11\"");
+            assert_eq!(run("BaseForm[255, 40]"), "BaseForm[255, 40]");
+        }
+
+        #[test]
+        fn it_leaves_exact_numbers_and_symbols_alone() {
+            assert_eq!(run("ScientificForm[12345]"), "ScientificForm[12345]");
+            assert_eq!(run("NumberForm[x, 3]"), "NumberForm[x, 3]");
+        }
+    }
+}
@@ -0,0 +1,504 @@
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+use expression::list::structure::SimplexList;
+
+use evaluation::dispatch::{Builtin, evaluate};
+use evaluation::utilities::{expression, list, integer, symbol, is_head, split_options, option_value};
+use arithmetic::canonical::{sum, product};
+use polynomials::expand::expand;
+use polynomials::rational::{fraction, together};
+use calculus::variables::{free_of, substitute};
+use calculus::derivative::{derivative_head, derivative_of};
+use calculus::limit::infinite_point;
+use solving::roots::coefficients;
+use solving::solve::solve;
+use numerical::real::{self, MACHINE_PRECISION, MAXIMUM_PRECISION};
+use numerical::complex::Complex;
+use numerical::value::{real_value, approximate};
+use numerical::precise::PRECISION_LIMIT;
+use numerical::roots::{self, newton, secant, brent, polynomial_roots};
+use numerical::minimize::{self, minimize};
+use numerical::quadrature::{Bound, integrate};
+use numerical::ode::{self, Problem};
+use numerical::interpolation::{interpolating_function, interpolate};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "FindRoot" => Some(find_root_builtin),
+        "NSolve" => Some(n_solve_builtin),
+        "FindMinimum" => Some(find_minimum_builtin),
+        "FindMaximum" => Some(find_maximum_builtin),
+        "NIntegrate" => Some(n_integrate_builtin),
+        "NDSolve" => Some(nd_solve_builtin),
+        "N" => Some(n_builtin),
+        _ => None,
+    }
+}
+
+// InterpolatingFunction[ ... ][t] and its derivatives at a point.
+pub fn apply_head(head: &SimplexPointer, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    let t = real_value(&args[0], &[])?;
+
+    if is_head(head, "InterpolatingFunction") {
+        return interpolate(head, 0, t);
+    }
+
+    match derivative_head(head) {
+        Some((ref orders, ref f)) if orders.len() == 1 && is_head(f, "InterpolatingFunction") => interpolate(f, orders[0], t),
+        _ => None,
+    }
+}
+
+// WorkingPrecision -> n in digits, up to what a d128 holds.
+fn precision(options: &[(String, SimplexPointer)]) -> Option<u32> {
+    match option_value(options, "WorkingPrecision") {
+        None => Some(MACHINE_PRECISION),
+        Some(ref p) if p.as_str() == "MachinePrecision" => Some(MACHINE_PRECISION),
+        Some(p) => match p.as_integer() {
+            Some(n) if n > 0 && n <= MAXIMUM_PRECISION as i64 => Some(n as u32),
+            _ => None,
+        },
+    }
+}
+
+// N[e] to machine precision and N[e, n] to n digits.
+fn n_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let digits = match args.len() {
+        1 => MACHINE_PRECISION,
+        2 => match args[1].as_integer() {
+            Some(n) if n > 0 && n <= PRECISION_LIMIT as i64 => n as u32,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(approximate(&args[0], digits))
+}
+
+fn method_name(options: &[(String, SimplexPointer)]) -> Option<String> {
+    option_value(options, "Method").map(|m| m.as_str().trim_matches('"').to_string())
+}
+
+// lhs == rhs as lhs - rhs, anything else as it is.
+fn residual(e: &SimplexPointer) -> Option<SimplexPointer> {
+    if is_head(e, "Equal") {
+        if e.get_length() != 2 {
+            return None;
+        }
+
+        Some(sum(&[e.get_part(1).unwrap(), product(&[integer(-1), e.get_part(2).unwrap()])]))
+    } else {
+        Some(e.clone())
+    }
+}
+
+fn residuals(e: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    if is_head(e, "List") || is_head(e, "And") {
+        e.get_parts().iter().map(residual).collect()
+    } else {
+        residual(e).map(|r| vec![r])
+    }
+}
+
+// {x, x0, ... } or a list of them, each variable with its starting values.
+fn starting_points(spec: &SimplexPointer) -> Option<Vec<(SimplexPointer, Vec<d128>)>> {
+    if !is_head(spec, "List") || spec.get_length() == 0 {
+        return None;
+    }
+
+    let parts = spec.get_parts();
+
+    if parts[0].as_symbol().is_none() {
+        return parts.iter().map(|p| starting_points(p).and_then(|mut v| if v.len() == 1 { v.pop() } else { None })).collect();
+    }
+
+    let starts = parts[1..].iter().map(|p| real_value(&evaluate(p), &[])).collect::<Option<Vec<d128>>>()?;
+
+    if starts.is_empty() {
+        return None;
+    }
+
+    Some(vec![(parts[0].clone(), starts)])
+}
+
+fn rules(vars: &[SimplexPointer], x: &[d128], digits: u32) -> SimplexPointer {
+    let rules = vars.iter().zip(x.iter())
+        .map(|(v, &t)| expression("Rule", &[v.clone(), real::to_expression(t, digits)]))
+        .collect::<Vec<SimplexPointer>>();
+
+    list(&rules)
+}
+
+// FindRoot[f, {x, x0}] by Newton's method, FindRoot[f, {x, x0, x1}] by
+// Brent's method when f changes sign between them and the secant method
+// otherwise, and FindRoot[{f1, ... }, {{x, x0}, ... }] by Newton's method on
+// the system.
+fn find_root_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 {
+        return None;
+    }
+
+    let (rest, options) = split_options(&args[2..]);
+
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let digits = precision(&options)?;
+    let method = match method_name(&options) {
+        None => None,
+        Some(ref m) if m == "Newton" => Some(roots::Method::Newton),
+        Some(ref m) if m == "Secant" => Some(roots::Method::Secant),
+        Some(ref m) if m == "Brent" => Some(roots::Method::Brent),
+        _ => return None,
+    };
+
+    let fs = residuals(&args[0])?;
+    let points = starting_points(&args[1])?;
+    let vars = points.iter().map(|&(ref v, _)| v.clone()).collect::<Vec<SimplexPointer>>();
+
+    if fs.len() != vars.len() {
+        return None;
+    }
+
+    let x = if vars.len() == 1 && method != Some(roots::Method::Newton) {
+        let (f, x) = (&fs[0], &vars[0]);
+        let starts = &points[0].1;
+        let a = starts[0];
+        let b = if starts.len() > 1 { starts[1] } else { a + (d128::from(1) + a.abs()) / d128::from(1000) };
+        let bracketed = starts.len() > 1 && brent(f, x, a, b, digits).is_some();
+
+        match method {
+            Some(roots::Method::Brent) => vec![brent(f, x, a, b, digits)?],
+            Some(roots::Method::Secant) => vec![secant(f, x, a, b, digits)?],
+            _ if bracketed => vec![brent(f, x, a, b, digits)?],
+            _ if starts.len() > 1 => vec![secant(f, x, a, b, digits)?],
+            _ => newton(&fs, &vars, &[a], digits)?,
+        }
+    } else {
+        let start = points.iter().map(|&(_, ref s)| s[0]).collect::<Vec<d128>>();
+        newton(&fs, &vars, &start, digits)?
+    };
+
+    Some(rules(&vars, &x, digits))
+}
+
+// A root as a real when its imaginary part is zero, so that a machine zero
+// does not pull a root found past machine precision down to it.
+fn complex_expression(z: Complex, digits: u32) -> SimplexPointer {
+    if z.im.is_zero() {
+        return real::to_expression(z.re, digits);
+    }
+
+    evaluate(&sum(&[real::to_expression(z.re, digits), product(&[real::to_expression(z.im, digits), symbol("I")])]))
+}
+
+// NSolve[p == q, x] for every root of a polynomial equation in x, complex
+// ones as a + b I.
+fn n_solve_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 || args[1].as_symbol().is_none() {
+        return None;
+    }
+
+    let (rest, options) = split_options(&args[2..]);
+
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let digits = precision(&options)?;
+    let fs = residuals(&args[0])?;
+
+    if fs.len() != 1 {
+        return None;
+    }
+
+    let numerator = expand(&fraction(&together(&evaluate(&fs[0]))).0);
+    let c = coefficients(&numerator, &args[1])?
+        .iter()
+        .map(|k| k.as_numeric().or_else(|| real_value(k, &[]).map(Numeric::LittleReal)))
+        .collect::<Option<Vec<Numeric>>>()?;
+
+    if c.len() < 2 {
+        return None;
+    }
+
+    let solutions = polynomial_roots(&c, digits)?
+        .into_iter()
+        .map(|z| list(&[expression("Rule", &[args[1].clone(), complex_expression(z, digits)])]))
+        .collect::<Vec<SimplexPointer>>();
+
+    Some(list(&solutions))
+}
+
+// FindMinimum[f, {x, x0}] and FindMinimum[f, {{x, x0}, ... }] as
+// {fmin, {x -> xmin, ... }}; FindMaximum the same with f turned over.
+fn find_extremum(args: &[SimplexPointer], sign: i64) -> Option<SimplexPointer> {
+    if args.len() < 2 {
+        return None;
+    }
+
+    let (rest, options) = split_options(&args[2..]);
+
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let digits = precision(&options)?;
+    let method = match method_name(&options) {
+        None => minimize::Method::QuasiNewton,
+        Some(ref m) if m == "QuasiNewton" || m == "Gradient" => minimize::Method::QuasiNewton,
+        Some(ref m) if m == "NelderMead" => minimize::Method::NelderMead,
+        _ => return None,
+    };
+
+    let points = starting_points(&args[1])?;
+    let vars = points.iter().map(|&(ref v, _)| v.clone()).collect::<Vec<SimplexPointer>>();
+    let start = points.iter().map(|&(_, ref s)| s[0]).collect::<Vec<d128>>();
+    let f = evaluate(&product(&[integer(sign), args[0].clone()]));
+
+    let (value, x) = minimize(&f, &vars, &start, method, digits)?;
+
+    Some(list(&[real::to_expression(d128::from(sign) * value, digits), rules(&vars, &x, digits)]))
+}
+
+fn find_minimum_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    find_extremum(args, 1)
+}
+
+fn find_maximum_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    find_extremum(args, -1)
+}
+
+fn bound(e: &SimplexPointer, bindings: &[(SimplexPointer, d128)]) -> Option<Bound> {
+    match infinite_point(e) {
+        Some(s) => Some(Bound::Infinite(s)),
+        None => real_value(e, bindings).map(Bound::Finite),
+    }
+}
+
+// The first range outermost, so that the bounds of the later ones may
+// depend on its variable.
+fn nested(f: &SimplexPointer, ranges: &[Vec<SimplexPointer>], bindings: &[(SimplexPointer, d128)], digits: u32) -> Option<d128> {
+    match ranges.split_first() {
+        None => real_value(f, bindings),
+        Some((range, rest)) => {
+            let (a, b) = (bound(&range[1], bindings)?, bound(&range[2], bindings)?);
+            let g = |t: d128| {
+                let mut inner = bindings.to_vec();
+                inner.push((range[0].clone(), t));
+                nested(f, rest, &inner, digits)
+            };
+
+            integrate(&g, a, b, digits)
+        }
+    }
+}
+
+// NIntegrate[f, {x, a, b}, ... ] by adaptive Gauss-Kronrod quadrature, over
+// infinite ranges as well.
+fn n_integrate_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 {
+        return None;
+    }
+
+    let (ranges, options) = split_options(&args[1..]);
+    let digits = precision(&options)?;
+
+    let ranges = ranges.iter().map(|r| {
+        if is_head(r, "List") && r.get_length() == 3 && r.get_part(1).unwrap().as_symbol().is_some() {
+            Some(r.get_parts())
+        } else {
+            None
+        }
+    }).collect::<Option<Vec<Vec<SimplexPointer>>>>()?;
+
+    if ranges.is_empty() {
+        return None;
+    }
+
+    nested(&args[0], &ranges, &[], digits).map(|v| real::to_expression(v, digits))
+}
+
+fn equations(e: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    if is_head(e, "List") || is_head(e, "And") {
+        Some(e.get_parts().iter().map(equations).collect::<Option<Vec<Vec<SimplexPointer>>>>()?.concat())
+    } else if is_head(e, "Equal") && e.get_length() == 2 {
+        Some(vec![e.clone()])
+    } else {
+        None
+    }
+}
+
+// y[x] for order 0, Derivative[k][y][x] above.
+fn derivative_at(y: &SimplexPointer, k: i64, x: &SimplexPointer) -> SimplexPointer {
+    let head = if k == 0 { y.clone() } else { derivative_of(&[k], y) };
+    SimplexPointer::from(SimplexList::with_head(&head).push_all(&[x.clone()]))
+}
+
+// y[c] or Derivative[k][y][c] for one of the functions, as its index, k
+// and c.
+fn application(e: &SimplexPointer, functions: &[SimplexPointer]) -> Option<(usize, i64, SimplexPointer)> {
+    if e.is_atomic() || e.get_length() != 1 {
+        return None;
+    }
+
+    let head = e.get_head()?;
+    let (k, f) = if head.is_atomic() {
+        (0, head)
+    } else {
+        match derivative_head(&head)? {
+            (ref orders, ref f) if orders.len() == 1 => (orders[0], f.clone()),
+            _ => return None,
+        }
+    };
+
+    functions.iter().position(|g| *g == f).map(|i| (i, k, e.get_part(1).unwrap()))
+}
+
+const ORDER_LIMIT: i64 = 10;
+
+// The equations as a first order system: y, y', ... up to one below the
+// highest derivative of each function become states, and the equations are
+// solved for the highest derivatives. The rest of the equations give the
+// initial values, all at the same point.
+fn first_order(eqns: &[SimplexPointer], functions: &[SimplexPointer], x: &SimplexPointer) -> Option<(Problem, Vec<usize>)> {
+    let (differential, initial): (Vec<SimplexPointer>, Vec<SimplexPointer>) = eqns.iter().cloned().partition(|e| !free_of(e, x));
+    let system = list(&differential);
+
+    let orders = functions.iter()
+        .map(|y| (1..ORDER_LIMIT + 1).filter(|&k| !free_of(&system, &derivative_at(y, k, x))).max())
+        .collect::<Option<Vec<i64>>>()?;
+
+    let mut states = Vec::new();
+    let mut first = Vec::new();
+    let mut highest = Vec::new();
+    let mut rewritten = system.clone();
+
+    for (i, y) in functions.iter().enumerate() {
+        first.push(states.len());
+
+        for k in 0..orders[i] {
+            let state = symbol(&format!("$State{}", states.len()));
+            rewritten = substitute(&rewritten, &derivative_at(y, k, x), &state);
+            states.push(state);
+        }
+
+        let rate = symbol(&format!("$Rate{}", i));
+        rewritten = substitute(&rewritten, &derivative_at(y, orders[i], x), &rate);
+        highest.push(rate);
+    }
+
+    if functions.iter().any(|y| !free_of(&rewritten, y)) {
+        return None;
+    }
+
+    let solutions = solve(&rewritten, &highest)?;
+
+    if solutions.len() != 1 || solutions[0].len() != highest.len() {
+        return None;
+    }
+
+    let solved = &solutions[0];
+    let mut rates = Vec::new();
+
+    for (i, rate) in highest.iter().enumerate() {
+        let value = solved.iter().find(|&&(ref v, _)| v == rate).map(|&(_, ref e)| e.clone())?;
+
+        if highest.iter().any(|h| !free_of(&value, h)) {
+            return None;
+        }
+
+        rates.extend(states[first[i] + 1..first[i] + orders[i] as usize].iter().cloned());
+        rates.push(value);
+    }
+
+    let mut values = vec![None; states.len()];
+    let mut start = None;
+
+    for e in &initial {
+        let (lhs, rhs) = (e.get_part(1).unwrap(), e.get_part(2).unwrap());
+        let (side, other) = match application(&lhs, functions) {
+            Some(a) => (a, rhs),
+            None => (application(&rhs, functions)?, lhs),
+        };
+
+        let (i, k, point) = side;
+        let point = real_value(&point, &[])?;
+
+        if k >= orders[i] || start.map_or(false, |s| s != point) {
+            return None;
+        }
+
+        start = Some(point);
+        values[first[i] + k as usize] = Some(real_value(&other, &[])?);
+    }
+
+    let problem = Problem {
+        variable: x.clone(),
+        start: start?,
+        states: states,
+        rates: rates,
+        initial: values.into_iter().collect::<Option<Vec<d128>>>()?,
+    };
+
+    Some((problem, first))
+}
+
+// NDSolve[eqns, y, {x, a, b}] and NDSolve[eqns, {y1, ... }, {x, a, b}] as
+// rules giving each function an InterpolatingFunction, by the
+// Runge-Kutta-Fehlberg method or with Method -> "BDF" for stiff systems.
+fn nd_solve_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 3 {
+        return None;
+    }
+
+    let (rest, options) = split_options(&args[3..]);
+
+    if !rest.is_empty() || !is_head(&args[2], "List") || args[2].get_length() != 3 {
+        return None;
+    }
+
+    let digits = precision(&options)?;
+    let goal = match option_value(&options, "PrecisionGoal") {
+        None => digits / 2,
+        Some(p) => match p.as_integer() {
+            Some(n) if n > 0 && n <= digits as i64 => n as u32,
+            _ => return None,
+        },
+    };
+
+    let method = match method_name(&options) {
+        None => ode::Method::RungeKuttaFehlberg,
+        Some(ref m) if m == "RungeKutta" || m == "ExplicitRungeKutta" => ode::Method::RungeKuttaFehlberg,
+        Some(ref m) if m == "BDF" => ode::Method::Bdf,
+        _ => return None,
+    };
+
+    let functions = if is_head(&args[1], "List") { args[1].get_parts() } else { vec![args[1].clone()] };
+    let range = args[2].get_parts();
+
+    if functions.iter().any(|y| y.as_symbol().is_none()) || range[0].as_symbol().is_none() {
+        return None;
+    }
+
+    let (a, b) = (real_value(&range[1], &[])?, real_value(&range[2], &[])?);
+    let (problem, first) = first_order(&equations(&args[0])?, &functions, &range[0])?;
+    let trajectory = ode::solve(&problem, a.min(problem.start), b.max(problem.start), method, goal)?;
+
+    let rules = functions.iter().zip(first.iter()).map(|(y, &i)| {
+        let values = trajectory.values.iter().map(|v| v[i]).collect::<Vec<d128>>();
+        let slopes = trajectory.rates.iter().map(|r| r[i]).collect::<Vec<d128>>();
+
+        expression("Rule", &[y.clone(), interpolating_function(&trajectory.points, &values, &slopes, digits)])
+    }).collect::<Vec<SimplexPointer>>();
+
+    Some(list(&[list(&rules)]))
+}
@@ -0,0 +1,148 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{symbol, is_head};
+use arithmetic::terms::coefficient_and_term;
+use numerical::real::{self, zero};
+
+// Just enough complex arithmetic over d128 for polynomial roots and the
+// special functions.
+#[derive(Clone, Copy, Debug)]
+pub struct Complex {
+    pub re: d128,
+    pub im: d128,
+}
+
+impl Complex {
+    pub fn new(re: d128, im: d128) -> Complex {
+        Complex { re: re, im: im }
+    }
+
+    pub fn real(re: d128) -> Complex {
+        Complex::new(re, zero())
+    }
+
+    // Modulus r at angle t.
+    pub fn polar(r: d128, t: d128) -> Complex {
+        Complex::new(r * real::cos(t), r * real::sin(t))
+    }
+
+    pub fn norm(&self) -> d128 {
+        real::sqrt(self.re * self.re + self.im * self.im)
+    }
+
+    // The angle in (-Pi, Pi].
+    pub fn arg(&self) -> d128 {
+        let half_pi = real::pi() / d128::from(2);
+
+        if self.re.is_positive() {
+            real::atan(self.im / self.re)
+        } else if self.re.is_negative() {
+            let t = real::atan(self.im / self.re);
+            if self.im.is_negative() { t - real::pi() } else { t + real::pi() }
+        } else if self.im.is_negative() {
+            -half_pi
+        } else if self.im.is_zero() {
+            zero()
+        } else {
+            half_pi
+        }
+    }
+
+    pub fn exp(self) -> Complex {
+        let r = real::exp(self.re);
+
+        if self.im.is_zero() {
+            Complex::real(r)
+        } else {
+            Complex::polar(r, self.im)
+        }
+    }
+
+    // The principal logarithm.
+    pub fn ln(self) -> Complex {
+        let modulus = if self.im.is_zero() { self.re.abs() } else { self.norm() };
+        Complex::new(real::ln(modulus), self.arg())
+    }
+
+    pub fn sin(self) -> Complex {
+        if self.im.is_zero() {
+            return Complex::real(real::sin(self.re));
+        }
+
+        let (a, b) = (real::exp(self.im), real::exp(-self.im));
+        let two = d128::from(2);
+        Complex::new(real::sin(self.re) * (a + b) / two, real::cos(self.re) * (a - b) / two)
+    }
+
+    // self^w on the principal branch.
+    pub fn power(self, w: Complex) -> Complex {
+        (w * self.ln()).exp()
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re)
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        let d = other.re * other.re + other.im * other.im;
+        Complex::new((self.re * other.re + self.im * other.im) / d, (self.im * other.re - self.re * other.im) / d)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+// a + b I with numbers a and b as (a, b).
+pub fn complex_parts(e: &SimplexPointer) -> Option<(Numeric, Numeric)> {
+    let naught = Numeric::LittleInteger(0);
+
+    if let Some(n) = e.as_numeric() {
+        return Some((n, naught));
+    }
+
+    if is_head(e, "Plus") && e.get_length() == 2 {
+        let a = e.get_part(1)?.as_numeric()?;
+        let (_, b) = complex_parts(&e.get_part(2)?)?;
+        return if a.is_zero() || !b.is_zero() { Some((a, b)) } else { None };
+    }
+
+    match coefficient_and_term(e) {
+        (b, Some(ref t)) if *t == symbol("I") => Some((naught, b)),
+        _ => None,
+    }
+}
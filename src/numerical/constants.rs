@@ -0,0 +1,77 @@
+use std::cmp::Ordering;
+
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::integer;
+use numerical::real::{self, tolerance, MAXIMUM_PRECISION};
+use numerical::value::real_value;
+
+pub const CONSTANTS: &'static [&'static str] = &["Pi", "E", "EulerGamma", "GoldenRatio", "Degree"];
+
+// Heads with the NumericFunction attribute: numeric whenever all of their
+// arguments are.
+pub const NUMERIC_FUNCTIONS: &'static [&'static str] = &[
+    "Plus", "Times", "Power", "Sqrt", "Exp", "Log",
+    "Sin", "Cos", "Tan", "Cot", "Sec", "Csc", "ArcSin", "ArcCos", "ArcTan", "ArcCot", "ArcSec", "ArcCsc",
+    "Sinh", "Cosh", "Tanh", "Coth", "Sech", "Csch", "ArcSinh", "ArcCosh", "ArcTanh", "ArcCoth",
+    "Gamma", "LogGamma", "Beta", "Zeta", "Erf", "Erfc", "BesselJ", "BesselY", "Binomial", "Factorial", "Pochhammer",
+    "LegendreP", "ChebyshevT", "HermiteH", "LaguerreL",
+    "Floor", "Ceiling", "Round", "IntegerPart", "FractionalPart", "Abs", "Sign", "Max", "Min", "Clip",
+];
+
+// The constants to all the digits a d128 holds.
+pub fn constant(name: &str) -> Option<d128> {
+    match name {
+        "Pi" => Some(real::pi()),
+        "E" => Some(real::e()),
+        "EulerGamma" => Some(real::euler_gamma()),
+        "GoldenRatio" => Some(real::golden_ratio()),
+        "Degree" => Some(real::degree()),
+        _ => None,
+    }
+}
+
+// NumericQ: numbers, the constants, and numeric functions of them.
+pub fn is_numeric(e: &SimplexPointer) -> bool {
+    if e.as_numeric().is_some() {
+        return true;
+    }
+
+    if e.is_atomic() {
+        return CONSTANTS.contains(&e.as_str().as_ref());
+    }
+
+    NUMERIC_FUNCTIONS.contains(&e.head_name().as_str()) && e.get_parts().iter().all(is_numeric)
+}
+
+// Numbers exactly, numeric quantities by their values as long as those are
+// far enough apart for working precision to tell them apart.
+pub fn numeric_order(a: &SimplexPointer, b: &SimplexPointer) -> Option<Ordering> {
+    if let (Some(x), Some(y)) = (a.as_numeric(), b.as_numeric()) {
+        return x.partial_cmp(&y);
+    }
+
+    if !is_numeric(a) || !is_numeric(b) {
+        return None;
+    }
+
+    let (x, y) = (real_value(a, &[])?, real_value(b, &[])?);
+
+    if (x - y).abs() <= (x.abs() + y.abs()) * tolerance(MAXIMUM_PRECISION - 4) {
+        None
+    } else {
+        x.partial_cmp(&y)
+    }
+}
+
+// -1, 0 or 1 for anything numeric whose sign can be told.
+pub fn sign(e: &SimplexPointer) -> Option<i64> {
+    match numeric_order(e, &integer(0))? {
+        Ordering::Less => Some(-1),
+        Ordering::Equal => Some(0),
+        Ordering::Greater => Some(1),
+    }
+}
@@ -0,0 +1,52 @@
+use decimal::d128;
+
+use numerical::real::zero;
+
+pub type Vector = Vec<d128>;
+
+pub fn dot(a: &[d128], b: &[d128]) -> d128 {
+    a.iter().zip(b.iter()).fold(zero(), |total, (&x, &y)| total + x * y)
+}
+
+pub fn largest(a: &[d128]) -> d128 {
+    a.iter().fold(zero(), |m, x| if x.abs() > m { x.abs() } else { m })
+}
+
+// Solves a x = b by Gaussian elimination with partial pivoting, None for
+// a singular a.
+pub fn solve_dense(a: &[Vector], b: &[d128]) -> Option<Vector> {
+    let n = b.len();
+    let mut rows = a.iter().zip(b.iter()).map(|(row, &rhs)| {
+        let mut augmented = row.clone();
+        augmented.push(rhs);
+        augmented
+    }).collect::<Vec<Vector>>();
+
+    for c in 0..n {
+        let pivot = (c..n).fold(c, |best, i| if rows[i][c].abs() > rows[best][c].abs() { i } else { best });
+
+        if rows[pivot][c].is_zero() {
+            return None;
+        }
+
+        rows.swap(c, pivot);
+
+        for i in c + 1..n {
+            let factor = rows[i][c] / rows[c][c];
+
+            for j in c..n + 1 {
+                let subtracted = factor * rows[c][j];
+                rows[i][j] = rows[i][j] - subtracted;
+            }
+        }
+    }
+
+    let mut x = vec![zero(); n];
+
+    for c in (0..n).rev() {
+        let rest = (c + 1..n).fold(rows[c][n], |total, j| total - rows[c][j] * x[j]);
+        x[c] = rest / rows[c][c];
+    }
+
+    Some(x)
+}
@@ -0,0 +1,61 @@
+use decimal::d128;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::{expression, list, integer, is_head};
+use numerical::real::{self, one, zero};
+use numerical::dense::Vector;
+
+// InterpolatingFunction[{a, b}, digits, {x1, ... }, {y1, ... }, {y1', ... }]
+// holds the values and slopes of a function at increasing points of its
+// domain, all of them and its values to the given working precision.
+pub fn interpolating_function(points: &[d128], values: &[d128], slopes: &[d128], digits: u32) -> SimplexPointer {
+    let numbers = |v: &[d128]| list(&v.iter().map(|&x| real::to_expression(x, digits)).collect::<Vec<SimplexPointer>>());
+    let domain = [points[0], points[points.len() - 1]];
+
+    expression("InterpolatingFunction", &[numbers(&domain), integer(digits as i64), numbers(points), numbers(values), numbers(slopes)])
+}
+
+fn reals(e: &SimplexPointer) -> Option<Vector> {
+    if !is_head(e, "List") {
+        return None;
+    }
+
+    e.get_parts().iter().map(|p| p.as_numeric().map(|n| n.to_d128())).collect()
+}
+
+// The cubic Hermite interpolant on the segment around t, or any of its
+// derivatives, zero past the third; None outside the domain.
+pub fn interpolate(f: &SimplexPointer, order: i64, t: d128) -> Option<SimplexPointer> {
+    if f.get_length() != 5 || order < 0 {
+        return None;
+    }
+
+    let digits = f.get_part(2).unwrap().as_integer()? as u32;
+    let (points, values, slopes) = (reals(&f.get_part(3).unwrap())?, reals(&f.get_part(4).unwrap())?, reals(&f.get_part(5).unwrap())?);
+    let n = points.len();
+
+    if n < 2 || values.len() != n || slopes.len() != n || t < points[0] || t > points[n - 1] {
+        return None;
+    }
+
+    let i = (0..n - 1).find(|&i| t <= points[i + 1]).unwrap_or(n - 2);
+    let h = points[i + 1] - points[i];
+    let s = (t - points[i]) / h;
+    let (two, three, four, six) = (d128::from(2), d128::from(3), d128::from(4), d128::from(6));
+
+    // The Hermite basis, or its derivatives in s, for y0, h y0', y1 and h y1'.
+    let basis = match order {
+        0 => [two * s * s * s - three * s * s + one(), s * s * s - two * s * s + s, three * s * s - two * s * s * s, s * s * s - s * s],
+        1 => [six * s * s - six * s, three * s * s - four * s + one(), six * s - six * s * s, three * s * s - two * s],
+        2 => [d128::from(12) * s - six, six * s - four, six - d128::from(12) * s, six * s - two],
+        3 => [d128::from(12), six, -d128::from(12), six],
+        _ => return Some(real::to_expression(zero(), digits)),
+    };
+
+    let value = basis[0] * values[i] + basis[1] * h * slopes[i] + basis[2] * values[i + 1] + basis[3] * h * slopes[i + 1];
+    let scale = (0..order).fold(one(), |c, _| c * h);
+
+    Some(real::to_expression(value / scale, digits))
+}
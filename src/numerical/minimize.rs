@@ -0,0 +1,186 @@
+use decimal::d128;
+
+use expression::structure::SimplexPointer;
+
+use calculus::derivative::d;
+use numerical::real::{one, zero, tolerance};
+use numerical::dense::{Vector, dot, largest};
+use numerical::value::real_value;
+use numerical::roots::{accuracy, bind};
+
+const ITERATIONS: usize = 500;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Method {
+    QuasiNewton,
+    NelderMead,
+}
+
+struct Objective<'a> {
+    f: &'a SimplexPointer,
+    vars: &'a [SimplexPointer],
+}
+
+impl<'a> Objective<'a> {
+    fn value(&self, x: &[d128]) -> Option<d128> {
+        real_value(self.f, &bind(self.vars, x))
+    }
+}
+
+fn identity(n: usize) -> Vec<Vector> {
+    (0..n).map(|i| (0..n).map(|j| if i == j { one() } else { zero() }).collect()).collect()
+}
+
+fn times(h: &[Vector], v: &[d128]) -> Vector {
+    h.iter().map(|row| dot(row, v)).collect()
+}
+
+// BFGS on the inverse Hessian with a backtracking line search, the gradient
+// taken symbolically.
+fn quasi_newton(objective: &Objective, start: &[d128], digits: u32) -> Option<Vector> {
+    let gradient = objective.vars.iter().map(|x| d(objective.f, x)).collect::<Option<Vec<SimplexPointer>>>()?;
+    let grad = |x: &[d128]| gradient.iter().map(|g| real_value(g, &bind(objective.vars, x))).collect::<Option<Vector>>();
+
+    let n = start.len();
+    let mut x = start.to_vec();
+    let mut fx = objective.value(&x)?;
+    let mut g = grad(&x)?;
+    let mut h = identity(n);
+
+    for _ in 0..ITERATIONS {
+        if largest(&g).is_zero() {
+            return Some(x);
+        }
+
+        let mut p = times(&h, &g).iter().map(|&v| -v).collect::<Vector>();
+
+        if !dot(&g, &p).is_negative() {
+            h = identity(n);
+            p = g.iter().map(|&v| -v).collect();
+        }
+
+        let slope = dot(&g, &p);
+        let mut t = one();
+
+        let (next, value) = loop {
+            let candidate = x.iter().zip(p.iter()).map(|(&a, &s)| a + t * s).collect::<Vector>();
+
+            match objective.value(&candidate) {
+                Some(v) if v <= fx + tolerance(4) * t * slope => break (candidate, v),
+                _ if t < tolerance(20) => return Some(x),
+                _ => t = t / d128::from(2),
+            }
+        };
+
+        let s = next.iter().zip(x.iter()).map(|(&a, &b)| a - b).collect::<Vector>();
+        let g1 = grad(&next)?;
+        let y = g1.iter().zip(g.iter()).map(|(&a, &b)| a - b).collect::<Vector>();
+        let sy = dot(&s, &y);
+
+        x = next;
+        fx = value;
+        g = g1;
+
+        if s.iter().zip(x.iter()).all(|(s, x)| s.abs() <= accuracy(digits) * (one() + x.abs())) {
+            return Some(x);
+        }
+
+        // H + (sy + y.H y) s s^T / sy^2 - (H y s^T + s y^T H) / sy
+        if sy.is_positive() {
+            let hy = times(&h, &y);
+            let scale = (sy + dot(&y, &hy)) / (sy * sy);
+
+            for i in 0..n {
+                for j in 0..n {
+                    let correction = scale * s[i] * s[j] - (hy[i] * s[j] + s[i] * hy[j]) / sy;
+                    h[i][j] = h[i][j] + correction;
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// The Nelder-Mead simplex, for objectives without a usable gradient.
+fn nelder_mead(objective: &Objective, start: &[d128], digits: u32) -> Option<Vector> {
+    let n = start.len();
+    let half = one() / d128::from(2);
+    let mut simplex = vec![start.to_vec()];
+
+    for i in 0..n {
+        let mut vertex = start.to_vec();
+        vertex[i] = vertex[i] + (one() + vertex[i].abs()) / d128::from(10);
+        simplex.push(vertex);
+    }
+
+    let mut values = simplex.iter().map(|v| objective.value(v)).collect::<Option<Vector>>()?;
+    // Only function values guide the simplex, so the location is good to
+    // about half the digits they carry.
+    let bound = accuracy(digits).max(tolerance(16));
+
+    let along = |a: &[d128], b: &[d128], t: d128| a.iter().zip(b.iter()).map(|(&p, &q)| p + t * (q - p)).collect::<Vector>();
+
+    for _ in 0..ITERATIONS * 10 * n {
+        let mut order = (0..n + 1).collect::<Vec<usize>>();
+        order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap());
+        simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+        values = order.iter().map(|&i| values[i]).collect();
+
+        let size = simplex[1..].iter()
+            .map(|v| largest(&v.iter().zip(simplex[0].iter()).map(|(&a, &b)| (a - b) / (one() + b.abs())).collect::<Vector>()))
+            .fold(zero(), |m, s| m.max(s));
+
+        if size <= bound {
+            return Some(simplex[0].clone());
+        }
+
+        let centroid = (0..n).map(|j| simplex[..n].iter().fold(zero(), |t, v| t + v[j]) / d128::from(n as i64)).collect::<Vector>();
+        let reflected = along(&centroid, &simplex[n], -one());
+        let fr = objective.value(&reflected)?;
+
+        if fr < values[0] {
+            let expanded = along(&centroid, &simplex[n], -d128::from(2));
+            let fe = objective.value(&expanded)?;
+
+            if fe < fr {
+                simplex[n] = expanded;
+                values[n] = fe;
+            } else {
+                simplex[n] = reflected;
+                values[n] = fr;
+            }
+        } else if fr < values[n - 1] {
+            simplex[n] = reflected;
+            values[n] = fr;
+        } else {
+            let contracted = along(&centroid, &simplex[n], half);
+            let fc = objective.value(&contracted)?;
+
+            if fc < values[n] {
+                simplex[n] = contracted;
+                values[n] = fc;
+            } else {
+                for i in 1..n + 1 {
+                    simplex[i] = along(&simplex[0], &simplex[i], half);
+                    values[i] = objective.value(&simplex[i])?;
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// A local minimum of f near the start as its value and location; the
+// quasi-Newton method falls back to Nelder-Mead where f has no gradient.
+pub fn minimize(f: &SimplexPointer, vars: &[SimplexPointer], start: &[d128], method: Method, digits: u32) -> Option<(d128, Vector)> {
+    let objective = Objective { f: f, vars: vars };
+
+    let x = match method {
+        Method::QuasiNewton => quasi_newton(&objective, start, digits).or_else(|| nelder_mead(&objective, start, digits))?,
+        Method::NelderMead => nelder_mead(&objective, start, digits)?,
+    };
+
+    Some((objective.value(&x)?, x))
+}
@@ -0,0 +1,13 @@
+pub mod real;
+pub mod complex;
+pub mod value;
+pub mod dense;
+pub mod roots;
+pub mod precise;
+pub mod constants;
+pub mod minimize;
+pub mod quadrature;
+pub mod ode;
+pub mod interpolation;
+pub mod builtins;
+pub mod test;
@@ -0,0 +1,274 @@
+use decimal::d128;
+
+use expression::structure::SimplexPointer;
+
+use calculus::derivative::d;
+use numerical::real::{one, zero, tolerance};
+use numerical::dense::{Vector, solve_dense, largest};
+use numerical::value::real_value;
+use numerical::roots::bind;
+
+// Steps either method may take across the range before giving up.
+const STEP_LIMIT: usize = 100000;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Method {
+    RungeKuttaFehlberg,
+    Bdf,
+}
+
+// y' = f(x, y) for the state symbols y, starting from their initial values.
+pub struct Problem {
+    pub variable: SimplexPointer,
+    pub start: d128,
+    pub states: Vec<SimplexPointer>,
+    pub rates: Vec<SimplexPointer>,
+    pub initial: Vector,
+}
+
+// The points reached, with the state and its rate of change at each.
+pub struct Trajectory {
+    pub points: Vector,
+    pub values: Vec<Vector>,
+    pub rates: Vec<Vector>,
+}
+
+impl Problem {
+    fn rate(&self, x: d128, y: &[d128]) -> Option<Vector> {
+        let mut bindings = bind(&self.states, y);
+        bindings.push((self.variable.clone(), x));
+
+        self.rates.iter().map(|r| real_value(r, &bindings)).collect()
+    }
+}
+
+fn combine(y: &[d128], terms: &[(d128, &[d128])]) -> Vector {
+    (0..y.len()).map(|i| terms.iter().fold(y[i], |t, &(c, k)| t + c * k[i])).collect()
+}
+
+// The largest component of the error measured against the tolerance, mixed
+// absolute and relative.
+fn scaled_error(error: &[d128], y: &[d128], goal: d128) -> d128 {
+    error.iter().zip(y.iter()).fold(zero(), |m, (&e, &v)| m.max(e.abs() / (goal * (one() + v.abs()))))
+}
+
+// The next step from how the last one went, for a method whose error
+// goes as the step to the given power, growing by at most the given factor.
+fn resize(h: d128, error: d128, power: i64, growth: d128) -> d128 {
+    let factor = if error.is_zero() {
+        growth
+    } else {
+        (real_ratio(9, 10) * (one() / error).pow(one() / d128::from(power))).max(real_ratio(1, 5)).min(growth)
+    };
+
+    h * factor
+}
+
+fn real_ratio(n: i64, d: i64) -> d128 {
+    d128::from(n) / d128::from(d)
+}
+
+// Fehlberg's embedded pair, carrying on with the fifth order solution.
+fn fehlberg(problem: &Problem, x: d128, y: &[d128], k1: &[d128], h: d128) -> Option<(Vector, Vector)> {
+    let r = real_ratio;
+    let k2 = problem.rate(x + h * r(1, 4), &combine(y, &[(h * r(1, 4), k1)]))?;
+    let k3 = problem.rate(x + h * r(3, 8), &combine(y, &[(h * r(3, 32), k1), (h * r(9, 32), &k2)]))?;
+    let k4 = problem.rate(x + h * r(12, 13), &combine(y, &[(h * r(1932, 2197), k1), (h * r(-7200, 2197), &k2), (h * r(7296, 2197), &k3)]))?;
+    let k5 = problem.rate(x + h, &combine(y, &[(h * r(439, 216), k1), (h * r(-8, 1), &k2), (h * r(3680, 513), &k3), (h * r(-845, 4104), &k4)]))?;
+    let k6 = problem.rate(x + h * r(1, 2), &combine(y, &[(h * r(-8, 27), k1), (h * r(2, 1), &k2), (h * r(-3544, 2565), &k3),
+                                                        (h * r(1859, 4104), &k4), (h * r(-11, 40), &k5)]))?;
+
+    let fifth = combine(y, &[(h * r(16, 135), k1), (h * r(6656, 12825), &k3), (h * r(28561, 56430), &k4), (h * r(-9, 50), &k5), (h * r(2, 55), &k6)]);
+    let fourth = combine(y, &[(h * r(25, 216), k1), (h * r(1408, 2565), &k3), (h * r(2197, 4104), &k4), (h * r(-1, 5), &k5)]);
+    let error = fifth.iter().zip(fourth.iter()).map(|(&a, &b)| a - b).collect();
+
+    Some((fifth, error))
+}
+
+// The Jacobian of the rates in the states, symbolic where it can be.
+struct Jacobian {
+    symbolic: Option<Vec<Vec<SimplexPointer>>>,
+}
+
+impl Jacobian {
+    fn new(problem: &Problem) -> Jacobian {
+        let symbolic = problem.rates.iter()
+            .map(|r| problem.states.iter().map(|y| d(r, y)).collect::<Option<Vec<SimplexPointer>>>())
+            .collect::<Option<Vec<Vec<SimplexPointer>>>>();
+
+        Jacobian { symbolic: symbolic }
+    }
+
+    fn at(&self, problem: &Problem, x: d128, y: &[d128], f: &[d128]) -> Option<Vec<Vector>> {
+        if let Some(ref rows) = self.symbolic {
+            let mut bindings = bind(&problem.states, y);
+            bindings.push((problem.variable.clone(), x));
+
+            if let Some(j) = rows.iter().map(|row| row.iter().map(|e| real_value(e, &bindings)).collect()).collect() {
+                return Some(j);
+            }
+        }
+
+        let mut columns = Vec::new();
+
+        for j in 0..y.len() {
+            let h = tolerance(16) * (one() + y[j].abs());
+            let mut shifted = y.to_vec();
+            shifted[j] = y[j] + h;
+
+            let g = problem.rate(x, &shifted)?;
+            columns.push(g.iter().zip(f.iter()).map(|(&a, &b)| (a - b) / h).collect::<Vector>());
+        }
+
+        Some((0..y.len()).map(|i| columns.iter().map(|c| c[i]).collect()).collect())
+    }
+}
+
+// Solves y = base + c f(x, y) for y by Newton's method from the guess.
+fn implicit(problem: &Problem, jacobian: &Jacobian, x: d128, base: &[d128], c: d128, guess: &[d128]) -> Option<Vector> {
+    let n = base.len();
+    let mut y = guess.to_vec();
+
+    for _ in 0..20 {
+        let f = problem.rate(x, &y)?;
+        let residual = (0..n).map(|i| y[i] - base[i] - c * f[i]).collect::<Vector>();
+        let j = jacobian.at(problem, x, &y, &f)?;
+        let matrix = (0..n).map(|r| (0..n).map(|s| (if r == s { one() } else { zero() }) - c * j[r][s]).collect()).collect::<Vec<Vector>>();
+        let step = solve_dense(&matrix, &residual.iter().map(|&v| -v).collect::<Vector>())?;
+
+        for i in 0..n {
+            y[i] = y[i] + step[i];
+        }
+
+        if largest(&step) <= tolerance(24) * (one() + largest(&y)) {
+            return Some(y);
+        }
+    }
+
+    None
+}
+
+// One step of the backward differentiation formula of second order with
+// variable steps, of backward Euler when there is no previous point yet.
+// The difference from an extrapolation of the same order through the last
+// points gives the error estimate.
+fn bdf(problem: &Problem, jacobian: &Jacobian, x: d128, y: &[d128], f: &[d128], h: d128,
+       previous: Option<&(d128, Vector)>) -> Option<(Vector, Vector)> {
+    let r = real_ratio;
+
+    match previous {
+        None => {
+            let guess = combine(y, &[(h, f)]);
+            let next = implicit(problem, jacobian, x + h, y, h, &guess)?;
+            let error = next.iter().zip(guess.iter()).map(|(&a, &b)| (a - b) * r(1, 2)).collect();
+
+            Some((next, error))
+        }
+        Some(&(last, ref before)) => {
+            let w = h / last;
+            let denominator = one() + d128::from(2) * w;
+            let (a, b, c) = ((one() + w) * (one() + w) / denominator, w * w / denominator, h * (one() + w) / denominator);
+
+            let base = (0..y.len()).map(|i| a * y[i] - b * before[i]).collect::<Vector>();
+            let curvature = (0..y.len()).map(|i| before[i] - y[i] + last * f[i]).collect::<Vector>();
+            let guess = combine(y, &[(h, f), (w * w, &curvature)]);
+            let next = implicit(problem, jacobian, x + h, &base, c, &guess)?;
+            let error = next.iter().zip(guess.iter()).map(|(&a, &b)| (a - b) * r(2, 5)).collect();
+
+            Some((next, error))
+        }
+    }
+}
+
+// From the initial point to the end with steps of either sign, the error
+// of each step held two digits below 10^-digits so that the errors adding
+// up over the range still leave that many.
+fn march(problem: &Problem, end: d128, method: Method, digits: u32) -> Option<Trajectory> {
+    let goal = tolerance(digits + 2);
+    let jacobian = Jacobian::new(problem);
+    let (power, growth) = match method {
+        Method::RungeKuttaFehlberg => (5, d128::from(5)),
+        Method::Bdf => (3, d128::from(2)),
+    };
+
+    let mut x = problem.start;
+    let mut y = problem.initial.clone();
+    let mut f = problem.rate(x, &y)?;
+    let mut h = (end - x) / d128::from(100);
+    let mut previous: Option<(d128, Vector)> = None;
+
+    let mut trajectory = Trajectory {
+        points: vec![x],
+        values: vec![y.clone()],
+        rates: vec![f.clone()],
+    };
+
+    for _ in 0..STEP_LIMIT {
+        let remaining = end - x;
+
+        if remaining.abs() <= tolerance(30) * (one() + end.abs()) {
+            return Some(trajectory);
+        }
+
+        if h.abs() > remaining.abs() {
+            h = remaining;
+        }
+
+        let attempt = match method {
+            Method::RungeKuttaFehlberg => fehlberg(problem, x, &y, &f, h),
+            Method::Bdf => bdf(problem, &jacobian, x, &y, &f, h, previous.as_ref()),
+        };
+
+        match attempt.map(|(next, error)| { let e = scaled_error(&error, &next, goal); (next, e) }) {
+            Some((next, error)) if error <= one() => {
+                x = x + h;
+                f = problem.rate(x, &next)?;
+                previous = Some((h, y));
+                y = next;
+
+                trajectory.points.push(x);
+                trajectory.values.push(y.clone());
+                trajectory.rates.push(f.clone());
+
+                h = resize(h, error, power, growth);
+            }
+            Some((_, error)) => h = resize(h, error, power, growth),
+            None => h = h / d128::from(4),
+        }
+
+        if h.abs() <= tolerance(30) * (one() + x.abs()) {
+            return None;
+        }
+    }
+
+    None
+}
+
+// The trajectory over [a, b], run in both directions from an initial point
+// inside it.
+pub fn solve(problem: &Problem, a: d128, b: d128, method: Method, digits: u32) -> Option<Trajectory> {
+    let x0 = problem.start;
+    let forward = march(problem, b.max(x0), method, digits)?;
+
+    if a >= x0 {
+        return Some(forward);
+    }
+
+    let mut backward = march(problem, a, method, digits)?;
+
+    backward.points.reverse();
+    backward.values.reverse();
+    backward.rates.reverse();
+
+    let n = backward.points.len() - 1;
+
+    backward.points.truncate(n);
+    backward.values.truncate(n);
+    backward.rates.truncate(n);
+
+    backward.points.extend(forward.points);
+    backward.values.extend(forward.values);
+    backward.rates.extend(forward.rates);
+
+    Some(backward)
+}
@@ -0,0 +1,648 @@
+use std::str::FromStr;
+
+use decimal::d128;
+use num::{self, BigInt, BigRational, Integer, Signed, Zero, ToPrimitive};
+
+use expression::atom::numbers::number::Numeric;
+use expression::atom::numbers::big_real::BigReal;
+use numerical::real::MAXIMUM_PRECISION;
+
+// Reals as integers in fixed point: x at precision p is the integer
+// nearest x 10^p. Every function here is good to within a unit or two in
+// the last place at the precision it is given, working a few digits past
+// it and rounding at the end.
+
+pub const GUARD: u32 = 10;
+
+// Past this many digits a result isn't worth chasing any further.
+pub const PRECISION_LIMIT: u32 = 8000;
+
+pub fn power_of_ten(p: u32) -> BigInt {
+    num::pow(BigInt::from(10), p as usize)
+}
+
+// a / b rounded to the nearest integer, halves away from zero.
+pub fn quotient(a: &BigInt, b: &BigInt) -> BigInt {
+    let (a, b) = if b.is_negative() { (-a, -b) } else { (a.clone(), b.clone()) };
+    let two = BigInt::from(2);
+    let twice = &a * &two;
+
+    if a.is_negative() {
+        -((-twice + &b).div_floor(&(&b * &two)))
+    } else {
+        (twice + &b).div_floor(&(&b * &two))
+    }
+}
+
+pub fn multiply(a: &BigInt, b: &BigInt, p: u32) -> BigInt {
+    quotient(&(a * b), &power_of_ten(p))
+}
+
+pub fn divide(a: &BigInt, b: &BigInt, p: u32) -> BigInt {
+    quotient(&(a * power_of_ten(p)), b)
+}
+
+// From precision p to precision q.
+pub fn rescale(x: &BigInt, p: u32, q: u32) -> BigInt {
+    if q >= p {
+        x * power_of_ten(q - p)
+    } else {
+        quotient(x, &power_of_ten(p - q))
+    }
+}
+
+pub fn from_integer(n: i64, p: u32) -> BigInt {
+    BigInt::from(n) * power_of_ten(p)
+}
+
+fn from_rational(q: &BigRational, p: u32) -> BigInt {
+    quotient(&(q.numer() * power_of_ten(p)), q.denom())
+}
+
+// Exactly for integers and rationals, and from the decimal digits of reals.
+pub fn from_numeric(n: &Numeric, p: u32) -> Option<BigInt> {
+    match *n {
+        Numeric::LittleReal(r) => from_decimal(&r.to_string(), p),
+        Numeric::BigReal(ref x) => Some(from_rational(&x.to_rational(), p)),
+        _ => Some(from_rational(&n.to_big_rational()?, p)),
+    }
+}
+
+// Parses the way d128 prints: an optional sign, digits with an optional
+// point and an optional exponent, into digits times a power of ten.
+fn decimal_parts(s: &str) -> Option<(BigInt, i64)> {
+    let (mantissa, exponent) = match s.find(|c| c == 'E' || c == 'e') {
+        Some(i) => (&s[..i], s[i + 1..].parse::<i64>().ok()?),
+        None => (s, 0),
+    };
+
+    let (whole, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+
+    let digits = BigInt::from_str(&format!("{}{}", whole, fraction)).ok()?;
+    Some((digits, exponent - fraction.len() as i64))
+}
+
+fn from_decimal(s: &str, p: u32) -> Option<BigInt> {
+    let (digits, exponent) = decimal_parts(s)?;
+    let shift = exponent + p as i64;
+
+    if shift >= 0 {
+        Some(digits * num::pow(BigInt::from(10), shift as usize))
+    } else {
+        Some(quotient(&digits, &num::pow(BigInt::from(10), (-shift) as usize)))
+    }
+}
+
+// Exactly the rational a real stands for.
+pub fn to_rational(x: d128) -> Option<BigRational> {
+    if !x.is_finite() {
+        return None;
+    }
+
+    let (digits, exponent) = decimal_parts(&x.to_string())?;
+    let scale = num::pow(BigInt::from(10), exponent.abs() as usize);
+
+    if exponent >= 0 {
+        Some(BigRational::from_integer(digits * scale))
+    } else {
+        Some(BigRational::new(digits, scale))
+    }
+}
+
+fn decimal_digits(x: &BigInt) -> u32 {
+    if x.is_zero() { 0 } else { x.abs().to_string().len() as u32 }
+}
+
+// x at precision p to the given number of significant digits.
+pub fn to_d128(x: &BigInt, p: u32, digits: u32) -> Option<d128> {
+    if x.is_zero() {
+        return Some(d128::zero());
+    }
+
+    let length = decimal_digits(x);
+    let drop = length.saturating_sub(digits);
+    let kept = quotient(x, &power_of_ten(drop));
+    let exponent = drop as i64 - p as i64;
+    let result = d128::from_str(&format!("{}E{}", kept, exponent)).ok()?;
+
+    if result.is_finite() { Some(result.reduce()) } else { None }
+}
+
+// Evaluates f, which gives its value at whatever precision it is asked
+// for, from precision p up until the result has the significant digits
+// wanted with some to spare.
+pub fn approximate<F>(f: F, digits: u32, p: u32) -> Option<d128>
+    where F: Fn(u32) -> Option<BigInt>
+{
+    let mut p = p;
+
+    loop {
+        let y = f(p)?;
+        let significant = decimal_digits(&y);
+
+        if significant >= digits + GUARD / 2 {
+            return to_d128(&y, p, digits);
+        }
+
+        if p > PRECISION_LIMIT {
+            return None;
+        }
+
+        p = if y.is_zero() { 2 * p } else { p + (digits + GUARD - significant).max(GUARD) };
+    }
+}
+
+// The sum of 1 / ((2k + 1) n^(2k + 1)), alternating in sign for the arc
+// tangent and not for the inverse hyperbolic tangent.
+fn inverse_series(n: i64, alternating: bool, p: u32) -> BigInt {
+    let square = BigInt::from(n * n);
+    let mut power = quotient(&power_of_ten(p), &BigInt::from(n));
+    let mut total = BigInt::zero();
+    let mut k = 0;
+
+    while !power.is_zero() {
+        let term = quotient(&power, &BigInt::from(2 * k + 1));
+        total = if alternating && k % 2 == 1 { total - term } else { total + term };
+        power = quotient(&power, &square);
+        k = k + 1;
+    }
+
+    total
+}
+
+// Machin's formula, Pi = 16 ArcTan[1/5] - 4 ArcTan[1/239].
+pub fn pi(p: u32) -> BigInt {
+    let q = p + GUARD;
+    let x = inverse_series(5, true, q) * 16 - inverse_series(239, true, q) * 4;
+    rescale(&x, q, p)
+}
+
+// The sum of 1/k!.
+pub fn e(p: u32) -> BigInt {
+    let q = p + GUARD;
+    let mut term = power_of_ten(q);
+    let mut total = BigInt::zero();
+    let mut k = 1;
+
+    while !term.is_zero() {
+        total = total + &term;
+        term = quotient(&term, &BigInt::from(k));
+        k = k + 1;
+    }
+
+    rescale(&total, q, p)
+}
+
+// Log[2] = 2 ArcTanh[1/3].
+fn ln2(p: u32) -> BigInt {
+    let q = p + GUARD;
+    rescale(&(inverse_series(3, false, q) * 2), q, p)
+}
+
+pub fn sqrt(x: &BigInt, p: u32) -> Option<BigInt> {
+    if x.is_negative() {
+        return None;
+    }
+
+    let q = p + GUARD;
+    Some(rescale(&(rescale(x, p, q) * power_of_ten(q)).sqrt(), q, p))
+}
+
+// Taylor series after halving x until it is small, squaring back after.
+pub fn exp(x: &BigInt, p: u32) -> Option<BigInt> {
+    if x.is_negative() {
+        let q = p + GUARD;
+        let y = exp(&rescale(&-x, p, q), q)?;
+        return Some(rescale(&divide(&power_of_ten(q), &y, q), q, p));
+    }
+
+    let magnitude = quotient(x, &power_of_ten(p)).to_i64()?;
+
+    if magnitude > 15000 {
+        return None;
+    }
+
+    let mut halvings = 0;
+    let mut r = x.clone();
+    let small = power_of_ten(p) / 1000;
+
+    while r > small {
+        r = quotient(&r, &BigInt::from(2));
+        halvings = halvings + 1;
+    }
+
+    // Squaring loses a digit for every three halvings, and the result can
+    // have as many digits before the point as the argument's magnitude.
+    let q = p + GUARD + halvings / 3 + 1;
+    let r = rescale(x, p, q) / num::pow(BigInt::from(2), halvings as usize);
+    let mut term = power_of_ten(q);
+    let mut total = BigInt::zero();
+    let mut k = 1;
+
+    while !term.is_zero() {
+        total = total + &term;
+        term = quotient(&multiply(&term, &r, q), &BigInt::from(k));
+        k = k + 1;
+    }
+
+    for _ in 0..halvings {
+        total = multiply(&total, &total, q);
+    }
+
+    Some(rescale(&total, q, p))
+}
+
+// x = 2^k m with m near one, Log[m] = 2 ArcTanh[(m - 1)/(m + 1)].
+pub fn ln(x: &BigInt, p: u32) -> Option<BigInt> {
+    if !x.is_positive() {
+        return None;
+    }
+
+    let q = p + GUARD;
+    let one = power_of_ten(q);
+    let x = rescale(x, p, q);
+    let k = x.bits() as i64 - one.bits() as i64;
+
+    let m = if k >= 0 { &x >> (k as usize) } else { &x << ((-k) as usize) };
+    let t = divide(&(&m - &one), &(&m + &one), q);
+    let square = multiply(&t, &t, q);
+    let mut power = t.clone();
+    let mut total = BigInt::zero();
+    let mut j = 0;
+
+    while !power.is_zero() {
+        total = total + quotient(&power, &BigInt::from(2 * j + 1));
+        power = multiply(&power, &square, q);
+        j = j + 1;
+    }
+
+    Some(rescale(&(total * 2 + ln2(q) * k), q, p))
+}
+
+// Brent and McMillan: with A and B summed as below from A = -Log[n] and
+// B = 1, EulerGamma is Sum[A] / Sum[B] to within Exp[-4 n].
+pub fn euler_gamma(p: u32) -> Option<BigInt> {
+    let q = p + GUARD;
+    let n = p as i64 * 3 / 5 + 2;
+    let square = BigInt::from(n * n);
+    let log = ln(&from_integer(n, q), q)?;
+    let (mut a, mut b) = (-log, power_of_ten(q));
+    let (mut u, mut v) = (a.clone(), b.clone());
+    let mut k = 1;
+
+    while !a.is_zero() || !b.is_zero() {
+        b = quotient(&(&b * &square), &BigInt::from(k * k));
+        a = quotient(&(quotient(&(&a * &square), &BigInt::from(k)) + &b), &BigInt::from(k));
+        u = u + &a;
+        v = v + &b;
+        k = k + 1;
+    }
+
+    Some(rescale(&divide(&u, &v, q), q, p))
+}
+
+// Sine and cosine together, from the Taylor series of x less the nearest
+// multiple of Pi/2, with Pi to as many more digits as x has before the
+// point.
+pub fn sin_cos(x: &BigInt, p: u32) -> Option<(BigInt, BigInt)> {
+    let whole = decimal_digits(&quotient(x, &power_of_ten(p)));
+
+    if whole > 400 {
+        return None;
+    }
+
+    let q = p + GUARD + whole;
+    let x = rescale(x, p, q);
+    let half_pi = quotient(&pi(q), &BigInt::from(2));
+    let n = quotient(&x, &half_pi);
+    let r = &x - &n * &half_pi;
+    let square = multiply(&r, &r, q);
+
+    // The terms of both series go down by -r^2 / ((k + 1) (k + 2)).
+    let series = |mut term: BigInt, mut k: i64| {
+        let mut total = BigInt::zero();
+
+        while !term.is_zero() {
+            total = total + &term;
+            term = -quotient(&multiply(&term, &square, q), &BigInt::from((k + 1) * (k + 2)));
+            k = k + 2;
+        }
+
+        total
+    };
+
+    let (s, c) = (series(r.clone(), 1), series(power_of_ten(q), 0));
+
+    let (s, c) = match n.mod_floor(&BigInt::from(4)).to_i64()? {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+
+    Some((rescale(&s, q, p), rescale(&c, q, p)))
+}
+
+// Halves the angle twice, x / (1 + Sqrt[1 + x^2]), then sums the series;
+// past one by way of Pi/2 - ArcTan[1/x].
+pub fn atan(x: &BigInt, p: u32) -> Option<BigInt> {
+    let q = p + GUARD;
+    let one = power_of_ten(q);
+    let x = rescale(x, p, q);
+
+    if x.abs() > one {
+        let half_pi = quotient(&pi(q), &BigInt::from(2));
+        let inner = atan(&divide(&one, &x, q), q)?;
+        let result = if x.is_negative() { -half_pi - inner } else { half_pi - inner };
+        return Some(rescale(&result, q, p));
+    }
+
+    let mut r = x;
+
+    for _ in 0..2 {
+        let root = sqrt(&(&one + multiply(&r, &r, q)), q)?;
+        r = divide(&r, &(&one + root), q);
+    }
+
+    let square = multiply(&r, &r, q);
+    let mut power = r.clone();
+    let mut total = BigInt::zero();
+    let mut k = 0;
+
+    while !power.is_zero() {
+        let term = quotient(&power, &BigInt::from(2 * k + 1));
+        total = if k % 2 == 1 { total - term } else { total + term };
+        power = multiply(&power, &square, q);
+        k = k + 1;
+    }
+
+    Some(rescale(&(total * 4), q, p))
+}
+
+// ArcSin[x] = ArcTan[x / Sqrt[1 - x^2]] inside (-1, 1).
+pub fn asin(x: &BigInt, p: u32) -> Option<BigInt> {
+    let q = p + GUARD;
+    let one = power_of_ten(q);
+    let x = rescale(x, p, q);
+
+    if x.abs() > one {
+        return None;
+    }
+
+    if x.abs() == one {
+        let half_pi = quotient(&pi(q), &BigInt::from(2));
+        return Some(rescale(&if x.is_negative() { -half_pi } else { half_pi }, q, p));
+    }
+
+    let root = sqrt(&(&one - multiply(&x, &x, q)), q)?;
+    atan(&divide(&x, &root, q), q).map(|y| rescale(&y, q, p))
+}
+
+// The elementary functions of a real x at precision p, None outside their
+// real domains.
+pub fn function(name: &str, x: &BigInt, p: u32) -> Option<BigInt> {
+    let q = p + GUARD;
+    let one = power_of_ten(q);
+    let x = rescale(x, p, q);
+    let half = |y: BigInt| quotient(&y, &BigInt::from(2));
+
+    let y = match name {
+        "Exp" => exp(&x, q)?,
+        "Log" => ln(&x, q)?,
+        "Sqrt" => sqrt(&x, q)?,
+        "Sin" => sin_cos(&x, q)?.0,
+        "Cos" => sin_cos(&x, q)?.1,
+        "Tan" | "Cot" | "Sec" | "Csc" => {
+            let (s, c) = sin_cos(&x, q)?;
+
+            match name {
+                "Tan" if !c.is_zero() => divide(&s, &c, q),
+                "Cot" if !s.is_zero() => divide(&c, &s, q),
+                "Sec" if !c.is_zero() => divide(&one, &c, q),
+                "Csc" if !s.is_zero() => divide(&one, &s, q),
+                _ => return None,
+            }
+        }
+        "ArcTan" => atan(&x, q)?,
+        "ArcCot" if !x.is_zero() => atan(&divide(&one, &x, q), q)?,
+        "ArcSin" => asin(&x, q)?,
+        "ArcCos" => half(pi(q)) - asin(&x, q)?,
+        "ArcCsc" if !x.is_zero() => asin(&divide(&one, &x, q), q)?,
+        "ArcSec" if !x.is_zero() => half(pi(q)) - asin(&divide(&one, &x, q), q)?,
+        "Sinh" | "Cosh" | "Tanh" | "Coth" | "Sech" | "Csch" => {
+            let (a, b) = (exp(&x, q)?, exp(&-&x, q)?);
+            let (s, c) = (half(&a - &b), half(&a + &b));
+
+            match name {
+                "Sinh" => s,
+                "Cosh" => c,
+                "Tanh" => divide(&s, &c, q),
+                "Sech" => divide(&one, &c, q),
+                "Coth" if !s.is_zero() => divide(&c, &s, q),
+                "Csch" if !s.is_zero() => divide(&one, &s, q),
+                _ => return None,
+            }
+        }
+        "ArcSinh" => {
+            let root = sqrt(&(multiply(&x, &x, q) + &one), q)?;
+            let y = ln(&(x.abs() + root), q)?;
+            if x.is_negative() { -y } else { y }
+        }
+        "ArcCosh" if x >= one => ln(&(&x + sqrt(&(multiply(&x, &x, q) - &one), q)?), q)?,
+        "ArcTanh" if x.abs() < one => half(ln(&divide(&(&one + &x), &(&one - &x), q), q)?),
+        "ArcCoth" if x.abs() > one => half(ln(&divide(&(&x + &one), &(&x - &one), q), q)?),
+        _ => return None,
+    };
+
+    Some(rescale(&y, q, p))
+}
+
+// x^y for a positive x, and for a zero x and a positive y.
+pub fn power(x: &BigInt, y: &BigInt, p: u32) -> Option<BigInt> {
+    if x.is_zero() {
+        return if y.is_positive() { Some(BigInt::zero()) } else { None };
+    }
+
+    let q = p + GUARD;
+    let log = ln(&rescale(x, p, q), q)?;
+    exp(&multiply(&log, &rescale(y, p, q), q), q).map(|z| rescale(&z, q, p))
+}
+
+// (-x)^y for a positive x, as the parts of x^y (Cos[Pi y] + I Sin[Pi y]):
+// machine reals when either is one, BigReals otherwise. A y that is a
+// multiple of a half leaves one part exactly zero.
+pub fn negative_power(x: &Numeric, y: &Numeric) -> Option<(Numeric, Numeric)> {
+    let part = |imaginary: bool| move |xs: &[BigInt], p: u32| {
+        let (s, c) = sin_cos(&multiply(&pi(p), &xs[1], p), p)?;
+        Some(multiply(&power(&xs[0], &xs[1], p)?, if imaginary { &s } else { &c }, p))
+    };
+
+    let args = [x.clone(), y.clone()];
+    let digits = MAXIMUM_PRECISION;
+    let evaluate = |f: &Fn(&[BigInt], u32) -> Option<BigInt>| if !x.is_machine() && !y.is_machine() {
+        arbitrary(f, &args)
+    } else {
+        approximate(|p| f(&[from_numeric(x, p)?, from_numeric(y, p)?], p), digits, digits + GUARD).map(Numeric::LittleReal)
+    };
+
+    let exact = match *y {
+        Numeric::LittleReal(r) => to_rational(r),
+        Numeric::BigReal(ref r) => Some(r.to_rational()),
+        _ => y.to_big_rational(),
+    };
+
+    let twice = exact.map(|q| q * BigRational::from_integer(BigInt::from(2)));
+
+    match twice {
+        Some(ref t) if t.is_integer() => {
+            let m = evaluate(&|xs, p| power(&xs[0], &xs[1], p))?;
+            let zero = Numeric::LittleInteger(0);
+
+            Some(match t.to_integer().mod_floor(&BigInt::from(4)).to_i64()? {
+                0 => (m, zero),
+                1 => (zero, m),
+                2 => (m.negate(), zero),
+                _ => (zero, m.negate()),
+            })
+        }
+        _ => Some((evaluate(&part(false))?, evaluate(&part(true))?)),
+    }
+}
+
+// f of arguments with BigReals among them, as a BigReal with the digits
+// that moving any of them by a unit in its last place leaves alone, and
+// never more than the least precise of them has. More digits are worked
+// with until either the result or how far it moves shows enough of them.
+pub fn arbitrary<F>(f: F, args: &[Numeric]) -> Option<Numeric>
+    where F: Fn(&[BigInt], u32) -> Option<BigInt>
+{
+    let reals = args.iter()
+        .filter_map(|a| match *a {
+            Numeric::BigReal(ref x) => Some(x),
+            _ => None,
+        })
+        .collect::<Vec<&BigReal>>();
+
+    let precision = reals.iter().map(|x| x.precision()).min()?;
+    let lowest = reals.iter().map(|x| x.exponent()).min()?;
+
+    if precision == 0 {
+        return None;
+    }
+
+    // f with the i-th argument moved by a unit in its last place.
+    let at = |p: u32, moved: Option<(usize, i64)>| {
+        let xs = args.iter().enumerate()
+            .map(|(i, a)| {
+                let x = from_numeric(a, p)?;
+
+                match (moved, a) {
+                    (Some((j, sign)), &Numeric::BigReal(ref r)) if i == j => {
+                        Some(x + power_of_ten((r.exponent() + p as i64) as u32) * sign)
+                    }
+                    _ => Some(x),
+                }
+            })
+            .collect::<Option<Vec<BigInt>>>()?;
+
+        f(&xs, p)
+    };
+
+    let mut p = (-lowest).max(0) as u32 + GUARD;
+
+    loop {
+        let y = at(p, None)?;
+        let mut moved = BigInt::zero();
+
+        for (i, a) in args.iter().enumerate() {
+            if let Numeric::BigReal(_) = *a {
+                for &sign in &[-1, 1] {
+                    if let Some(z) = at(p, Some((i, sign))) {
+                        moved = moved.max((z - &y).abs());
+                    }
+                }
+            }
+        }
+
+        let (known, error) = (decimal_digits(&y), decimal_digits(&moved));
+
+        if known >= precision + GUARD || error >= GUARD {
+            let last = decimal_digits(&(moved + 2)) as i64 - 1;
+            let x = BigReal::with_exponent(&BigRational::new(y, power_of_ten(p)), last - p as i64);
+            return Some(Numeric::BigReal(x.round(precision)));
+        }
+
+        if p > PRECISION_LIMIT {
+            return None;
+        }
+
+        p = p + (precision + GUARD - known).max(GUARD);
+    }
+}
+
+// Where a function is exactly zero, which no amount of precision shows.
+fn vanishes(name: &str, x: &Numeric) -> bool {
+    match name {
+        "Sin" | "Tan" | "ArcSin" | "ArcTan" | "Sinh" | "Tanh" | "ArcSinh" | "ArcTanh" | "Sqrt" => *x == Numeric::LittleInteger(0),
+        "Log" | "ArcCos" | "ArcSec" | "ArcCosh" => *x == Numeric::LittleInteger(1),
+        _ => false,
+    }
+}
+
+// f[x] to the given number of significant digits, starting with as many
+// more as x has zeros after the point.
+pub fn evaluate(name: &str, x: Numeric, digits: u32) -> Option<d128> {
+    if vanishes(name, &x) {
+        return Some(d128::zero());
+    }
+
+    let small = match x {
+        Numeric::LittleReal(r) if !r.is_zero() => {
+            let leading: i32 = r.logb().into();
+            (-leading).max(0) as u32
+        }
+        _ => 0,
+    };
+
+    approximate(|p| function(name, &from_numeric(&x, p)?, p), digits, digits + GUARD + small)
+}
+
+fn constant_at(name: &str, p: u32) -> Option<BigInt> {
+    match name {
+        "Pi" => Some(pi(p)),
+        "E" => Some(e(p)),
+        "EulerGamma" => euler_gamma(p),
+        "GoldenRatio" => sqrt(&from_integer(5, p), p).map(|r| quotient(&(r + power_of_ten(p)), &BigInt::from(2))),
+        "Degree" => Some(quotient(&pi(p), &BigInt::from(180))),
+        _ => None,
+    }
+}
+
+// The constants to the given number of significant digits.
+pub fn constant(name: &str, digits: u32) -> Option<d128> {
+    approximate(|p| constant_at(name, p), digits, digits + GUARD)
+}
+
+// The constants as BigReals with the given number of digits.
+pub fn arbitrary_constant(name: &str, precision: u32) -> Option<BigReal> {
+    let p = precision + GUARD;
+    let y = constant_at(name, p)?;
+    Some(BigReal::with_precision(&BigRational::new(y, power_of_ten(p)), precision))
+}
+
+// Log[b, x] as one quotient of logarithms, both worked out at the same
+// precision.
+pub fn logarithm(b: Numeric, x: Numeric, digits: u32) -> Option<d128> {
+    if vanishes("Log", &x) {
+        return Some(d128::zero());
+    }
+
+    approximate(|p| {
+        let base = ln(&from_numeric(&b, p)?, p)?;
+        let y = ln(&from_numeric(&x, p)?, p)?;
+        if base.is_zero() { None } else { Some(divide(&y, &base, p)) }
+    }, digits, digits + GUARD)
+}
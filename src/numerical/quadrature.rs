@@ -0,0 +1,140 @@
+use decimal::d128;
+
+use numerical::real::{real, one, zero, tolerance};
+
+// The 15-point Kronrod extension of the 7-point Gauss rule on [-1, 1], the
+// odd nodes being the Gauss ones, symmetric about the last.
+const KRONROD_NODES: [&'static str; 8] = [
+    "0.9914553711208126392068546975263285",
+    "0.9491079123427585245261896840478513",
+    "0.8648644233597690727897127886409262",
+    "0.7415311855993944398638647732807884",
+    "0.5860872354676911302941448382587296",
+    "0.4058451513773971669066064120769615",
+    "0.2077849550078984676006894037732449",
+    "0",
+];
+
+const KRONROD_WEIGHTS: [&'static str; 8] = [
+    "0.02293532201052922496373200805896959",
+    "0.06309209262997855329070066318920429",
+    "0.1047900103222501838398763225415180",
+    "0.1406532597155259187451895905102379",
+    "0.1690047266392679028265834265985503",
+    "0.1903505780647854099132564024210137",
+    "0.2044329400752988924141619992346491",
+    "0.2094821410847278280129991748917143",
+];
+
+const GAUSS_WEIGHTS: [&'static str; 4] = [
+    "0.1294849661688696932706114326790820",
+    "0.2797053914892766679014677714237796",
+    "0.3818300505051189449503697754889751",
+    "0.4179591836734693877551020408163265",
+];
+
+// Subintervals the adaptive rule may split the range into before giving up.
+const SEGMENT_LIMIT: usize = 2000;
+
+struct Segment {
+    a: d128,
+    b: d128,
+    integral: d128,
+    error: d128,
+}
+
+// The Kronrod estimate on [a, b], its distance from the Gauss one standing
+// in for the error.
+fn kronrod<F>(f: &F, a: d128, b: d128) -> Option<Segment>
+    where F: Fn(d128) -> Option<d128>
+{
+    let centre = (a + b) / d128::from(2);
+    let half = (b - a) / d128::from(2);
+    let mut k = zero();
+    let mut g = zero();
+
+    for i in 0..8 {
+        let x = half * real(KRONROD_NODES[i]);
+        let values = if i == 7 { f(centre)? } else { f(centre - x)? + f(centre + x)? };
+
+        k = k + real(KRONROD_WEIGHTS[i]) * values;
+
+        if i % 2 == 1 {
+            g = g + real(GAUSS_WEIGHTS[i / 2]) * values;
+        }
+    }
+
+    Some(Segment { a: a, b: b, integral: k * half, error: ((k - g) * half).abs() })
+}
+
+// The integral of f over [a, b], bisecting the segment with the largest
+// error until the total is good to the given number of digits.
+pub fn adaptive<F>(f: &F, a: d128, b: d128, digits: u32) -> Option<d128>
+    where F: Fn(d128) -> Option<d128>
+{
+    let goal = tolerance(digits);
+    let mut segments = vec![kronrod(f, a, b)?];
+
+    loop {
+        let total = segments.iter().fold(zero(), |t, s| t + s.integral);
+        let error = segments.iter().fold(zero(), |t, s| t + s.error);
+
+        if error <= goal * total.abs().max(goal) {
+            return Some(total);
+        }
+
+        if segments.len() == SEGMENT_LIMIT {
+            return None;
+        }
+
+        let worst = (0..segments.len()).fold(0, |w, i| if segments[i].error > segments[w].error { i } else { w });
+        let s = segments.swap_remove(worst);
+        let middle = (s.a + s.b) / d128::from(2);
+
+        segments.push(kronrod(f, s.a, middle)?);
+        segments.push(kronrod(f, middle, s.b)?);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Bound {
+    Finite(d128),
+    Infinite(i64),
+}
+
+// Over an infinite range through a change of variable onto a finite one:
+// x = a + t / (1 - t) for [a, Infinity), x = t / (1 - t^2) for the whole
+// line, and the integral turned over for the ranges that run backwards.
+pub fn integrate<F>(f: &F, a: Bound, b: Bound, digits: u32) -> Option<d128>
+    where F: Fn(d128) -> Option<d128>
+{
+    match (a, b) {
+        (Bound::Finite(a), Bound::Finite(b)) => adaptive(f, a, b, digits),
+        (Bound::Finite(a), Bound::Infinite(1)) => {
+            let g = |t: d128| {
+                let r = one() / (one() - t);
+                f(a + t * r).map(|v| v * r * r)
+            };
+
+            adaptive(&g, zero(), one(), digits)
+        }
+        (Bound::Infinite(-1), Bound::Finite(b)) => {
+            let g = |t: d128| {
+                let r = one() / (one() - t);
+                f(b - t * r).map(|v| v * r * r)
+            };
+
+            adaptive(&g, zero(), one(), digits)
+        }
+        (Bound::Infinite(-1), Bound::Infinite(1)) => {
+            let g = |t: d128| {
+                let r = one() / (one() - t * t);
+                f(t * r).map(|v| v * (one() + t * t) * r * r)
+            };
+
+            adaptive(&g, -one(), one(), digits)
+        }
+        (a, b) if a == b => Some(zero()),
+        (a, b) => integrate(f, b, a, digits).map(|v| -v),
+    }
+}
@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use decimal::d128;
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+use expression::atom::numbers::big_real::BigReal;
+
+use arithmetic::terms::number;
+use numerical::precise::to_rational;
+
+// Digits a d128 carries, and the precision of every inexact result unless
+// asked for another; MachinePrecision rounds to the digits of a double.
+pub const MAXIMUM_PRECISION: u32 = 34;
+pub const MACHINE_PRECISION: u32 = 16;
+
+pub fn real(s: &str) -> d128 {
+    d128::from_str(s).unwrap()
+}
+
+pub fn pi() -> d128 {
+    real("3.141592653589793238462643383279503")
+}
+
+pub fn e() -> d128 {
+    real("2.718281828459045235360287471352662")
+}
+
+pub fn euler_gamma() -> d128 {
+    real("0.5772156649015328606065120900824024")
+}
+
+pub fn golden_ratio() -> d128 {
+    real("1.618033988749894848204586834365638")
+}
+
+pub fn degree() -> d128 {
+    real("0.01745329251994329576923690768488613")
+}
+
+pub fn zero() -> d128 {
+    d128::zero()
+}
+
+pub fn one() -> d128 {
+    d128::from(1)
+}
+
+// 10^-digits, the relative accuracy a result at that precision needs.
+pub fn tolerance(digits: u32) -> d128 {
+    real(&format!("1E-{}", digits))
+}
+
+// x to the given number of significant digits, trailing zeros dropped.
+pub fn round(x: d128, digits: u32) -> d128 {
+    if x.is_zero() || !x.is_finite() {
+        return x;
+    }
+
+    let leading: i32 = x.logb().into();
+    x.quantize(one().scaleb(d128::from(leading - digits as i32 + 1))).reduce()
+}
+
+// A machine real as it is shown: x rounded to machine precision when it
+// carries more digits, so that printing never exposes the d128 guard digits.
+pub fn machine(x: d128) -> d128 {
+    if x.digits() > MACHINE_PRECISION { round(x, MACHINE_PRECISION) } else { x }
+}
+
+// A machine real up to machine precision, past it a BigReal keeping every
+// digit asked for.
+pub fn to_expression(x: d128, digits: u32) -> SimplexPointer {
+    if digits > MACHINE_PRECISION && !x.is_zero() {
+        if let Some(q) = to_rational(x) {
+            return number(Numeric::BigReal(BigReal::with_precision(&q, digits)));
+        }
+    }
+
+    number(Numeric::LittleReal(round(x, digits)))
+}
+
+pub fn sqrt(x: d128) -> d128 {
+    if x.is_zero() { x } else { x.pow(real("0.5")) }
+}
+
+// Exp through a power of E: d128's own exp passes its operands to decNumber
+// out of order.
+pub fn exp(x: d128) -> d128 {
+    e().pow(x)
+}
+
+pub fn ln(x: d128) -> d128 {
+    x.ln()
+}
+
+// Sine and cosine by their Taylor series after reducing the argument into
+// [-Pi, Pi].
+fn reduce(x: d128) -> d128 {
+    let period = pi() * d128::from(2);
+    let turns = (x / period).quantize(one());
+
+    x - turns * period
+}
+
+fn taylor(x: d128, first: d128, start: i64) -> d128 {
+    let square = x * x;
+    let mut term = first;
+    let mut total = first;
+    let mut k = start;
+
+    while term.abs() > tolerance(MAXIMUM_PRECISION + 4) * (one() + total.abs()) {
+        term = -term * square / d128::from((k + 1) * (k + 2));
+        total = total + term;
+        k = k + 2;
+    }
+
+    total
+}
+
+pub fn sin(x: d128) -> d128 {
+    let x = reduce(x);
+    taylor(x, x, 1)
+}
+
+pub fn cos(x: d128) -> d128 {
+    taylor(reduce(x), one(), 0)
+}
+
+// ArcTan by its series once the argument is below 1/2: 1/x for large ones
+// and x / (1 + Sqrt[1 + x^2]), which halves the angle, for the rest.
+pub fn atan(x: d128) -> d128 {
+    if x.is_negative() {
+        return -atan(-x);
+    }
+
+    if x > one() {
+        return pi() / d128::from(2) - atan(one() / x);
+    }
+
+    if x > real("0.5") {
+        return d128::from(2) * atan(x / (one() + sqrt(one() + x * x)));
+    }
+
+    let square = x * x;
+    let mut power = x;
+    let mut total = x;
+    let mut k = 1;
+
+    while power.abs() > tolerance(MAXIMUM_PRECISION + 4) {
+        power = -power * square;
+        k = k + 2;
+        total = total + power / d128::from(k);
+    }
+
+    total
+}
+
+pub fn asin(x: d128) -> Option<d128> {
+    if x.abs() > one() {
+        None
+    } else if x.abs() == one() {
+        Some(x * pi() / d128::from(2))
+    } else {
+        Some(atan(x / sqrt(one() - x * x)))
+    }
+}
+
+// The elementary functions of a real argument, None outside their real
+// domain.
+pub fn function(name: &str, x: d128) -> Option<d128> {
+    let result = match name {
+        "Exp" => exp(x),
+        "Log" if x.is_positive() => ln(x),
+        "Sqrt" if !x.is_negative() => sqrt(x),
+        "Sin" => sin(x),
+        "Cos" => cos(x),
+        "Tan" => sin(x) / cos(x),
+        "Cot" => cos(x) / sin(x),
+        "Sec" => one() / cos(x),
+        "Csc" => one() / sin(x),
+        "Sinh" => (exp(x) - exp(-x)) / d128::from(2),
+        "Cosh" => (exp(x) + exp(-x)) / d128::from(2),
+        "Tanh" => (exp(x) - exp(-x)) / (exp(x) + exp(-x)),
+        "ArcTan" => atan(x),
+        "ArcSin" => asin(x)?,
+        "ArcCos" => pi() / d128::from(2) - asin(x)?,
+        "ArcSinh" => ln(x + sqrt(x * x + one())),
+        "Abs" => x.abs(),
+        _ => return None,
+    };
+
+    if result.is_finite() { Some(result) } else { None }
+}
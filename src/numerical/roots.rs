@@ -0,0 +1,336 @@
+use std::cmp::Ordering;
+
+use decimal::d128;
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use polynomials::factor::factor_coefficients;
+use calculus::derivative::d;
+use numerical::real::{self, one, zero, tolerance, MAXIMUM_PRECISION};
+use numerical::complex::Complex;
+use numerical::dense::{Vector, solve_dense, largest};
+use numerical::value::{Bindings, real_value};
+
+const ITERATIONS: usize = 200;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Method {
+    Newton,
+    Secant,
+    Brent,
+}
+
+// How close successive iterates must come before a result is reported to
+// the given number of digits, kept a few digits past it where a d128 allows.
+pub fn accuracy(digits: u32) -> d128 {
+    tolerance((digits + 4).min(MAXIMUM_PRECISION - 2))
+}
+
+fn converged(step: &[d128], x: &[d128], digits: u32) -> bool {
+    step.iter().zip(x.iter()).all(|(s, x)| s.abs() <= accuracy(digits) * (one() + x.abs()))
+}
+
+pub fn bind(vars: &[SimplexPointer], x: &[d128]) -> Vec<(SimplexPointer, d128)> {
+    vars.iter().cloned().zip(x.iter().cloned()).collect()
+}
+
+fn residuals(fs: &[SimplexPointer], bindings: &Bindings) -> Option<Vector> {
+    fs.iter().map(|f| real_value(f, bindings)).collect()
+}
+
+// The Jacobian from symbolic derivatives where every one of them exists and
+// has a value, otherwise by forward differences.
+struct Jacobian {
+    symbolic: Option<Vec<Vec<SimplexPointer>>>,
+}
+
+impl Jacobian {
+    fn new(fs: &[SimplexPointer], vars: &[SimplexPointer]) -> Jacobian {
+        let symbolic = fs.iter()
+            .map(|f| vars.iter().map(|x| d(f, x)).collect::<Option<Vec<SimplexPointer>>>())
+            .collect::<Option<Vec<Vec<SimplexPointer>>>>();
+
+        Jacobian { symbolic: symbolic }
+    }
+
+    fn at(&self, fs: &[SimplexPointer], vars: &[SimplexPointer], x: &[d128], fx: &[d128]) -> Option<Vec<Vector>> {
+        let bindings = bind(vars, x);
+
+        if let Some(ref rows) = self.symbolic {
+            if let Some(j) = rows.iter().map(|row| residuals(row, &bindings)).collect() {
+                return Some(j);
+            }
+        }
+
+        let mut columns = Vec::new();
+
+        for j in 0..x.len() {
+            let h = real::sqrt(tolerance(MAXIMUM_PRECISION)) * (one() + x[j].abs());
+            let mut shifted = x.to_vec();
+            shifted[j] = x[j] + h;
+
+            let f = residuals(fs, &bind(vars, &shifted))?;
+            columns.push(f.iter().zip(fx.iter()).map(|(&a, &b)| (a - b) / h).collect::<Vector>());
+        }
+
+        Some((0..fs.len()).map(|i| columns.iter().map(|c| c[i]).collect()).collect())
+    }
+}
+
+// Newton's method, each step halved until the residuals shrink.
+pub fn newton(fs: &[SimplexPointer], vars: &[SimplexPointer], start: &[d128], digits: u32) -> Option<Vector> {
+    let jacobian = Jacobian::new(fs, vars);
+    let mut x = start.to_vec();
+    let mut fx = residuals(fs, &bind(vars, &x))?;
+
+    for _ in 0..ITERATIONS {
+        if fx.iter().all(|f| f.is_zero()) {
+            return Some(x);
+        }
+
+        let j = jacobian.at(fs, vars, &x, &fx)?;
+        let step = solve_dense(&j, &fx.iter().map(|&f| -f).collect::<Vector>())?;
+        let mut t = one();
+
+        loop {
+            let next = x.iter().zip(step.iter()).map(|(&a, &s)| a + t * s).collect::<Vector>();
+
+            match residuals(fs, &bind(vars, &next)) {
+                Some(ref f) if largest(f) < largest(&fx) || t < tolerance(8) => {
+                    let taken = step.iter().map(|&s| t * s).collect::<Vector>();
+
+                    x = next;
+                    fx = f.clone();
+
+                    if converged(&taken, &x, digits) {
+                        return Some(x);
+                    }
+
+                    break;
+                }
+                _ if t < tolerance(8) => return None,
+                _ => t = t / d128::from(2),
+            }
+        }
+    }
+
+    None
+}
+
+// The secant method from two starting points in one variable.
+pub fn secant(f: &SimplexPointer, x: &SimplexPointer, a: d128, b: d128, digits: u32) -> Option<d128> {
+    let value = |t: d128| real_value(f, &[(x.clone(), t)]);
+    let (mut x0, mut x1) = (a, b);
+    let (mut f0, mut f1) = (value(x0)?, value(x1)?);
+
+    for _ in 0..ITERATIONS {
+        if f1.is_zero() {
+            return Some(x1);
+        }
+
+        if f1 == f0 {
+            return None;
+        }
+
+        let x2 = x1 - f1 * (x1 - x0) / (f1 - f0);
+
+        if converged(&[x2 - x1], &[x2], digits) {
+            return Some(x2);
+        }
+
+        x0 = x1;
+        f0 = f1;
+        x1 = x2;
+        f1 = value(x1)?;
+    }
+
+    None
+}
+
+// Brent's method, bisection safeguarded by inverse quadratic interpolation
+// and secant steps, inside a bracket where f changes sign.
+pub fn brent(f: &SimplexPointer, x: &SimplexPointer, a: d128, b: d128, digits: u32) -> Option<d128> {
+    let value = |t: d128| real_value(f, &[(x.clone(), t)]);
+    let two = d128::from(2);
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (value(a)?, value(b)?);
+
+    if fa.is_zero() {
+        return Some(a);
+    }
+
+    if fa.is_positive() == fb.is_positive() && !fb.is_zero() {
+        return None;
+    }
+
+    let (mut c, mut fc) = (a, fa);
+    let mut e = b - a;
+    let mut step = e;
+
+    for _ in 0..ITERATIONS {
+        if fb.is_positive() == fc.is_positive() && !fb.is_zero() {
+            c = a;
+            fc = fa;
+            e = b - a;
+            step = e;
+        }
+
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let bound = accuracy(digits) * (one() + b.abs());
+        let middle = (c - b) / two;
+
+        if middle.abs() <= bound || fb.is_zero() {
+            return Some(b);
+        }
+
+        if e.abs() >= bound && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (two * middle * s, one() - s)
+            } else {
+                let (q, r) = (fa / fc, fb / fc);
+                (s * (two * middle * q * (q - r) - (b - a) * (r - one())), (q - one()) * (r - one()) * (s - one()))
+            };
+
+            if p.is_positive() {
+                q = -q;
+            } else {
+                p = -p;
+            }
+
+            let limit = (d128::from(3) * middle * q - (bound * q).abs()).min((e * q).abs());
+
+            if two * p < limit {
+                e = step;
+                step = p / q;
+            } else {
+                step = middle;
+                e = step;
+            }
+        } else {
+            step = middle;
+            e = step;
+        }
+
+        a = b;
+        fa = fb;
+        b = if step.abs() > bound { b + step } else if middle.is_positive() { b + bound } else { b - bound };
+        fb = value(b)?;
+    }
+
+    None
+}
+
+fn horner(c: &[Complex], z: Complex) -> (Complex, Complex) {
+    let mut p = Complex::real(zero());
+    let mut dp = Complex::real(zero());
+
+    for &k in c.iter().rev() {
+        dp = dp * z + p;
+        p = p * z + k;
+    }
+
+    (p, dp)
+}
+
+// Every root of c0 + c1 x + ... at once by the Aberth-Ehrlich iteration,
+// starting from a circle about their centroid.
+fn aberth(c: &[d128], digits: u32) -> Option<Vec<Complex>> {
+    let n = c.len() - 1;
+
+    if n == 0 {
+        return Some(Vec::new());
+    }
+
+    let leading = c[n];
+    let monic = c.iter().map(|&k| Complex::real(k / leading)).collect::<Vec<Complex>>();
+
+    if n == 1 {
+        return Some(vec![-monic[0]]);
+    }
+
+    let centre = -monic[n - 1].re / d128::from(n as i64);
+    let radius = monic[..n].iter().fold(zero(), |m, k| m.max(k.norm())) + one();
+    let mut z = (0..n).map(|k| {
+        let angle = real::pi() * d128::from(2 * k as i64) / d128::from(n as i64) + real::real("0.4");
+        Complex::real(centre) + Complex::polar(radius, angle)
+    }).collect::<Vec<Complex>>();
+
+    for _ in 0..ITERATIONS * 5 {
+        let mut done = true;
+
+        for k in 0..n {
+            let (p, dp) = horner(&monic, z[k]);
+
+            if p.norm().is_zero() {
+                continue;
+            }
+
+            let ratio = p / dp;
+            let repulsion = (0..n).filter(|&j| j != k).fold(Complex::real(zero()), |s, j| s + Complex::real(one()) / (z[k] - z[j]));
+            let offset = ratio / (Complex::real(one()) - ratio * repulsion);
+
+            z[k] = z[k] - offset;
+
+            if offset.norm() > accuracy(digits) * (one() + z[k].norm()) {
+                done = false;
+            }
+        }
+
+        if done {
+            return Some(z);
+        }
+    }
+
+    None
+}
+
+fn to_real(n: &Numeric) -> d128 {
+    n.to_d128()
+}
+
+// A root rounded to the working precision, with a part negligible beside
+// the other dropped.
+fn settle(z: Complex, digits: u32) -> Complex {
+    let negligible = |t: d128| t.abs() <= tolerance(digits + 2) * z.norm();
+    let part = |t: d128| if negligible(t) { zero() } else { real::round(t, digits) };
+
+    Complex::new(part(z.re), part(z.im))
+}
+
+// All roots of the polynomial with the given coefficients, constant term
+// first, rounded and in order of their real and then imaginary parts. Exact
+// coefficients are factored first so that repeated roots come out of
+// square-free factors at full accuracy.
+pub fn polynomial_roots(c: &[Numeric], digits: u32) -> Option<Vec<Complex>> {
+    let factors = if c.iter().all(|n| n.is_exact()) {
+        let (_, factors) = factor_coefficients(c)?;
+        factors.into_iter().map(|(g, m)| (g.iter().map(to_real).collect::<Vector>(), m)).collect::<Vec<(Vector, usize)>>()
+    } else {
+        vec![(c.iter().map(to_real).collect::<Vector>(), 1)]
+    };
+
+    let mut roots = Vec::new();
+
+    for (g, multiplicity) in factors {
+        let zeros = g.iter().take_while(|k| k.is_zero()).count();
+        let found = aberth(&g[zeros..], digits)?;
+
+        for _ in 0..multiplicity {
+            roots.extend((0..zeros).map(|_| Complex::real(zero())));
+            roots.extend(found.iter().map(|&z| settle(z, digits)));
+        }
+    }
+
+    roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap_or(Ordering::Equal).then(a.im.partial_cmp(&b.im).unwrap_or(Ordering::Equal)));
+    Some(roots)
+}
@@ -0,0 +1,238 @@
+#[cfg(test)]
+mod tests {
+    mod test_find_root {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_finds_roots_by_newtons_method() {
+            assert_eq!(run("FindRoot[x^2 == 2, {x, 1}]"), "List[Rule[x, 1.414213562373095]]");
+            assert_eq!(run("FindRoot[Cos[x] == x, {x, 0}]"), "List[Rule[x, 0.7390851332151606]]");
+            assert_eq!(run("FindRoot[Sin[x], {x, 3}]"), "List[Rule[x, 3.141592653589793]]");
+        }
+
+        #[test]
+        fn it_finds_roots_from_two_starting_points() {
+            assert_eq!(run("FindRoot[x^3 - 2 x - 5, {x, 2, 3}]"), "List[Rule[x, 2.094551481542327]]");
+            assert_eq!(run("FindRoot[x^3 - 2 x - 5, {x, 2}, Method -> \"Secant\"]"), "List[Rule[x, 2.094551481542327]]");
+            assert_eq!(run("FindRoot[Exp[x] - 2, {x, 0, 1}, Method -> \"Brent\"]"), "List[Rule[x, 0.6931471805599453]]");
+        }
+
+        #[test]
+        fn it_solves_systems() {
+            assert_eq!(run("FindRoot[{x^2 + y^2 == 4, x y == 1}, {{x, 2}, {y, 0.5}}]"),
+                       "List[Rule[x, 1.931851652578137], Rule[y, 0.5176380902050415]]");
+        }
+
+        #[test]
+        fn it_works_at_higher_precision() {
+            assert_eq!(run("FindRoot[Sin[x], {x, 3}, WorkingPrecision -> 30]"), "List[Rule[x, 3.14159265358979323846264338328]]");
+            assert_eq!(run("FindRoot[f[x], {x, 1}]"), "FindRoot[f[x], List[x, 1]]");
+        }
+    }
+
+    mod test_n_solve {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_finds_real_roots() {
+            assert_eq!(run("NSolve[x^2 - 2 == 0, x]"), "List[List[Rule[x, -1.414213562373095]], List[Rule[x, 1.414213562373095]]]");
+            assert_eq!(run("NSolve[x^3 == x, x]"), "List[List[Rule[x, -1]], List[Rule[x, 0]], List[Rule[x, 1]]]");
+            assert_eq!(run("NSolve[(x - 1)^2 (x + 2) == 0, x]"), "List[List[Rule[x, -2]], List[Rule[x, 1]], List[Rule[x, 1]]]");
+            assert_eq!(run("NSolve[1.5 x^2 - 3 x + 0.5 == 0, x]"), "List[List[Rule[x, 0.183503419072274]], List[Rule[x, 1.816496580927726]]]");
+        }
+
+        #[test]
+        fn it_finds_complex_roots() {
+            assert_eq!(run("NSolve[x^2 + 1 == 0, x]"), "List[List[Rule[x, Plus[0, Times[-1, I]]]], List[Rule[x, Plus[0, Times[1, I]]]]]");
+            assert_eq!(run("NSolve[x^5 + x + 3 == 0, x][[1]]"), "List[Rule[x, -1.132997565885065]]");
+            assert_eq!(run("NSolve[x^5 + x + 3 == 0, x][[5]]"), "List[Rule[x, Plus[1.041879539612082, Times[0.8228703381099578, I]]]]");
+        }
+
+        #[test]
+        fn it_works_at_higher_precision() {
+            assert_eq!(run("NSolve[x^2 - Pi == 0, x, WorkingPrecision -> 20]"),
+                       "List[List[Rule[x, -1.7724538509055160273]], List[Rule[x, 1.7724538509055160273]]]");
+        }
+    }
+
+    mod test_find_minimum {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_minimizes_with_gradients() {
+            assert_eq!(run("FindMinimum[x^2 - 4 x + 1, {x, 0}]"), "List[-3, List[Rule[x, 2]]]");
+            assert_eq!(run("FindMinimum[Cos[x], {x, 3}]"), "List[-1, List[Rule[x, 3.141592653589793]]]");
+            assert_eq!(run("FindMinimum[(x - 1)^2 + 100 (y - x^2)^2, {{x, -1}, {y, 1}}]"), "List[0.0000, List[Rule[x, 1], Rule[y, 1]]]");
+        }
+
+        #[test]
+        fn it_minimizes_without_derivatives() {
+            assert_eq!(run("FindMinimum[(x - 1)^2 + 100 (y - x^2)^2, {{x, -1}, {y, 1}}, Method -> \"NelderMead\", WorkingPrecision -> 8][[2]]"),
+                       "List[Rule[x, 1], Rule[y, 1]]");
+        }
+
+        #[test]
+        fn it_maximizes() {
+            assert_eq!(run("FindMaximum[Sin[x], {x, 1}]"), "List[1, List[Rule[x, 1.570796326794897]]]");
+            assert_eq!(run("FindMaximum[x Exp[-x], {x, 0.5}]"), "List[0.3678794411714423, List[Rule[x, 1]]]");
+        }
+    }
+
+    mod test_n_integrate {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_integrates_over_finite_ranges() {
+            assert_eq!(run("NIntegrate[x^2, {x, 0, 1}]"), "0.3333333333333333");
+            assert_eq!(run("NIntegrate[Sin[x], {x, 0, Pi}]"), "2");
+            assert_eq!(run("NIntegrate[1/(1 + x^2), {x, 1, 0}]"), "-0.7853981633974483");
+            assert_eq!(run("NIntegrate[f[x], {x, 0, 1}]"), "NIntegrate[f[x], List[x, 0, 1]]");
+        }
+
+        #[test]
+        fn it_integrates_singularities_at_the_ends() {
+            assert_eq!(run("NIntegrate[1/Sqrt[x], {x, 0, 1}, WorkingPrecision -> 8]"), "2");
+            assert_eq!(run("NIntegrate[Log[x], {x, 0, 1}, WorkingPrecision -> 8]"), "-1");
+        }
+
+        // Slow: run with cargo test --release -- --ignored
+        #[test]
+        #[ignore]
+        fn it_integrates_singularities_at_the_ends_to_machine_precision() {
+            assert_eq!(run("NIntegrate[1/Sqrt[x], {x, 0, 1}]"), "2");
+            assert_eq!(run("NIntegrate[Log[x], {x, 0, 1}]"), "-1");
+        }
+
+        #[test]
+        fn it_integrates_over_infinite_ranges() {
+            assert_eq!(run("NIntegrate[Exp[-x^2], {x, -Infinity, Infinity}]"), "1.772453850905516");
+            assert_eq!(run("NIntegrate[1/x^2, {x, 1, Infinity}]"), "1");
+            assert_eq!(run("NIntegrate[Exp[x], {x, -Infinity, 0}]"), "1");
+        }
+
+        #[test]
+        fn it_integrates_over_several_variables() {
+            assert_eq!(run("NIntegrate[x y, {x, 0, 1}, {y, 0, x}]"), "0.125");
+        }
+
+        #[test]
+        fn it_works_at_higher_precision() {
+            assert_eq!(run("NIntegrate[Exp[-x^2], {x, 0, 1}, WorkingPrecision -> 30]"), "0.746824132812427025399467436132");
+        }
+    }
+
+    mod test_nd_solve {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_gives_interpolating_functions() {
+            assert_eq!(run("NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 1]]"), "y");
+            assert_eq!(run("Head[NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]]]"), "InterpolatingFunction");
+        }
+
+        #[test]
+        fn it_solves_initial_value_problems() {
+            assert_eq!(run("NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]][1]"), "2.718281828270904");
+            assert_eq!(run("NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]][0.5]"), "1.648721263491227");
+            assert_eq!(run("NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}, PrecisionGoal -> 12][[1, 1, 2]][1]"), "2.718281828459027");
+            assert_eq!(run("NDSolve[{y'[x] == -2 x y[x], y[1] == 1}, y, {x, 0, 2}][[1, 1, 2]][0]"), "2.718281828737398");
+        }
+
+        #[test]
+        fn it_solves_systems_and_higher_orders() {
+            assert_eq!(run("NDSolve[{y'[x] == z[x], z'[x] == -y[x], y[0] == 0, z[0] == 1}, {y, z}, {x, 0, 2}][[1, 2, 2]][1]"), "0.540302304766088");
+            assert_eq!(run("NDSolve[{y''[x] == -y[x], y[0] == 0, y'[0] == 1}, y, {x, 0, 2}][[1, 1, 2]][Pi/2]"), "0.9999999993117377");
+        }
+
+        #[test]
+        fn it_differentiates_the_solution() {
+            assert_eq!(run("Derivative[1][NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]]][0.5]"), "1.64872180504022");
+            assert_eq!(run("D[NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]][t], t][[0]][0.5]"), "1.64872180504022");
+            assert_eq!(run("D[NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]][t], {t, 2}][[0]][0.5]"), "1.648789807184317");
+            assert_eq!(run("D[NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]][t^2], t][[1]]"), "2");
+            assert_eq!(run("Derivative[4][NDSolve[{y'[x] == y[x], y[0] == 1}, y, {x, 0, 1}][[1, 1, 2]]][0.5]"), "0");
+        }
+
+        #[test]
+        fn it_solves_stiff_problems() {
+            assert_eq!(run("NDSolve[{y'[x] == -1000 (y[x] - Cos[x]), y[0] == 0}, y, {x, 0, 1}, Method -> \"BDF\", PrecisionGoal -> 4][[1, 1, 2]][1]"),
+                       "0.5411432589516685");
+        }
+
+        // Slow: run with cargo test --release -- --ignored
+        #[test]
+        #[ignore]
+        fn it_solves_stiff_problems_to_the_default_goal() {
+            assert_eq!(run("NDSolve[{y'[x] == -1000 (y[x] - Cos[x]), y[0] == 0}, y, {x, 0, 1}, Method -> \"BDF\"][[1, 1, 2]][1]"), "0.5411432358356701");
+        }
+    }
+
+    mod test_constants {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_keeps_constants_exact() {
+            assert_eq!(run("2 Pi"), "Times[2, Pi]");
+            assert_eq!(run("Sin[30 Degree]"), "Rational[1, 2]");
+        }
+
+        #[test]
+        fn it_approximates_constants_to_any_precision() {
+            assert_eq!(run("N[Pi]"), "3.141592653589793");
+            assert_eq!(run("N[Pi, 34]"), "3.141592653589793238462643383279503");
+            assert_eq!(run("N[E, 34]"), "2.718281828459045235360287471352662");
+            assert_eq!(run("N[EulerGamma, 34]"), "0.5772156649015328606065120900824024");
+            assert_eq!(run("N[GoldenRatio, 20]"), "1.6180339887498948482");
+            assert_eq!(run("N[Degree, 5]"), "0.017453");
+            assert_eq!(run("N[Pi, 50]"), "3.1415926535897932384626433832795028841971693993751");
+            assert_eq!(run("N[E, 40]"), "2.718281828459045235360287471352662497757");
+        }
+
+        #[test]
+        fn it_approximates_expressions() {
+            assert_eq!(run("N[1/3]"), "0.3333333333333333");
+            assert_eq!(run("N[Sin[1], 20]"), "0.84147098480789650665");
+            assert_eq!(run("N[x^2 + Pi x]"), "Plus[Times[3.141592653589793, x], Power[x, 2]]");
+            assert_eq!(run("N[Sqrt[2], 40]"), "1.414213562373095048801688724209698078570");
+            assert_eq!(run("N[Sin[1], 40]"), "0.8414709848078965066525023216302989996226");
+            assert_eq!(run("N[Log[2, 3], 40]"), "1.584962500721156181453738943947816508760");
+        }
+
+        #[test]
+        fn it_works_past_cancellation() {
+            assert_eq!(run("N[Sin[Pi - 10^-25], 20]"), "1.0000000000000000000E-25");
+            assert_eq!(run("N[Exp[Pi Sqrt[163]], 34]"), "262537412640768743.9999999999992501");
+            assert_eq!(run("N[Pi - 314159265358979323846264338327950288419716939937510/10^50, 20]"),
+                       "5.8209749445923078164E-51");
+        }
+
+        #[test]
+        fn it_gives_every_machine_result_the_same_digits() {
+            assert_eq!(run("N[Pi]"), "3.141592653589793");
+            assert_eq!(run("Sin[2.]"), "0.9092974268256817");
+            assert_eq!(run("Gamma[0.5]"), "1.772453850905516");
+            assert_eq!(run("1/3."), "0.3333333333333333");
+            assert_eq!(run("N[Pi, 30]"), "3.14159265358979323846264338328");
+        }
+
+        #[test]
+        fn it_tracks_precision() {
+            assert_eq!(run("Head[N[Pi, 50]]"), "Real");
+            assert_eq!(run("N[Pi, 50]^2"), "9.8696044010893586188344909998761511353136994072408");
+            assert_eq!(run("N[Pi, 50] - N[Pi, 40]"), "0E-39");
+            assert_eq!(run("Sin[N[Pi, 50]]"), "0E-49");
+            assert_eq!(run("N[Pi, 50] + 1.5"), "4.641592653589793");
+            assert_eq!(run("Floor[N[Pi, 50]]"), "3");
+        }
+
+        #[test]
+        fn it_compares_numeric_quantities() {
+            assert_eq!(run("Pi > 3"), "True");
+            assert_eq!(run("E < Pi < GoldenRatio"), "False");
+            assert_eq!(run("Pi == 3"), "False");
+            assert_eq!(run("Sqrt[2] > 1"), "True");
+            assert_eq!(run("x > Pi"), "Greater[x, Pi]");
+            assert_eq!(run("Limit[-E x, x -> Infinity]"), "Times[-1, Infinity]");
+        }
+    }
+}
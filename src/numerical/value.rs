@@ -0,0 +1,161 @@
+use decimal::d128;
+use num::Zero;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+use expression::atom::numbers::big_real::BigReal;
+
+use evaluation::dispatch::evaluate;
+use evaluation::utilities::is_head;
+use arithmetic::terms::number;
+use numerical::real::{self, one, zero, MACHINE_PRECISION};
+use numerical::constants::{constant, CONSTANTS};
+use numerical::precise::{self, GUARD};
+
+pub type Bindings = [(SimplexPointer, d128)];
+
+// b^n over the reals: integer powers by repeated squaring so negative bases
+// keep working, every other power only of a positive base.
+fn power(b: d128, n: d128) -> Option<d128> {
+    if n.is_integer() && n.abs() <= d128::from(1 << 20) {
+        let k: i32 = n.into();
+        let mut result = one();
+        let mut square = b;
+        let mut remaining = k.abs();
+
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                result = result * square;
+            }
+
+            square = square * square;
+            remaining = remaining / 2;
+        }
+
+        return Some(if k < 0 { one() / result } else { result });
+    }
+
+    if b.is_positive() {
+        Some(b.pow(n))
+    } else if b.is_zero() && n.is_positive() {
+        Some(zero())
+    } else {
+        None
+    }
+}
+
+// The value of e as a real number with the given symbols bound, None for
+// anything that isn't one.
+pub fn real_value(e: &SimplexPointer, bindings: &Bindings) -> Option<d128> {
+    if let Some(n) = e.as_numeric() {
+        let x = n.to_d128();
+        return if x.is_finite() { Some(x) } else { None };
+    }
+
+    if let Some(&(_, x)) = bindings.iter().find(|&&(ref s, _)| s == e) {
+        return Some(x);
+    }
+
+    if e.is_atomic() {
+        return constant(&e.as_str());
+    }
+
+    let parts = e.get_parts();
+    let values = parts.iter().map(|p| real_value(p, bindings)).collect::<Option<Vec<d128>>>()?;
+
+    let result = if is_head(e, "Plus") {
+        values.iter().fold(zero(), |total, &x| total + x)
+    } else if is_head(e, "Times") {
+        values.iter().fold(one(), |total, &x| total * x)
+    } else if is_head(e, "Power") && values.len() == 2 {
+        power(values[0], values[1])?
+    } else if is_head(e, "Log") && values.len() == 2 {
+        real::function("Log", values[1])? / real::function("Log", values[0])?
+    } else if values.len() == 1 {
+        real::function(&e.head_name(), values[0])?
+    } else {
+        return None;
+    };
+
+    if result.is_finite() { Some(result) } else { None }
+}
+
+// How many more digits than asked for N works with before settling for
+// fewer good ones.
+const EXTRA_PRECISION: u32 = 50;
+
+// Exact numbers and constants in e as reals of the given precision,
+// integer exponents excepted.
+fn inexact(e: &SimplexPointer, precision: u32) -> SimplexPointer {
+    if let Some(n) = e.as_numeric() {
+        return match n.to_big_rational() {
+            Some(ref q) if !q.is_zero() => number(Numeric::BigReal(BigReal::with_precision(q, precision))),
+            _ => e.clone(),
+        };
+    }
+
+    if e.is_atomic() {
+        return match CONSTANTS.iter().find(|&&c| c == e.as_str()) {
+            Some(c) => precise::arbitrary_constant(c, precision).map_or(e.clone(), |x| number(Numeric::BigReal(x))),
+            None => e.clone(),
+        };
+    }
+
+    let parts = e.get_parts().iter().enumerate()
+        .map(|(i, p)| if is_head(e, "Power") && i == 1 && p.as_integer().is_some() { p.clone() } else { inexact(p, precision) })
+        .collect::<Vec<SimplexPointer>>();
+
+    e.with_parts(&parts)
+}
+
+// The fewest digits any BigReal in e has.
+fn least_precision(e: &SimplexPointer) -> Option<u32> {
+    match e.as_numeric() {
+        Some(Numeric::BigReal(x)) => Some(x.precision()),
+        Some(_) => None,
+        None if e.is_atomic() => None,
+        None => e.get_parts().iter().filter_map(least_precision).min(),
+    }
+}
+
+// Reals to the digits asked for, or to the digits they have when that is
+// fewer; machine reals up to machine precision.
+fn rounded(e: &SimplexPointer, digits: u32) -> SimplexPointer {
+    match e.as_numeric() {
+        Some(Numeric::LittleReal(x)) => real::to_expression(x, digits),
+        Some(Numeric::BigReal(x)) => {
+            let x = x.round(digits);
+            let y = x.to_d128();
+
+            if digits > MACHINE_PRECISION || !y.is_finite() {
+                number(Numeric::BigReal(x))
+            } else if x.is_zero() {
+                number(Numeric::LittleReal(y))
+            } else {
+                real::to_expression(y, x.precision())
+            }
+        }
+        Some(_) => e.clone(),
+        None if e.is_atomic() => e.clone(),
+        None => e.with_parts(&e.get_parts().iter().map(|p| rounded(p, digits)).collect::<Vec<SimplexPointer>>()),
+    }
+}
+
+// N[e, digits]: e evaluated with every exact number in it a real, with
+// more digits each time cancellation leaves the result short of the ones
+// asked for, and every real in the result then rounded to them.
+pub fn approximate(e: &SimplexPointer, digits: u32) -> SimplexPointer {
+    let mut working = digits + GUARD;
+
+    loop {
+        let result = evaluate(&inexact(e, working));
+
+        match least_precision(&result) {
+            Some(p) if p < digits + 2 && working < digits + EXTRA_PRECISION => {
+                working = working + digits + GUARD - p.min(digits);
+            }
+            _ => return rounded(&result, digits),
+        }
+    }
+}
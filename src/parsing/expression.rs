@@ -0,0 +1,485 @@
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+use expression::atom::structure::SimplexAtom;
+use expression::atom::numbers::number::Numeric;
+
+// Longest operators first so that `->` is never read as `-` then `>`.
+const OPERATORS: &'static [&'static str] = &[
+    "[[", "<|", "|>", "->", ":>", "==", "!=", "<=", ">=", "&&", "||",
+    ".", "+", "-", "*", "/", "^", "(", ")", "[", "]", "{", "}", ",", "'", "<", ">",
+];
+
+const RELATIONS: &'static [(&'static str, &'static str)] = &[
+    ("==", "Equal"), ("!=", "Unequal"), ("<=", "LessEqual"), (">=", "GreaterEqual"), ("<", "Less"), (">", "Greater"),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Token {
+    Number(String),
+    Text(String),
+    Identifier(String),
+    // name_head, either side optional as in x_, _Integer or x_Integer.
+    Blank(Option<String>, Option<String>),
+    Operator(&'static str),
+}
+
+fn identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '$'
+}
+
+fn identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '$'
+}
+
+fn take_while<F>(chars: &[char], from: usize, predicate: F) -> usize
+    where F: Fn(char) -> bool
+{
+    let mut end = from;
+
+    while end < chars.len() && predicate(chars[end]) {
+        end = end + 1;
+    }
+
+    end
+}
+
+fn at_text(chars: &[char], from: usize, text: &str) -> bool {
+    text.chars().enumerate().all(|(k, c)| chars.get(from + k) == Some(&c))
+}
+
+fn at_digit(chars: &[char], from: usize) -> bool {
+    chars.get(from).map_or(false, |c| c.is_digit(10))
+}
+
+// Where a numeral starting at from ends: digits with an optional point, in
+// a base as in 16^^FF, then a precision mark as in 1.5`30 or 1.5``10 and an
+// exponent as in 1.5*^10.
+fn numeral_end(chars: &[char], from: usize) -> usize {
+    let mut end = take_while(chars, from, |c| c.is_digit(10));
+
+    if at_text(chars, end, "^^") && chars.get(end + 2).map_or(false, |c| c.is_alphanumeric() || *c == '.') {
+        end = take_while(chars, end + 2, |c| c.is_alphanumeric());
+
+        if at_text(chars, end, ".") {
+            end = take_while(chars, end + 1, |c| c.is_alphanumeric());
+        }
+    } else if at_text(chars, end, ".") {
+        end = take_while(chars, end + 1, |c| c.is_digit(10));
+    }
+
+    if at_text(chars, end, "`") {
+        end = take_while(chars, end + 1, |c| c == '`');
+
+        if at_text(chars, end, "-") && at_digit(chars, end + 1) {
+            end = end + 1;
+        }
+
+        end = take_while(chars, end, |c| c.is_digit(10));
+
+        if at_text(chars, end, ".") && at_digit(chars, end + 1) {
+            end = take_while(chars, end + 1, |c| c.is_digit(10));
+        }
+    }
+
+    if at_text(chars, end, "*^") {
+        let sign = if at_text(chars, end + 2, "-") || at_text(chars, end + 2, "+") { 1 } else { 0 };
+
+        if at_digit(chars, end + 2 + sign) {
+            end = take_while(chars, end + 2 + sign, |c| c.is_digit(10));
+        }
+    }
+
+    end
+}
+
+pub fn tokenize(s: &str) -> Result<Vec<Token>, String> {
+    let chars = s.chars().collect::<Vec<char>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i = i + 1;
+            continue;
+        }
+
+        if c.is_digit(10) || (c == '.' && i + 1 < chars.len() && chars[i + 1].is_digit(10)) {
+            let end = numeral_end(&chars, i);
+            tokens.push(Token::Number(chars[i..end].iter().collect()));
+            i = end;
+            continue;
+        }
+
+        if c == '"' {
+            let end = take_while(&chars, i + 1, |c| c != '"');
+
+            if end == chars.len() {
+                return Err(format!("unterminated string starting at {}", i));
+            }
+
+            // Strings keep their quotes, the way string atoms print.
+            tokens.push(Token::Text(chars[i..end + 1].iter().collect()));
+            i = end + 1;
+            continue;
+        }
+
+        if identifier_start(c) || c == '_' {
+            let end = take_while(&chars, i, identifier_char);
+            let name = chars[i..end].iter().collect::<String>();
+
+            if end < chars.len() && chars[end] == '_' {
+                let head_end = take_while(&chars, end + 1, identifier_char);
+                let head = chars[end + 1..head_end].iter().collect::<String>();
+
+                tokens.push(Token::Blank(if name.is_empty() { None } else { Some(name) },
+                                         if head.is_empty() { None } else { Some(head) }));
+                i = head_end;
+            } else {
+                tokens.push(Token::Identifier(name));
+                i = end;
+            }
+
+            continue;
+        }
+
+        let rest = chars[i..].iter().collect::<String>();
+
+        match OPERATORS.iter().find(|o| rest.starts_with(*o)) {
+            Some(o) => {
+                tokens.push(Token::Operator(o));
+                i = i + o.chars().count();
+            }
+            None => return Err(format!("unexpected character '{}' at {}", c, i)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn expression(head_name: &str, parts: &[SimplexPointer]) -> SimplexPointer {
+    SimplexPointer::from(SimplexList::new(head_name).push_all(parts))
+}
+
+fn symbol(s: &str) -> SimplexPointer {
+    SimplexPointer::from(SimplexAtom::SimplexSymbol(s.to_string()))
+}
+
+fn minus_one() -> SimplexPointer {
+    SimplexPointer::from(SimplexAtom::from(-1))
+}
+
+// -3 stays a number, -x is Times[-1, x] and -x y is Times[-1, x, y].
+fn negate(e: &SimplexPointer) -> SimplexPointer {
+    match e.as_numeric() {
+        Some(n) => SimplexPointer::from(SimplexAtom::SimplexNumeric(n.negate())),
+        None => {
+            if !e.is_atomic() && e.head_name() == "Times" {
+                let mut parts = vec![minus_one()];
+                parts.extend(e.get_parts());
+                expression("Times", &parts)
+            } else {
+                expression("Times", &[minus_one(), e.clone()])
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn at(&self, operator: &str) -> bool {
+        match self.peek() {
+            Some(&Token::Operator(o)) => o == operator,
+            _ => false,
+        }
+    }
+
+    fn accept(&mut self, operator: &str) -> bool {
+        if self.at(operator) {
+            self.position = self.position + 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, operator: &str) -> Result<(), String> {
+        if self.accept(operator) {
+            Ok(())
+        } else {
+            Err(format!("expected '{}' at token {}, found {:?}", operator, self.position, self.peek()))
+        }
+    }
+
+    // Whether the next token can begin an operand, which makes `2 x` a product.
+    fn at_operand(&self) -> bool {
+        match self.peek() {
+            Some(&Token::Operator(o)) => o == "(" || o == "{" || o == "<|",
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<SimplexPointer, String> {
+        self.parse_rule()
+    }
+
+    fn parse_rule(&mut self) -> Result<SimplexPointer, String> {
+        let lhs = self.parse_or()?;
+
+        for &(operator, head) in [("->", "Rule"), (":>", "RuleDelayed")].iter() {
+            if self.accept(operator) {
+                let rhs = self.parse_rule()?;
+                return Ok(expression(head, &[lhs, rhs]));
+            }
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_or(&mut self) -> Result<SimplexPointer, String> {
+        let mut operands = vec![self.parse_and()?];
+
+        while self.accept("||") {
+            operands.push(self.parse_and()?);
+        }
+
+        Ok(if operands.len() == 1 { operands.pop().unwrap() } else { expression("Or", &operands) })
+    }
+
+    fn parse_and(&mut self) -> Result<SimplexPointer, String> {
+        let mut operands = vec![self.parse_relation()?];
+
+        while self.accept("&&") {
+            operands.push(self.parse_relation()?);
+        }
+
+        Ok(if operands.len() == 1 { operands.pop().unwrap() } else { expression("And", &operands) })
+    }
+
+    fn accept_relation(&mut self) -> Option<&'static str> {
+        RELATIONS.iter().find(|&&(operator, _)| self.accept(operator)).map(|&(_, head)| head)
+    }
+
+    // a == b == c is Equal[a, b, c]; a chain mixing relations, as in
+    // a < b <= c, is the And of each neighbouring pair.
+    fn parse_relation(&mut self) -> Result<SimplexPointer, String> {
+        let mut operands = vec![self.parse_additive()?];
+        let mut heads = Vec::new();
+
+        while let Some(head) = self.accept_relation() {
+            heads.push(head);
+            operands.push(self.parse_additive()?);
+        }
+
+        if heads.is_empty() {
+            return Ok(operands.pop().unwrap());
+        }
+
+        if heads.iter().all(|&h| h == heads[0]) {
+            return Ok(expression(heads[0], &operands));
+        }
+
+        let pairs = heads.iter()
+            .enumerate()
+            .map(|(i, &h)| expression(h, &[operands[i].clone(), operands[i + 1].clone()]))
+            .collect::<Vec<SimplexPointer>>();
+
+        Ok(expression("And", &pairs))
+    }
+
+    fn parse_additive(&mut self) -> Result<SimplexPointer, String> {
+        let mut terms = vec![self.parse_multiplicative()?];
+
+        loop {
+            if self.accept("+") {
+                terms.push(self.parse_multiplicative()?);
+            } else if self.accept("-") {
+                let term = self.parse_multiplicative()?;
+                terms.push(negate(&term));
+            } else {
+                break;
+            }
+        }
+
+        Ok(if terms.len() == 1 { terms.pop().unwrap() } else { expression("Plus", &terms) })
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<SimplexPointer, String> {
+        if self.accept("-") {
+            let operand = self.parse_multiplicative()?;
+            return Ok(negate(&operand));
+        }
+
+        if self.accept("+") {
+            return self.parse_multiplicative();
+        }
+
+        let mut factors = vec![self.parse_unary()?];
+
+        loop {
+            if self.accept("*") {
+                factors.push(self.parse_unary()?);
+            } else if self.accept("/") {
+                let divisor = self.parse_unary()?;
+                factors.push(expression("Power", &[divisor, minus_one()]));
+            } else if self.at_operand() {
+                factors.push(self.parse_unary()?);
+            } else {
+                break;
+            }
+        }
+
+        Ok(if factors.len() == 1 { factors.pop().unwrap() } else { expression("Times", &factors) })
+    }
+
+    fn parse_unary(&mut self) -> Result<SimplexPointer, String> {
+        if self.accept("-") {
+            let operand = self.parse_unary()?;
+            Ok(negate(&operand))
+        } else {
+            self.parse_dot()
+        }
+    }
+
+    // Between products and powers, so a.b^2 is a.(b^2) and -a.b is -(a.b).
+    fn parse_dot(&mut self) -> Result<SimplexPointer, String> {
+        let mut factors = vec![self.parse_power()?];
+
+        while self.accept(".") {
+            factors.push(self.parse_power()?);
+        }
+
+        Ok(if factors.len() == 1 { factors.pop().unwrap() } else { expression("Dot", &factors) })
+    }
+
+    // Right associative, and binding tighter than a leading minus: -x^2 is
+    // -(x^2) and 2^3^2 is 2^(3^2).
+    fn parse_power(&mut self) -> Result<SimplexPointer, String> {
+        let base = self.parse_postfix()?;
+
+        if self.accept("^") {
+            let exponent = self.parse_unary()?;
+            Ok(expression("Power", &[base, exponent]))
+        } else {
+            Ok(base)
+        }
+    }
+
+    // f[ ... ], e[[ ... ]] and f' bind tighter than anything else.
+    fn parse_postfix(&mut self) -> Result<SimplexPointer, String> {
+        let mut e = self.parse_primary()?;
+
+        loop {
+            if self.at("'") {
+                let mut n = 0;
+
+                while self.accept("'") {
+                    n = n + 1;
+                }
+
+                let derivative = expression("Derivative", &[SimplexPointer::from(SimplexAtom::from(n))]);
+                e = SimplexPointer::from(SimplexList::with_head(&derivative).push_all(&[e]));
+            } else if self.accept("[[") {
+                let mut parts = vec![e];
+                parts.extend(self.parse_sequence("]")?);
+                self.expect("]")?;
+                self.expect("]")?;
+                e = expression("Part", &parts);
+            } else if self.accept("[") {
+                let arguments = self.parse_sequence("]")?;
+                self.expect("]")?;
+                e = SimplexPointer::from(SimplexList::with_head(&e).push_all(&arguments));
+            } else {
+                return Ok(e);
+            }
+        }
+    }
+
+    // Comma separated expressions up to, but not including, the closer.
+    fn parse_sequence(&mut self, closer: &str) -> Result<Vec<SimplexPointer>, String> {
+        let mut elements = Vec::new();
+
+        if self.at(closer) {
+            return Ok(elements);
+        }
+
+        loop {
+            elements.push(self.parse_expression()?);
+
+            if !self.accept(",") {
+                return Ok(elements);
+            }
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<SimplexPointer, String> {
+        let token = match self.peek() {
+            Some(t) => t.clone(),
+            None => return Err("unexpected end of input".to_string()),
+        };
+
+        self.position = self.position + 1;
+
+        match token {
+            Token::Number(n) => match Numeric::from(n.as_str()) {
+                Numeric::NaN => Err(format!("malformed number {} at token {}", n, self.position - 1)),
+                x => Ok(SimplexPointer::from(SimplexAtom::SimplexNumeric(x))),
+            },
+            Token::Text(s) => Ok(SimplexPointer::from(SimplexAtom::SimplexString(s))),
+            Token::Identifier(s) => Ok(symbol(&s)),
+            Token::Blank(name, head) => {
+                let blank = match head {
+                    Some(h) => expression("Blank", &[symbol(&h)]),
+                    None => expression("Blank", &[]),
+                };
+
+                Ok(match name {
+                    Some(n) => expression("Pattern", &[symbol(&n), blank]),
+                    None => blank,
+                })
+            }
+            Token::Operator("(") => {
+                let e = self.parse_expression()?;
+                self.expect(")")?;
+                Ok(e)
+            }
+            Token::Operator("{") => {
+                let elements = self.parse_sequence("}")?;
+                self.expect("}")?;
+                Ok(expression("List", &elements))
+            }
+            Token::Operator("<|") => {
+                let elements = self.parse_sequence("|>")?;
+                self.expect("|>")?;
+                Ok(expression("Association", &elements))
+            }
+            Token::Operator(o) => Err(format!("unexpected '{}' at token {}", o, self.position - 1)),
+        }
+    }
+}
+
+// Reads InputForm text such as `f[x, {1, 2}] + 3 y` into its FullForm
+// expression, left unevaluated.
+pub fn parse(s: &str) -> Result<SimplexPointer, String> {
+    let mut parser = Parser {
+        tokens: tokenize(s)?,
+        position: 0,
+    };
+
+    let e = parser.parse_expression()?;
+
+    match parser.peek() {
+        None => Ok(e),
+        Some(t) => Err(format!("unexpected {:?} at token {}", t, parser.position)),
+    }
+}
@@ -1 +1,3 @@
 pub mod utilities;
+pub mod expression;
+pub mod test;
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    mod test_expression {
+        use expression::traits::BaseExpression;
+        use parsing::expression::parse;
+
+        fn full_form(s: &str) -> String {
+            parse(s).unwrap().as_str().into_owned()
+        }
+
+        #[test]
+        fn it_parses_applications() {
+            assert_eq!(full_form("f[x, g[y], {1, 2.5}]"), "f[x, g[y], List[1, 2.5]]");
+            assert_eq!(full_form("f[]"), "f[]");
+            assert_eq!(full_form("f[a][b]"), "f[a][b]");
+        }
+
+        #[test]
+        fn it_parses_arithmetic() {
+            assert_eq!(full_form("a + b + c"), "Plus[a, b, c]");
+            assert_eq!(full_form("a - b"), "Plus[a, Times[-1, b]]");
+            assert_eq!(full_form("a - 3"), "Plus[a, -3]");
+            assert_eq!(full_form("a/b"), "Times[a, Power[b, -1]]");
+            assert_eq!(full_form("-a b"), "Times[-1, a, b]");
+            assert_eq!(full_form("2 (x + 1)"), "Times[2, Plus[x, 1]]");
+        }
+
+        #[test]
+        fn it_parses_rules_and_parts() {
+            assert_eq!(full_form("a -> b -> c"), "Rule[a, Rule[b, c]]");
+            assert_eq!(full_form("x :> f[x]"), "RuleDelayed[x, f[x]]");
+            assert_eq!(full_form("l[[1, 2]]"), "Part[l, 1, 2]");
+            assert_eq!(full_form("f[g[l[[1]]]]"), "f[g[Part[l, 1]]]");
+        }
+
+        #[test]
+        fn it_parses_patterns_and_strings() {
+            assert_eq!(full_form("f[x_, _Integer, y_Real]"),
+                       "f[Pattern[x, Blank[]], Blank[Integer], Pattern[y, Blank[Real]]]");
+            assert_eq!(full_form("\"a b\" -> 1"), "Rule[\"a b\", 1]");
+        }
+
+        #[test]
+        fn it_parses_associations() {
+            assert_eq!(full_form("<|a -> 1, \"b\" -> 2|>"), "Association[Rule[a, 1], Rule[\"b\", 2]]");
+            assert_eq!(full_form("<||>"), "Association[]");
+            assert_eq!(full_form("<|a -> 1|>[a]"), "Association[Rule[a, 1]][a]");
+        }
+
+        #[test]
+        fn it_parses_relations_and_logic() {
+            assert_eq!(full_form("x + 1 == 2 y"), "Equal[Plus[x, 1], Times[2, y]]");
+            assert_eq!(full_form("a == b == c"), "Equal[a, b, c]");
+            assert_eq!(full_form("a != b"), "Unequal[a, b]");
+            assert_eq!(full_form("0 < x <= 1"), "And[Less[0, x], LessEqual[x, 1]]");
+            assert_eq!(full_form("x > 1 && y >= 2 || z"), "Or[And[Greater[x, 1], GreaterEqual[y, 2]], z]");
+            assert_eq!(full_form("x -> a == b"), "Rule[x, Equal[a, b]]");
+        }
+
+        #[test]
+        fn it_parses_primes_as_derivatives() {
+            assert_eq!(full_form("f'[x]"), "Derivative[1][f][x]");
+            assert_eq!(full_form("f'''[x]"), "Derivative[3][f][x]");
+            assert_eq!(full_form("2 f'[x]^2"), "Times[2, Power[Derivative[1][f][x], 2]]");
+        }
+
+        #[test]
+        fn it_parses_dots() {
+            assert_eq!(full_form("a.b.c"), "Dot[a, b, c]");
+            assert_eq!(full_form("{1, 2}.{3, 4}"), "Dot[List[1, 2], List[3, 4]]");
+            assert_eq!(full_form("2 a.b^2"), "Times[2, Dot[a, Power[b, 2]]]");
+            assert_eq!(full_form("1.5 .x"), "Dot[1.5, x]");
+        }
+
+        #[test]
+        fn it_parses_numerals() {
+            assert_eq!(full_form("1.5*^10"), "1.5E+10");
+            assert_eq!(full_form("2*^3"), "2000");
+            assert_eq!(full_form("2*^-3"), "Rational[1, 500]");
+            assert_eq!(full_form("16^^FF"), "255");
+            assert_eq!(full_form("2^^1011.01"), "11.25");
+        }
+
+        #[test]
+        fn it_parses_precision_and_accuracy_marks() {
+            assert_eq!(full_form("3.14159`3"), "3.14");
+            assert_eq!(full_form("1.5`30"), "1.50000000000000000000000000000");
+            assert_eq!(full_form("1.5``10"), "1.5000000000");
+            assert_eq!(full_form("1.5`20*^3"), "1500.0000000000000000");
+        }
+
+        #[test]
+        fn it_keeps_marks_past_machine_digits() {
+            assert_eq!(full_form("1.5`50"), "1.5000000000000000000000000000000000000000000000000");
+            assert_eq!(full_form("1.5``40"), "1.5000000000000000000000000000000000000000");
+            assert_eq!(full_form("2^^1.1`40"), "1.500000000000000000000000000000000000000");
+            assert!(parse("1.5`9000").is_err());
+        }
+
+        #[test]
+        fn it_rejects_malformed_input() {
+            assert!(parse("f[x").is_err());
+            assert!(parse("{1, 2").is_err());
+            assert!(parse("a +").is_err());
+            assert!(parse("\"open").is_err());
+            assert!(parse("a ? b").is_err());
+            assert!(parse("2^^12").is_err());
+            assert!(parse("40^^1").is_err());
+        }
+    }
+}
@@ -1,4 +1,13 @@
+use std::str::FromStr;
+
 use regex::Regex;
+use decimal::d128;
+use num::{BigInt, BigRational, Zero};
+
+use expression::atom::numbers::big_real::{BigReal, magnitude};
+use expression::atom::numbers::number::Numeric;
+use numerical::precise::PRECISION_LIMIT;
+use numerical::real::{real, MACHINE_PRECISION};
 
 #[allow(dead_code)]
 pub fn representable_integer(s: &str) -> bool {
@@ -9,112 +18,219 @@ pub fn representable_integer(s: &str) -> bool {
     RE.is_match(s)
 }
 
-// TODO: Fix bug that could occur during dynamic parsing with exponent d128
-// numbers.
 #[allow(dead_code)]
 pub fn representable_numeric(s: &str) -> bool {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r"^[+-]?[0-9]*(\.?)([0-9]*)$").unwrap();
+        static ref RE: Regex = Regex::new(r"^[+-]?([0-9]+\.?[0-9]*|\.[0-9]+)(E[+-]?[0-9]+)?$").unwrap();
     }
 
-    RE.is_match(s)
+    RE.is_match(s) || numeral(s).is_some()
 }
 
 #[allow(dead_code)]
 pub fn get_representable_numeric(s: &str) -> Option<&str> {
-    lazy_static! {
-        static ref RE: Regex = Regex::new(r"^(?P<numeric>[+-]?[0-9]*(\.?)([0-9]*))$").unwrap();
+    if representable_numeric(s) { Some(s) } else { None }
+}
+
+// A decimal such as `-12.50` or `1.2E+3`, the way d128 prints, as its sign,
+// its digits and the power of ten they are scaled by: (true, "1250", -2)
+// for `-12.50`.
+pub fn decimal_digits(s: &str) -> Option<(bool, String, i64)> {
+    let (negative, s) = match s.chars().next() {
+        Some('-') => (true, &s[1..]),
+        Some('+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let (mantissa, exponent) = match s.find('E') {
+        Some(i) => (&s[..i], s[i + 1..].parse::<i64>().ok()?),
+        None => (s, 0),
+    };
+
+    let (whole, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+
+    let digits = format!("{}{}", whole, fraction);
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(10)) {
+        return None;
     }
 
-    if RE.is_match(s) { Some(s) } else { None }
+    Some((negative, digits, exponent - fraction.len() as i64))
 }
 
+// The integer a decimal stands for, as long as it has no fractional part
+// and fits.
 #[allow(dead_code)]
 pub fn get_representable_integer(s: &str) -> Option<i64> {
-    lazy_static! {
-        static ref REG_NOTATION: Regex = Regex::new(r"^(?P<digit>[+-]?([0-9]*))(\.?)([0]*)$").unwrap();
-        static ref EXP_NOTATION: Regex = Regex::new(r"^(?P<lhs>[+-]?([0-9]*\.[0-9]*))[E](?P<sign>[+-])(?P<rhs>[0-9]+)$").unwrap();
-    }
+    let (negative, digits, exponent) = decimal_digits(s)?;
+    let digits = digits.trim_start_matches('0');
 
-    let captures = REG_NOTATION.captures(s);
+    let whole = if exponent >= 0 {
+        // Anything longer than 19 digits is past an i64.
+        if digits.len() as i64 + exponent > 19 {
+            return None;
+        }
 
-    match captures {
-        Some(c) => {
-            match c.name("digit").unwrap().parse::<i64>() {
-                Ok(num) => Some(num),
-                Err(_) => None,
-            }
+        format!("{}{}", digits, "0".repeat(if digits.is_empty() { 0 } else { exponent as usize }))
+    } else {
+        let dropped = ::std::cmp::min(-exponent, digits.len() as i64) as usize;
+        let (kept, fraction) = digits.split_at(digits.len() - dropped);
+
+        if fraction.chars().any(|c| c != '0') {
+            return None;
         }
 
-        None => {
-            // Refactor this a bit: We could use closures
-            // and unwrap_or_else to make this less "callback hell
-            // eque"
-            let other_captures = EXP_NOTATION.captures(s);
-            match other_captures {
-                Some(c) => {
-                    let lhs = c.name("lhs").unwrap();
-                    let rhs = c.name("rhs").unwrap();
-                    let sign = c.name("sign").unwrap();
-
-                    match rhs.parse::<u64>() {
-                        Ok(num) => {
-                            if sign == "-" {
-                                None
-                            } else if num > 10 {
-                                None
-                            } else {
-                                let mut s = String::new();
-                                let mut curr = num;
-                                let mut flag = false;
-                                let mut u_flag = false;
-                                let mut d_flag = false;
-
-                                for letter in lhs.chars() {
-                                    if letter == '.' {
-                                        flag = true;
-                                    } else {
-                                        if !u_flag {
-                                            if curr != 0 || !d_flag {
-                                                s.push(letter);
-                                                d_flag = true;
-                                            }
-                                        }
-
-                                        if flag {
-                                            if curr == 0 {
-                                                if letter != '0' {
-                                                    u_flag = true;
-                                                }
-                                            } else {
-                                                curr = curr - 1;
-                                            }
-                                        }
-                                    }
-                                }
-
-                                if !u_flag {
-                                    while curr != 0 {
-                                        s.push('0');
-                                        curr = curr - 1;
-                                    }
-
-                                    match s.parse::<i64>() {
-                                        Ok(num) => Some(num),
-                                        _ => None,
-                                    }
-                                } else {
-                                    None
-                                }
-                            }
-                        }
-                        Err(_) => None,
-                    }
-                }
-                None => None,
-            }
+        kept.to_string()
+    };
+
+    let whole = if whole.is_empty() { "0".to_string() } else { whole };
+    format!("{}{}", if negative { "-" } else { "" }, whole).parse::<i64>().ok()
+}
+
+// Applies a precision mark to x, exactly q: `` ` `` alone for machine
+// precision, `` `p `` for p significant digits and ``` ``a ``` for a digits
+// after the point. More digits than machine precision make a BigReal.
+fn marked(x: d128, q: &BigRational, mark: &str) -> Option<Numeric> {
+    let (accuracy, digits) = if mark.starts_with('`') { (true, &mark[1..]) } else { (false, mark) };
+
+    let digits = match digits.find('.') {
+        Some(i) => &digits[..i],
+        None => digits,
+    };
+
+    let n = match digits.parse::<i64>() {
+        Ok(n) => n,
+        Err(_) if digits.is_empty() && !accuracy => MACHINE_PRECISION as i64,
+        Err(_) => return None,
+    };
+
+    let precision = if accuracy {
+        if q.is_zero() { 0 } else { magnitude(q) + n + 1 }
+    } else if n < 1 {
+        return None;
+    } else {
+        n
+    };
+
+    if precision > PRECISION_LIMIT as i64 {
+        return None;
+    }
+
+    if precision > MACHINE_PRECISION as i64 {
+        return Some(Numeric::BigReal(if accuracy {
+            BigReal::with_exponent(q, -n)
+        } else {
+            BigReal::with_precision(q, n as u32)
+        }));
+    }
+
+    let quantum = if accuracy {
+        -n
+    } else {
+        let leading: i32 = if x.is_zero() { 0 } else { x.logb().into() };
+        leading as i64 - n + 1
+    };
+
+    // Past the exponents a d128 holds x is as precise as it gets.
+    let quantized = x.quantize(real(&format!("1E{}", quantum)));
+    Some(Numeric::LittleReal(if quantized.is_nan() { x } else { quantized }))
+}
+
+// Reads a Mathematica numeral: digits with an optional point, in base b
+// when written b^^digits, then an optional precision mark and an optional
+// *^e for times b^e. Integers without a point or mark stay exact, so 2*^-3
+// is 1/500.
+pub fn numeral(s: &str) -> Option<Numeric> {
+    let (negative, s) = match s.chars().next() {
+        Some('-') => (true, &s[1..]),
+        Some('+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let (s, exponent) = match s.find("*^") {
+        Some(i) => (&s[..i], s[i + 2..].parse::<i64>().ok()?),
+        None => (s, 0),
+    };
+
+    let (s, mark) = match s.find('`') {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let (base, digits) = match s.find("^^") {
+        Some(i) => (s[..i].parse::<u32>().ok()?, &s[i + 2..]),
+        None => (10, s),
+    };
+
+    if base < 2 || base > 36 {
+        return None;
+    }
+
+    let (whole, fraction) = match digits.find('.') {
+        Some(i) => (&digits[..i], Some(&digits[i + 1..])),
+        None => (digits, None),
+    };
+
+    let values = whole.chars()
+        .chain(fraction.unwrap_or("").chars())
+        .map(|c| c.to_digit(base))
+        .collect::<Option<Vec<u32>>>()?;
+
+    if values.is_empty() {
+        return None;
+    }
+
+    // Integers times a power of the base stay exact, unless the power is
+    // too large to write out.
+    if fraction.is_none() && mark.is_none() {
+        let n = values.iter().fold(BigInt::zero(), |n, &v| n * BigInt::from(base) + BigInt::from(v));
+        let n = Numeric::from_big_integer(if negative { -n } else { n });
+
+        if let Some(scale) = Numeric::LittleInteger(base as i64).power(Numeric::LittleInteger(exponent)) {
+            return Some(n * scale);
         }
     }
+
+    let b = d128::from(base);
+
+    let mut x = if base == 10 {
+        // Straight from the text, keeping the digits written.
+        let x = d128::from_str(digits).ok()?;
+        x.scaleb(d128::from(exponent))
+    } else {
+        let scale = exponent - fraction.map_or(0, |f| f.chars().count() as i64);
+        let x = values.iter().fold(d128::zero(), |x, &v| x * b + d128::from(v));
+        x * b.pow(d128::from(scale))
+    };
+
+    if !x.is_finite() {
+        return None;
+    }
+
+    if negative {
+        x = -x;
+    }
+
+    // Decimals written with more digits than machine precision keep them
+    // all, as if marked with that many.
+    let significant = values.iter().skip_while(|&&v| v == 0).count();
+    let precision = significant.to_string();
+    let mark = if mark.is_none() && base == 10 && significant > MACHINE_PRECISION as usize { Some(precision.as_str()) } else { mark };
+
+    if let Some(mark) = mark {
+        let n = values.iter().fold(BigInt::zero(), |n, &v| n * BigInt::from(base) + BigInt::from(v));
+        let scale = exponent - fraction.map_or(0, |f| f.chars().count() as i64);
+        let power = BigRational::from_integer(BigInt::from(base)).pow(scale.abs() as i32);
+        let q = BigRational::from_integer(if negative { -n } else { n });
+        let q = if scale >= 0 { q * power } else { q / power };
+
+        return marked(x, &q, mark);
+    }
+
+    Some(Numeric::LittleReal(x))
 }
 
 #[inline]
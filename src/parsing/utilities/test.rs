@@ -369,6 +369,53 @@ mod tests {
             fn it_doesnt_convert_from_decimals_10() {
                 assert_eq!(None, get_representable_integer("50.10"));
             }
+
+            #[test]
+            fn it_converts_from_exponent_notation_past_10() {
+                assert_eq!(Some(2100000000000000000), get_representable_integer("2.1E+18"));
+            }
+
+            #[test]
+            fn it_converts_from_exponent_notation_without_a_point() {
+                assert_eq!(Some(-300), get_representable_integer("-3E+2"));
+            }
+
+            #[test]
+            fn it_converts_from_negative_exponent_notation() {
+                assert_eq!(Some(5), get_representable_integer("500E-2"));
+            }
+
+            #[test]
+            fn it_doesnt_convert_past_an_integer() {
+                assert_eq!(None, get_representable_integer("1E+19"));
+            }
+        }
+
+        mod numeral_tests {
+            use parsing::utilities::numerics::representable_numeric;
+
+            #[test]
+            fn it_represents_exponent_notation() {
+                assert_eq!(true, representable_numeric("1.5E+30"));
+            }
+
+            #[test]
+            fn it_represents_numerals() {
+                assert_eq!(true, representable_numeric("1.5*^10"));
+                assert_eq!(true, representable_numeric("16^^FF"));
+                assert_eq!(true, representable_numeric("1.5`30"));
+            }
+
+            #[test]
+            fn it_doesnt_represent_digits_out_of_base() {
+                assert_eq!(false, representable_numeric("2^^102"));
+            }
+
+            #[test]
+            fn it_doesnt_represent_nothing() {
+                assert_eq!(false, representable_numeric(""));
+                assert_eq!(false, representable_numeric("."));
+            }
         }
     }
     mod symbol_tests {
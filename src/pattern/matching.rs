@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::expression;
+
+pub type Bindings = HashMap<String, SimplexPointer>;
+
+// Patterns are plain expressions: Blank[], Blank[h], Pattern[x, p],
+// Alternatives[p1, p2, ...], anything else has to match structurally.
+pub fn matches(e: &SimplexPointer, pattern: &SimplexPointer) -> bool {
+    let mut bindings = Bindings::new();
+    match_pattern(e, pattern, &mut bindings)
+}
+
+pub fn match_pattern(e: &SimplexPointer, pattern: &SimplexPointer, bindings: &mut Bindings) -> bool {
+    if pattern.is_atomic() {
+        return e == pattern;
+    }
+
+    let parts = pattern.get_parts();
+
+    match pattern.head_name().as_str() {
+        "Blank" => {
+            match parts.first() {
+                Some(h) => e.head_name() == h.as_str(),
+                None => true,
+            }
+        }
+
+        "Pattern" if parts.len() == 2 => {
+            let name = parts[0].as_str().to_string();
+
+            if let Some(bound) = bindings.get(&name).cloned() {
+                return bound == *e && match_pattern(e, &parts[1], bindings);
+            }
+
+            if match_pattern(e, &parts[1], bindings) {
+                bindings.insert(name, e.clone());
+                true
+            } else {
+                false
+            }
+        }
+
+        "Alternatives" => {
+            for p in parts.iter() {
+                let mut attempt = bindings.clone();
+                if match_pattern(e, p, &mut attempt) {
+                    *bindings = attempt;
+                    return true;
+                }
+            }
+
+            false
+        }
+
+        _ => {
+            if e.is_atomic() || e.get_length() != parts.len() {
+                return false;
+            }
+
+            let mut attempt = bindings.clone();
+
+            match (e.get_head(), pattern.get_head()) {
+                (Some(ref h), Some(ref p)) if match_pattern(h, p, &mut attempt) => {}
+                _ => return false,
+            }
+
+            for (part, p) in e.get_parts().iter().zip(parts.iter()) {
+                if !match_pattern(part, p, &mut attempt) {
+                    return false;
+                }
+            }
+
+            *bindings = attempt;
+            true
+        }
+    }
+}
+
+// Swaps every symbol bound in the bindings for its value.
+pub fn substitute(e: &SimplexPointer, bindings: &Bindings) -> SimplexPointer {
+    if let Some(name) = e.as_symbol() {
+        return bindings.get(&name).cloned().unwrap_or(e.clone());
+    }
+
+    if e.is_atomic() {
+        return e.clone();
+    }
+
+    let parts = e.get_parts().iter().map(|p| substitute(p, bindings)).collect::<Vec<SimplexPointer>>();
+    let rebuilt = e.with_parts(&parts);
+
+    match e.get_head().and_then(|h| h.as_symbol()).and_then(|h| bindings.get(&h)).and_then(|h| h.as_symbol()) {
+        Some(head) => expression(head.as_str(), &parts),
+        None => rebuilt,
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    mod test_matching {
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use pattern::matching::{matches, match_pattern, Bindings};
+
+        fn blank() -> SimplexPointer {
+            expression("Blank", &[])
+        }
+
+        #[test]
+        fn it_matches_literals() {
+            assert!(matches(&symbol("x"), &symbol("x")));
+            assert!(!matches(&symbol("x"), &symbol("y")));
+        }
+
+        #[test]
+        fn it_matches_blanks() {
+            assert!(matches(&integer(3), &blank()));
+            assert!(matches(&integer(3), &expression("Blank", &[symbol("Integer")])));
+            assert!(!matches(&symbol("x"), &expression("Blank", &[symbol("Integer")])));
+        }
+
+        #[test]
+        fn it_matches_structurally() {
+            let e = expression("f", &[integer(1), symbol("x")]);
+            assert!(matches(&e, &expression("f", &[blank(), symbol("x")])));
+            assert!(!matches(&e, &expression("g", &[blank(), symbol("x")])));
+            assert!(!matches(&e, &expression("f", &[blank()])));
+        }
+
+        #[test]
+        fn it_binds_consistently() {
+            let p = list(&[expression("Pattern", &[symbol("a"), blank()]),
+                           expression("Pattern", &[symbol("a"), blank()])]);
+            let mut bindings = Bindings::new();
+
+            assert!(match_pattern(&list(&[integer(1), integer(1)]), &p, &mut bindings));
+            assert_eq!(bindings.get("a"), Some(&integer(1)));
+            assert!(!matches(&list(&[integer(1), integer(2)]), &p));
+        }
+
+        #[test]
+        fn it_matches_alternatives() {
+            let p = expression("Alternatives", &[symbol("x"), symbol("y")]);
+            assert!(matches(&symbol("y"), &p));
+            assert!(!matches(&symbol("z"), &p));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use num::{BigInt, One, Integer};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::integer;
+use arithmetic::canonical::{sum, product, power};
+use polynomials::factor::factor_polynomial;
+use polynomials::rational::{together, fraction};
+use polynomials::terms::{exact_coefficients, from_coefficients};
+
+// Dense polynomials over the rationals, constant term first, as in dense.rs.
+type RationalPolynomial = Vec<Numeric>;
+
+fn trim(mut p: RationalPolynomial) -> RationalPolynomial {
+    while p.last().map_or(false, |c| c.is_zero()) {
+        p.pop();
+    }
+
+    p
+}
+
+fn mul(a: &[Numeric], b: &[Numeric]) -> RationalPolynomial {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut product = vec![Numeric::LittleInteger(0); a.len() + b.len() - 1];
+
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] = product[i + j].clone() + x.clone() * y.clone();
+        }
+    }
+
+    trim(product)
+}
+
+fn sub(a: &[Numeric], b: &[Numeric]) -> RationalPolynomial {
+    let zero = Numeric::LittleInteger(0);
+
+    trim((0..a.len().max(b.len()))
+        .map(|i| a.get(i).unwrap_or(&zero).clone() - b.get(i).unwrap_or(&zero).clone())
+        .collect())
+}
+
+// Quotient and remainder, b non-zero.
+fn divide(a: &[Numeric], b: &[Numeric]) -> (RationalPolynomial, RationalPolynomial) {
+    let mut remainder = trim(a.to_vec());
+    let mut quotient = vec![Numeric::LittleInteger(0); (a.len() + 1).saturating_sub(b.len())];
+    let lb = b.last().unwrap().clone();
+
+    while remainder.len() >= b.len() {
+        let shift = remainder.len() - b.len();
+        let q = remainder.last().unwrap().clone() / lb.clone();
+        quotient[shift] = q.clone();
+
+        for (i, c) in b.iter().enumerate() {
+            remainder[i + shift] = remainder[i + shift].clone() - q.clone() * c.clone();
+        }
+
+        remainder.pop();
+        remainder = trim(remainder);
+    }
+
+    (trim(quotient), remainder)
+}
+
+// The inverse of a modulo m by the extended Euclidean algorithm, None when
+// they share a factor.
+fn inverse(a: &[Numeric], m: &[Numeric]) -> Option<RationalPolynomial> {
+    let (mut r0, mut r1) = (m.to_vec(), divide(a, m).1);
+    let (mut s0, mut s1) = (Vec::new(), vec![Numeric::LittleInteger(1)]);
+
+    while !r1.is_empty() {
+        let (q, r) = divide(&r0, &r1);
+        let s = sub(&s0, &mul(&q, &s1));
+
+        r0 = r1;
+        r1 = r;
+        s0 = s1;
+        s1 = s;
+    }
+
+    if r0.len() != 1 {
+        return None;
+    }
+
+    let c = Numeric::LittleInteger(1) / r0[0].clone();
+    Some(divide(&s0.iter().map(|x| x.clone() * c.clone()).collect::<RationalPolynomial>(), m).1)
+}
+
+fn rationals(p: &[BigInt]) -> RationalPolynomial {
+    p.iter().map(|c| Numeric::from_big_integer(c.clone())).collect()
+}
+
+fn integers(p: &[Numeric], scale: &BigInt) -> Vec<BigInt> {
+    p.iter()
+        .map(|c| {
+            let q = c.to_big_rational().unwrap();
+            q.numer() * (scale / q.denom())
+        })
+        .collect()
+}
+
+// Partial fractions in one variable over the rationals: the polynomial part
+// of e and, for every irreducible factor f of the denominator to the power
+// m, numerators of degree below f over f, f^2, ..., f^m. None when e is not
+// a rational function of the variable with exact coefficients.
+pub fn apart(e: &SimplexPointer, variable: &SimplexPointer) -> Option<SimplexPointer> {
+    let (n, d) = fraction(&together(e));
+    let numerator = trim(exact_coefficients(&n, variable)?);
+    let denominator = trim(exact_coefficients(&d, variable)?);
+
+    if denominator.len() < 2 {
+        return Some(e.clone());
+    }
+
+    // d = c / s f1^m1 ... fk^mk with the fi primitive and irreducible.
+    let scale = denominator.iter()
+        .filter_map(|c| c.to_big_rational())
+        .fold(BigInt::one(), |l, q| l.lcm(q.denom()));
+    let (content, factors) = factor_polynomial(&integers(&denominator, &scale));
+    let content = Numeric::from_big_integer(content) / Numeric::from_big_integer(scale);
+
+    let mut powers = Vec::with_capacity(factors.len());
+
+    for (f, m) in factors {
+        let f = rationals(&f);
+        let fm = (0..m).fold(vec![Numeric::LittleInteger(1)], |p, _| mul(&p, &f));
+        powers.push((f, m, fm));
+    }
+
+    let whole = powers.iter().fold(vec![Numeric::LittleInteger(1)], |p, &(_, _, ref q)| mul(&p, q));
+    let numerator = numerator.iter().map(|c| c.clone() / content.clone()).collect::<RationalPolynomial>();
+    let (quotient, remainder) = divide(&numerator, &whole);
+
+    let mut terms = vec![from_coefficients(&quotient, variable)];
+
+    for &(ref f, m, ref fm) in &powers {
+        let cofactor = divide(&whole, fm).0;
+        let mut part = divide(&mul(&remainder, &inverse(&cofactor, fm)?), fm).1;
+        let base = from_coefficients(f, variable);
+
+        // part / f^m peeled one power of f at a time.
+        for k in (1..m + 1).rev() {
+            let (q, r) = divide(&part, f);
+
+            if !r.is_empty() {
+                terms.push(product(&[from_coefficients(&r, variable), power(&base, &integer(-(k as i64)))]));
+            }
+
+            part = q;
+        }
+    }
+
+    Some(sum(&terms))
+}
@@ -0,0 +1,273 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{list, integer, is_head};
+use polynomials::expand::{expand, expand_all};
+use polynomials::coefficients::{coefficient, exponent, exponents, coefficient_list, collect};
+use polynomials::factor::factor;
+use polynomials::terms::variables;
+use polynomials::rational::{fraction, together, cancel, expand_numerator, expand_denominator};
+use polynomials::apart::apart;
+use polynomials::sparse::{SparsePolynomial, gcd, lcm, resultant, discriminant};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Expand" => Some(expand_builtin),
+        "ExpandAll" => Some(expand_all_builtin),
+        "Collect" => Some(collect_builtin),
+        "Coefficient" => Some(coefficient_builtin),
+        "CoefficientList" => Some(coefficient_list_builtin),
+        "Exponent" => Some(exponent_builtin),
+        "Factor" => Some(factor_builtin),
+        "PolynomialQuotient" => Some(polynomial_quotient_builtin),
+        "PolynomialRemainder" => Some(polynomial_remainder_builtin),
+        "PolynomialGCD" => Some(polynomial_gcd_builtin),
+        "PolynomialLCM" => Some(polynomial_lcm_builtin),
+        "Resultant" => Some(resultant_builtin),
+        "Discriminant" => Some(discriminant_builtin),
+        "Together" => Some(together_builtin),
+        "Cancel" => Some(cancel_builtin),
+        "Apart" => Some(apart_builtin),
+        "Numerator" => Some(numerator_builtin),
+        "Denominator" => Some(denominator_builtin),
+        "ExpandNumerator" => Some(expand_numerator_builtin),
+        "ExpandDenominator" => Some(expand_denominator_builtin),
+        _ => None,
+    }
+}
+
+// A variable, or a list of them.
+fn variables_of(e: &SimplexPointer) -> Vec<SimplexPointer> {
+    if is_head(e, "List") {
+        e.get_parts()
+    } else {
+        vec![e.clone()]
+    }
+}
+
+fn expand_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(expand(&args[0]))
+}
+
+fn expand_all_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(expand_all(&args[0]))
+}
+
+fn collect_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() < 2 || args.len() > 3 {
+        return None;
+    }
+
+    Some(collect(&args[0], &variables_of(&args[1]), args.get(2)))
+}
+
+fn coefficient_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let n = match args.len() {
+        2 => 1,
+        3 => args[2].as_integer()?,
+        _ => return None,
+    };
+
+    Some(coefficient(&args[0], &args[1], n))
+}
+
+fn coefficient_list_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    coefficient_list(&args[0], &variables_of(&args[1]))
+}
+
+// Exponent[e, x] is the highest power, Exponent[e, x, List] all of them.
+fn exponent_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    match args.len() {
+        2 => Some(exponent(&args[0], &args[1])),
+        3 => {
+            let ks = exponents(&args[0], &args[1]);
+
+            match args[2].as_str().as_ref() {
+                "List" => Some(list(&ks.iter().map(|&k| integer(k)).collect::<Vec<SimplexPointer>>())),
+                "Min" => ks.first().map(|&k| integer(k)),
+                "Max" => Some(exponent(&args[0], &args[1])),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn factor_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(factor(&args[0]))
+}
+
+// Every variable of the polynomials, the main one first when given.
+fn all_variables(polynomials: &[SimplexPointer], main: Option<&SimplexPointer>) -> Vec<SimplexPointer> {
+    let mut found: Vec<SimplexPointer> = main.into_iter().cloned().collect();
+
+    for p in polynomials {
+        for v in variables(p) {
+            if !found.contains(&v) {
+                found.push(v);
+            }
+        }
+    }
+
+    found
+}
+
+fn sparse_all(polynomials: &[SimplexPointer], vars: &[SimplexPointer]) -> Option<Vec<SparsePolynomial>> {
+    polynomials.iter().map(|p| SparsePolynomial::from_expression(p, vars)).collect()
+}
+
+// Long division in x, other variables counting as coefficients. Left alone
+// unless the divisor's leading coefficient in x is a number.
+fn divide(args: &[SimplexPointer]) -> Option<(SimplexPointer, SimplexPointer)> {
+    if args.len() != 3 {
+        return None;
+    }
+
+    let vars = all_variables(&args[..2], Some(&args[2]));
+    let ps = sparse_all(&args[..2], &vars)?;
+
+    if ps[1].leading_coefficient(0).as_constant().is_none() {
+        return None;
+    }
+
+    let (q, r) = ps[0].divide(&ps[1])?;
+    Some((q.to_expression(&vars), r.to_expression(&vars)))
+}
+
+fn polynomial_quotient_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    divide(args).map(|(q, _)| q)
+}
+
+fn polynomial_remainder_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    divide(args).map(|(_, r)| r)
+}
+
+// Folds gcd or lcm over polynomials with their denominators cleared.
+fn fold_polynomials(args: &[SimplexPointer], f: fn(&SparsePolynomial, &SparsePolynomial) -> SparsePolynomial) -> Option<SimplexPointer> {
+    if args.is_empty() {
+        return None;
+    }
+
+    let vars = all_variables(args, None);
+    let ps = sparse_all(args, &vars)?;
+
+    let result = ps[1..].iter()
+        .fold(ps[0].clear_denominators().normalized(), |g, p| f(&g, &p.clear_denominators()));
+
+    Some(result.to_expression(&vars))
+}
+
+fn polynomial_gcd_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    fold_polynomials(args, gcd)
+}
+
+fn polynomial_lcm_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    fold_polynomials(args, lcm)
+}
+
+fn resultant_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 3 {
+        return None;
+    }
+
+    let vars = all_variables(&args[..2], Some(&args[2]));
+    let ps = sparse_all(&args[..2], &vars)?;
+
+    Some(resultant(&ps[0], &ps[1], 0).to_expression(&vars))
+}
+
+fn discriminant_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    let vars = all_variables(&args[..1], Some(&args[1]));
+    let p = SparsePolynomial::from_expression(&args[0], &vars)?;
+
+    discriminant(&p, 0).map(|d| d.to_expression(&vars))
+}
+
+fn together_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(together(&args[0]))
+}
+
+fn cancel_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(cancel(&args[0]))
+}
+
+// Apart[e] needs e to have a single variable, Apart[e, x] treats x as one.
+fn apart_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let variable = match args.len() {
+        1 => {
+            let (n, d) = fraction(&together(&args[0]));
+            let vars = all_variables(&[n, d], None);
+
+            match vars.len() {
+                0 => return Some(args[0].clone()),
+                1 => vars[0].clone(),
+                _ => return None,
+            }
+        }
+        2 => args[1].clone(),
+        _ => return None,
+    };
+
+    Some(apart(&args[0], &variable).unwrap_or_else(|| args[0].clone()))
+}
+
+fn numerator_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(fraction(&args[0]).0)
+}
+
+fn denominator_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(fraction(&args[0]).1)
+}
+
+fn expand_numerator_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(expand_numerator(&args[0]))
+}
+
+fn expand_denominator_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    Some(expand_denominator(&args[0]))
+}
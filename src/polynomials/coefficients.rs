@@ -0,0 +1,139 @@
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::apply;
+use evaluation::utilities::{expression, list, integer, is_head};
+use arithmetic::terms::base_and_exponent;
+use arithmetic::canonical::{sum, product, power};
+use polynomials::terms::{monomials, collect_monomials};
+
+// Coefficient[e, x^k y, n] looks for x^(k n) y^n.
+fn split_form(form: &SimplexPointer, n: i64) -> (Vec<SimplexPointer>, Vec<i64>) {
+    let factors = if is_head(form, "Times") { form.get_parts() } else { vec![form.clone()] };
+    let mut variables = Vec::with_capacity(factors.len());
+    let mut degrees = Vec::with_capacity(factors.len());
+
+    for f in factors {
+        let (base, exponent) = base_and_exponent(&f);
+
+        match exponent.as_integer() {
+            Some(k) if is_head(&f, "Power") => {
+                variables.push(base);
+                degrees.push(k * n);
+            }
+            _ => {
+                variables.push(f);
+                degrees.push(n);
+            }
+        }
+    }
+
+    (variables, degrees)
+}
+
+pub fn coefficient(e: &SimplexPointer, form: &SimplexPointer, n: i64) -> SimplexPointer {
+    let (variables, degrees) = split_form(form, n);
+
+    let matching = monomials(e, &variables)
+        .into_iter()
+        .filter(|&(ref k, _)| *k == degrees)
+        .map(|(_, c)| c)
+        .collect::<Vec<SimplexPointer>>();
+
+    sum(&matching)
+}
+
+// Exponents of the variable that occur, lowest first.
+pub fn exponents(e: &SimplexPointer, variable: &SimplexPointer) -> Vec<i64> {
+    let mut found = collect_monomials(&monomials(e, &[variable.clone()]))
+        .iter()
+        .map(|&(ref k, _)| k[0])
+        .collect::<Vec<i64>>();
+
+    found.sort();
+    found.dedup();
+    found
+}
+
+// The highest exponent, -Infinity for 0.
+pub fn exponent(e: &SimplexPointer, variable: &SimplexPointer) -> SimplexPointer {
+    match exponents(e, variable).last() {
+        Some(&k) => integer(k),
+        None => expression("DirectedInfinity", &[integer(-1)]),
+    }
+}
+
+// Nested lists of coefficients, constant terms first, padded with zeros to
+// a rectangular array.
+pub fn coefficient_list(e: &SimplexPointer, variables: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let mut degrees = Vec::with_capacity(variables.len());
+
+    for v in variables {
+        let ks = exponents(e, v);
+
+        if ks.first().map_or(false, |&k| k < 0) {
+            return None;
+        }
+
+        degrees.push(ks.last().cloned().unwrap_or(0));
+    }
+
+    let terms = collect_monomials(&monomials(e, variables));
+
+    if terms.is_empty() {
+        return Some(list(&[]));
+    }
+
+    Some(nested(&terms, &degrees, &mut Vec::new()))
+}
+
+fn nested(terms: &[(Vec<i64>, SimplexPointer)], degrees: &[i64], prefix: &mut Vec<i64>) -> SimplexPointer {
+    if prefix.len() == degrees.len() {
+        return terms.iter()
+            .find(|&&(ref k, _)| k == prefix)
+            .map(|&(_, ref c)| c.clone())
+            .unwrap_or_else(|| integer(0));
+    }
+
+    let level = degrees[prefix.len()];
+    let mut elements = Vec::with_capacity(level as usize + 1);
+
+    for k in 0..level + 1 {
+        prefix.push(k);
+        elements.push(nested(terms, degrees, prefix));
+        prefix.pop();
+    }
+
+    list(&elements)
+}
+
+// Gathers the terms of e by powers of the first variable, the coefficients
+// collected in turn by the rest and finally handed to h when given.
+pub fn collect(e: &SimplexPointer, variables: &[SimplexPointer], h: Option<&SimplexPointer>) -> SimplexPointer {
+    let (variable, rest) = match variables.split_first() {
+        Some(split) => split,
+        None => {
+            return match h {
+                Some(h) => apply(h, &[e.clone()]).unwrap_or_else(|| e.clone()),
+                None => e.clone(),
+            };
+        }
+    };
+
+    let mut grouped: Vec<(i64, Vec<SimplexPointer>)> = Vec::new();
+
+    for (k, c) in monomials(e, &[variable.clone()]) {
+        match grouped.iter().position(|&(j, _)| j == k[0]) {
+            Some(i) => grouped[i].1.push(c),
+            None => grouped.push((k[0], vec![c])),
+        }
+    }
+
+    let terms = grouped.into_iter()
+        .map(|(k, cs)| {
+            let c = collect(&sum(&cs), rest, h);
+            product(&[c, power(variable, &integer(k))])
+        })
+        .collect::<Vec<SimplexPointer>>();
+
+    sum(&terms)
+}
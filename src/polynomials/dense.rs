@@ -0,0 +1,188 @@
+use num::{BigInt, Zero, One, Signed, Integer};
+
+// Dense polynomials over the integers, constant term first and never with
+// a zero leading coefficient; the zero polynomial is empty.
+pub type IntegerPolynomial = Vec<BigInt>;
+
+pub fn trim(mut p: IntegerPolynomial) -> IntegerPolynomial {
+    while p.last().map_or(false, |c| c.is_zero()) {
+        p.pop();
+    }
+
+    p
+}
+
+pub fn degree(p: &[BigInt]) -> i64 {
+    p.len() as i64 - 1
+}
+
+pub fn leading(p: &[BigInt]) -> BigInt {
+    p.last().cloned().unwrap_or_else(BigInt::zero)
+}
+
+pub fn constant(c: BigInt) -> IntegerPolynomial {
+    trim(vec![c])
+}
+
+pub fn add(a: &[BigInt], b: &[BigInt]) -> IntegerPolynomial {
+    let n = a.len().max(b.len());
+    let zero = BigInt::zero();
+
+    trim((0..n).map(|i| a.get(i).unwrap_or(&zero) + b.get(i).unwrap_or(&zero)).collect())
+}
+
+pub fn sub(a: &[BigInt], b: &[BigInt]) -> IntegerPolynomial {
+    add(a, &scale(b, &-BigInt::one()))
+}
+
+pub fn scale(p: &[BigInt], c: &BigInt) -> IntegerPolynomial {
+    trim(p.iter().map(|x| x * c).collect())
+}
+
+pub fn mul(a: &[BigInt], b: &[BigInt]) -> IntegerPolynomial {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut product = vec![BigInt::zero(); a.len() + b.len() - 1];
+
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] = &product[i + j] + x * y;
+        }
+    }
+
+    trim(product)
+}
+
+pub fn derivative(p: &[BigInt]) -> IntegerPolynomial {
+    trim(p.iter().enumerate().skip(1).map(|(k, c)| c * BigInt::from(k)).collect())
+}
+
+pub fn content(p: &[BigInt]) -> BigInt {
+    p.iter().fold(BigInt::zero(), |g, c| g.gcd(c))
+}
+
+// Divided by its content, with a positive leading coefficient.
+pub fn primitive(p: &[BigInt]) -> IntegerPolynomial {
+    let c = content(p);
+
+    if c.is_zero() {
+        return Vec::new();
+    }
+
+    let c = if leading(p).is_negative() { -c } else { c };
+    p.iter().map(|x| x / &c).collect()
+}
+
+// The quotient when b divides a exactly over the integers.
+pub fn divide_exact(a: &[BigInt], b: &[BigInt]) -> Option<IntegerPolynomial> {
+    if b.is_empty() {
+        return None;
+    }
+
+    let mut remainder = a.to_vec();
+    let mut quotient = vec![BigInt::zero(); (a.len() + 1).saturating_sub(b.len())];
+    let lb = leading(b);
+
+    while degree(&remainder) >= degree(b) {
+        let (q, r) = leading(&remainder).div_rem(&lb);
+
+        if !r.is_zero() {
+            return None;
+        }
+
+        let shift = (degree(&remainder) - degree(b)) as usize;
+        quotient[shift] = q.clone();
+
+        for (i, c) in b.iter().enumerate() {
+            remainder[i + shift] = &remainder[i + shift] - &q * c;
+        }
+
+        remainder = trim(remainder);
+    }
+
+    if remainder.is_empty() {
+        Some(trim(quotient))
+    } else {
+        None
+    }
+}
+
+// lc(b)^k a mod b for the smallest k that keeps everything integral.
+pub fn pseudo_remainder(a: &[BigInt], b: &[BigInt]) -> IntegerPolynomial {
+    let mut remainder = a.to_vec();
+    let lb = leading(b);
+
+    while !remainder.is_empty() && degree(&remainder) >= degree(b) {
+        let lr = leading(&remainder);
+        let shift = (degree(&remainder) - degree(b)) as usize;
+
+        remainder = remainder.iter().map(|c| c * &lb).collect();
+
+        for (i, c) in b.iter().enumerate() {
+            remainder[i + shift] = &remainder[i + shift] - &lr * c;
+        }
+
+        remainder = trim(remainder);
+    }
+
+    remainder
+}
+
+// Primitive remainder sequence, the result primitive with a positive
+// leading coefficient times the gcd of the contents.
+pub fn gcd(a: &[BigInt], b: &[BigInt]) -> IntegerPolynomial {
+    if a.is_empty() {
+        return primitive(b).iter().map(|c| c * content(b)).collect();
+    }
+
+    if b.is_empty() {
+        return primitive(a).iter().map(|c| c * content(a)).collect();
+    }
+
+    let c = content(a).gcd(&content(b));
+    let mut x = primitive(a);
+    let mut y = primitive(b);
+
+    while !y.is_empty() {
+        let r = pseudo_remainder(&x, &y);
+        x = y;
+        y = primitive(&r);
+    }
+
+    scale(&primitive(&x), &c)
+}
+
+// Yun's algorithm: square-free, pairwise coprime primitive factors with
+// their multiplicities, p being primitive itself.
+pub fn square_free(p: &[BigInt]) -> Vec<(IntegerPolynomial, usize)> {
+    let mut factors = Vec::new();
+
+    if degree(p) < 1 {
+        return factors;
+    }
+
+    let mut c = primitive(&gcd(p, &derivative(p)));
+    let mut w = divide_exact(p, &c).unwrap();
+    let mut i = 1;
+
+    while degree(&c) > 0 {
+        let y = primitive(&gcd(&w, &c));
+        let z = divide_exact(&w, &y).unwrap();
+
+        if degree(&z) > 0 {
+            factors.push((primitive(&z), i));
+        }
+
+        i = i + 1;
+        c = divide_exact(&c, &y).unwrap();
+        w = y;
+    }
+
+    if degree(&w) > 0 {
+        factors.push((primitive(&w), i));
+    }
+
+    factors
+}
@@ -0,0 +1,98 @@
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::{expression, integer, is_head};
+use arithmetic::terms::{operands, base_and_exponent};
+use arithmetic::canonical::{sum, product, power};
+
+// Multiplies out two sums term by term.
+fn distribute(lhs: &SimplexPointer, rhs: &SimplexPointer) -> SimplexPointer {
+    let lhs_terms = operands(lhs, "Plus");
+    let rhs_terms = operands(rhs, "Plus");
+
+    if lhs_terms.len() == 1 && rhs_terms.len() == 1 {
+        return product(&[lhs.clone(), rhs.clone()]);
+    }
+
+    let mut terms = Vec::with_capacity(lhs_terms.len() * rhs_terms.len());
+
+    for a in &lhs_terms {
+        for b in &rhs_terms {
+            terms.push(product(&[a.clone(), b.clone()]));
+        }
+    }
+
+    sum(&terms)
+}
+
+// Repeated squaring, so (1 + x)^16 takes four products rather than fifteen.
+fn expand_power(base: &SimplexPointer, n: i64) -> SimplexPointer {
+    let mut result = integer(1);
+    let mut square = base.clone();
+    let mut n = n;
+
+    while n > 0 {
+        if n % 2 == 1 {
+            result = distribute(&result, &square);
+        }
+
+        n = n / 2;
+
+        if n > 0 {
+            square = distribute(&square, &square);
+        }
+    }
+
+    result
+}
+
+// Products and positive integer powers of sums multiplied out at the top
+// level: (1 + x)^2 y is y + 2 x y + x^2 y. Denominators stay as they are.
+pub fn expand(e: &SimplexPointer) -> SimplexPointer {
+    if is_head(e, "Plus") {
+        return sum(&e.get_parts().iter().map(expand).collect::<Vec<SimplexPointer>>());
+    }
+
+    if is_head(e, "Times") {
+        return e.get_parts().iter()
+            .map(expand)
+            .fold(integer(1), |acc, f| distribute(&acc, &f));
+    }
+
+    if is_head(e, "Power") {
+        let (base, exponent) = base_and_exponent(e);
+
+        if let Some(n) = exponent.as_integer() {
+            let base = expand(&base);
+
+            if n > 1 && is_head(&base, "Plus") {
+                return expand_power(&base, n);
+            }
+
+            return power(&base, &exponent);
+        }
+    }
+
+    e.clone()
+}
+
+// Expand applied everywhere, denominators and function arguments included.
+pub fn expand_all(e: &SimplexPointer) -> SimplexPointer {
+    if e.is_atomic() {
+        return e.clone();
+    }
+
+    let parts = e.get_parts().iter().map(expand_all).collect::<Vec<SimplexPointer>>();
+    let rebuilt = e.with_parts(&parts);
+
+    if is_head(&rebuilt, "Power") {
+        let (base, exponent) = base_and_exponent(&rebuilt);
+
+        if let Some(n) = exponent.as_integer() {
+            if n < -1 && is_head(&base, "Plus") {
+                return expression("Power", &[expand_power(&base, -n), integer(-1)]);
+            }
+        }
+    }
+
+    expand(&rebuilt)
+}
@@ -0,0 +1,401 @@
+use num::{BigInt, Zero, One, Signed, Integer};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{integer, is_head};
+use arithmetic::terms::{number, base_and_exponent};
+use arithmetic::canonical::{product, power};
+use polynomials::dense::{self, IntegerPolynomial};
+use polynomials::modular::{self, ModularPolynomial};
+use polynomials::terms::{variables, exact_coefficients, from_coefficients, monomials, collect_monomials, from_monomials};
+
+const PRIMES: &'static [u64] = &[
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+];
+
+// Kernel of a matrix over the integers mod p, one basis vector per free column.
+fn kernel(mut a: Vec<Vec<u64>>, p: u64) -> Vec<Vec<u64>> {
+    let columns = a.first().map_or(0, |r| r.len());
+    let mut pivots = Vec::new();
+
+    for c in 0..columns {
+        let r = pivots.len();
+
+        let found = match (r..a.len()).find(|&i| a[i][c] != 0) {
+            Some(i) => i,
+            None => continue,
+        };
+
+        a.swap(r, found);
+        let normalizer = modular::inverse(a[r][c], p);
+        a[r] = a[r].iter().map(|x| x * normalizer % p).collect();
+
+        for i in 0..a.len() {
+            if i != r && a[i][c] != 0 {
+                let f = a[i][c];
+                a[i] = a[i].iter().zip(a[r].iter()).map(|(x, y)| (x + p - f * y % p) % p).collect();
+            }
+        }
+
+        pivots.push(c);
+    }
+
+    (0..columns)
+        .filter(|c| !pivots.contains(c))
+        .map(|free| {
+            let mut v = vec![0; columns];
+            v[free] = 1;
+
+            for (row, &c) in pivots.iter().enumerate() {
+                v[c] = (p - a[row][free]) % p;
+            }
+
+            v
+        })
+        .collect()
+}
+
+// Berlekamp: the irreducible factors of a monic square-free f mod p come from
+// the polynomials v with v^p = v mod f, by gcds of f with v - s.
+pub fn berlekamp(f: &[u64], p: u64) -> Vec<ModularPolynomial> {
+    let n = modular::degree(f) as usize;
+
+    if n <= 1 {
+        return vec![f.to_vec()];
+    }
+
+    let xp = modular::power_mod_polynomial(&[0, 1], p, f, p);
+    let mut row = vec![1];
+    let mut q = Vec::with_capacity(n);
+
+    for _ in 0..n {
+        let mut padded = row.clone();
+        padded.resize(n, 0);
+        q.push(padded);
+        row = modular::remainder(&modular::mul(&row, &xp, p), f, p);
+    }
+
+    // Rows of Q - I transposed, so that the kernel is {v : v (Q - I) = 0}.
+    let transposed = (0..n)
+        .map(|j| (0..n).map(|i| (q[i][j] + p - if i == j { 1 } else { 0 }) % p).collect())
+        .collect::<Vec<Vec<u64>>>();
+
+    let basis = kernel(transposed, p);
+    let mut factors = vec![f.to_vec()];
+
+    for v in basis.iter().map(|v| modular::trim(v.clone())).filter(|v| modular::degree(v) > 0) {
+        if factors.len() == basis.len() {
+            break;
+        }
+
+        let mut refined = Vec::new();
+
+        for u in factors {
+            let mut rest = u;
+
+            for s in 0..p {
+                if modular::degree(&rest) <= 0 {
+                    break;
+                }
+
+                let g = modular::gcd(&rest, &modular::sub(&v, &[s], p), p);
+
+                if modular::degree(&g) > 0 {
+                    rest = modular::divide(&rest, &g, p).0;
+                    refined.push(g);
+                }
+            }
+        }
+
+        factors = refined;
+    }
+
+    factors
+}
+
+fn to_integers(a: &[u64]) -> IntegerPolynomial {
+    a.iter().map(|&c| BigInt::from(c)).collect()
+}
+
+fn reduce_mod(a: &[BigInt], m: &BigInt) -> IntegerPolynomial {
+    dense::trim(a.iter().map(|c| c.mod_floor(m)).collect())
+}
+
+// Lifts f = g h mod p, g monic, to f = G H mod p^k.
+fn hensel_lift(f: &[BigInt], g: &[u64], h: &[u64], p: u64, k: u32) -> (IntegerPolynomial, IntegerPolynomial) {
+    let (s, t) = modular::bezout(g, h, p);
+    let mut big_g = to_integers(g);
+    let mut big_h = to_integers(h);
+    let mut modulus = BigInt::from(p);
+
+    for _ in 1..k {
+        let error = dense::sub(f, &dense::mul(&big_g, &big_h));
+        let e = modular::reduce(&error.iter().map(|c| c / &modulus).collect::<IntegerPolynomial>(), p);
+
+        let (q, tau) = modular::divide(&modular::mul(&t, &e, p), g, p);
+        let sigma = modular::add(&modular::mul(&s, &e, p), &modular::mul(&q, h, p), p);
+
+        big_g = dense::add(&big_g, &dense::scale(&to_integers(&tau), &modulus));
+        big_h = dense::add(&big_h, &dense::scale(&to_integers(&sigma), &modulus));
+        modulus = modulus * BigInt::from(p);
+    }
+
+    (reduce_mod(&big_g, &modulus), reduce_mod(&big_h, &modulus))
+}
+
+// Monic lifts mod p^k of every factor of f = lc(f) g1 g2 ... mod p.
+fn lift_all(f: &[BigInt], factors: &[ModularPolynomial], p: u64, k: u32) -> Vec<IntegerPolynomial> {
+    let modulus = BigInt::from(p).pow(k);
+
+    if factors.len() == 1 {
+        let inverse = dense::leading(f).extended_gcd(&modulus).x;
+        return vec![reduce_mod(&dense::scale(f, &inverse), &modulus)];
+    }
+
+    let g = &factors[0];
+    let h = modular::divide(&modular::reduce(f, p), g, p).0;
+    let (big_g, big_h) = hensel_lift(f, g, &h, p, k);
+
+    let mut lifted = vec![big_g];
+    lifted.extend(lift_all(&big_h, &factors[1..], p, k));
+    lifted
+}
+
+fn symmetric(a: &[BigInt], m: &BigInt) -> IntegerPolynomial {
+    let half = m / BigInt::from(2);
+    dense::trim(a.iter().map(|c| {
+        let r = c.mod_floor(m);
+        if r > half { r - m } else { r }
+    }).collect())
+}
+
+// Index sets of the given size, in lexicographic order.
+fn subsets(n: usize, size: usize) -> Vec<Vec<usize>> {
+    if size == 0 {
+        return vec![Vec::new()];
+    }
+
+    if size > n {
+        return Vec::new();
+    }
+
+    let mut result = subsets(n - 1, size);
+
+    for mut s in subsets(n - 1, size - 1) {
+        s.push(n - 1);
+        result.push(s);
+    }
+
+    result
+}
+
+// Zassenhaus: factors mod a prime lifted past a bound on the coefficients
+// of any true factor, then recombined by trial division, fewest first.
+pub fn factor_square_free(f: &[BigInt]) -> Vec<IntegerPolynomial> {
+    let n = dense::degree(f);
+
+    if n <= 1 {
+        return vec![f.to_vec()];
+    }
+
+    let lc = dense::leading(f);
+
+    let p = match PRIMES.iter().cloned().find(|&p| {
+        let fp = modular::reduce(f, p);
+        (&lc % BigInt::from(p)) != BigInt::zero()
+            && modular::degree(&modular::gcd(&fp, &modular::derivative(&fp, p), p)) == 0
+    }) {
+        Some(p) => p,
+        None => return vec![f.to_vec()],
+    };
+
+    let modular_factors = berlekamp(&modular::monic(&modular::reduce(f, p), p), p);
+
+    if modular_factors.len() == 1 {
+        return vec![f.to_vec()];
+    }
+
+    // Mignotte's bound, generously rounded up.
+    let largest = f.iter().map(|c| c.abs()).max().unwrap();
+    let bound = BigInt::from(2) * lc.abs() * BigInt::from(2).pow(n as u32) * BigInt::from(n + 1) * largest;
+
+    let mut k = 1;
+    let mut modulus = BigInt::from(p);
+
+    while modulus <= bound {
+        modulus = modulus * BigInt::from(p);
+        k = k + 1;
+    }
+
+    let mut remaining = lift_all(f, &modular_factors, p, k);
+    let mut f = f.to_vec();
+    let mut found = Vec::new();
+    let mut size = 1;
+
+    while 2 * size <= remaining.len() {
+        let mut split = None;
+
+        for subset in subsets(remaining.len(), size) {
+            let candidate = subset.iter()
+                .fold(vec![dense::leading(&f)], |acc, &i| reduce_mod(&dense::mul(&acc, &remaining[i]), &modulus));
+            let candidate = dense::primitive(&symmetric(&candidate, &modulus));
+
+            if let Some(quotient) = dense::divide_exact(&f, &candidate) {
+                split = Some((subset, candidate, quotient));
+                break;
+            }
+        }
+
+        match split {
+            Some((subset, candidate, quotient)) => {
+                found.push(candidate);
+                f = quotient;
+                remaining = remaining.into_iter()
+                    .enumerate()
+                    .filter(|&(i, _)| !subset.contains(&i))
+                    .map(|(_, r)| r)
+                    .collect();
+            }
+            None => size = size + 1,
+        }
+    }
+
+    if dense::degree(&f) > 0 {
+        found.push(dense::primitive(&f));
+    }
+
+    found
+}
+
+// The content, its sign that of the leading coefficient, and the
+// irreducible factors with their multiplicities.
+pub fn factor_polynomial(p: &[BigInt]) -> (BigInt, Vec<(IntegerPolynomial, usize)>) {
+    let c = dense::content(p);
+    let c = if dense::leading(p).is_negative() { -c } else { c };
+
+    let mut factors = Vec::new();
+
+    for (q, multiplicity) in dense::square_free(&dense::primitive(p)) {
+        for g in factor_square_free(&q) {
+            factors.push((g, multiplicity));
+        }
+    }
+
+    (c, factors)
+}
+
+fn numeric(c: &BigInt) -> Numeric {
+    Numeric::from_big_integer(c.clone())
+}
+
+fn lcm_of_denominators(coefficients: &[Numeric]) -> BigInt {
+    coefficients.iter()
+        .filter_map(|c| c.to_big_rational())
+        .fold(BigInt::one(), |l, q| l.lcm(q.denom()))
+}
+
+fn scaled_integers(coefficients: &[Numeric], scale: &BigInt) -> IntegerPolynomial {
+    coefficients.iter()
+        .map(|c| {
+            let q = c.to_big_rational().unwrap();
+            q.numer() * (scale / q.denom())
+        })
+        .collect()
+}
+
+// The leading number and the irreducible factors with their multiplicities
+// of a polynomial with exact coefficients, constant terms first.
+pub fn factor_coefficients(coefficients: &[Numeric]) -> Option<(Numeric, Vec<(Vec<Numeric>, usize)>)> {
+    let scale = lcm_of_denominators(coefficients);
+    let (content, factors) = factor_polynomial(&dense::trim(scaled_integers(coefficients, &scale)));
+
+    let factors = factors.iter()
+        .map(|&(ref g, multiplicity)| (g.iter().map(numeric).collect::<Vec<Numeric>>(), multiplicity))
+        .collect::<Vec<(Vec<Numeric>, usize)>>();
+
+    Some((numeric(&content) / numeric(&scale), factors))
+}
+
+fn factor_univariate(coefficients: &[Numeric], variable: &SimplexPointer) -> Option<SimplexPointer> {
+    let (content, factors) = factor_coefficients(coefficients)?;
+    let mut result = vec![number(content)];
+
+    for (g, multiplicity) in factors {
+        result.push(power(&from_coefficients(&g, variable), &integer(multiplicity as i64)));
+    }
+
+    Some(product(&result))
+}
+
+// Several variables: only the numeric content and the common monomial
+// come out, as in 2 x^2 y + 4 x y^2 = 2 x y (x + 2 y).
+fn factor_multivariate(e: &SimplexPointer, vars: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let terms = collect_monomials(&monomials(e, vars));
+
+    let coefficients = terms.iter()
+        .map(|&(_, ref c)| c.as_numeric().filter(|n| n.is_exact()))
+        .collect::<Option<Vec<Numeric>>>()?;
+
+    let scale = lcm_of_denominators(&coefficients);
+    let integers = scaled_integers(&coefficients, &scale);
+    let mut content = dense::content(&integers);
+
+    if integers.first().map_or(false, |c| c.is_negative()) {
+        content = -content;
+    }
+
+    let lowest = (0..vars.len())
+        .map(|i| terms.iter().map(|&(ref k, _)| k[i]).min().unwrap_or(0))
+        .collect::<Vec<i64>>();
+
+    let rest = terms.iter()
+        .zip(integers.iter())
+        .map(|(&(ref k, _), c)| {
+            let shifted = k.iter().zip(lowest.iter()).map(|(a, b)| a - b).collect::<Vec<i64>>();
+            (shifted, number(numeric(&(c / &content))))
+        })
+        .collect::<Vec<(Vec<i64>, SimplexPointer)>>();
+
+    let mut factors = vec![number(numeric(&content) / numeric(&scale))];
+
+    for (v, &k) in vars.iter().zip(lowest.iter()) {
+        factors.push(power(v, &integer(k)));
+    }
+
+    factors.push(from_monomials(&rest, vars));
+    Some(product(&factors))
+}
+
+// Factors polynomials with rational coefficients over the integers, and
+// products and powers of them factor by factor.
+pub fn factor(e: &SimplexPointer) -> SimplexPointer {
+    if e.is_atomic() {
+        return e.clone();
+    }
+
+    if is_head(e, "Times") {
+        return product(&e.get_parts().iter().map(factor).collect::<Vec<SimplexPointer>>());
+    }
+
+    if is_head(e, "Power") {
+        let (base, exponent) = base_and_exponent(e);
+
+        if exponent.as_integer().is_some() {
+            return power(&factor(&base), &exponent);
+        }
+
+        return e.clone();
+    }
+
+    let vars = variables(e);
+
+    let factored = match vars.len() {
+        0 => None,
+        1 => exact_coefficients(e, &vars[0]).and_then(|c| factor_univariate(&c, &vars[0])),
+        _ => factor_multivariate(e, &vars),
+    };
+
+    factored.unwrap_or_else(|| e.clone())
+}
@@ -0,0 +1,11 @@
+pub mod expand;
+pub mod terms;
+pub mod dense;
+pub mod sparse;
+pub mod modular;
+pub mod coefficients;
+pub mod factor;
+pub mod rational;
+pub mod apart;
+pub mod builtins;
+pub mod test;
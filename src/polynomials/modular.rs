@@ -0,0 +1,162 @@
+use num::{BigInt, Integer, ToPrimitive};
+
+// Dense polynomials over the integers mod a small prime p, constant term
+// first with no zero leading coefficient.
+pub type ModularPolynomial = Vec<u64>;
+
+pub fn trim(mut a: ModularPolynomial) -> ModularPolynomial {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+
+    a
+}
+
+pub fn degree(a: &[u64]) -> i64 {
+    a.len() as i64 - 1
+}
+
+pub fn reduce(a: &[BigInt], p: u64) -> ModularPolynomial {
+    let modulus = BigInt::from(p);
+    trim(a.iter().map(|c| c.mod_floor(&modulus).to_u64().unwrap()).collect())
+}
+
+pub fn power_mod(base: u64, exponent: u64, p: u64) -> u64 {
+    let mut result = 1;
+    let mut square = base % p;
+    let mut e = exponent;
+
+    while e > 0 {
+        if e % 2 == 1 {
+            result = result * square % p;
+        }
+
+        square = square * square % p;
+        e = e / 2;
+    }
+
+    result
+}
+
+pub fn inverse(a: u64, p: u64) -> u64 {
+    power_mod(a, p - 2, p)
+}
+
+pub fn add(a: &[u64], b: &[u64], p: u64) -> ModularPolynomial {
+    let n = a.len().max(b.len());
+    trim((0..n).map(|i| (a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0)) % p).collect())
+}
+
+pub fn sub(a: &[u64], b: &[u64], p: u64) -> ModularPolynomial {
+    let n = a.len().max(b.len());
+    trim((0..n).map(|i| (a.get(i).unwrap_or(&0) + p - b.get(i).unwrap_or(&0)) % p).collect())
+}
+
+pub fn scale(a: &[u64], c: u64, p: u64) -> ModularPolynomial {
+    trim(a.iter().map(|x| x * c % p).collect())
+}
+
+pub fn mul(a: &[u64], b: &[u64], p: u64) -> ModularPolynomial {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut product = vec![0; a.len() + b.len() - 1];
+
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] = (product[i + j] + x * y) % p;
+        }
+    }
+
+    trim(product)
+}
+
+pub fn divide(a: &[u64], b: &[u64], p: u64) -> (ModularPolynomial, ModularPolynomial) {
+    let mut remainder = a.to_vec();
+    let mut quotient = vec![0; (a.len() + 1).saturating_sub(b.len())];
+    let lead_inverse = inverse(*b.last().unwrap(), p);
+
+    while degree(&remainder) >= degree(b) {
+        let shift = (degree(&remainder) - degree(b)) as usize;
+        let q = remainder.last().unwrap() * lead_inverse % p;
+        quotient[shift] = q;
+
+        for (i, c) in b.iter().enumerate() {
+            remainder[i + shift] = (remainder[i + shift] + p - q * c % p) % p;
+        }
+
+        remainder = trim(remainder);
+    }
+
+    (trim(quotient), remainder)
+}
+
+pub fn remainder(a: &[u64], b: &[u64], p: u64) -> ModularPolynomial {
+    divide(a, b, p).1
+}
+
+pub fn monic(a: &[u64], p: u64) -> ModularPolynomial {
+    match a.last() {
+        Some(&l) => scale(a, inverse(l, p), p),
+        None => Vec::new(),
+    }
+}
+
+pub fn gcd(a: &[u64], b: &[u64], p: u64) -> ModularPolynomial {
+    let mut x = a.to_vec();
+    let mut y = b.to_vec();
+
+    while !y.is_empty() {
+        let r = remainder(&x, &y, p);
+        x = y;
+        y = r;
+    }
+
+    monic(&x, p)
+}
+
+// s and t with s a + t b = 1, for coprime a and b.
+pub fn bezout(a: &[u64], b: &[u64], p: u64) -> (ModularPolynomial, ModularPolynomial) {
+    let (mut r0, mut r1) = (a.to_vec(), b.to_vec());
+    let (mut s0, mut s1) = (vec![1], Vec::new());
+    let (mut t0, mut t1) = (Vec::new(), vec![1]);
+
+    while !r1.is_empty() {
+        let (q, r) = divide(&r0, &r1, p);
+        let s = sub(&s0, &mul(&q, &s1, p), p);
+        let t = sub(&t0, &mul(&q, &t1, p), p);
+
+        r0 = r1;
+        r1 = r;
+        s0 = s1;
+        s1 = s;
+        t0 = t1;
+        t1 = t;
+    }
+
+    let normalizer = inverse(*r0.last().unwrap(), p);
+    (scale(&s0, normalizer, p), scale(&t0, normalizer, p))
+}
+
+pub fn derivative(a: &[u64], p: u64) -> ModularPolynomial {
+    trim(a.iter().enumerate().skip(1).map(|(k, c)| (k as u64 % p) * c % p).collect())
+}
+
+// base^e mod (modulus, p) by repeated squaring.
+pub fn power_mod_polynomial(base: &[u64], e: u64, modulus: &[u64], p: u64) -> ModularPolynomial {
+    let mut result = vec![1];
+    let mut square = remainder(base, modulus, p);
+    let mut e = e;
+
+    while e > 0 {
+        if e % 2 == 1 {
+            result = remainder(&mul(&result, &square, p), modulus, p);
+        }
+
+        square = remainder(&mul(&square, &square, p), modulus, p);
+        e = e / 2;
+    }
+
+    result
+}
@@ -0,0 +1,167 @@
+use num::Integer;
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{integer, is_head};
+use arithmetic::terms::{number, operands, base_and_exponent};
+use arithmetic::canonical::{sum, product, power};
+use polynomials::expand::expand;
+use polynomials::terms::variables;
+use polynomials::sparse::{SparsePolynomial, gcd};
+
+// Splits a product into the factors with negative exponents, which make up
+// the denominator, and the rest: 3 x / (4 y^2) is 3 x over 4 y^2.
+pub fn fraction(e: &SimplexPointer) -> (SimplexPointer, SimplexPointer) {
+    let mut numerator = Vec::new();
+    let mut denominator = Vec::new();
+
+    for f in operands(e, "Times") {
+        if let Some((p, q)) = f.as_numeric().and_then(|n| n.as_rational()) {
+            numerator.push(integer(p));
+            denominator.push(integer(q));
+            continue;
+        }
+
+        let (base, exponent) = base_and_exponent(&f);
+
+        match exponent.as_numeric() {
+            Some(k) if k < Numeric::LittleInteger(0) => {
+                denominator.push(power(&base, &number(Numeric::LittleInteger(0) - k)))
+            }
+            _ => numerator.push(f),
+        }
+    }
+
+    (product(&numerator), product(&denominator))
+}
+
+fn over(numerator: &SimplexPointer, denominator: &SimplexPointer) -> SimplexPointer {
+    product(&[numerator.clone(), power(denominator, &integer(-1))])
+}
+
+fn is_one(e: &SimplexPointer) -> bool {
+    e.as_numeric() == Some(Numeric::LittleInteger(1))
+}
+
+// Numerator and denominator of e with sums over common denominators.
+fn combine(e: &SimplexPointer) -> (SimplexPointer, SimplexPointer) {
+    if is_head(e, "Plus") {
+        let parts = e.get_parts();
+        let first = combine(&parts[0]);
+
+        return parts[1..].iter().map(combine).fold(first, |(n, d), (m, c)| {
+            if d == c {
+                (sum(&[n, m]), d)
+            } else {
+                (sum(&[product(&[n, c.clone()]), product(&[m, d.clone()])]), product(&[d, c]))
+            }
+        });
+    }
+
+    if is_head(e, "Times") {
+        let (numerators, denominators): (Vec<SimplexPointer>, Vec<SimplexPointer>) =
+            e.get_parts().iter().map(combine).unzip();
+
+        return (product(&numerators), product(&denominators));
+    }
+
+    if is_head(e, "Power") {
+        let (base, exponent) = base_and_exponent(e);
+
+        if let Some(k) = exponent.as_integer() {
+            let (n, d) = combine(&base);
+
+            return if k >= 0 {
+                (power(&n, &exponent), power(&d, &exponent))
+            } else {
+                (power(&d, &integer(-k)), power(&n, &integer(-k)))
+            };
+        }
+    }
+
+    fraction(e)
+}
+
+// Divides out the polynomial gcd of numerator and denominator, leaving
+// integer coefficients with no common factor and a denominator whose
+// leading coefficient is positive.
+pub fn cancel_fraction(numerator: &SimplexPointer, denominator: &SimplexPointer) -> SimplexPointer {
+    let mut vars = variables(numerator);
+
+    for v in variables(denominator) {
+        if !vars.contains(&v) {
+            vars.push(v);
+        }
+    }
+
+    let (n, d) = match (SparsePolynomial::from_expression(numerator, &vars),
+                        SparsePolynomial::from_expression(denominator, &vars)) {
+        (Some(n), Some(d)) => (n, d),
+        _ => return over(numerator, denominator),
+    };
+
+    if d.is_zero() {
+        return over(numerator, denominator);
+    }
+
+    let g = gcd(&n.clear_denominators(), &d.clear_denominators());
+    let n = n.divide_exact(&g).unwrap();
+    let d = d.divide_exact(&g).unwrap();
+
+    let scale = Numeric::from_big_integer(n.common_denominator() * d.common_denominator());
+    let (n, d) = (n.scale(scale.clone()), d.scale(scale));
+
+    let content = n.integer_content().gcd(&d.integer_content());
+    let negative = d.leading_term().map_or(false, |(_, c)| *c < Numeric::LittleInteger(0));
+    let divisor = Numeric::from_big_integer(if negative { -content } else { content });
+
+    over(&n.scale(Numeric::LittleInteger(1) / divisor.clone()).to_expression(&vars),
+         &d.scale(Numeric::LittleInteger(1) / divisor).to_expression(&vars))
+}
+
+// Everything over a single common denominator, cancelled: 1/x + 1/y is
+// (x + y) / (x y).
+pub fn together(e: &SimplexPointer) -> SimplexPointer {
+    let (n, d) = combine(e);
+
+    if is_one(&d) {
+        return e.clone();
+    }
+
+    cancel_fraction(&n, &d)
+}
+
+// Common factors of each term's numerator and denominator divided out,
+// without combining terms.
+pub fn cancel(e: &SimplexPointer) -> SimplexPointer {
+    if is_head(e, "Plus") {
+        return sum(&e.get_parts().iter().map(cancel).collect::<Vec<SimplexPointer>>());
+    }
+
+    let (n, d) = fraction(e);
+
+    if is_one(&d) {
+        return e.clone();
+    }
+
+    cancel_fraction(&n, &d)
+}
+
+pub fn expand_numerator(e: &SimplexPointer) -> SimplexPointer {
+    if is_head(e, "Plus") {
+        return sum(&e.get_parts().iter().map(expand_numerator).collect::<Vec<SimplexPointer>>());
+    }
+
+    let (n, d) = fraction(e);
+    over(&expand(&n), &d)
+}
+
+pub fn expand_denominator(e: &SimplexPointer) -> SimplexPointer {
+    if is_head(e, "Plus") {
+        return sum(&e.get_parts().iter().map(expand_denominator).collect::<Vec<SimplexPointer>>());
+    }
+
+    let (n, d) = fraction(e);
+    over(&n, &expand(&d))
+}
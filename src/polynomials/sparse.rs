@@ -0,0 +1,389 @@
+use std::collections::BTreeMap;
+
+use num::{BigInt, Integer, Zero, One};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use arithmetic::terms::number;
+use polynomials::terms::{Monomial, monomials, collect_monomials, from_monomials};
+
+// Polynomials in a fixed list of variables with exact coefficients, one
+// entry per exponent vector that has a non-zero coefficient. Exponents
+// compare lexicographically, so the last entry is the leading term with the
+// first variable weighing most.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparsePolynomial {
+    arity: usize,
+    terms: BTreeMap<Vec<i64>, Numeric>,
+}
+
+// The numerator of an exact constant, 1 for anything else.
+fn numerator(c: Option<Numeric>) -> BigInt {
+    c.and_then(|c| c.to_big_rational()).map_or(BigInt::one(), |q| q.numer().clone())
+}
+
+impl SparsePolynomial {
+    pub fn zero(arity: usize) -> SparsePolynomial {
+        SparsePolynomial {
+            arity: arity,
+            terms: BTreeMap::new(),
+        }
+    }
+
+    pub fn constant(arity: usize, c: Numeric) -> SparsePolynomial {
+        let mut p = SparsePolynomial::zero(arity);
+        p.insert(vec![0; arity], c);
+        p
+    }
+
+    // None unless e is a polynomial in the variables with exact numbers
+    // for coefficients.
+    pub fn from_expression(e: &SimplexPointer, variables: &[SimplexPointer]) -> Option<SparsePolynomial> {
+        let mut p = SparsePolynomial::zero(variables.len());
+
+        for (exponents, c) in collect_monomials(&monomials(e, variables)) {
+            if exponents.iter().any(|&k| k < 0) {
+                return None;
+            }
+
+            match c.as_numeric() {
+                Some(n) if n.is_exact() => p.insert(exponents, n),
+                _ => return None,
+            }
+        }
+
+        Some(p)
+    }
+
+    pub fn to_expression(&self, variables: &[SimplexPointer]) -> SimplexPointer {
+        let terms = self.terms.iter()
+            .map(|(k, c)| (k.clone(), number(c.clone())))
+            .collect::<Vec<Monomial>>();
+
+        from_monomials(&terms, variables)
+    }
+
+    // Adds c to the coefficient of the exponents, dropping it at zero.
+    fn insert(&mut self, exponents: Vec<i64>, c: Numeric) {
+        let total = match self.terms.get(&exponents) {
+            Some(d) => d.clone() + c,
+            None => c,
+        };
+
+        if total.is_zero() {
+            self.terms.remove(&exponents);
+        } else {
+            self.terms.insert(exponents, total);
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn as_constant(&self) -> Option<Numeric> {
+        match self.terms.len() {
+            0 => Some(Numeric::LittleInteger(0)),
+            1 => self.terms.get(&vec![0; self.arity]).cloned(),
+            _ => None,
+        }
+    }
+
+    pub fn leading_term(&self) -> Option<(&Vec<i64>, &Numeric)> {
+        self.terms.iter().next_back()
+    }
+
+    pub fn add(&self, other: &SparsePolynomial) -> SparsePolynomial {
+        let mut p = self.clone();
+
+        for (k, c) in &other.terms {
+            p.insert(k.clone(), c.clone());
+        }
+
+        p
+    }
+
+    pub fn sub(&self, other: &SparsePolynomial) -> SparsePolynomial {
+        self.add(&other.scale(Numeric::LittleInteger(-1)))
+    }
+
+    pub fn scale(&self, c: Numeric) -> SparsePolynomial {
+        let mut p = SparsePolynomial::zero(self.arity);
+
+        for (k, d) in &self.terms {
+            p.insert(k.clone(), d.clone() * c.clone());
+        }
+
+        p
+    }
+
+    pub fn mul(&self, other: &SparsePolynomial) -> SparsePolynomial {
+        let mut p = SparsePolynomial::zero(self.arity);
+
+        for (a, c) in &self.terms {
+            for (b, d) in &other.terms {
+                p.insert(a.iter().zip(b.iter()).map(|(i, j)| i + j).collect(), c.clone() * d.clone());
+            }
+        }
+
+        p
+    }
+
+    // Multiplies by the monomial c x^k.
+    fn shift(&self, exponents: &[i64], c: Numeric) -> SparsePolynomial {
+        let mut p = SparsePolynomial::zero(self.arity);
+
+        for (k, d) in &self.terms {
+            p.insert(k.iter().zip(exponents.iter()).map(|(i, j)| i + j).collect(), d.clone() * c.clone());
+        }
+
+        p
+    }
+
+    pub fn degree(&self, variable: usize) -> i64 {
+        self.terms.keys().map(|k| k[variable]).max().unwrap_or(-1)
+    }
+
+    // The coefficient of x^n as a polynomial in the other variables.
+    pub fn coefficient(&self, variable: usize, n: i64) -> SparsePolynomial {
+        let mut p = SparsePolynomial::zero(self.arity);
+
+        for (k, c) in self.terms.iter().filter(|&(k, _)| k[variable] == n) {
+            let mut exponents = k.clone();
+            exponents[variable] = 0;
+            p.insert(exponents, c.clone());
+        }
+
+        p
+    }
+
+    pub fn leading_coefficient(&self, variable: usize) -> SparsePolynomial {
+        self.coefficient(variable, self.degree(variable))
+    }
+
+    pub fn derivative(&self, variable: usize) -> SparsePolynomial {
+        let mut p = SparsePolynomial::zero(self.arity);
+
+        for (k, c) in self.terms.iter().filter(|&(k, _)| k[variable] > 0) {
+            let mut exponents = k.clone();
+            exponents[variable] = exponents[variable] - 1;
+            p.insert(exponents, c.clone() * Numeric::LittleInteger(k[variable]));
+        }
+
+        p
+    }
+
+    // Division by leading terms: whatever the divisor's leading term does not
+    // divide goes to the remainder. With a number for the divisor's leading
+    // coefficient in the first variable this is ordinary long division in
+    // that variable.
+    pub fn divide(&self, divisor: &SparsePolynomial) -> Option<(SparsePolynomial, SparsePolynomial)> {
+        let (lead, lc) = match divisor.leading_term() {
+            Some((k, c)) => (k.clone(), c.clone()),
+            None => return None,
+        };
+
+        let mut quotient = SparsePolynomial::zero(self.arity);
+        let mut remainder = SparsePolynomial::zero(self.arity);
+        let mut rest = self.clone();
+
+        while let Some((k, c)) = rest.leading_term().map(|(k, c)| (k.clone(), c.clone())) {
+            if k.iter().zip(lead.iter()).all(|(i, j)| i >= j) {
+                let exponents = k.iter().zip(lead.iter()).map(|(i, j)| i - j).collect::<Vec<i64>>();
+                let factor = c / lc.clone();
+
+                quotient.insert(exponents.clone(), factor.clone());
+                rest = rest.sub(&divisor.shift(&exponents, factor));
+            } else {
+                remainder.insert(k.clone(), c);
+                rest.terms.remove(&k);
+            }
+        }
+
+        Some((quotient, remainder))
+    }
+
+    pub fn divide_exact(&self, divisor: &SparsePolynomial) -> Option<SparsePolynomial> {
+        match self.divide(divisor) {
+            Some((q, ref r)) if r.is_zero() => Some(q),
+            _ => None,
+        }
+    }
+
+    // lc^k p mod q in the variable, keeping integer coefficients integral.
+    pub fn pseudo_remainder(&self, divisor: &SparsePolynomial, variable: usize) -> SparsePolynomial {
+        let m = divisor.degree(variable);
+        let lc = divisor.leading_coefficient(variable);
+        let mut remainder = self.clone();
+
+        while !remainder.is_zero() && remainder.degree(variable) >= m {
+            let mut exponents = vec![0; self.arity];
+            exponents[variable] = remainder.degree(variable) - m;
+
+            let term = remainder.leading_coefficient(variable).shift(&exponents, Numeric::LittleInteger(1));
+            remainder = remainder.mul(&lc).sub(&term.mul(divisor));
+        }
+
+        remainder
+    }
+
+    pub fn common_denominator(&self) -> BigInt {
+        self.terms.values()
+            .filter_map(|c| c.to_big_rational())
+            .fold(BigInt::one(), |l, q| l.lcm(q.denom()))
+    }
+
+    // The gcd of the numerators, 0 for the zero polynomial.
+    pub fn integer_content(&self) -> BigInt {
+        self.terms.values()
+            .filter_map(|c| c.to_big_rational())
+            .fold(BigInt::zero(), |g, q| g.gcd(q.numer()))
+    }
+
+    // Multiplied through by the common denominator of the coefficients.
+    pub fn clear_denominators(&self) -> SparsePolynomial {
+        self.scale(Numeric::from_big_integer(self.common_denominator()))
+    }
+
+    // With a positive leading coefficient.
+    pub fn normalized(&self) -> SparsePolynomial {
+        match self.leading_term() {
+            Some((_, c)) if *c < Numeric::LittleInteger(0) => self.scale(Numeric::LittleInteger(-1)),
+            _ => self.clone(),
+        }
+    }
+
+    // The gcd of the coefficients in the variable, a polynomial in the others.
+    fn content(&self, variable: usize) -> SparsePolynomial {
+        (0..self.degree(variable) + 1)
+            .map(|n| self.coefficient(variable, n))
+            .fold(SparsePolynomial::zero(self.arity), |g, c| gcd(&g, &c))
+    }
+
+    fn main_variable(&self, other: &SparsePolynomial) -> Option<usize> {
+        (0..self.arity).find(|&i| self.degree(i) > 0 || other.degree(i) > 0)
+    }
+}
+
+// Greatest common divisor of polynomials with integer coefficients: the
+// contents recursively in the remaining variables, the primitive parts by
+// the primitive remainder sequence in the main variable.
+pub fn gcd(a: &SparsePolynomial, b: &SparsePolynomial) -> SparsePolynomial {
+    if a.is_zero() {
+        return b.normalized();
+    }
+
+    if b.is_zero() {
+        return a.normalized();
+    }
+
+    let i = match a.main_variable(b) {
+        Some(i) => i,
+        None => {
+            let (p, q) = (numerator(a.as_constant()), numerator(b.as_constant()));
+            return SparsePolynomial::constant(a.arity, Numeric::from_big_integer(p.gcd(&q)));
+        }
+    };
+
+    let ca = a.content(i);
+    let cb = b.content(i);
+    let c = gcd(&ca, &cb);
+
+    let mut f = a.divide_exact(&ca).unwrap();
+    let mut g = b.divide_exact(&cb).unwrap();
+
+    if f.degree(i) < g.degree(i) {
+        ::std::mem::swap(&mut f, &mut g);
+    }
+
+    while !g.is_zero() {
+        let r = f.pseudo_remainder(&g, i);
+        f = g;
+        g = if r.is_zero() { r } else { r.divide_exact(&r.content(i)).unwrap() };
+    }
+
+    let f = if f.degree(i) > 0 { f } else { SparsePolynomial::constant(a.arity, Numeric::LittleInteger(1)) };
+    f.mul(&c).normalized()
+}
+
+pub fn lcm(a: &SparsePolynomial, b: &SparsePolynomial) -> SparsePolynomial {
+    if a.is_zero() || b.is_zero() {
+        return SparsePolynomial::zero(a.arity);
+    }
+
+    a.divide_exact(&gcd(a, b)).unwrap().mul(b).normalized()
+}
+
+// The determinant of the Sylvester matrix, by fraction-free elimination.
+pub fn resultant(a: &SparsePolynomial, b: &SparsePolynomial, variable: usize) -> SparsePolynomial {
+    let m = a.degree(variable).max(0) as usize;
+    let n = b.degree(variable).max(0) as usize;
+    let size = m + n;
+    let zero = SparsePolynomial::zero(a.arity);
+    let one = SparsePolynomial::constant(a.arity, Numeric::LittleInteger(1));
+
+    if a.is_zero() || b.is_zero() {
+        return zero;
+    }
+
+    let mut matrix = vec![vec![zero.clone(); size]; size];
+
+    for row in 0..n {
+        for k in 0..m + 1 {
+            matrix[row][row + m - k] = a.coefficient(variable, k as i64);
+        }
+    }
+
+    for row in 0..m {
+        for k in 0..n + 1 {
+            matrix[n + row][row + n - k] = b.coefficient(variable, k as i64);
+        }
+    }
+
+    let mut negated = false;
+    let mut previous = one.clone();
+
+    for k in 0..size {
+        if matrix[k][k].is_zero() {
+            match (k + 1..size).find(|&r| !matrix[r][k].is_zero()) {
+                Some(r) => {
+                    matrix.swap(k, r);
+                    negated = !negated;
+                }
+                None => return zero,
+            }
+        }
+
+        for i in k + 1..size {
+            for j in k + 1..size {
+                let cross = matrix[i][j].mul(&matrix[k][k]).sub(&matrix[i][k].mul(&matrix[k][j]));
+                matrix[i][j] = cross.divide_exact(&previous).unwrap();
+            }
+        }
+
+        previous = matrix[k][k].clone();
+    }
+
+    let determinant = if size == 0 { one } else { previous };
+
+    if negated {
+        determinant.scale(Numeric::LittleInteger(-1))
+    } else {
+        determinant
+    }
+}
+
+// (-1)^(n (n - 1) / 2) Resultant[p, p'] / lc(p), None below degree one.
+pub fn discriminant(p: &SparsePolynomial, variable: usize) -> Option<SparsePolynomial> {
+    let n = p.degree(variable);
+
+    if n < 1 {
+        return None;
+    }
+
+    let r = resultant(p, &p.derivative(variable), variable);
+    let sign = if (n * (n - 1) / 2) % 2 == 0 { 1 } else { -1 };
+
+    r.divide_exact(&p.leading_coefficient(variable)).map(|d| d.scale(Numeric::LittleInteger(sign)))
+}
@@ -0,0 +1,141 @@
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+use expression::ordering::canonical_order;
+
+use evaluation::utilities::{integer, is_head};
+use arithmetic::terms::{number, operands, base_and_exponent};
+use arithmetic::canonical::{sum, product, power};
+use polynomials::expand::expand;
+
+// One term of an expanded polynomial: the exponent of each variable and
+// whatever multiplies them, which need not be a number.
+pub type Monomial = (Vec<i64>, SimplexPointer);
+
+// The exponent of the variable in a single factor, if it is the variable
+// or an integer power of it.
+fn exponent_of(factor: &SimplexPointer, variable: &SimplexPointer) -> Option<i64> {
+    if factor == variable {
+        return Some(1);
+    }
+
+    let (base, exponent) = base_and_exponent(factor);
+
+    if is_head(factor, "Power") && base == *variable {
+        exponent.as_integer()
+    } else {
+        None
+    }
+}
+
+// Expands e and splits every term into its powers of the variables and its
+// coefficient. Terms with equal exponents are not merged.
+pub fn monomials(e: &SimplexPointer, variables: &[SimplexPointer]) -> Vec<Monomial> {
+    operands(&expand(e), "Plus").iter()
+        .map(|term| {
+            let mut exponents = vec![0; variables.len()];
+            let mut coefficient = Vec::new();
+
+            for factor in operands(term, "Times") {
+                match variables.iter().position(|v| exponent_of(&factor, v).is_some()) {
+                    Some(i) => exponents[i] = exponents[i] + exponent_of(&factor, &variables[i]).unwrap(),
+                    None => coefficient.push(factor),
+                }
+            }
+
+            (exponents, product(&coefficient))
+        })
+        .filter(|&(_, ref c)| c.as_numeric() != Some(Numeric::LittleInteger(0)))
+        .collect()
+}
+
+// Sums the coefficients of equal exponents, in order of first appearance.
+pub fn collect_monomials(terms: &[Monomial]) -> Vec<Monomial> {
+    let mut collected: Vec<(Vec<i64>, Vec<SimplexPointer>)> = Vec::new();
+
+    for &(ref exponents, ref c) in terms {
+        match collected.iter().position(|&(ref e, _)| e == exponents) {
+            Some(i) => collected[i].1.push(c.clone()),
+            None => collected.push((exponents.clone(), vec![c.clone()])),
+        }
+    }
+
+    collected.into_iter()
+        .map(|(e, cs)| (e, sum(&cs)))
+        .filter(|&(_, ref c)| c.as_numeric() != Some(Numeric::LittleInteger(0)))
+        .collect()
+}
+
+pub fn from_monomials(terms: &[Monomial], variables: &[SimplexPointer]) -> SimplexPointer {
+    let products = terms.iter()
+        .map(|&(ref exponents, ref c)| {
+            let mut factors = vec![c.clone()];
+
+            for (v, &k) in variables.iter().zip(exponents.iter()) {
+                factors.push(power(v, &integer(k)));
+            }
+
+            product(&factors)
+        })
+        .collect::<Vec<SimplexPointer>>();
+
+    sum(&products)
+}
+
+// Coefficients of a polynomial in one variable, constant term first, None
+// unless every coefficient is an exact number and every exponent is natural.
+pub fn exact_coefficients(e: &SimplexPointer, variable: &SimplexPointer) -> Option<Vec<Numeric>> {
+    let terms = collect_monomials(&monomials(e, &[variable.clone()]));
+    let degree = terms.iter().map(|&(ref k, _)| k[0]).max().unwrap_or(0);
+
+    if terms.iter().any(|&(ref k, _)| k[0] < 0) {
+        return None;
+    }
+
+    let mut coefficients = vec![Numeric::LittleInteger(0); degree as usize + 1];
+
+    for &(ref k, ref c) in &terms {
+        match c.as_numeric() {
+            Some(n) if n.is_exact() => coefficients[k[0] as usize] = n,
+            _ => return None,
+        }
+    }
+
+    Some(coefficients)
+}
+
+pub fn from_coefficients(coefficients: &[Numeric], variable: &SimplexPointer) -> SimplexPointer {
+    let terms = coefficients.iter()
+        .enumerate()
+        .map(|(k, c)| product(&[number(c.clone()), power(variable, &integer(k as i64))]))
+        .collect::<Vec<SimplexPointer>>();
+
+    sum(&terms)
+}
+
+// The symbols and other non-numeric building blocks a polynomial is made of,
+// x and Sin[y] in x^2 Sin[y] + 3, in canonical order.
+pub fn variables(e: &SimplexPointer) -> Vec<SimplexPointer> {
+    let mut found: Vec<SimplexPointer> = Vec::new();
+
+    for term in operands(&expand(e), "Plus") {
+        for factor in operands(&term, "Times") {
+            if factor.as_numeric().is_some() {
+                continue;
+            }
+
+            let (base, exponent) = base_and_exponent(&factor);
+
+            let v = match exponent.as_integer() {
+                Some(n) if n > 0 && !is_head(&base, "Plus") => base,
+                _ => factor.clone(),
+            };
+
+            if !found.contains(&v) {
+                found.push(v);
+            }
+        }
+    }
+
+    found.sort_by(canonical_order);
+    found
+}
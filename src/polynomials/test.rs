@@ -0,0 +1,276 @@
+#[cfg(test)]
+mod tests {
+    mod test_expand {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_expands_products_and_powers() {
+            assert_eq!(run("Expand[(x + 1)^2]"), "Plus[1, Times[2, x], Power[x, 2]]");
+            assert_eq!(run("Expand[(x + y) (x - y)]"), "Plus[Power[x, 2], Times[-1, Power[y, 2]]]");
+            assert_eq!(run("Expand[(a + b)^3]"),
+                       "Plus[Power[a, 3], Times[3, a, Power[b, 2]], Times[3, Power[a, 2], b], Power[b, 3]]");
+            assert_eq!(run("Expand[2 (x + 1) y]"), "Plus[Times[2, x, y], Times[2, y]]");
+        }
+
+        #[test]
+        fn it_leaves_denominators_alone() {
+            assert_eq!(run("Expand[(x + 1)^2 / y]"),
+                       "Plus[Times[2, x, Power[y, -1]], Times[Power[x, 2], Power[y, -1]], Power[y, -1]]");
+            assert_eq!(run("Expand[1 / (x + 1)^2]"), "Power[Plus[1, x], -2]");
+            assert_eq!(run("ExpandAll[1 / (x + 1)^2]"), "Power[Plus[1, Times[2, x], Power[x, 2]], -1]");
+        }
+
+        #[test]
+        fn it_expands_inside_functions_with_expand_all() {
+            assert_eq!(run("Expand[f[(x + 1)^2]]"), "f[Power[Plus[1, x], 2]]");
+            assert_eq!(run("ExpandAll[f[(x + 1)^2]]"), "f[Plus[1, Times[2, x], Power[x, 2]]]");
+        }
+
+        #[test]
+        fn it_expands_large_powers() {
+            assert_eq!(run("Coefficient[Expand[(1 + x)^10], x, 5]"), "252");
+            assert_eq!(run("Coefficient[Expand[(x + 2)^70], x, 35]"), "3854691154260297639909943934976");
+            assert_eq!(run("Coefficient[Expand[(x + 2)^70], x, 0]"), "1180591620717411303424");
+        }
+    }
+
+    mod test_coefficients {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_finds_coefficients() {
+            assert_eq!(run("Coefficient[a x^2 + b x + c, x, 2]"), "a");
+            assert_eq!(run("Coefficient[a x^2 + b x + c, x]"), "b");
+            assert_eq!(run("Coefficient[a x^2 + b x + c, x, 0]"), "c");
+            assert_eq!(run("Coefficient[(x + y)^2, x y]"), "2");
+            assert_eq!(run("Coefficient[x^4 + 3 x^2, x^2, 2]"), "1");
+            assert_eq!(run("Coefficient[x y + 2 x, x]"), "Plus[2, y]");
+        }
+
+        #[test]
+        fn it_lists_coefficients() {
+            assert_eq!(run("CoefficientList[1 + 2 x^3, x]"), "List[1, 0, 0, 2]");
+            assert_eq!(run("CoefficientList[(1 + x) (1 + y), {x, y}]"), "List[List[1, 1], List[1, 1]]");
+            assert_eq!(run("CoefficientList[x + y^2, {x, y}]"), "List[List[0, 0, 1], List[1, 0, 0]]");
+            assert_eq!(run("CoefficientList[0, x]"), "List[]");
+        }
+
+        #[test]
+        fn it_finds_exponents() {
+            assert_eq!(run("Exponent[x^3 + x + 1, x]"), "3");
+            assert_eq!(run("Exponent[(x + 1)^5, x]"), "5");
+            assert_eq!(run("Exponent[x^3 + x, x, Min]"), "1");
+            assert_eq!(run("Exponent[x^3 + x, x, List]"), "List[1, 3]");
+            assert_eq!(run("Exponent[0, x]"), "DirectedInfinity[-1]");
+        }
+
+        #[test]
+        fn it_collects_by_variables() {
+            assert_eq!(run("Collect[a x + b x + c, x]"), "Plus[c, Times[x, Plus[a, b]]]");
+            assert_eq!(run("Collect[(x + y + 1)^2, x]"),
+                       "Plus[1, Power[x, 2], Times[x, Plus[2, Times[2, y]]], Times[2, y], Power[y, 2]]");
+            assert_eq!(run("Collect[a x + b x, x, h]"), "Times[x, h[Plus[a, b]]]");
+        }
+    }
+
+    mod test_factor {
+        use num::BigInt;
+        use polynomials::dense::square_free;
+        use polynomials::factor::{berlekamp, factor_polynomial};
+        use evaluation::testing::run;
+
+        fn integers(cs: &[i64]) -> Vec<BigInt> {
+            cs.iter().map(|&c| BigInt::from(c)).collect()
+        }
+
+        #[test]
+        fn it_finds_square_free_parts() {
+            // (x - 1)^2 (x + 2)
+            let factors = square_free(&integers(&[2, -3, 0, 1]));
+            assert_eq!(factors, vec![(integers(&[2, 1]), 1), (integers(&[-1, 1]), 2)]);
+        }
+
+        #[test]
+        fn it_factors_mod_p() {
+            // x^4 + 1 splits into two quadratics mod 3.
+            let factors = berlekamp(&[1, 0, 0, 0, 1], 3);
+            assert_eq!(factors.len(), 2);
+            assert!(factors.iter().all(|f| f.len() == 3));
+        }
+
+        #[test]
+        fn it_factors_over_the_integers() {
+            let (content, factors) = factor_polynomial(&integers(&[-6, 0, 6]));
+            assert_eq!(content, BigInt::from(6));
+            assert_eq!(factors.len(), 2);
+
+            // x^4 + 1 is irreducible over the integers despite splitting mod every prime.
+            assert_eq!(factor_polynomial(&integers(&[1, 0, 0, 0, 1])).1.len(), 1);
+        }
+
+        #[test]
+        fn it_factors_expressions() {
+            assert_eq!(run("Factor[x^2 - 1]"), "Times[Plus[-1, x], Plus[1, x]]");
+            assert_eq!(run("Factor[x^2 + 2 x + 1]"), "Power[Plus[1, x], 2]");
+            assert_eq!(run("Factor[2 x^2 - 2]"), "Times[2, Plus[-1, x], Plus[1, x]]");
+            assert_eq!(run("Factor[x^3 - x]"), "Times[x, Plus[-1, x], Plus[1, x]]");
+            assert_eq!(run("Factor[x^2 + 1]"), "Plus[1, Power[x, 2]]");
+            assert_eq!(run("Factor[6 x^2 + 5 x + 1]"), "Times[Plus[1, Times[2, x]], Plus[1, Times[3, x]]]");
+            assert_eq!(run("Factor[Expand[(x^2 + 2^40) (x - 3^30)]]"),
+                       "Times[Plus[-205891132094649, x], Plus[1099511627776, Power[x, 2]]]");
+            assert_eq!(run("Factor[Expand[(x + 2)^20]]"), "Power[Plus[2, x], 20]");
+        }
+
+        #[test]
+        fn it_factors_higher_degrees() {
+            assert_eq!(run("Factor[x^4 - 1]"), "Times[Plus[-1, x], Plus[1, x], Plus[1, Power[x, 2]]]");
+            assert_eq!(run("Factor[x^6 - 1]"),
+                       "Times[Plus[-1, x], Plus[1, x], Plus[1, x, Power[x, 2]], Plus[1, Times[-1, x], Power[x, 2]]]");
+            assert_eq!(run("Expand[Factor[Expand[(x^3 + 2 x + 7) (x^4 - 3 x + 1) (2 x - 5)^2]]]"),
+                       run("Expand[(x^3 + 2 x + 7) (x^4 - 3 x + 1) (2 x - 5)^2]"));
+            assert_eq!(run("Length[Factor[Expand[(x^3 + 2 x + 7) (x^4 - 3 x + 1) (2 x - 5)^2]]]"), "3");
+        }
+
+        #[test]
+        fn it_factors_rational_coefficients() {
+            assert_eq!(run("Factor[x^2/4 - 1]"), "Times[Rational[1, 4], Plus[-2, x], Plus[2, x]]");
+        }
+
+        #[test]
+        fn it_pulls_out_common_monomials() {
+            assert_eq!(run("Factor[2 x^2 y + 4 x y^2]"), "Times[2, x, y, Plus[x, Times[2, y]]]");
+        }
+
+        #[test]
+        fn it_leaves_non_polynomials_alone() {
+            assert_eq!(run("Factor[f[x]]"), "f[x]");
+            assert_eq!(run("Factor[7]"), "7");
+        }
+    }
+
+    mod test_sparse {
+        use expression::atom::numbers::number::Numeric;
+        use parsing::expression::parse;
+        use evaluation::utilities::symbol;
+        use polynomials::sparse::{SparsePolynomial, gcd, resultant};
+
+        fn sparse(s: &str) -> SparsePolynomial {
+            SparsePolynomial::from_expression(&parse(s).unwrap(), &[symbol("x"), symbol("y")]).unwrap()
+        }
+
+        #[test]
+        fn it_converts_exact_polynomials_only() {
+            let vars = [symbol("x")];
+
+            assert!(SparsePolynomial::from_expression(&parse("x^2 + 1/2").unwrap(), &vars).is_some());
+            assert!(SparsePolynomial::from_expression(&parse("x^2 + a").unwrap(), &vars).is_none());
+            assert!(SparsePolynomial::from_expression(&parse("1/x").unwrap(), &vars).is_none());
+            assert!(SparsePolynomial::from_expression(&parse("x + 0.5").unwrap(), &vars).is_none());
+        }
+
+        #[test]
+        fn it_divides_with_remainder() {
+            let (q, r) = sparse("x^3 + 2 x + 1").divide(&sparse("x - 1")).unwrap();
+
+            assert_eq!(q, sparse("x^2 + x + 3"));
+            assert_eq!(r, sparse("4"));
+            assert_eq!(sparse("x^2 - y^2").divide_exact(&sparse("x + y")), Some(sparse("x - y")));
+            assert_eq!(sparse("x^2 + 1").divide_exact(&sparse("x + 1")), None);
+        }
+
+        #[test]
+        fn it_finds_multivariate_gcds() {
+            assert_eq!(gcd(&sparse("x^2 y - y^3"), &sparse("x y + y^2")), sparse("x y + y^2"));
+            assert_eq!(gcd(&sparse("6 x + 6"), &sparse("4 x^2 - 4")), sparse("2 x + 2"));
+            assert_eq!(gcd(&sparse("x + 1"), &sparse("x + 2")), SparsePolynomial::constant(2, Numeric::LittleInteger(1)));
+        }
+
+        #[test]
+        fn it_computes_resultants() {
+            assert_eq!(resultant(&sparse("x^2 - 1"), &sparse("x - 1"), 0), sparse("0"));
+            assert_eq!(resultant(&sparse("x^2 + 1"), &sparse("x - y"), 0), sparse("y^2 + 1"));
+        }
+    }
+
+    mod test_polynomial_algebra {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_divides_polynomials() {
+            assert_eq!(run("PolynomialQuotient[x^3 + 2 x + 1, x - 1, x]"), "Plus[3, x, Power[x, 2]]");
+            assert_eq!(run("PolynomialRemainder[x^3 + 2 x + 1, x - 1, x]"), "4");
+            assert_eq!(run("PolynomialQuotient[x^2, 2 x + 1, x]"), "Plus[Rational[-1, 4], Times[Rational[1, 2], x]]");
+            assert_eq!(run("PolynomialRemainder[x^2 + y x + 1, x + y, x]"), "1");
+            assert_eq!(run("PolynomialQuotient[x^2, y x + 1, x]"), "PolynomialQuotient[Power[x, 2], Plus[1, Times[x, y]], x]");
+            assert_eq!(run("PolynomialRemainder[x, 0, x]"), "PolynomialRemainder[x, 0, x]");
+        }
+
+        #[test]
+        fn it_finds_gcds_and_lcms() {
+            assert_eq!(run("PolynomialGCD[x^2 - 1, x^2 + 2 x + 1]"), "Plus[1, x]");
+            assert_eq!(run("PolynomialGCD[x^2 - 1, x^3 - 1, x^2 - 3 x + 2]"), "Plus[-1, x]");
+            assert_eq!(run("PolynomialGCD[12, 18]"), "6");
+            assert_eq!(run("PolynomialGCD[x^2 y + x y^2, x^2 - y^2]"), "Plus[x, y]");
+            assert_eq!(run("PolynomialLCM[x^2 - 1, x + 1]"), "Plus[-1, Power[x, 2]]");
+            assert_eq!(run("PolynomialLCM[x, y]"), "Times[x, y]");
+            assert_eq!(run("PolynomialGCD[Sin[x], x]"), "1");
+        }
+
+        #[test]
+        fn it_computes_resultants_and_discriminants() {
+            assert_eq!(run("Resultant[x^2 - 2 x + 1, x - 1, x]"), "0");
+            assert_eq!(run("Resultant[x^2 + 1, x^2 - 1, x]"), "4");
+            assert_eq!(run("Resultant[x - a, x - b, x]"), "Plus[a, Times[-1, b]]");
+            assert_eq!(run("Discriminant[a x^2 + b x + c, x]"), "Plus[Times[-4, a, c], Power[b, 2]]");
+            assert_eq!(run("Discriminant[x^2 + 3 x + 1, x]"), "5");
+            assert_eq!(run("Discriminant[x^3 - x, x]"), "4");
+            assert_eq!(run("Discriminant[x^2 + y x + 1, x]"), "Plus[-4, Power[y, 2]]");
+        }
+    }
+
+    mod test_rational_functions {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_splits_fractions() {
+            assert_eq!(run("Numerator[3 x / (4 y^2)]"), "Times[3, x]");
+            assert_eq!(run("Denominator[3 x / (4 y^2)]"), "Times[4, Power[y, 2]]");
+            assert_eq!(run("Numerator[2/3]"), "2");
+            assert_eq!(run("Denominator[x + 1]"), "1");
+            assert_eq!(run("Denominator[x^(-1/2)]"), "Power[x, Rational[1, 2]]");
+        }
+
+        #[test]
+        fn it_combines_over_common_denominators() {
+            assert_eq!(run("Together[1/x + 1/y]"), "Times[Power[x, -1], Power[y, -1], Plus[x, y]]");
+            assert_eq!(run("Together[1/(x - 1) - 1/(x + 1)]"), "Times[2, Power[Plus[-1, Power[x, 2]], -1]]");
+            assert_eq!(run("Together[x/2 + 1/3]"), "Times[Rational[1, 6], Plus[2, Times[3, x]]]");
+            assert_eq!(run("Together[x/(x^2 - 1) + 1/(x^2 - 1)]"), "Power[Plus[-1, x], -1]");
+            assert_eq!(run("Together[(x + 1)^2]"), "Power[Plus[1, x], 2]");
+        }
+
+        #[test]
+        fn it_cancels_common_factors() {
+            assert_eq!(run("Cancel[(x^2 - 1)/(x - 1)]"), "Plus[1, x]");
+            assert_eq!(run("Cancel[(x^2 - y^2)/(x - y) + 1/x]"), "Plus[Power[x, -1], x, y]");
+            assert_eq!(run("Cancel[(2 x + 2)/(4 x^2 - 4)]"), "Power[Plus[-2, Times[2, x]], -1]");
+            assert_eq!(run("Cancel[x/(1 - x)]"), "Times[-1, x, Power[Plus[-1, x], -1]]");
+        }
+
+        #[test]
+        fn it_expands_numerators_and_denominators() {
+            assert_eq!(run("ExpandNumerator[(x + 1)^2/(y + 1)^2]"), "Times[Power[Plus[1, y], -2], Plus[1, Times[2, x], Power[x, 2]]]");
+            assert_eq!(run("ExpandDenominator[(x + 1)^2/(y + 1)^2]"), "Times[Power[Plus[1, x], 2], Power[Plus[1, Times[2, y], Power[y, 2]], -1]]");
+        }
+
+        #[test]
+        fn it_finds_partial_fractions() {
+            assert_eq!(run("Apart[1/(x^2 - 1)]"), "Plus[Times[Rational[1, 2], Power[Plus[-1, x], -1]], Times[Rational[-1, 2], Power[Plus[1, x], -1]]]");
+            assert_eq!(run("Apart[(x^3 + 1)/(x^2 + x), x]"), "Plus[-1, Power[x, -1], x]");
+            assert_eq!(run("Apart[1/(x (x + 1)^2)]"), "Plus[Power[x, -1], Times[-1, Power[Plus[1, x], -2]], Times[-1, Power[Plus[1, x], -1]]]");
+            assert_eq!(run("Apart[1/(x^3 - 1)]"), "Plus[Times[Rational[1, 3], Power[Plus[-1, x], -1]], Times[Plus[Rational[-2, 3], Times[Rational[-1, 3], x]], Power[Plus[1, x, Power[x, 2]], -1]]]");
+            assert_eq!(run("Apart[x^2 + 1]"), "Plus[1, Power[x, 2]]");
+            assert_eq!(run("Together[Apart[(3 x + 5)/(x^3 - x)]]"), run("Together[(3 x + 5)/(x^3 - x)]"));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::is_head;
+use polynomials::terms::variables;
+use solving::relations::{equal, unequal, order, and, or, not};
+use solving::solve::{solve, to_rules, reduce};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Equal" => Some(equal),
+        "Unequal" => Some(unequal),
+        "Less" => Some(less),
+        "Greater" => Some(greater),
+        "LessEqual" => Some(less_equal),
+        "GreaterEqual" => Some(greater_equal),
+        "And" => Some(and),
+        "Or" => Some(or),
+        "Not" => Some(not),
+        "Solve" => Some(solve_builtin),
+        "Reduce" => Some(reduce_builtin),
+        _ => None,
+    }
+}
+
+fn less(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    order(args, |o| o == Ordering::Less)
+}
+
+fn greater(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    order(args, |o| o == Ordering::Greater)
+}
+
+fn less_equal(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    order(args, |o| o != Ordering::Greater)
+}
+
+fn greater_equal(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    order(args, |o| o != Ordering::Less)
+}
+
+// Every symbol the sides of the equations are polynomial in, in order of
+// first appearance.
+fn unknowns(e: &SimplexPointer) -> Vec<SimplexPointer> {
+    let mut found: Vec<SimplexPointer> = Vec::new();
+
+    if e.is_atomic() {
+        return found;
+    }
+
+    for p in e.get_parts() {
+        let inner = if is_head(&p, "List") || is_head(&p, "And") || is_head(&p, "Equal") {
+            unknowns(&p)
+        } else {
+            variables(&p)
+        };
+
+        for v in inner {
+            if v.as_symbol().is_some() && !found.contains(&v) {
+                found.push(v);
+            }
+        }
+    }
+
+    found
+}
+
+fn symbols(e: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    let vars = if is_head(e, "List") { e.get_parts() } else { vec![e.clone()] };
+
+    if vars.iter().any(|v| v.as_symbol().is_none()) {
+        return None;
+    }
+
+    Some(vars)
+}
+
+// Solve[eqns, vars] as a list of rule lists, one for every solution;
+// Solve[eqns] for all the symbols in them.
+fn solve_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let vars = match args.len() {
+        1 => unknowns(&args[0]),
+        2 => symbols(&args[1])?,
+        _ => return None,
+    };
+
+    solve(&args[0], &vars).map(|s| to_rules(&s))
+}
+
+// Reduce[expr, x] for equations and linear inequalities in one variable.
+fn reduce_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 || args[1].as_symbol().is_none() {
+        return None;
+    }
+
+    reduce(&args[0], &args[1])
+}
@@ -0,0 +1,103 @@
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::{integer, negative, is_zero};
+use arithmetic::canonical::{sum, product, power};
+use polynomials::expand::expand;
+use polynomials::rational::{cancel_fraction, together};
+
+pub type Matrix = Vec<Vec<SimplexPointer>>;
+
+// a / b where b is known to divide a, as it always does in Bareiss' method.
+fn divide_exact(a: &SimplexPointer, b: &SimplexPointer) -> SimplexPointer {
+    if a.as_numeric().is_some() && b.as_numeric().is_some() {
+        product(&[a.clone(), power(b, &integer(-1))])
+    } else {
+        expand(&cancel_fraction(&expand(a), &expand(b)))
+    }
+}
+
+// Fraction-free Gaussian elimination (Bareiss) over the first `columns`
+// columns: every division is exact, so integer matrices stay integral and
+// polynomial ones polynomial. Gives the (row, column) of every pivot and
+// the number of row swaps made on the way.
+pub fn bareiss(rows: &mut Matrix, columns: usize) -> (Vec<(usize, usize)>, usize) {
+    let mut pivots = Vec::new();
+    let mut previous = integer(1);
+    let mut swaps = 0;
+    let mut r = 0;
+
+    for c in 0..columns {
+        if r == rows.len() {
+            break;
+        }
+
+        let found = match (r..rows.len()).find(|&i| !is_zero(&expand(&rows[i][c]))) {
+            Some(i) => i,
+            None => continue,
+        };
+
+        if found != r {
+            rows.swap(found, r);
+            swaps = swaps + 1;
+        }
+
+        let pivot = rows[r][c].clone();
+
+        for i in r + 1..rows.len() {
+            let factor = rows[i][c].clone();
+
+            for j in c + 1..rows[i].len() {
+                let cross = sum(&[product(&[pivot.clone(), rows[i][j].clone()]),
+                                  negative(product(&[factor.clone(), rows[r][j].clone()]))]);
+                rows[i][j] = divide_exact(&cross, &previous);
+            }
+
+            rows[i][c] = integer(0);
+        }
+
+        pivots.push((r, c));
+        previous = pivot;
+        r = r + 1;
+    }
+
+    (pivots, swaps)
+}
+
+// Solves a x = b for x by elimination and back substitution. Variables
+// without a pivot stay free, and the rest come out in terms of them as
+// (index, value) pairs; None when the system is inconsistent.
+pub fn solve_linear(a: &[Vec<SimplexPointer>], b: &[SimplexPointer], vars: &[SimplexPointer]) -> Option<Vec<(usize, SimplexPointer)>> {
+    let n = vars.len();
+
+    let mut rows = a.iter()
+        .zip(b.iter())
+        .map(|(row, rhs)| {
+            let mut augmented = row.clone();
+            augmented.push(rhs.clone());
+            augmented
+        })
+        .collect::<Matrix>();
+
+    let (pivots, _) = bareiss(&mut rows, n);
+
+    if rows[pivots.len()..].iter().any(|row| !is_zero(&expand(&row[n]))) {
+        return None;
+    }
+
+    let mut values: Vec<SimplexPointer> = vars.to_vec();
+    let mut solved = Vec::new();
+
+    for &(r, c) in pivots.iter().rev() {
+        let mut rest = vec![rows[r][n].clone()];
+
+        for j in c + 1..n {
+            rest.push(negative(product(&[rows[r][j].clone(), values[j].clone()])));
+        }
+
+        values[c] = together(&product(&[sum(&rest), power(&rows[r][c], &integer(-1))]));
+        solved.push((c, values[c].clone()));
+    }
+
+    solved.reverse();
+    Some(solved)
+}
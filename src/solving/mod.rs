@@ -0,0 +1,6 @@
+pub mod relations;
+pub mod linear;
+pub mod roots;
+pub mod solve;
+pub mod builtins;
+pub mod test;
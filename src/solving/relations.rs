@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::{expression, boolean};
+use numerical::constants::numeric_order;
+
+// Numbers and strings are values that can be told apart; symbols and
+// everything built from them may still turn out equal to something else.
+fn is_value(e: &SimplexPointer) -> bool {
+    e.is_atomic() && e.as_symbol().is_none()
+}
+
+fn compare(a: &SimplexPointer, b: &SimplexPointer) -> Option<Ordering> {
+    numeric_order(a, b)
+}
+
+fn is_true(e: &SimplexPointer) -> bool {
+    e.as_str() == "True"
+}
+
+fn is_false(e: &SimplexPointer) -> bool {
+    e.as_str() == "False"
+}
+
+// True when every neighbouring pair is identical or the same number, False
+// as soon as two values or numeric quantities differ, None while some pair
+// can't be told apart.
+pub fn equal(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let mut decided = true;
+
+    for pair in args.windows(2) {
+        if pair[0] == pair[1] || compare(&pair[0], &pair[1]) == Some(Ordering::Equal) {
+            continue;
+        }
+
+        if (is_value(&pair[0]) && is_value(&pair[1])) || compare(&pair[0], &pair[1]).is_some() {
+            return Some(boolean(false));
+        }
+
+        decided = false;
+    }
+
+    if decided { Some(boolean(true)) } else { None }
+}
+
+pub fn unequal(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    equal(args).map(|e| boolean(is_false(&e)))
+}
+
+// Less, Greater and the rest over numbers and numeric quantities such as
+// Pi, each neighbouring pair in turn.
+pub fn order<F>(args: &[SimplexPointer], holds: F) -> Option<SimplexPointer>
+    where F: Fn(Ordering) -> bool
+{
+    let mut decided = true;
+
+    for pair in args.windows(2) {
+        match compare(&pair[0], &pair[1]) {
+            Some(o) if !holds(o) => return Some(boolean(false)),
+            Some(_) => {}
+            None => decided = false,
+        }
+    }
+
+    if decided { Some(boolean(true)) } else { None }
+}
+
+// And drops every True and is False with any False; Or the other way round.
+fn connective(head_name: &str, args: &[SimplexPointer], unit: bool) -> Option<SimplexPointer> {
+    let (absorbing, neutral): (fn(&SimplexPointer) -> bool, fn(&SimplexPointer) -> bool) =
+        if unit { (is_false, is_true) } else { (is_true, is_false) };
+
+    if args.iter().any(absorbing) {
+        return Some(boolean(!unit));
+    }
+
+    let rest = args.iter().filter(|a| !neutral(a)).cloned().collect::<Vec<SimplexPointer>>();
+
+    match rest.len() {
+        0 => Some(boolean(unit)),
+        1 => Some(rest[0].clone()),
+        n if n < args.len() => Some(expression(head_name, &rest)),
+        _ => None,
+    }
+}
+
+pub fn and(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    connective("And", args, true)
+}
+
+pub fn or(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    connective("Or", args, false)
+}
+
+pub fn not(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    if is_true(&args[0]) || is_false(&args[0]) {
+        Some(boolean(is_false(&args[0])))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,192 @@
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{expression, integer, is_zero, ratio, half, negative, over};
+use arithmetic::terms::number;
+use arithmetic::canonical::{sum, product, power};
+use polynomials::expand::expand;
+use polynomials::rational::together;
+use polynomials::terms::{collect_monomials, monomials, exact_coefficients, from_coefficients};
+use polynomials::factor::factor_coefficients;
+use calculus::variables::free_of;
+
+fn sqrt(e: &SimplexPointer) -> SimplexPointer {
+    power(&expand(e), &half())
+}
+
+fn cbrt(e: &SimplexPointer) -> SimplexPointer {
+    power(&expand(e), &ratio(1, 3))
+}
+
+// Coefficients of p in x, constant term first, None unless p is a
+// polynomial in x with coefficients free of it.
+pub fn coefficients(p: &SimplexPointer, x: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    let terms = collect_monomials(&monomials(p, &[x.clone()]));
+
+    if terms.iter().any(|&(ref k, ref c)| k[0] < 0 || !free_of(c, x)) {
+        return None;
+    }
+
+    let degree = terms.iter().map(|&(ref k, _)| k[0]).max().unwrap_or(0);
+    let mut result = vec![integer(0); degree as usize + 1];
+
+    for &(ref k, ref c) in &terms {
+        result[k[0] as usize] = c.clone();
+    }
+
+    while result.len() > 1 && is_zero(&expand(result.last().unwrap())) {
+        result.pop();
+    }
+
+    Some(result)
+}
+
+fn quadratic(c: &[SimplexPointer]) -> Vec<SimplexPointer> {
+    let (a, b) = (&c[2], &c[1]);
+    let discriminant = sum(&[power(b, &integer(2)), product(&[integer(-4), a.clone(), c[0].clone()])]);
+    let root = sqrt(&discriminant);
+    let twice = product(&[integer(2), a.clone()]);
+
+    [-1, 1].iter()
+        .map(|&s| over(sum(&[negative(b.clone()), product(&[integer(s), root.clone()])]), &twice))
+        .collect()
+}
+
+// Cardano on the depressed t^3 + p t + q with x = t - b / (3 a), the three
+// cube roots of unity being 1 and (-1)^(2/3) to the first and second power.
+fn cubic(c: &[SimplexPointer]) -> Vec<SimplexPointer> {
+    let (a, b) = (&c[3], &c[2]);
+    let monic = |k: usize| over(c[k].clone(), a);
+    let (b1, c1, d1) = (monic(2), monic(1), monic(0));
+
+    let p = sum(&[c1.clone(), product(&[ratio(-1, 3), power(&b1, &integer(2))])]);
+    let q = sum(&[product(&[ratio(2, 27), power(&b1, &integer(3))]),
+                  product(&[ratio(-1, 3), b1.clone(), c1]),
+                  d1]);
+    let shift = negative(over(b.clone(), &product(&[integer(3), a.clone()])));
+    let omega = power(&integer(-1), &ratio(2, 3));
+
+    let u = if is_zero(&expand(&p)) {
+        cbrt(&negative(q.clone()))
+    } else {
+        let inner = sqrt(&sum(&[product(&[ratio(1, 4), power(&q, &integer(2))]),
+                                product(&[ratio(1, 27), power(&p, &integer(3))])]));
+        cbrt(&sum(&[product(&[ratio(-1, 2), q.clone()]), inner]))
+    };
+
+    (0..3)
+        .map(|k| {
+            let uk = product(&[power(&omega, &integer(k)), u.clone()]);
+            let vk = if is_zero(&expand(&p)) { integer(0) } else { negative(over(p.clone(), &product(&[integer(3), uk.clone()]))) };
+            expand(&sum(&[shift.clone(), uk, vk]))
+        })
+        .collect()
+}
+
+// Ferrari on the depressed y^4 + p y^2 + q y + r with x = y - b / (4 a),
+// through a non-zero root m of the resolvent 8 m^3 + 8 p m^2 + (2 p^2 - 8 r) m - q^2.
+fn quartic(c: &[SimplexPointer]) -> Option<Vec<SimplexPointer>> {
+    let a = &c[4];
+    let monic = |k: usize| over(c[k].clone(), a);
+    let (b1, c1, d1, e1) = (monic(3), monic(2), monic(1), monic(0));
+
+    let p = expand(&sum(&[c1.clone(), product(&[ratio(-3, 8), power(&b1, &integer(2))])]));
+    let q = expand(&sum(&[product(&[ratio(1, 8), power(&b1, &integer(3))]),
+                          product(&[ratio(-1, 2), b1.clone(), c1.clone()]),
+                          d1.clone()]));
+    let r = expand(&sum(&[product(&[ratio(-3, 256), power(&b1, &integer(4))]),
+                          product(&[ratio(1, 16), power(&b1, &integer(2)), c1]),
+                          product(&[ratio(-1, 4), b1.clone(), d1]),
+                          e1]));
+    let shift = product(&[ratio(-1, 4), b1]);
+
+    // Biquadratic: y^2 is a root of z^2 + p z + r.
+    let ys = if is_zero(&expand(&q)) {
+        quadratic(&[r, p, integer(1)])
+            .iter()
+            .flat_map(|z| vec![negative(sqrt(z)), sqrt(z)])
+            .collect::<Vec<SimplexPointer>>()
+    } else {
+        let resolvent = vec![negative(power(&q, &integer(2))),
+                             sum(&[product(&[integer(2), power(&p, &integer(2))]), product(&[integer(-8), r])]),
+                             product(&[integer(8), p.clone()]),
+                             integer(8)];
+
+        let m = solve_coefficients(&resolvent)?.into_iter().find(|m| !is_zero(&expand(m)))?;
+        let s = sqrt(&product(&[integer(2), m.clone()]));
+        let base = sum(&[product(&[integer(2), p]), product(&[integer(2), m.clone()])]);
+
+        let mut ys = Vec::new();
+
+        for &s1 in [-1, 1].iter() {
+            let tilt = product(&[integer(s1), sqrt(&integer(2)), q.clone(), power(&m, &ratio(-1, 2))]);
+            let inner = sqrt(&negative(sum(&[base.clone(), tilt])));
+
+            for &s2 in [-1, 1].iter() {
+                ys.push(product(&[ratio(1, 2), sum(&[product(&[integer(s1), s.clone()]), product(&[integer(s2), inner.clone()])])]));
+            }
+        }
+
+        ys
+    };
+
+    Some(ys.iter().map(|y| expand(&sum(&[shift.clone(), y.clone()]))).collect())
+}
+
+// The roots of c0 + c1 x + ... by formula, up to the fourth degree.
+fn solve_coefficients(c: &[SimplexPointer]) -> Option<Vec<SimplexPointer>> {
+    match c.len() {
+        1 => Some(Vec::new()),
+        2 => Some(vec![together(&negative(over(c[0].clone(), &c[1])))]),
+        3 => Some(quadratic(c)),
+        4 => Some(cubic(c)),
+        5 => quartic(c),
+        _ => None,
+    }
+}
+
+// Root[f, k] for the k-th root of an irreducible polynomial past the fourth
+// degree, f the polynomial as a pure function of its slot.
+fn root_objects(g: &[Numeric]) -> Vec<SimplexPointer> {
+    let f = expression("Function", &[from_coefficients(g, &expression("Slot", &[integer(1)]))]);
+    (1..g.len() as i64).map(|k| expression("Root", &[f.clone(), integer(k)])).collect()
+}
+
+// Exact coefficients factor first, so that rational roots come out as they
+// are and only the irreducible factors go through the formulas.
+fn exact_roots(c: &[Numeric]) -> Option<Vec<SimplexPointer>> {
+    let (_, factors) = factor_coefficients(c)?;
+    let mut roots = Vec::new();
+
+    for (g, multiplicity) in factors {
+        let found = if g.len() > 5 {
+            root_objects(&g)
+        } else {
+            solve_coefficients(&g.iter().cloned().map(number).collect::<Vec<SimplexPointer>>())?
+        };
+
+        for _ in 0..multiplicity {
+            roots.extend(found.iter().cloned());
+        }
+    }
+
+    // Rational roots in increasing order ahead of the rest.
+    let (mut rational, rest): (Vec<SimplexPointer>, Vec<SimplexPointer>) =
+        roots.into_iter().partition(|r| r.as_numeric().is_some());
+
+    rational.sort_by(|a, b| a.as_numeric().partial_cmp(&b.as_numeric()).unwrap());
+    rational.extend(rest);
+    Some(rational)
+}
+
+// Every root of the polynomial p in x, repeated by multiplicity; None past
+// the fourth degree unless the coefficients are exact numbers.
+pub fn polynomial_roots(p: &SimplexPointer, x: &SimplexPointer) -> Option<Vec<SimplexPointer>> {
+    if let Some(c) = exact_coefficients(p, x) {
+        if c.len() > 1 {
+            return exact_roots(&c);
+        }
+    }
+
+    solve_coefficients(&coefficients(p, x)?)
+}
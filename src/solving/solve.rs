@@ -0,0 +1,298 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::dispatch::evaluate;
+use evaluation::utilities::{expression, list, integer, symbol, boolean, is_head, is_zero};
+use arithmetic::terms::number;
+use arithmetic::canonical::{sum, product};
+use polynomials::expand::expand;
+use polynomials::rational::{fraction, together};
+use polynomials::terms::monomials;
+use calculus::variables::{free_of, substitute};
+use solving::linear::solve_linear;
+use solving::roots::{coefficients, polynomial_roots};
+
+pub type Solution = Vec<(SimplexPointer, SimplexPointer)>;
+
+const INEQUALITIES: &'static [&'static str] = &["Less", "Greater", "LessEqual", "GreaterEqual", "Unequal"];
+
+// lhs == rhs as the numerator of lhs - rhs over a common denominator, and
+// the denominator, which no solution may make vanish.
+fn normalize(lhs: &SimplexPointer, rhs: &SimplexPointer) -> (SimplexPointer, SimplexPointer) {
+    let difference = sum(&[lhs.clone(), product(&[integer(-1), rhs.clone()])]);
+    let (n, d) = fraction(&together(&evaluate(&difference)));
+
+    (expand(&n), d)
+}
+
+// The equations and the other conditions out of a List or And of them; None
+// for anything that is neither.
+fn constraints(e: &SimplexPointer, equations: &mut Vec<(SimplexPointer, SimplexPointer)>, conditions: &mut Vec<SimplexPointer>) -> Option<()> {
+    if is_head(e, "List") || is_head(e, "And") {
+        for p in e.get_parts() {
+            constraints(&p, equations, conditions)?;
+        }
+    } else if is_head(e, "Equal") {
+        let parts = e.get_parts();
+
+        for pair in parts.windows(2) {
+            equations.push(normalize(&pair[0], &pair[1]));
+        }
+    } else if e.as_str() == "False" {
+        equations.push((integer(1), integer(1)));
+    } else if !e.is_atomic() && INEQUALITIES.contains(&e.head_name().as_str()) {
+        conditions.push(e.clone());
+    } else if e.as_str() != "True" {
+        return None;
+    }
+
+    Some(())
+}
+
+fn is_linear(p: &SimplexPointer, vars: &[SimplexPointer]) -> bool {
+    monomials(p, vars).iter().all(|&(ref k, ref c)| k.iter().sum::<i64>() <= 1 && k.iter().all(|&e| e >= 0) && vars.iter().all(|v| free_of(c, v)))
+}
+
+fn occurring(p: &SimplexPointer, vars: &[SimplexPointer]) -> Vec<SimplexPointer> {
+    vars.iter().filter(|v| !free_of(p, v)).cloned().collect()
+}
+
+fn replace(e: &SimplexPointer, x: &SimplexPointer, value: &SimplexPointer) -> SimplexPointer {
+    evaluate(&substitute(e, x, value))
+}
+
+fn linear_system(polys: &[SimplexPointer], vars: &[SimplexPointer]) -> Option<Vec<Solution>> {
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+
+    for p in polys {
+        let mut row = vec![integer(0); vars.len()];
+        let mut constant = Vec::new();
+
+        for (k, c) in monomials(p, vars) {
+            match k.iter().position(|&e| e == 1) {
+                Some(j) => row[j] = sum(&[row[j].clone(), c]),
+                None => constant.push(product(&[integer(-1), c])),
+            }
+        }
+
+        a.push(row);
+        b.push(sum(&constant));
+    }
+
+    Some(match solve_linear(&a, &b, vars) {
+        Some(values) => vec![values.into_iter().map(|(j, v)| (vars[j].clone(), v)).collect()],
+        None => Vec::new(),
+    })
+}
+
+// x = value put into the remaining equations, which are then solved for the
+// remaining variables; value itself is finished off with their solutions.
+fn eliminate(x: &SimplexPointer, values: &[SimplexPointer], polys: &[SimplexPointer], vars: &[SimplexPointer]) -> Option<Vec<Solution>> {
+    let rest = vars.iter().filter(|v| *v != x).cloned().collect::<Vec<SimplexPointer>>();
+    let mut solutions = Vec::new();
+
+    for value in values {
+        let reduced = polys.iter()
+            .map(|p| expand(&fraction(&together(&replace(p, x, value))).0))
+            .collect::<Vec<SimplexPointer>>();
+
+        for mut s in system(&reduced, &rest)? {
+            let finished = s.iter().fold(value.clone(), |v, &(ref y, ref w)| replace(&v, y, w));
+            s.insert(0, (x.clone(), finished));
+            solutions.push(s);
+        }
+    }
+
+    Some(solutions)
+}
+
+// Polynomial equations p == 0: linear systems by elimination all at once,
+// otherwise one variable after the other, first from any equation in that
+// variable alone and then from any equation linear in it.
+fn system(polys: &[SimplexPointer], vars: &[SimplexPointer]) -> Option<Vec<Solution>> {
+    let polys = polys.iter().filter(|p| !is_zero(&expand(p))).cloned().collect::<Vec<SimplexPointer>>();
+
+    if polys.is_empty() {
+        return Some(vec![Vec::new()]);
+    }
+
+    for p in &polys {
+        if occurring(p, vars).is_empty() {
+            return match p.as_numeric() {
+                Some(_) => Some(Vec::new()),
+                None => None,
+            };
+        }
+    }
+
+    if polys.iter().all(|p| is_linear(p, vars)) {
+        return linear_system(&polys, vars);
+    }
+
+    for (i, p) in polys.iter().enumerate() {
+        let present = occurring(p, vars);
+
+        if present.len() == 1 {
+            let roots = polynomial_roots(p, &present[0])?;
+            let others = polys.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, q)| q.clone()).collect::<Vec<SimplexPointer>>();
+
+            return eliminate(&present[0], &roots, &others, vars);
+        }
+    }
+
+    for (i, p) in polys.iter().enumerate() {
+        for x in occurring(p, vars) {
+            match coefficients(p, &x) {
+                Some(ref c) if c.len() == 2 => {
+                    let value = together(&product(&[integer(-1), c[0].clone(), expression("Power", &[c[1].clone(), integer(-1)])]));
+                    let others = polys.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, q)| q.clone()).collect::<Vec<SimplexPointer>>();
+
+                    return eliminate(&x, &[evaluate(&value)], &others, vars);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    None
+}
+
+fn apply(e: &SimplexPointer, s: &Solution) -> SimplexPointer {
+    s.iter().fold(e.clone(), |e, &(ref x, ref v)| replace(&e, x, v))
+}
+
+// The solutions of the equations among the constraints, less any that make
+// a denominator vanish or a condition False; None where it can't solve them.
+pub fn solve(e: &SimplexPointer, vars: &[SimplexPointer]) -> Option<Vec<Solution>> {
+    let mut equations = Vec::new();
+    let mut conditions = Vec::new();
+
+    constraints(e, &mut equations, &mut conditions)?;
+
+    let polys = equations.iter().map(|&(ref n, _)| n.clone()).collect::<Vec<SimplexPointer>>();
+    let solutions = system(&polys, vars)?;
+
+    Some(solutions.into_iter()
+        .filter(|s| equations.iter().all(|&(_, ref d)| !is_zero(&expand(&apply(d, s)))))
+        .filter(|s| conditions.iter().all(|c| apply(c, s).as_str() != "False"))
+        .collect())
+}
+
+pub fn to_rules(solutions: &[Solution]) -> SimplexPointer {
+    let rules = solutions.iter()
+        .map(|s| list(&s.iter().map(|&(ref x, ref v)| expression("Rule", &[x.clone(), v.clone()])).collect::<Vec<SimplexPointer>>()))
+        .collect::<Vec<SimplexPointer>>();
+
+    list(&rules)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Side {
+    Below,
+    Above,
+}
+
+// A bound on x from a x + b against 0, strict or not.
+struct Bound {
+    value: Numeric,
+    strict: bool,
+}
+
+fn tighter(current: Option<Bound>, next: Bound, side: Side) -> Option<Bound> {
+    match current {
+        None => Some(next),
+        Some(c) => {
+            let replaces = match side {
+                Side::Above => next.value > c.value || (next.value == c.value && next.strict),
+                Side::Below => next.value < c.value || (next.value == c.value && next.strict),
+            };
+
+            Some(if replaces { next } else { c })
+        }
+    }
+}
+
+fn relation(strict: bool, lower: bool) -> &'static str {
+    match (strict, lower) {
+        (true, true) => "Greater",
+        (false, true) => "GreaterEqual",
+        (true, false) => "Less",
+        (false, false) => "LessEqual",
+    }
+}
+
+// Linear inequalities in a single variable narrowed down to an interval.
+fn interval(conditions: &[SimplexPointer], x: &SimplexPointer) -> Option<SimplexPointer> {
+    let mut lower: Option<Bound> = None;
+    let mut upper: Option<Bound> = None;
+
+    for c in conditions {
+        let name = c.head_name();
+
+        if c.get_length() != 2 || name == "Unequal" {
+            return None;
+        }
+
+        let (n, d) = normalize(&c.get_part(1).unwrap(), &c.get_part(2).unwrap());
+        let k = coefficients(&n, x)?;
+
+        if d.as_integer() != Some(1) || k.len() != 2 {
+            return None;
+        }
+
+        let (b, a) = (k[0].as_numeric()?, k[1].as_numeric()?);
+        let value = (Numeric::LittleInteger(0) - b) / a.clone();
+        let strict = name == "Less" || name == "Greater";
+
+        // a x + b < 0 bounds x above for positive a, below for negative a.
+        let below = (name == "Less" || name == "LessEqual") == (a > Numeric::LittleInteger(0));
+        let bound = Bound { value: value, strict: strict };
+
+        if below {
+            upper = tighter(upper, bound, Side::Below);
+        } else {
+            lower = tighter(lower, bound, Side::Above);
+        }
+    }
+
+    Some(match (lower, upper) {
+        (None, None) => boolean(true),
+        (Some(l), None) => expression(relation(l.strict, true), &[x.clone(), number(l.value)]),
+        (None, Some(u)) => expression(relation(u.strict, false), &[x.clone(), number(u.value)]),
+        (Some(l), Some(u)) => {
+            if l.value > u.value || (l.value == u.value && (l.strict || u.strict)) {
+                boolean(false)
+            } else if l.value == u.value {
+                expression("Equal", &[x.clone(), number(l.value)])
+            } else if l.strict == u.strict {
+                expression(relation(l.strict, false), &[number(l.value), x.clone(), number(u.value)])
+            } else {
+                expression("Inequality", &[number(l.value), symbol(relation(l.strict, false)), x.clone(),
+                                           symbol(relation(u.strict, false)), number(u.value)])
+            }
+        }
+    })
+}
+
+// Reduce for a single variable: equations become an Or of x == value, and
+// linear inequalities on their own an interval.
+pub fn reduce(e: &SimplexPointer, x: &SimplexPointer) -> Option<SimplexPointer> {
+    let mut equations = Vec::new();
+    let mut conditions = Vec::new();
+
+    constraints(e, &mut equations, &mut conditions)?;
+
+    if equations.is_empty() {
+        return interval(&conditions, x);
+    }
+
+    let solutions = solve(e, &[x.clone()])?;
+
+    let alternatives = solutions.iter()
+        .filter_map(|s| s.first().map(|&(ref y, ref v)| expression("Equal", &[y.clone(), v.clone()])))
+        .collect::<Vec<SimplexPointer>>();
+
+    Some(evaluate(&expression("Or", &alternatives)))
+}
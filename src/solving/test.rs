@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    mod test_relations {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_decides_equality() {
+            assert_eq!(run("1 == 1"), "True");
+            assert_eq!(run("x == x"), "True");
+            assert_eq!(run("1 == 2"), "False");
+            assert_eq!(run("x == y"), "Equal[x, y]");
+            assert_eq!(run("1 != 2"), "True");
+        }
+
+        #[test]
+        fn it_orders_numbers() {
+            assert_eq!(run("1 < 2 < 3"), "True");
+            assert_eq!(run("2 <= 1"), "False");
+            assert_eq!(run("3 >= 3"), "True");
+            assert_eq!(run("x < 1"), "Less[x, 1]");
+        }
+
+        #[test]
+        fn it_simplifies_connectives() {
+            assert_eq!(run("True && x"), "x");
+            assert_eq!(run("False && x"), "False");
+            assert_eq!(run("False || x"), "x");
+            assert_eq!(run("Not[True]"), "False");
+        }
+    }
+
+    mod test_solve {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_solves_linear_systems() {
+            assert_eq!(run("Solve[2 x + 3 == 7, x]"), "List[List[Rule[x, 2]]]");
+            assert_eq!(run("Solve[{x + y == 3, x - y == 1}, {x, y}]"), "List[List[Rule[x, 2], Rule[y, 1]]]");
+            assert_eq!(run("Solve[{x + y == 3, 2 x + 2 y == 6}, {x, y}]"), "List[List[Rule[x, Plus[3, Times[-1, y]]]]]");
+            assert_eq!(run("Solve[{x + y == 3, x + y == 4}, {x, y}]"), "List[]");
+            assert_eq!(run("Solve[a x + b == 0, x]"), "List[List[Rule[x, Times[-1, Power[a, -1], b]]]]");
+        }
+
+        #[test]
+        fn it_solves_polynomials_in_closed_form() {
+            assert_eq!(run("Solve[x^2 == 4, x]"), "List[List[Rule[x, -2]], List[Rule[x, 2]]]");
+            assert_eq!(run("Solve[x^3 - 6 x^2 + 11 x - 6 == 0, x]"), "List[List[Rule[x, 1]], List[Rule[x, 2]], List[Rule[x, 3]]]");
+            assert_eq!(run("Solve[x^3 == 2, x]"),
+                       "List[List[Rule[x, Power[2, Rational[1, 3]]]], \
+                        List[Rule[x, Times[Power[-1, Rational[2, 3]], Power[2, Rational[1, 3]]]]], \
+                        List[Rule[x, Times[Power[-1, Rational[4, 3]], Power[2, Rational[1, 3]]]]]]");
+            assert_eq!(run("Solve[x^4 - 5 x^2 + 6 == 0, x]"),
+                       "List[List[Rule[x, Times[-1, Power[2, Rational[1, 2]]]]], List[Rule[x, Power[2, Rational[1, 2]]]], \
+                        List[Rule[x, Times[-1, Power[3, Rational[1, 2]]]]], List[Rule[x, Power[3, Rational[1, 2]]]]]");
+        }
+
+        #[test]
+        fn it_gives_root_objects_past_the_fourth_degree() {
+            assert_eq!(run("Solve[x^5 + x + 3 == 0, x][[1]]"), "List[Rule[x, Root[Function[Plus[3, Slot[1], Power[Slot[1], 5]]], 1]]]");
+            assert_eq!(run("Length[Solve[x^5 + x + 3 == 0, x]]"), "5");
+        }
+
+        #[test]
+        fn it_solves_nonlinear_systems() {
+            assert_eq!(run("Solve[{x + y == 3, x y == 2}, {x, y}]"), "List[List[Rule[x, 2], Rule[y, 1]], List[Rule[x, 1], Rule[y, 2]]]");
+            assert_eq!(run("Solve[x^2 - 5 x + 6 == 0]"), "List[List[Rule[x, 2]], List[Rule[x, 3]]]");
+        }
+
+        #[test]
+        fn it_drops_spurious_solutions() {
+            assert_eq!(run("Solve[1/x == 2, x]"), "List[List[Rule[x, Rational[1, 2]]]]");
+            assert_eq!(run("Solve[x^2 == 1 && x > 0, x]"), "List[List[Rule[x, 1]]]");
+        }
+    }
+
+    mod test_reduce {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_reduces_linear_inequalities() {
+            assert_eq!(run("Reduce[2 x + 1 < 5, x]"), "Less[x, 2]");
+            assert_eq!(run("Reduce[x > 1 && x <= 3, x]"), "Inequality[1, Less, x, LessEqual, 3]");
+            assert_eq!(run("Reduce[x > 3 && x < 1, x]"), "False");
+        }
+
+        #[test]
+        fn it_reduces_equations_to_alternatives() {
+            assert_eq!(run("Reduce[x^2 == 9, x]"), "Or[Equal[x, -3], Equal[x, 3]]");
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use decimal::d128;
+use num::{BigInt, Zero};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{expression, integer, symbol, is_zero};
+use arithmetic::terms::number;
+use arithmetic::canonical::{product, power};
+use numerical::real;
+use numerical::complex::Complex;
+use numerical::precise::{self, GUARD, approximate, power_of_ten, quotient, multiply, divide, rescale, from_integer, from_numeric};
+use special::combinatorial::factorial;
+use special::gamma::gamma;
+
+// Past this the series would need more digits than it is worth.
+const ARGUMENT_LIMIT: i64 = 1000;
+
+// Bessel functions at zero, and the orders 1/2 and -1/2, at which they are
+// elementary: Sqrt[2 / (Pi x)] times a sine or a cosine.
+pub fn exact_bessel(name: &str, nu: Numeric, x: &SimplexPointer) -> Option<SimplexPointer> {
+    if is_zero(x) {
+        return match (name, nu.as_rational()?) {
+            ("BesselJ", (0, 1)) => Some(integer(1)),
+            ("BesselJ", (_, 1)) => Some(integer(0)),
+            ("BesselY", (0, 1)) => Some(product(&[integer(-1), symbol("Infinity")])),
+            ("BesselY", (_, 1)) => Some(symbol("ComplexInfinity")),
+            _ => None,
+        };
+    }
+
+    let scale = power(&product(&[integer(2), power(&product(&[symbol("Pi"), x.clone()]), &integer(-1))]), &number(Numeric::ratio(1, 2)));
+    let (sine, cosine) = (expression("Sin", &[x.clone()]), expression("Cos", &[x.clone()]));
+
+    match (name, nu.as_rational()?) {
+        ("BesselJ", (1, 2)) | ("BesselY", (-1, 2)) => Some(product(&[scale, sine])),
+        ("BesselJ", (-1, 2)) => Some(product(&[scale, cosine])),
+        ("BesselY", (1, 2)) => Some(product(&[integer(-1), scale, cosine])),
+        _ => None,
+    }
+}
+
+fn integral_order(nu: &Numeric) -> Option<i64> {
+    match *nu {
+        Numeric::LittleInteger(n) => Some(n),
+        Numeric::LittleReal(r) if r.is_finite() && r.abs() < d128::from(ARGUMENT_LIMIT) && r.quantize(real::one()) == r => {
+            let n: i32 = r.quantize(real::one()).into();
+            Some(n as i64)
+        }
+        _ => None,
+    }
+}
+
+// The digits the series loses to cancellation, its largest term being
+// about Exp[|x|].
+fn cancellation(x: &Numeric) -> Option<u32> {
+    let size = x.to_d128().abs();
+
+    if size > d128::from(ARGUMENT_LIMIT) {
+        return None;
+    }
+
+    let digits: i32 = (size * real::real("0.44")).quantize(real::one()).into();
+    Some(digits as u32 + 1)
+}
+
+// The terms (-1)^k y^(2k) / (k! (nu + 1) ... (nu + k)) times first, for nu
+// and y = x/2 at precision q, down to the first one that vanishes.
+fn terms(nu: &BigInt, y: &BigInt, first: BigInt, q: u32) -> Vec<BigInt> {
+    let square = multiply(y, y, q);
+    let mut term = first;
+    let mut terms = vec![];
+    let mut k = 0;
+
+    while !term.is_zero() {
+        terms.push(term.clone());
+        k = k + 1;
+
+        let denominator = (nu + from_integer(k, q)) * BigInt::from(k);
+        term = -divide(&multiply(&term, &square, q), &denominator, q);
+    }
+
+    terms
+}
+
+// y^n / n! at precision q.
+fn leading(y: &BigInt, n: i64, q: u32) -> BigInt {
+    (1..n + 1).fold(power_of_ten(q), |t, k| quotient(&multiply(&t, y, q), &BigInt::from(k)))
+}
+
+fn half(x: &Numeric, q: u32) -> Option<BigInt> {
+    Some(quotient(&from_numeric(x, q)?, &BigInt::from(2)))
+}
+
+// BesselJ[n, x] for n >= 0 at precision p.
+fn integer_j(n: i64, x: &Numeric, p: u32) -> Option<BigInt> {
+    let q = p + GUARD + cancellation(x)?;
+    let y = half(x, q)?;
+    let total = terms(&from_integer(n, q), &y, leading(&y, n, q), q).iter().fold(BigInt::zero(), |t, a| t + a);
+
+    Some(rescale(&total, q, p))
+}
+
+// BesselY[n, x] for n >= 0 and x > 0 at precision p,
+// (2 / Pi) BesselJ[n, x] (Log[x/2] + EulerGamma)
+//   - (1 / Pi) Sum[(n - k - 1)! / k! (x/2)^(2k - n), {k, 0, n - 1}]
+//   - (1 / Pi) Sum[t[k] (H[k] + H[n + k]), {k, 0, Infinity}]
+// with t[k] the terms of BesselJ and H[k] the harmonic numbers.
+fn integer_y(n: i64, x: &Numeric, p: u32) -> Option<BigInt> {
+    let q = p + GUARD + cancellation(x)?;
+    let y = half(x, q)?;
+    let one = power_of_ten(q);
+    let ts = terms(&from_integer(n, q), &y, leading(&y, n, q), q);
+    let j = ts.iter().fold(BigInt::zero(), |t, a| t + a);
+
+    let logarithm = precise::ln(&y, q)? + precise::euler_gamma(q)?;
+    let first = multiply(&j, &logarithm, q) * 2;
+
+    let square = multiply(&y, &y, q);
+    let power = (0..n).fold(one.clone(), |t, _| multiply(&t, &y, q));
+    let mut term = divide(&(&one * factorial(n - 1)), &power, q);
+    let mut finite = BigInt::zero();
+
+    for k in 0..n {
+        finite = finite + &term;
+
+        if k + 1 < n {
+            term = quotient(&multiply(&term, &square, q), &BigInt::from((k + 1) * (n - k - 1)));
+        }
+    }
+
+    let mut low = BigInt::zero();
+    let mut high = (1..n + 1).fold(BigInt::zero(), |h, k| h + quotient(&one, &BigInt::from(k)));
+    let mut harmonic = BigInt::zero();
+
+    for (k, t) in ts.iter().enumerate() {
+        if k > 0 {
+            low = low + quotient(&one, &BigInt::from(k as i64));
+            high = high + quotient(&one, &BigInt::from(n + k as i64));
+        }
+
+        harmonic = harmonic + multiply(t, &(&low + &high), q);
+    }
+
+    Some(rescale(&divide(&(first - finite - harmonic), &precise::pi(q), q), q, p))
+}
+
+// BesselJ[nu, x] for any other real nu and x > 0 as
+// (x/2)^nu / Gamma[nu + 1] times the sum of the terms, the sum being the
+// part that cancels.
+fn fractional_j(nu: &Numeric, x: &Numeric, digits: u32) -> Option<d128> {
+    let (v, z) = (nu.to_d128(), x.to_d128());
+
+    if !z.is_positive() {
+        return None;
+    }
+
+    let scale = gamma(Complex::real(v + real::one()))?;
+    let prefactor = real::exp(v * real::ln(z / d128::from(2))) / scale.re;
+
+    let sum = approximate(|p| {
+        let q = p + GUARD + cancellation(x)?;
+        let total = terms(&from_numeric(nu, q)?, &half(x, q)?, power_of_ten(q), q).iter().fold(BigInt::zero(), |t, a| t + a);
+        Some(rescale(&total, q, p))
+    }, digits, digits + GUARD)?;
+
+    Some(prefactor * sum)
+}
+
+fn sign(n: i64) -> d128 {
+    if n % 2 == 0 { real::one() } else { -real::one() }
+}
+
+// BesselJ[nu, x] to the given digits, BesselJ[-n, x] being
+// (-1)^n BesselJ[n, x].
+pub fn bessel_j(nu: Numeric, x: Numeric, digits: u32) -> Option<d128> {
+    match integral_order(&nu) {
+        Some(n) => approximate(|p| integer_j(n.abs(), &x, p), digits, digits + GUARD).map(|j| j * sign(n.min(0))),
+        None => fractional_j(&nu, &x, digits),
+    }
+}
+
+// BesselY[nu, x] for x > 0; away from the integers
+// (BesselJ[nu, x] Cos[nu Pi] - BesselJ[-nu, x]) / Sin[nu Pi].
+pub fn bessel_y(nu: Numeric, x: Numeric, digits: u32) -> Option<d128> {
+    if !x.to_d128().is_positive() {
+        return None;
+    }
+
+    if let Some(n) = integral_order(&nu) {
+        return approximate(|p| integer_y(n.abs(), &x, p), digits, digits + GUARD).map(|y| y * sign(n.min(0)));
+    }
+
+    let angle = nu.to_d128() * real::pi();
+    let negative = Numeric::LittleReal(-nu.to_d128());
+    let (j, k) = (fractional_j(&nu, &x, digits)?, fractional_j(&negative, &x, digits)?);
+
+    Some((j * real::cos(angle) - k) / real::sin(angle))
+}
+
@@ -0,0 +1,322 @@
+use num::BigRational;
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{expression, integer, symbol, negative};
+use arithmetic::terms::{number, coefficient_and_term};
+use arithmetic::canonical::{thread, sum, product, power};
+use numerical::real::{self, MACHINE_PRECISION, MAXIMUM_PRECISION};
+use numerical::complex::{Complex, complex_parts};
+use special::combinatorial::{LIMIT, to_numeric, from_numeric, factorial, binomial, pochhammer};
+use special::gamma::{exact_gamma, gamma, log_gamma};
+use special::zeta::{exact_zeta, zeta};
+use special::error::{erf, erfc};
+use special::bessel::{exact_bessel, bessel_j, bessel_y};
+use special::orthogonal::{DEGREE_LIMIT, polynomial, at_rational, at_real};
+
+// The series and reflections here are good to a few digits short of what a
+// d128 holds, which is still well past the machine precision of results.
+const DIGITS: u32 = MAXIMUM_PRECISION - 4;
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Gamma" => Some(gamma_builtin),
+        "LogGamma" => Some(log_gamma_builtin),
+        "Beta" => Some(beta_builtin),
+        "Zeta" => Some(zeta_builtin),
+        "Erf" => Some(erf_builtin),
+        "Erfc" => Some(erfc_builtin),
+        "BesselJ" => Some(bessel_j_builtin),
+        "BesselY" => Some(bessel_y_builtin),
+        "Binomial" => Some(binomial_builtin),
+        "Factorial" => Some(factorial_builtin),
+        "Pochhammer" => Some(pochhammer_builtin),
+        "LegendreP" => Some(legendre_p_builtin),
+        "ChebyshevT" => Some(chebyshev_t_builtin),
+        "HermiteH" => Some(hermite_h_builtin),
+        "LaguerreL" => Some(laguerre_l_builtin),
+        _ => None,
+    }
+}
+
+fn exact(e: &SimplexPointer) -> Option<Numeric> {
+    e.as_numeric().and_then(|n| if n.is_exact() { Some(n) } else { None })
+}
+
+// A result as a real, or as a + b I when it is off the real line.
+fn value(z: Complex) -> Option<SimplexPointer> {
+    if !z.re.is_finite() || !z.im.is_finite() {
+        return None;
+    }
+
+    let re = real::to_expression(z.re, MACHINE_PRECISION);
+
+    if z.im.is_zero() {
+        Some(re)
+    } else {
+        Some(sum(&[re, product(&[real::to_expression(z.im, MACHINE_PRECISION), symbol("I")])]))
+    }
+}
+
+fn one() -> Complex {
+    Complex::real(real::one())
+}
+
+// Lists thread, inexact arguments evaluate with the given function, and
+// exact ones are left to the given rules.
+fn special<F, G>(name: &str, args: &[SimplexPointer], numeric: F, rules: G) -> Option<SimplexPointer>
+    where F: Fn(&[Complex]) -> Option<Complex>,
+          G: Fn(&[SimplexPointer]) -> Option<SimplexPointer>
+{
+    if let Some(threaded) = thread(name, args) {
+        return Some(threaded);
+    }
+
+    let parts = args.iter().map(complex_parts).collect::<Option<Vec<(Numeric, Numeric)>>>();
+
+    if let Some(parts) = parts {
+        if parts.iter().any(|&(ref a, ref b)| !a.is_exact() || !b.is_exact()) {
+            let zs = parts.iter().map(|&(ref a, ref b)| Complex::new(a.to_d128(), b.to_d128())).collect::<Vec<Complex>>();
+            return numeric(&zs).and_then(value);
+        }
+    }
+
+    rules(args)
+}
+
+fn gamma_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    special("Gamma", args, |z| gamma(z[0]), |a| exact_gamma(exact(&a[0])?))
+}
+
+// LogGamma at the positive integers and half integers as the Log of the
+// exact Gamma.
+fn log_gamma_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    special("LogGamma", args, |z| log_gamma(z[0]), |a| {
+        let n = exact(&a[0])?;
+
+        if n > Numeric::LittleInteger(0) {
+            Some(expression("Log", &[exact_gamma(n)?]))
+        } else {
+            None
+        }
+    })
+}
+
+// Gamma[a] Gamma[b] / Gamma[a + b].
+fn beta_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    special("Beta", args, |z| Some(gamma(z[0])? * gamma(z[1])? / gamma(z[0] + z[1])?), |a| {
+        let (x, y) = (exact(&a[0])?, exact(&a[1])?);
+        let (p, q, r) = (exact_gamma(x.clone())?, exact_gamma(y.clone())?, exact_gamma(x + y)?);
+        let infinity = symbol("ComplexInfinity");
+
+        if p == infinity || q == infinity || r == infinity {
+            None
+        } else {
+            Some(product(&[p, q, power(&r, &integer(-1))]))
+        }
+    })
+}
+
+fn zeta_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    special("Zeta", args, |z| zeta(z[0]), |a| exact_zeta(exact(&a[0])?))
+}
+
+fn is_negative(e: &SimplexPointer) -> bool {
+    coefficient_and_term(e).0 < Numeric::LittleInteger(0)
+}
+
+// Erf at 0 and the infinities, and Erf[-x] as -Erf[x].
+fn erf_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    special("Erf", args, |z| erf(z[0]), |a| {
+        let x = &a[0];
+
+        if exact(x).map_or(false, |n| n.is_zero()) {
+            Some(integer(0))
+        } else if *x == symbol("Infinity") {
+            Some(integer(1))
+        } else if *x == negative(symbol("Infinity")) {
+            Some(integer(-1))
+        } else if is_negative(x) {
+            Some(negative(expression("Erf", &[negative(x.clone())])))
+        } else {
+            None
+        }
+    })
+}
+
+fn erfc_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    special("Erfc", args, |z| erfc(z[0]), |a| {
+        let x = &a[0];
+
+        if exact(x).map_or(false, |n| n.is_zero()) {
+            Some(integer(1))
+        } else if *x == symbol("Infinity") {
+            Some(integer(0))
+        } else if *x == negative(symbol("Infinity")) {
+            Some(integer(2))
+        } else {
+            None
+        }
+    })
+}
+
+// Bessel functions of a real order at a real argument, and exactly at zero
+// and the orders 1/2 and -1/2.
+fn bessel(name: &str, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread(name, args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 2 {
+        return None;
+    }
+
+    let nu = args[0].as_numeric()?;
+
+    if let Some(x) = args[1].as_numeric() {
+        if !nu.is_exact() || !x.is_exact() {
+            let result = if name == "BesselJ" { bessel_j(nu, x, DIGITS) } else { bessel_y(nu, x, DIGITS) };
+            return result.and_then(|y| value(Complex::real(y)));
+        }
+    }
+
+    exact_bessel(name, nu, &args[1])
+}
+
+fn bessel_j_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    bessel("BesselJ", args)
+}
+
+fn bessel_y_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    bessel("BesselY", args)
+}
+
+fn exact_number(q: &BigRational) -> Option<SimplexPointer> {
+    Some(number(to_numeric(q)))
+}
+
+// Binomial[n, k] exactly for integer k, whatever n is, symbolically for k
+// of 0 and 1, and by Gamma otherwise.
+fn binomial_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    special("Binomial", args, |z| Some(gamma(z[0] + one())? / (gamma(z[1] + one())? * gamma(z[0] - z[1] + one())?)), |a| {
+        let k = match exact(&a[1])? {
+            Numeric::LittleInteger(k) if k.abs() <= LIMIT => k,
+            _ => return None,
+        };
+
+        match (exact(&a[0]), k) {
+            (Some(Numeric::LittleInteger(n)), _) => exact_number(&BigRational::from_integer(binomial(n, k))),
+            (_, k) if k < 0 => Some(integer(0)),
+            (Some(n), _) => {
+                let bottom = from_numeric(&(n.clone() - Numeric::LittleInteger(k - 1)))?;
+                exact_number(&(pochhammer(&bottom, k)? / BigRational::from_integer(factorial(k))))
+            }
+            (None, 0) => Some(integer(1)),
+            (None, 1) => Some(a[0].clone()),
+            _ => None,
+        }
+    })
+}
+
+// Factorial[n] as Gamma[n + 1].
+fn factorial_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 1 {
+        return None;
+    }
+
+    special("Factorial", args, |z| gamma(z[0] + one()), |a| exact_gamma(exact(&a[0])? + Numeric::LittleInteger(1)))
+}
+
+// Pochhammer[a, n] exactly for integer n, and as Gamma[a + n] / Gamma[a]
+// otherwise.
+fn pochhammer_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    special("Pochhammer", args, |z| Some(gamma(z[0] + z[1])? / gamma(z[0])?), |a| {
+        let n = match exact(&a[1])? {
+            Numeric::LittleInteger(n) if n.abs() <= LIMIT => n,
+            _ => return None,
+        };
+
+        match (exact(&a[0]), n) {
+            (Some(x), _) => match pochhammer(&from_numeric(&x)?, n) {
+                Some(q) => exact_number(&q),
+                None => Some(symbol("ComplexInfinity")),
+            },
+            (None, 0) => Some(integer(1)),
+            (None, 1) => Some(a[0].clone()),
+            _ => None,
+        }
+    })
+}
+
+// The polynomials at a real, at an exact number, and as polynomials in
+// anything else, for degrees that are integers up to DEGREE_LIMIT.
+fn orthogonal(name: &str, args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if let Some(threaded) = thread(name, args) {
+        return Some(threaded);
+    }
+
+    if args.len() != 2 {
+        return None;
+    }
+
+    let n = match args[0].as_numeric()? {
+        Numeric::LittleInteger(n) if n >= 0 && n <= DEGREE_LIMIT => n,
+        _ => return None,
+    };
+
+    match args[1].as_numeric() {
+        Some(x) if x.is_exact() => exact_number(&at_rational(name, n, &from_numeric(&x)?)),
+        Some(x) => value(Complex::real(at_real(name, n, x.to_d128()))),
+        None => polynomial(name, n, &args[1]),
+    }
+}
+
+fn legendre_p_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    orthogonal("LegendreP", args)
+}
+
+fn chebyshev_t_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    orthogonal("ChebyshevT", args)
+}
+
+fn hermite_h_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    orthogonal("HermiteH", args)
+}
+
+fn laguerre_l_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    orthogonal("LaguerreL", args)
+}
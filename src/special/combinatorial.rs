@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+use decimal::d128;
+use num::{BigInt, BigRational, Zero, One};
+
+use expression::atom::numbers::number::Numeric;
+
+// Products longer than this are left alone rather than multiplied out.
+pub const LIMIT: i64 = 1000;
+
+pub fn to_numeric(q: &BigRational) -> Numeric {
+    Numeric::from_big_rational(q.clone())
+}
+
+pub fn from_numeric(n: &Numeric) -> Option<BigRational> {
+    n.to_big_rational()
+}
+
+pub fn to_d128(q: &BigRational) -> d128 {
+    d128::from_str(&q.numer().to_string()).unwrap() / d128::from_str(&q.denom().to_string()).unwrap()
+}
+
+fn rational(n: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(n))
+}
+
+pub fn factorial(n: i64) -> BigInt {
+    (1..n + 1).fold(BigInt::one(), |f, k| f * BigInt::from(k))
+}
+
+// n choose k for any integer n, (-1)^k Binomial[k - n - 1, k] for negative
+// ones, and zero for negative k.
+pub fn binomial(n: i64, k: i64) -> BigInt {
+    if k < 0 {
+        return BigInt::zero();
+    }
+
+    if n < 0 {
+        let c = binomial(k - n - 1, k);
+        return if k % 2 == 0 { c } else { -c };
+    }
+
+    if k > n {
+        return BigInt::zero();
+    }
+
+    (0..k.min(n - k)).fold(BigInt::one(), |c, i| c * BigInt::from(n - i) / BigInt::from(i + 1))
+}
+
+// a (a + 1) ... (a + n - 1), and for negative n 1 / ((a - 1) ... (a + n)),
+// None where that divides by zero.
+pub fn pochhammer(a: &BigRational, n: i64) -> Option<BigRational> {
+    if n >= 0 {
+        return Some((0..n).fold(BigRational::one(), |p, k| p * (a + rational(k))));
+    }
+
+    let d = (1..-n + 1).fold(BigRational::one(), |p, k| p * (a - rational(k)));
+    if d.is_zero() { None } else { Some(d.recip()) }
+}
+
+// B[0] up to B[n], B[1] being -1/2, from Sum[Binomial[m + 1, k] B[k], {k, 0, m}] = 0.
+pub fn bernoulli(n: usize) -> Vec<BigRational> {
+    let mut b = vec![BigRational::one()];
+
+    for m in 1..n + 1 {
+        let total = (0..m).fold(BigRational::zero(), |t, k| t + BigRational::from_integer(binomial(m as i64 + 1, k as i64)) * &b[k]);
+        b.push(-total / rational(m as i64 + 1));
+    }
+
+    b
+}
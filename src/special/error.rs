@@ -0,0 +1,76 @@
+use decimal::d128;
+
+use numerical::real::{self, tolerance, MAXIMUM_PRECISION};
+use numerical::complex::Complex;
+
+// Where the continued fraction for Erfc takes over from the series for Erf,
+// and how far from the origin the series is trusted off the real line.
+const CROSSOVER: &'static str = "1.5";
+const RADIUS: i64 = 2;
+const FRACTION_TERMS: i64 = 1000;
+
+fn two_over_root_pi() -> d128 {
+    d128::from(2) / real::sqrt(real::pi())
+}
+
+fn is_small(x: d128) -> bool {
+    x.abs() < real::real(CROSSOVER)
+}
+
+// 2 z Exp[-z^2] / Sqrt[Pi] Sum[(2 z^2)^n / (2n + 1)!!], every term of which
+// is positive on the real line.
+fn series(z: Complex) -> Complex {
+    let square = z * z;
+    let twice = square * Complex::real(d128::from(2));
+    let mut term = Complex::real(real::one());
+    let mut total = term;
+    let mut n = 0;
+
+    while term.norm() > tolerance(MAXIMUM_PRECISION + 4) * total.norm() {
+        n = n + 1;
+        term = term * twice / Complex::real(d128::from(2 * n + 1));
+        total = total + term;
+    }
+
+    z * (-square).exp() * total * Complex::real(two_over_root_pi())
+}
+
+// Erfc[x] for x at least CROSSOVER, by Laplace's continued fraction
+// Exp[-x^2] / Sqrt[Pi] / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ...)))),
+// evaluated from the back.
+fn fraction(x: d128) -> d128 {
+    let half = real::real("0.5");
+    let mut t = x;
+
+    for k in (1..FRACTION_TERMS + 1).rev() {
+        t = x + d128::from(k) * half / t;
+    }
+
+    real::exp(-x * x) / (real::sqrt(real::pi()) * t)
+}
+
+pub fn erf(z: Complex) -> Option<Complex> {
+    if !z.im.is_zero() {
+        return if z.norm() <= d128::from(RADIUS) { Some(series(z)) } else { None };
+    }
+
+    let x = z.re;
+
+    if is_small(x) {
+        Some(series(z))
+    } else if x.is_positive() {
+        Some(Complex::real(real::one() - fraction(x)))
+    } else {
+        Some(Complex::real(fraction(-x) - real::one()))
+    }
+}
+
+pub fn erfc(z: Complex) -> Option<Complex> {
+    let x = z.re;
+
+    if z.im.is_zero() && !is_small(x) {
+        return Some(Complex::real(if x.is_positive() { fraction(x) } else { d128::from(2) - fraction(-x) }));
+    }
+
+    erf(z).map(|e| Complex::real(real::one()) - e)
+}
@@ -0,0 +1,111 @@
+use decimal::d128;
+use num::{BigInt, BigRational};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::symbol;
+use arithmetic::terms::number;
+use arithmetic::canonical::{product, power};
+use numerical::real::{self, zero};
+use numerical::complex::Complex;
+use special::combinatorial::{LIMIT, to_numeric, to_d128, factorial, pochhammer, bernoulli};
+
+// Stirling's series is summed to this many terms once Re z is at least
+// SHIFT, past which the next term is below working precision.
+const TERMS: usize = 15;
+const SHIFT: i64 = 40;
+
+// Gamma at integers and half integers: (n - 1)! at positive integers, poles
+// at the others, and rational multiples of Sqrt[Pi] half way between.
+pub fn exact_gamma(a: Numeric) -> Option<SimplexPointer> {
+    let (n, d) = a.as_rational()?;
+
+    match d {
+        1 if n <= 0 => Some(symbol("ComplexInfinity")),
+        1 if n <= LIMIT => Some(number(to_numeric(&BigRational::from_integer(factorial(n - 1))))),
+        2 if n.abs() <= 2 * LIMIT => {
+            let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+            let c = to_numeric(&pochhammer(&half, (n - 1) / 2)?);
+            Some(product(&[number(c), power(&symbol("Pi"), &number(Numeric::ratio(1, 2)))]))
+        }
+        _ => None,
+    }
+}
+
+// B[2k] / (2k (2k - 1)), the coefficient of z^(1 - 2k) in the series.
+fn stirling_coefficients() -> Vec<d128> {
+    let b = bernoulli(2 * TERMS);
+
+    (1..TERMS + 1)
+        .map(|k| to_d128(&(&b[2 * k] / BigRational::from_integer(BigInt::from(2 * k * (2 * k - 1))))))
+        .collect()
+}
+
+// Stirling's series for LogGamma[z], z far enough to the right.
+fn stirling(z: Complex) -> Complex {
+    lazy_static! {
+        static ref COEFFICIENTS: Vec<d128> = stirling_coefficients();
+    }
+
+    let two = d128::from(2);
+    let half = Complex::real(real::one() / two);
+    let constant = Complex::real(real::ln(real::pi() * two) / two);
+    let inverse = Complex::real(real::one()) / z;
+    let square = inverse * inverse;
+    let mut power = inverse;
+    let mut total = (z - half) * z.ln() - z + constant;
+
+    for &c in COEFFICIENTS.iter() {
+        total = total + power * Complex::real(c);
+        power = power * square;
+    }
+
+    total
+}
+
+// LogGamma on the right half plane: the series at z + n, less the logs of
+// z, z + 1, ..., z + n - 1.
+pub fn log_gamma(z: Complex) -> Option<Complex> {
+    if !z.re.is_positive() {
+        return None;
+    }
+
+    let one = Complex::real(real::one());
+    let mut w = z;
+    let mut logs = Complex::real(zero());
+
+    while w.re < d128::from(SHIFT) {
+        logs = logs + w.ln();
+        w = w + one;
+    }
+
+    Some(stirling(w) - logs)
+}
+
+// Gamma everywhere but its poles, by reflection left of 1/2, Gamma[z]
+// Gamma[1 - z] being Pi / Sin[Pi z].
+pub fn gamma(z: Complex) -> Option<Complex> {
+    let one = Complex::real(real::one());
+
+    if z.re < real::real("0.5") {
+        let pi = Complex::real(real::pi());
+        let s = (pi * z).sin();
+
+        if s.re.is_zero() && s.im.is_zero() {
+            return None;
+        }
+
+        return Some(pi / (s * gamma(one - z)?));
+    }
+
+    let mut w = z;
+    let mut factors = one;
+
+    while w.re < d128::from(SHIFT) {
+        factors = factors * w;
+        w = w + one;
+    }
+
+    Some(stirling(w).exp() / factors)
+}
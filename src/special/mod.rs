@@ -0,0 +1,8 @@
+pub mod combinatorial;
+pub mod gamma;
+pub mod zeta;
+pub mod error;
+pub mod bessel;
+pub mod orthogonal;
+pub mod builtins;
+pub mod test;
@@ -0,0 +1,95 @@
+use decimal::d128;
+use num::{BigInt, BigRational, Zero, One};
+
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::integer;
+use arithmetic::terms::number;
+use arithmetic::canonical::{sum, product, power};
+use special::combinatorial::{to_numeric, to_d128};
+
+// Degrees past this are left alone.
+pub const DEGREE_LIMIT: i64 = 200;
+
+pub const POLYNOMIALS: &'static [&'static str] = &["LegendreP", "ChebyshevT", "HermiteH", "LaguerreL"];
+
+fn rational(n: i64, d: i64) -> BigRational {
+    BigRational::new(BigInt::from(n), BigInt::from(d))
+}
+
+// (a, b, c) with p[m + 1] = (a + b x) p[m] - c p[m - 1], starting from
+// p[-1] = 0 and p[0] = 1.
+fn recurrence(name: &str, m: i64) -> (BigRational, BigRational, BigRational) {
+    match name {
+        "LegendreP" => (BigRational::zero(), rational(2 * m + 1, m + 1), rational(m, m + 1)),
+        "ChebyshevT" => (BigRational::zero(), rational(if m == 0 { 1 } else { 2 }, 1), BigRational::one()),
+        "HermiteH" => (BigRational::zero(), rational(2, 1), rational(2 * m, 1)),
+        _ => (rational(2 * m + 1, m + 1), rational(-1, m + 1), rational(m, m + 1)),
+    }
+}
+
+// The coefficients of p[n], constant term first.
+fn coefficients(name: &str, n: i64) -> Vec<BigRational> {
+    let (mut previous, mut current) = (vec![], vec![BigRational::one()]);
+
+    for m in 0..n {
+        let (a, b, c) = recurrence(name, m);
+        let mut next = vec![BigRational::zero(); current.len() + 1];
+
+        for (k, p) in current.iter().enumerate() {
+            next[k] = &next[k] + &a * p;
+            next[k + 1] = &next[k + 1] + &b * p;
+        }
+
+        for (k, p) in previous.iter().enumerate() {
+            next[k] = &next[k] - &c * p;
+        }
+
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+// p[n] as a polynomial in x.
+pub fn polynomial(name: &str, n: i64, x: &SimplexPointer) -> Option<SimplexPointer> {
+    let mut terms = vec![];
+
+    for (k, c) in coefficients(name, n).iter().enumerate() {
+        if !c.is_zero() {
+            terms.push(product(&[number(to_numeric(c)), power(x, &integer(k as i64))]));
+        }
+    }
+
+    Some(sum(&terms))
+}
+
+// p[n] at an exact x, by the recurrence itself.
+pub fn at_rational(name: &str, n: i64, x: &BigRational) -> BigRational {
+    let (mut previous, mut current) = (BigRational::zero(), BigRational::one());
+
+    for m in 0..n {
+        let (a, b, c) = recurrence(name, m);
+        let next = (a + b * x) * &current - c * previous;
+        previous = current;
+        current = next;
+    }
+
+    current
+}
+
+// p[n] at a real x, by the recurrence, which is stable where summing the
+// coefficients is not.
+pub fn at_real(name: &str, n: i64, x: d128) -> d128 {
+    let (mut previous, mut current) = (d128::zero(), d128::from(1));
+
+    for m in 0..n {
+        let (a, b, c) = recurrence(name, m);
+        let next = (to_d128(&a) + to_d128(&b) * x) * current - to_d128(&c) * previous;
+        previous = current;
+        current = next;
+    }
+
+    current
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    mod test_exact {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_gives_gamma_at_integers_and_half_integers() {
+            assert_eq!(run("Gamma[5]"), "24");
+            assert_eq!(run("Gamma[-3]"), "ComplexInfinity");
+            assert_eq!(run("Gamma[5/2]"), "Times[Rational[3, 4], Power[Pi, Rational[1, 2]]]");
+            assert_eq!(run("Gamma[-1/2]"), "Times[-2, Power[Pi, Rational[1, 2]]]");
+            assert_eq!(run("Gamma[{1, 2, 3}]"), "List[1, 1, 2]");
+            assert_eq!(run("LogGamma[3]"), "Log[2]");
+            assert_eq!(run("Beta[2, 3]"), "Rational[1, 12]");
+        }
+
+        #[test]
+        fn it_gives_zeta_at_integers() {
+            assert_eq!(run("Zeta[2]"), "Times[Rational[1, 6], Power[Pi, 2]]");
+            assert_eq!(run("Zeta[4]"), "Times[Rational[1, 90], Power[Pi, 4]]");
+            assert_eq!(run("Zeta[0]"), "Rational[-1, 2]");
+            assert_eq!(run("Zeta[-1]"), "Rational[-1, 12]");
+            assert_eq!(run("Zeta[-2]"), "0");
+            assert_eq!(run("Zeta[1]"), "ComplexInfinity");
+            assert_eq!(run("Zeta[3]"), "Zeta[3]");
+            assert_eq!(run("Zeta[-41]"), "Rational[-1520097643918070802691, 75852]");
+        }
+
+        #[test]
+        fn it_gives_error_function_special_values() {
+            assert_eq!(run("Erf[0]"), "0");
+            assert_eq!(run("Erf[Infinity]"), "1");
+            assert_eq!(run("Erf[-x]"), "Times[-1, Erf[x]]");
+            assert_eq!(run("Erfc[0]"), "1");
+            assert_eq!(run("Erfc[-Infinity]"), "2");
+        }
+
+        #[test]
+        fn it_gives_bessel_functions_at_half_integer_orders() {
+            assert_eq!(run("BesselJ[0, 0]"), "1");
+            assert_eq!(run("BesselJ[2, 0]"), "0");
+            assert_eq!(run("BesselY[0, 0]"), "Times[-1, Infinity]");
+            assert_eq!(run("BesselJ[1/2, x]"), "Times[Sin[x], Power[Times[2, Power[Pi, -1], Power[x, -1]], Rational[1, 2]]]");
+        }
+
+        #[test]
+        fn it_gives_binomials_factorials_and_pochhammer_symbols() {
+            assert_eq!(run("Binomial[10, 3]"), "120");
+            assert_eq!(run("Binomial[-3, 2]"), "6");
+            assert_eq!(run("Binomial[5/2, 2]"), "Rational[15, 8]");
+            assert_eq!(run("Binomial[n, 1]"), "n");
+            assert_eq!(run("Factorial[20]"), "2432902008176640000");
+            assert_eq!(run("Factorial[-1]"), "ComplexInfinity");
+            assert_eq!(run("Factorial[21]"), "51090942171709440000");
+            assert_eq!(run("Factorial[25]"), "15511210043330985984000000");
+            assert_eq!(run("Binomial[100, 50]"), "100891344545564193334812497256");
+            assert_eq!(run("Pochhammer[10, 20]"), "24365525776399090483200000");
+            assert_eq!(run("Pochhammer[3, 4]"), "360");
+            assert_eq!(run("Pochhammer[a, 0]"), "1");
+        }
+
+        #[test]
+        fn it_expands_orthogonal_polynomials() {
+            assert_eq!(run("LegendreP[3, x]"), "Plus[Times[Rational[-3, 2], x], Times[Rational[5, 2], Power[x, 3]]]");
+            assert_eq!(run("ChebyshevT[4, x]"), "Plus[1, Times[-8, Power[x, 2]], Times[8, Power[x, 4]]]");
+            assert_eq!(run("HermiteH[3, x]"), "Plus[Times[-12, x], Times[8, Power[x, 3]]]");
+            assert_eq!(run("LaguerreL[2, x]"), "Plus[1, Times[-2, x], Times[Rational[1, 2], Power[x, 2]]]");
+            assert_eq!(run("LegendreP[2, 1/2]"), "Rational[-1, 8]");
+        }
+    }
+
+    mod test_numeric {
+        use evaluation::testing::run;
+
+        #[test]
+        fn it_evaluates_gamma_on_reals_and_complexes() {
+            assert_eq!(run("Gamma[0.5]"), "1.772453850905516");
+            assert_eq!(run("Gamma[-2.5]"), "-0.9453087204829419");
+            assert_eq!(run("LogGamma[10.]"), "12.80182748008147");
+            assert_eq!(run("Factorial[3.5]"), "11.63172839656745");
+            assert_eq!(run("Beta[0.5, 0.5]"), "3.141592653589793");
+            assert_eq!(run("Binomial[10.5, 3]"), "141.3125");
+        }
+
+        #[test]
+        fn it_evaluates_zeta() {
+            assert_eq!(run("Zeta[3.]"), "1.202056903159594");
+            assert_eq!(run("Zeta[-2.5]"), "0.008516928777850331");
+        }
+
+        #[test]
+        fn it_evaluates_error_functions() {
+            assert_eq!(run("Erf[0.5]"), "0.5204998778130465");
+            assert_eq!(run("Erfc[1.5]"), "0.03389485352468927");
+            assert_eq!(run("Erf[3.]"), "0.9999779095030014");
+            assert_eq!(run("Erf[1. + I]"), "Plus[1.316151281697948, Times[0.1904534692378347, I]]");
+        }
+
+        #[test]
+        fn it_evaluates_bessel_functions() {
+            assert_eq!(run("BesselJ[0, 1.]"), "0.7651976865579666");
+            assert_eq!(run("BesselJ[1, 10.]"), "0.04347274616886144");
+            assert_eq!(run("BesselY[0, 1.]"), "0.08825696421567696");
+            assert_eq!(run("BesselY[2, 3.]"), "-0.1604003934849237");
+            assert_eq!(run("BesselJ[0.5, 2.]"), "0.5130161365618278");
+        }
+
+        #[test]
+        fn it_evaluates_orthogonal_polynomials() {
+            assert_eq!(run("LegendreP[2, 0.3]"), "-0.365");
+        }
+    }
+}
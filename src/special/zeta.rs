@@ -0,0 +1,100 @@
+use decimal::d128;
+use num::{BigInt, BigRational, Zero, One};
+
+use expression::structure::SimplexPointer;
+use expression::atom::numbers::number::Numeric;
+
+use evaluation::utilities::{integer, symbol};
+use arithmetic::terms::number;
+use arithmetic::canonical::{product, power};
+use numerical::real;
+use numerical::complex::Complex;
+use special::combinatorial::{to_numeric, to_d128, factorial, bernoulli};
+use special::gamma::gamma;
+
+// Past this the rational parts outgrow an integer anyway.
+const EXACT_LIMIT: i64 = 64;
+
+// Zeta at the integers: a pole at 1, rational multiples of Pi^n at positive
+// even n, and (-1)^n B[n + 1] / (n + 1) at -n.
+pub fn exact_zeta(s: Numeric) -> Option<SimplexPointer> {
+    let n = match s {
+        Numeric::LittleInteger(n) if n.abs() <= EXACT_LIMIT => n,
+        _ => return None,
+    };
+
+    if n == 1 {
+        return Some(symbol("ComplexInfinity"));
+    }
+
+    if n <= 0 {
+        let m = (1 - n) as usize;
+        let value = &bernoulli(m)[m] / BigRational::from_integer(BigInt::from(m));
+        return Some(number(to_numeric(&if n % 2 == 0 { value } else { -value })));
+    }
+
+    if n % 2 == 1 {
+        return None;
+    }
+
+    // (-1)^(n/2 + 1) B[n] 2^(n - 1) / n!
+    let b = &bernoulli(n as usize)[n as usize];
+    let scale = BigRational::new(BigInt::from(2).pow((n - 1) as u32), factorial(n));
+    let c = b * scale;
+    let c = if (n / 2) % 2 == 0 { -c } else { c };
+
+    Some(product(&[number(to_numeric(&c)), power(&symbol("Pi"), &integer(n))]))
+}
+
+// Borwein's d[0], ..., d[n] with d[k] = n Sum[(n + i - 1)! 4^i / ((n - i)! (2 i)!), {i, 0, k}].
+fn borwein(n: usize) -> Vec<BigRational> {
+    let mut term = BigRational::one();
+    let mut total = BigRational::zero();
+    let mut d = Vec::with_capacity(n + 1);
+
+    for i in 0..n + 1 {
+        total = total + &term;
+        d.push(total.clone());
+
+        let (i, n) = (i as i64, n as i64);
+        term = term * BigRational::new(BigInt::from(2 * (n + i) * (n - i)), BigInt::from((2 * i + 1) * (i + 1)));
+    }
+
+    d
+}
+
+// Zeta by Borwein's alternating series,
+// (1 - 2^(1 - s)) d[n] Zeta[s] = -Sum[(-1)^k (d[k] - d[n]) / (k + 1)^s, {k, 0, n - 1}]
+// to within 3 / (3 + Sqrt[8])^n, with more terms the further s is off the
+// real line. Left of 1/2 the functional equation
+// Zeta[s] = 2^s Pi^(s - 1) Sin[Pi s / 2] Gamma[1 - s] Zeta[1 - s] brings it
+// back to the right.
+pub fn zeta(s: Complex) -> Option<Complex> {
+    let one = Complex::real(real::one());
+    let two = Complex::real(d128::from(2));
+
+    if s.re < real::real("0.5") {
+        let pi = Complex::real(real::pi());
+        return Some(two.power(s) * pi.power(s - one) * (pi * s / two).sin() * gamma(one - s)? * zeta(one - s)?);
+    }
+
+    if s.im.is_zero() && s.re == real::one() {
+        return None;
+    }
+
+    if s.im.abs() > d128::from(1000) {
+        return None;
+    }
+
+    let extra: i32 = (s.im.abs() * d128::from(2)).quantize(real::one()).into();
+    let n = 60 + extra as usize;
+    let d = borwein(n);
+    let mut total = Complex::real(real::zero());
+
+    for k in 0..n {
+        let term = Complex::real(to_d128(&(&d[k] - &d[n]))) / Complex::real(d128::from(k as i64 + 1)).power(s);
+        total = if k % 2 == 0 { total + term } else { total - term };
+    }
+
+    Some(-total / (Complex::real(to_d128(&d[n])) * (one - two.power(one - s))))
+}
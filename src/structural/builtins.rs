@@ -0,0 +1,218 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::dispatch::Builtin;
+use evaluation::utilities::{integer, list, rule_parts, split_options, option_value, integer_list, is_head};
+use structural::level::{LevelSpec, depth, level};
+use structural::measure::{head, length, leaf_count, byte_count};
+use structural::position::{PartSpec, position, extract, part};
+use structural::parts::{delete, insert, replace_part, map_at, map};
+
+pub fn lookup(name: &str) -> Option<Builtin> {
+    match name {
+        "Head" => Some(head_builtin),
+        "Length" => Some(length_builtin),
+        "Depth" => Some(depth_builtin),
+        "LeafCount" => Some(leaf_count_builtin),
+        "ByteCount" => Some(byte_count_builtin),
+        "Level" => Some(level_builtin),
+        "Position" => Some(position_builtin),
+        "Extract" => Some(extract_builtin),
+        "Delete" => Some(delete_builtin),
+        "Insert" => Some(insert_builtin),
+        "ReplacePart" => Some(replace_part_builtin),
+        "MapAt" => Some(map_at_builtin),
+        "Part" => Some(part_builtin),
+        "Map" => Some(map_builtin),
+        _ => None,
+    }
+}
+
+// Accepts n, {i, j, ...} or {{i, j, ...}, {k, ...}, ...}.
+fn positions_of(e: &SimplexPointer) -> Option<Vec<Vec<i64>>> {
+    if let Some(n) = e.as_integer() {
+        return Some(vec![vec![n]]);
+    }
+
+    if let Some(single) = integer_list(e) {
+        return Some(vec![single]);
+    }
+
+    if is_head(e, "List") {
+        return e.get_parts().iter().map(|p| integer_list(p)).collect();
+    }
+
+    None
+}
+
+fn position_list(positions: &[Vec<i64>]) -> SimplexPointer {
+    list(&positions.iter()
+        .map(|p| list(&p.iter().map(|&i| integer(i)).collect::<Vec<SimplexPointer>>()))
+        .collect::<Vec<SimplexPointer>>())
+}
+
+// Separates the Heads -> True|False option from the positional arguments.
+fn heads_option(args: &[SimplexPointer], default: bool) -> (Vec<SimplexPointer>, bool) {
+    let (args, options) = split_options(args);
+    let heads = option_value(&options, "Heads").map(|h| h.as_str() == "True");
+
+    (args, heads.unwrap_or(default))
+}
+
+fn head_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() == 1 { Some(head(&args[0])) } else { None }
+}
+
+fn length_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() == 1 { Some(integer(length(&args[0]) as i64)) } else { None }
+}
+
+fn depth_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() == 1 { Some(integer(depth(&args[0]))) } else { None }
+}
+
+fn leaf_count_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() == 1 { Some(integer(leaf_count(&args[0]) as i64)) } else { None }
+}
+
+fn byte_count_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() == 1 { Some(integer(byte_count(&args[0]) as i64)) } else { None }
+}
+
+fn level_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let (args, heads) = heads_option(args, false);
+
+    if args.len() != 2 {
+        return None;
+    }
+
+    LevelSpec::from_expression(&args[1])
+        .map(|spec| list(&level(&args[0], &spec.with_heads(heads))))
+}
+
+fn position_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let (args, heads) = heads_option(args, true);
+
+    let spec = match args.len() {
+        2 => LevelSpec::all().with_heads(heads),
+        3 => {
+            match LevelSpec::from_expression(&args[2]) {
+                Some(s) => s.with_heads(heads),
+                None => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    Some(position_list(&position(&args[0], &args[1], &spec)))
+}
+
+fn extract_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    // A flat list of integers is a single position, a list of lists many.
+    if let Some(single) = integer_list(&args[1]) {
+        return extract(&args[0], &single);
+    }
+
+    positions_of(&args[1]).and_then(|positions| {
+        positions.iter()
+            .map(|p| extract(&args[0], p))
+            .collect::<Option<Vec<SimplexPointer>>>()
+            .map(|parts| list(&parts))
+    })
+}
+
+fn delete_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    positions_of(&args[1]).and_then(|p| delete(&args[0], &p))
+}
+
+fn insert_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 3 {
+        return None;
+    }
+
+    positions_of(&args[2]).and_then(|p| insert(&args[0], &args[1], &p))
+}
+
+fn replace_part_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 2 {
+        return None;
+    }
+
+    let rules = if is_head(&args[1], "List") { args[1].get_parts() } else { vec![args[1].clone()] };
+    let mut replacements = Vec::with_capacity(rules.len());
+
+    for rule in rules {
+        match rule_parts(&rule) {
+            Some((lhs, rhs)) => {
+                match positions_of(&lhs) {
+                    Some(positions) => {
+                        for p in positions {
+                            replacements.push((p, rhs.clone()));
+                        }
+                    }
+                    None => return None,
+                }
+            }
+            None => return None,
+        }
+    }
+
+    replace_part(&args[0], &replacements)
+}
+
+fn map_at_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    if args.len() != 3 {
+        return None;
+    }
+
+    positions_of(&args[2]).and_then(|p| map_at(&args[0], &args[1], &p))
+}
+
+fn part_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let (e, indices) = match args.split_first() {
+        Some(split) => split,
+        None => return None,
+    };
+
+    let mut specs = Vec::with_capacity(indices.len());
+
+    for index in indices {
+        let spec = match (index.as_integer(), integer_list(index)) {
+            (Some(i), _) => PartSpec::Index(i),
+            (None, Some(is)) => PartSpec::Indices(is),
+            _ if index.as_str() == "All" => PartSpec::All,
+            _ if is_head(index, "Key") && index.get_length() == 1 => PartSpec::Key(index.get_part(1).unwrap()),
+            _ if e.get_association().is_some() => PartSpec::Key(index.clone()),
+            _ => return None,
+        };
+
+        specs.push(spec);
+    }
+
+    part(e, &specs)
+}
+
+fn map_builtin(args: &[SimplexPointer]) -> Option<SimplexPointer> {
+    let (args, heads) = heads_option(args, false);
+
+    let spec = match args.len() {
+        2 => LevelSpec::new(1, 1),
+        3 => {
+            match LevelSpec::from_expression(&args[2]) {
+                Some(s) => s,
+                None => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    map(&args[0], &args[1], &spec.with_heads(heads))
+}
@@ -0,0 +1,124 @@
+use std::i64;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::is_head;
+
+// Levels count down from the root (level 0), negative levels count up from
+// the leaves by depth: {-1} being every atom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LevelSpec {
+    pub min: i64,
+    pub max: i64,
+    pub heads: bool,
+}
+
+impl LevelSpec {
+    pub fn new(min: i64, max: i64) -> LevelSpec {
+        LevelSpec {
+            min: min,
+            max: max,
+            heads: false,
+        }
+    }
+
+    pub fn all() -> LevelSpec {
+        LevelSpec::new(0, i64::MAX)
+    }
+
+    pub fn with_heads(mut self, heads: bool) -> LevelSpec {
+        self.heads = heads;
+        self
+    }
+
+    // Reads n, {n}, {m, n}, Infinity and All.
+    pub fn from_expression(e: &SimplexPointer) -> Option<LevelSpec> {
+        if let Some(n) = level_bound(e) {
+            return Some(LevelSpec::new(1, n));
+        }
+
+        if e.as_symbol() == Some("All".to_string()) {
+            return Some(LevelSpec::all());
+        }
+
+        if !is_head(e, "List") {
+            return None;
+        }
+
+        let bounds = e.get_parts().iter().map(|p| level_bound(p)).collect::<Option<Vec<i64>>>();
+
+        match bounds {
+            Some(ref b) if b.len() == 1 => Some(LevelSpec::new(b[0], b[0])),
+            Some(ref b) if b.len() == 2 => Some(LevelSpec::new(b[0], b[1])),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, level: i64, depth: i64) -> bool {
+        let above_min = if self.min >= 0 { level >= self.min } else { -depth >= self.min };
+        let below_max = if self.max >= 0 { level <= self.max } else { -depth <= self.max };
+
+        above_min && below_max
+    }
+}
+
+fn level_bound(e: &SimplexPointer) -> Option<i64> {
+    match e.as_symbol() {
+        Some(ref s) if s == "Infinity" => Some(i64::MAX),
+        _ => e.as_integer(),
+    }
+}
+
+// Packed arrays know theirs without visiting every element.
+pub fn depth(e: &SimplexPointer) -> i64 {
+    if let Some(packed) = e.get_packed() {
+        return packed.dimensions().len() as i64 + 1;
+    }
+
+    1 + e.get_parts().iter().map(|p| depth(p)).max().unwrap_or(0)
+}
+
+// Visits every subexpression selected by the specification depth first,
+// children before their parents, handing over each one's position.
+pub fn walk<F>(e: &SimplexPointer, spec: &LevelSpec, visitor: &mut F)
+    where F: FnMut(&SimplexPointer, &[i64])
+{
+    let mut position = Vec::new();
+    walk_from(e, spec, &mut position, visitor);
+}
+
+fn walk_from<F>(e: &SimplexPointer, spec: &LevelSpec, position: &mut Vec<i64>, visitor: &mut F) -> i64
+    where F: FnMut(&SimplexPointer, &[i64])
+{
+    let mut deepest = 0;
+
+    if !e.is_atomic() {
+        let first = if spec.heads { 0 } else { 1 };
+
+        for n in first..(e.get_length() + 1) {
+            if let Some(part) = e.get_part(n) {
+                position.push(n as i64);
+                let part_depth = walk_from(&part, spec, position, visitor);
+                position.pop();
+
+                // Heads never count towards Depth[ ... ].
+                if n != 0 && part_depth > deepest {
+                    deepest = part_depth;
+                }
+            }
+        }
+    }
+
+    if spec.contains(position.len() as i64, deepest + 1) {
+        visitor(e, position.as_slice());
+    }
+
+    deepest + 1
+}
+
+pub fn level(e: &SimplexPointer, spec: &LevelSpec) -> Vec<SimplexPointer> {
+    let mut found = Vec::new();
+    walk(e, spec, &mut |part, _| found.push(part.clone()));
+    found
+}
@@ -0,0 +1,53 @@
+use std::mem;
+
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+use expression::list::structure::SimplexList;
+use expression::atom::structure::SimplexAtom;
+use expression::packed::structure::SimplexPackedArray;
+
+use evaluation::utilities::symbol;
+
+pub fn head(e: &SimplexPointer) -> SimplexPointer {
+    match e.get_atom() {
+        Some(a) => symbol(a.head_name()),
+        None => e.get_head().unwrap_or(symbol("Symbol")),
+    }
+}
+
+pub fn length(e: &SimplexPointer) -> usize {
+    e.get_length()
+}
+
+// Every atom counts once, heads included.
+pub fn leaf_count(e: &SimplexPointer) -> usize {
+    if e.is_atomic() {
+        return 1;
+    }
+
+    (0..e.get_length() + 1)
+        .filter_map(|n| e.get_part(n))
+        .map(|p| leaf_count(&p))
+        .sum()
+}
+
+// An estimate of the memory the tree occupies, shared subtrees counted each
+// time they appear.
+pub fn byte_count(e: &SimplexPointer) -> usize {
+    let pointer = mem::size_of::<SimplexPointer>();
+
+    if let Some(packed) = e.get_packed() {
+        let element = if packed.is_real() { 16 } else { 8 };
+        return pointer + mem::size_of::<SimplexPackedArray>() + element * packed.size();
+    }
+
+    match e.get_atom() {
+        Some(SimplexAtom::SimplexNumeric(ref n)) => pointer + mem::size_of::<SimplexAtom>() + n.capacity() / 8,
+        Some(SimplexAtom::SimplexString(ref s)) => pointer + mem::size_of::<SimplexAtom>() + s.len(),
+        Some(SimplexAtom::SimplexSymbol(_)) => 0,
+        None => {
+            pointer + mem::size_of::<SimplexList>() +
+            e.get_parts().iter().map(|p| byte_count(p)).sum::<usize>()
+        }
+    }
+}
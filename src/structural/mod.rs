@@ -0,0 +1,6 @@
+pub mod level;
+pub mod measure;
+pub mod position;
+pub mod parts;
+pub mod builtins;
+pub mod test;
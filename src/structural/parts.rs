@@ -0,0 +1,192 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use evaluation::utilities::expression;
+use structural::level::{LevelSpec, depth};
+use structural::position::{resolve, extract};
+
+// Rebuilds the tree along the path with the subexpression at its end handed
+// to the modifier, a replaced head having to stay a symbol.
+pub fn modify_at<F>(e: &SimplexPointer, path: &[usize], modifier: &mut F) -> Option<SimplexPointer>
+    where F: FnMut(&SimplexPointer) -> Option<SimplexPointer>
+{
+    if path.is_empty() {
+        return modifier(e);
+    }
+
+    let part = match e.get_part(path[0]) {
+        Some(p) => p,
+        None => return None,
+    };
+
+    let changed = match modify_at(&part, &path[1..], modifier) {
+        Some(c) => c,
+        None => return None,
+    };
+
+    let mut parts = e.get_parts();
+
+    if path[0] == 0 {
+        changed.as_symbol().map(|h| expression(h.as_str(), &parts))
+    } else {
+        parts[path[0] - 1] = changed;
+        Some(e.with_parts(&parts))
+    }
+}
+
+// Resolves every position against the untouched expression, then works from
+// the last position backwards so earlier removals don't shift later ones.
+fn resolve_all(e: &SimplexPointer, positions: &[Vec<i64>]) -> Option<Vec<Vec<usize>>> {
+    let mut resolved = positions.iter().map(|p| resolve(e, p)).collect::<Option<Vec<Vec<usize>>>>();
+
+    if let Some(ref mut r) = resolved {
+        r.sort();
+        r.dedup();
+        r.reverse();
+    }
+
+    resolved
+}
+
+pub fn delete(e: &SimplexPointer, positions: &[Vec<i64>]) -> Option<SimplexPointer> {
+    let resolved = match resolve_all(e, positions) {
+        Some(r) => r,
+        None => return None,
+    };
+
+    let mut current = e.clone();
+
+    for path in resolved {
+        let (index, parent) = match path.split_last() {
+            Some((&i, p)) if i != 0 => (i, p.to_vec()),
+            _ => return None,
+        };
+
+        current = match modify_at(&current, &parent, &mut |p| {
+            let mut parts = p.get_parts();
+            parts.remove(index - 1);
+            Some(p.with_parts(&parts))
+        }) {
+            Some(c) => c,
+            None => return None,
+        };
+    }
+
+    Some(current)
+}
+
+pub fn insert(e: &SimplexPointer, element: &SimplexPointer, positions: &[Vec<i64>]) -> Option<SimplexPointer> {
+    // The last index names a slot between parts rather than a part, so it is
+    // resolved against the parent's length plus one.
+    let mut slots = Vec::with_capacity(positions.len());
+
+    for position in positions {
+        let (&index, prefix) = match position.split_last() {
+            Some(split) => split,
+            None => return None,
+        };
+
+        let parent = match resolve(e, prefix) {
+            Some(p) => p,
+            None => return None,
+        };
+
+        let length = match extract(e, prefix) {
+            Some(ref p) if !p.is_atomic() => p.get_length() as i64,
+            _ => return None,
+        };
+
+        let slot = if index < 0 { length + 2 + index } else { index };
+
+        if slot < 1 || slot > length + 1 {
+            return None;
+        }
+
+        let mut path = parent;
+        path.push(slot as usize);
+        slots.push(path);
+    }
+
+    slots.sort();
+    slots.dedup();
+    slots.reverse();
+
+    let mut current = e.clone();
+
+    for path in slots {
+        let (&slot, parent) = path.split_last().unwrap();
+
+        current = match modify_at(&current, parent, &mut |p| {
+            let mut parts = p.get_parts();
+            parts.insert(slot - 1, element.clone());
+            Some(p.with_parts(&parts))
+        }) {
+            Some(c) => c,
+            None => return None,
+        };
+    }
+
+    Some(current)
+}
+
+pub fn replace_part(e: &SimplexPointer, rules: &[(Vec<i64>, SimplexPointer)]) -> Option<SimplexPointer> {
+    let mut current = e.clone();
+
+    for &(ref position, ref new) in rules {
+        let path = match resolve(&current, position) {
+            Some(p) => p,
+            None => continue,
+        };
+
+        current = match modify_at(&current, &path, &mut |_| Some(new.clone())) {
+            Some(c) => c,
+            None => return None,
+        };
+    }
+
+    Some(current)
+}
+
+pub fn map_at(f: &SimplexPointer, e: &SimplexPointer, positions: &[Vec<i64>]) -> Option<SimplexPointer> {
+    let name = match f.as_symbol() {
+        Some(n) => n,
+        None => return None,
+    };
+
+    let resolved = match resolve_all(e, positions) {
+        Some(r) => r,
+        None => return None,
+    };
+
+    let mut current = e.clone();
+
+    for path in resolved {
+        current = match modify_at(&current, &path, &mut |p| Some(expression(name.as_str(), &[p.clone()]))) {
+            Some(c) => c,
+            None => return None,
+        };
+    }
+
+    Some(current)
+}
+
+// Wraps f around every subexpression the level specification selects,
+// working from the leaves up.
+pub fn map(f: &SimplexPointer, e: &SimplexPointer, spec: &LevelSpec) -> Option<SimplexPointer> {
+    f.as_symbol().map(|name| map_from(name.as_str(), e, spec, 0))
+}
+
+fn map_from(name: &str, e: &SimplexPointer, spec: &LevelSpec, level: i64) -> SimplexPointer {
+    let mapped = if e.is_atomic() {
+        e.clone()
+    } else {
+        let parts = e.get_parts().iter().map(|p| map_from(name, p, spec, level + 1)).collect::<Vec<SimplexPointer>>();
+        e.with_parts(&parts)
+    };
+
+    if spec.contains(level, depth(e)) {
+        expression(name, &[mapped])
+    } else {
+        mapped
+    }
+}
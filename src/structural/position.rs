@@ -0,0 +1,103 @@
+use expression::traits::BaseExpression;
+use expression::structure::SimplexPointer;
+
+use pattern::matching::matches;
+use structural::level::{LevelSpec, walk};
+
+pub fn position(e: &SimplexPointer, pattern: &SimplexPointer, spec: &LevelSpec) -> Vec<Vec<i64>> {
+    let mut found = Vec::new();
+
+    walk(e, spec, &mut |part, at| {
+        if matches(part, pattern) {
+            found.push(at.to_vec());
+        }
+    });
+
+    found
+}
+
+// Turns negative indices (counted from the end) into absolute ones, failing
+// on anything that doesn't exist in the expression. Only a literal 0 reaches
+// the head; -n on something of length n is its first part, not its head.
+pub fn resolve(e: &SimplexPointer, position: &[i64]) -> Option<Vec<usize>> {
+    let mut current = e.clone();
+    let mut resolved = Vec::with_capacity(position.len());
+
+    for &index in position {
+        let length = current.get_length() as i64;
+        let absolute = if index < 0 { length + 1 + index } else { index };
+
+        if absolute < 0 || absolute > length || (index < 0 && absolute == 0) || current.is_atomic() {
+            return None;
+        }
+
+        current = match current.get_part(absolute as usize) {
+            Some(part) => part,
+            None => return None,
+        };
+
+        resolved.push(absolute as usize);
+    }
+
+    Some(resolved)
+}
+
+pub fn extract(e: &SimplexPointer, position: &[i64]) -> Option<SimplexPointer> {
+    let resolved = match resolve(e, position) {
+        Some(r) => r,
+        None => return None,
+    };
+
+    let mut current = e.clone();
+
+    for index in resolved {
+        current = match current.get_part(index) {
+            Some(part) => part,
+            None => return None,
+        };
+    }
+
+    Some(current)
+}
+
+// One index of Part[expr, i, j, ... ].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PartSpec {
+    Index(i64),
+    Indices(Vec<i64>),
+    All,
+    // A key of an association, as in assoc[["key"]] or Part[assoc, Key[k]].
+    Key(SimplexPointer),
+}
+
+pub fn part(e: &SimplexPointer, specs: &[PartSpec]) -> Option<SimplexPointer> {
+    let (spec, rest) = match specs.split_first() {
+        Some(split) => split,
+        None => return Some(e.clone()),
+    };
+
+    if e.is_atomic() {
+        return None;
+    }
+
+    match spec {
+        &PartSpec::Index(i) => extract(e, &[i]).and_then(|p| part(&p, rest)),
+        &PartSpec::Indices(ref is) => {
+            is.iter()
+                .map(|&i| extract(e, &[i]).and_then(|p| part(&p, rest)))
+                .collect::<Option<Vec<SimplexPointer>>>()
+                .map(|parts| e.with_parts(&parts))
+        }
+        &PartSpec::All => {
+            e.get_parts().iter()
+                .map(|p| part(p, rest))
+                .collect::<Option<Vec<SimplexPointer>>>()
+                .map(|parts| e.with_parts(&parts))
+        }
+        &PartSpec::Key(ref k) => {
+            e.get_association()
+                .and_then(|a| a.get(k))
+                .and_then(|p| part(&p, rest))
+        }
+    }
+}
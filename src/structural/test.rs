@@ -0,0 +1,256 @@
+#[cfg(test)]
+mod tests {
+    mod test_measure {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list};
+        use structural::measure::{head, length, leaf_count, byte_count};
+
+        #[test]
+        fn it_gets_heads() {
+            let f = expression("f", &[SimplexPointer::from("x")]);
+            assert_eq!(head(&f).as_str(), "f");
+            assert_eq!(head(&SimplexPointer::from("x")).as_str(), "Symbol");
+            assert_eq!(head(&SimplexPointer::from("12")).as_str(), "Integer");
+            assert_eq!(head(&SimplexPointer::from("1.5")).as_str(), "Real");
+            assert_eq!(head(&SimplexPointer::from("\"s\"")).as_str(), "String");
+        }
+
+        #[test]
+        fn it_gets_lengths() {
+            let l = list(&[SimplexPointer::from("a"), SimplexPointer::from("b")]);
+            assert_eq!(length(&l), 2);
+            assert_eq!(length(&SimplexPointer::from("a")), 0);
+        }
+
+        #[test]
+        fn it_counts_leaves() {
+            let f = expression("f", &[SimplexPointer::from("x"), list(&[SimplexPointer::from("y")])]);
+            assert_eq!(leaf_count(&f), 4);
+        }
+
+        #[test]
+        fn it_counts_bytes_of_larger_trees_higher() {
+            let a = list(&[SimplexPointer::from("1")]);
+            let b = list(&[SimplexPointer::from("1"), SimplexPointer::from("2")]);
+            assert!(byte_count(&b) > byte_count(&a));
+        }
+    }
+
+    mod test_level {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+        use structural::level::{LevelSpec, depth};
+
+        fn nested() -> SimplexPointer {
+            // {a, {b, f[c]}}
+            list(&[symbol("a"), list(&[symbol("b"), expression("f", &[symbol("c")])])])
+        }
+
+        #[test]
+        fn it_computes_depth() {
+            assert_eq!(depth(&symbol("a")), 1);
+            assert_eq!(depth(&list(&[])), 1);
+            assert_eq!(depth(&nested()), 4);
+        }
+
+        #[test]
+        fn it_reads_specifications() {
+            assert_eq!(LevelSpec::from_expression(&integer(2)), Some(LevelSpec::new(1, 2)));
+            assert_eq!(LevelSpec::from_expression(&list(&[integer(2)])), Some(LevelSpec::new(2, 2)));
+            assert_eq!(LevelSpec::from_expression(&list(&[integer(0), symbol("Infinity")])),
+                       Some(LevelSpec::all()));
+        }
+
+        #[test]
+        fn it_gets_single_level() {
+            let e = expression("Level", &[nested(), list(&[integer(2)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[b, f[c]]");
+        }
+
+        #[test]
+        fn it_gets_level_range_children_first() {
+            let e = expression("Level", &[nested(), integer(2)]);
+            assert_eq!(evaluate(&e).as_str(), "List[a, b, f[c], List[b, f[c]]]");
+        }
+
+        #[test]
+        fn it_gets_negative_levels() {
+            let e = expression("Level", &[nested(), list(&[integer(-1)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[a, b, c]");
+        }
+
+        #[test]
+        fn it_includes_heads() {
+            let heads = expression("Rule", &[symbol("Heads"), symbol("True")]);
+            let e = expression("Level", &[expression("f", &[symbol("x")]), integer(1), heads]);
+            assert_eq!(evaluate(&e).as_str(), "List[f, x]");
+        }
+
+        #[test]
+        fn it_gets_depth_builtin() {
+            let e = expression("Depth", &[nested()]);
+            assert_eq!(evaluate(&e).as_str(), "4");
+        }
+    }
+
+    mod test_position {
+        use expression::traits::BaseExpression;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        #[test]
+        fn it_finds_positions_of_literals() {
+            let l = list(&[symbol("a"), list(&[symbol("a"), symbol("b")])]);
+            let e = expression("Position", &[l, symbol("a")]);
+            assert_eq!(evaluate(&e).as_str(), "List[List[1], List[2, 1]]");
+        }
+
+        #[test]
+        fn it_finds_positions_of_patterns() {
+            let l = list(&[integer(1), symbol("x"), integer(3)]);
+            let e = expression("Position", &[l, expression("Blank", &[symbol("Integer")])]);
+            assert_eq!(evaluate(&e).as_str(), "List[List[1], List[3]]");
+        }
+
+        #[test]
+        fn it_finds_heads_by_default() {
+            let e = expression("Position", &[expression("f", &[symbol("f")]), symbol("f")]);
+            assert_eq!(evaluate(&e).as_str(), "List[List[0], List[1]]");
+        }
+
+        #[test]
+        fn it_extracts() {
+            let l = list(&[symbol("a"), list(&[symbol("b"), symbol("c")])]);
+            let single = expression("Extract", &[l.clone(), list(&[integer(2), integer(-1)])]);
+            assert_eq!(evaluate(&single).as_str(), "c");
+
+            let many = expression("Extract", &[l, list(&[list(&[integer(1)]), list(&[integer(2), integer(1)])])]);
+            assert_eq!(evaluate(&many).as_str(), "List[a, b]");
+        }
+
+        #[test]
+        fn it_leaves_bad_extracts_unevaluated() {
+            let e = expression("Extract", &[list(&[symbol("a")]), list(&[integer(3)])]);
+            assert_eq!(evaluate(&e).as_str(), "Extract[List[a], List[3]]");
+        }
+
+        #[test]
+        fn it_doesnt_extract_heads_with_negative_indices() {
+            let l = list(&[integer(1), integer(2), integer(3)]);
+            assert_eq!(evaluate(&expression("Extract", &[l.clone(), list(&[integer(-3)])])).as_str(), "1");
+            assert_eq!(evaluate(&expression("Extract", &[l.clone(), list(&[integer(-4)])])).as_str(), "Extract[List[1, 2, 3], List[-4]]");
+            assert_eq!(evaluate(&expression("Extract", &[l, integer(-4)])).as_str(), "Extract[List[1, 2, 3], -4]");
+        }
+    }
+
+    mod test_parts {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        fn abc() -> SimplexPointer {
+            list(&[symbol("a"), symbol("b"), symbol("c")])
+        }
+
+        #[test]
+        fn it_deletes() {
+            let e = expression("Delete", &[abc(), integer(2)]);
+            assert_eq!(evaluate(&e).as_str(), "List[a, c]");
+
+            let many = expression("Delete", &[abc(), list(&[list(&[integer(1)]), list(&[integer(-1)])])]);
+            assert_eq!(evaluate(&many).as_str(), "List[b]");
+        }
+
+        #[test]
+        fn it_inserts() {
+            let e = expression("Insert", &[abc(), symbol("x"), integer(2)]);
+            assert_eq!(evaluate(&e).as_str(), "List[a, x, b, c]");
+
+            let end = expression("Insert", &[abc(), symbol("x"), integer(-1)]);
+            assert_eq!(evaluate(&end).as_str(), "List[a, b, c, x]");
+        }
+
+        #[test]
+        fn it_inserts_nested() {
+            let l = list(&[symbol("a"), list(&[symbol("b")])]);
+            let e = expression("Insert", &[l, symbol("x"), list(&[integer(2), integer(1)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[a, List[x, b]]");
+        }
+
+        #[test]
+        fn it_replaces_parts() {
+            let rule = expression("Rule", &[integer(2), symbol("x")]);
+            let e = expression("ReplacePart", &[abc(), rule]);
+            assert_eq!(evaluate(&e).as_str(), "List[a, x, c]");
+        }
+
+        #[test]
+        fn it_replaces_heads() {
+            let rule = expression("Rule", &[integer(0), symbol("g")]);
+            let e = expression("ReplacePart", &[abc(), rule]);
+            assert_eq!(evaluate(&e).as_str(), "g[a, b, c]");
+        }
+
+        #[test]
+        fn it_doesnt_replace_heads_with_negative_indices() {
+            let rule = expression("Rule", &[integer(-4), symbol("x")]);
+            let e = expression("ReplacePart", &[abc(), rule]);
+            assert_eq!(evaluate(&e).as_str(), "List[a, b, c]");
+        }
+
+        #[test]
+        fn it_maps_at() {
+            let e = expression("MapAt", &[symbol("f"), abc(), list(&[list(&[integer(1)]), list(&[integer(3)])])]);
+            assert_eq!(evaluate(&e).as_str(), "List[f[a], b, f[c]]");
+        }
+    }
+
+    mod test_part_and_map {
+        use expression::traits::BaseExpression;
+        use expression::structure::SimplexPointer;
+        use evaluation::utilities::{expression, list, integer, symbol};
+        use evaluation::dispatch::evaluate;
+
+        fn matrix() -> SimplexPointer {
+            list(&[list(&[symbol("a"), symbol("b")]), list(&[symbol("c"), symbol("d")])])
+        }
+
+        #[test]
+        fn it_takes_parts() {
+            assert_eq!(evaluate(&expression("Part", &[matrix(), integer(2), integer(1)])).as_str(), "c");
+            assert_eq!(evaluate(&expression("Part", &[matrix(), integer(-1)])).as_str(), "List[c, d]");
+            assert_eq!(evaluate(&expression("Part", &[matrix(), integer(1), integer(0)])).as_str(), "List");
+        }
+
+        #[test]
+        fn it_doesnt_take_heads_with_negative_indices() {
+            assert_eq!(evaluate(&expression("Part", &[matrix(), integer(-2)])).as_str(), "List[a, b]");
+            assert_eq!(evaluate(&expression("Part", &[matrix(), integer(-3)])).as_str(), "Part[List[List[a, b], List[c, d]], -3]");
+        }
+
+        #[test]
+        fn it_takes_columns_and_selections() {
+            assert_eq!(evaluate(&expression("Part", &[matrix(), symbol("All"), integer(2)])).as_str(), "List[b, d]");
+            assert_eq!(evaluate(&expression("Part", &[matrix(), list(&[integer(2), integer(1)])])).as_str(),
+                       "List[List[c, d], List[a, b]]");
+        }
+
+        #[test]
+        fn it_maps() {
+            assert_eq!(evaluate(&expression("Map", &[symbol("f"), matrix()])).as_str(),
+                       "List[f[List[a, b]], f[List[c, d]]]");
+            assert_eq!(evaluate(&expression("Map", &[symbol("f"), matrix(), list(&[integer(2)])])).as_str(),
+                       "List[List[f[a], f[b]], List[f[c], f[d]]]");
+        }
+
+        #[test]
+        fn it_maps_over_leaves() {
+            let e = expression("Map", &[symbol("f"), list(&[symbol("a"), list(&[symbol("b")])]), list(&[integer(-1)])]);
+            assert_eq!(evaluate(&e).as_str(), "List[f[a], List[f[b]]]");
+        }
+    }
+}